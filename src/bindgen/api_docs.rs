@@ -0,0 +1,420 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A Markdown reference of the C API, with a section per exported item holding its declaration,
+//! its documentation, and links to the types it uses and to the items using it.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::ir::{
+    AnnotationSet, Documentation, Field, GenericArgument, Item, ItemContainer, Type, VariantBody,
+};
+use crate::bindgen::language_backend::{CLikeLanguageBackend, LanguageBackend};
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::Bindings;
+
+/// The section of an item.
+struct Entry<'a> {
+    name: &'a str,
+    documentation: &'a Documentation,
+    annotations: &'a AnnotationSet,
+    /// The declarations of the item, several if it's defined under different cfgs.
+    declarations: Vec<String>,
+    /// The names of the types used by the item.
+    uses: Vec<&'a str>,
+    /// The documented fields or variants of a type, as their comments are left out of the
+    /// declaration.
+    members: Vec<(&'a str, &'a Documentation)>,
+}
+
+/// A group of sections, like the functions.
+struct Group<'a> {
+    title: &'static str,
+    entries: Vec<Entry<'a>>,
+}
+
+impl<'a> Group<'a> {
+    fn new(title: &'static str) -> Self {
+        Group {
+            title,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds the section of an item, or the declaration to the section of the item with the same
+    /// name, and returns the index of the section.
+    fn push(
+        &mut self,
+        name: &'a str,
+        documentation: &'a Documentation,
+        annotations: &'a AnnotationSet,
+        declaration: String,
+        uses: Vec<&'a str>,
+    ) -> usize {
+        if let Some(index) = self.entries.iter().position(|e| e.name == name) {
+            let entry = &mut self.entries[index];
+            entry.declarations.push(declaration);
+            entry.uses.extend(uses);
+            return index;
+        }
+        self.entries.push(Entry {
+            name,
+            documentation,
+            annotations,
+            declarations: vec![declaration],
+            uses,
+            members: Vec::new(),
+        });
+        self.entries.len() - 1
+    }
+
+    fn add_members(
+        &mut self,
+        index: usize,
+        members: impl IntoIterator<Item = (&'a str, &'a Documentation)>,
+    ) {
+        let entry = &mut self.entries[index];
+        for (name, documentation) in members {
+            let is_new = !entry.members.iter().any(|&(n, _)| n == name);
+            if is_new && !documentation.doc_comment.is_empty() {
+                entry.members.push((name, documentation));
+            }
+        }
+    }
+}
+
+/// The names of the types used by `ty`.
+fn type_names<'a>(ty: &'a Type, names: &mut Vec<&'a str>) {
+    match *ty {
+        Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => type_names(ty, names),
+        Type::Path(ref path) => {
+            names.push(path.export_name());
+            for generic in path.generics() {
+                if let GenericArgument::Type(ref ty) = *generic {
+                    type_names(ty, names);
+                }
+            }
+        }
+        Type::Primitive(..) => {}
+        Type::FuncPtr {
+            ref ret, ref args, ..
+        } => {
+            type_names(ret, names);
+            for (_, ty) in args {
+                type_names(ty, names);
+            }
+        }
+    }
+}
+
+fn field_type_names(fields: &[Field]) -> Vec<&str> {
+    let mut names = Vec::new();
+    for field in fields {
+        type_names(&field.ty, &mut names);
+    }
+    names
+}
+
+/// The anchors of the headings, as generated by GitHub and most Markdown renderers: the lowercase
+/// heading without punctuation, with dashes for spaces, and a `-1`, `-2`... suffix for duplicates.
+#[derive(Default)]
+struct Anchors {
+    used: HashSet<String>,
+    duplicates: HashMap<String, usize>,
+}
+
+impl Anchors {
+    fn add(&mut self, heading: &str) -> String {
+        let slug: String = heading
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|&c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+            .map(|c| if c == ' ' { '-' } else { c })
+            .collect();
+        let mut anchor = slug.clone();
+        while self.used.contains(&anchor) {
+            let count = self.duplicates.entry(slug.clone()).or_insert(0);
+            *count += 1;
+            anchor = format!("{slug}-{count}");
+        }
+        self.used.insert(anchor.clone());
+        anchor
+    }
+}
+
+/// Writes the declaration of an item with `write`, without the documentation.
+fn declaration<F>(b: &Bindings, config: &Config, write: F) -> String
+where
+    F: FnOnce(&mut CLikeLanguageBackend, &mut SourceWriter<&mut Vec<u8>>),
+{
+    let mut lb = CLikeLanguageBackend::new(config);
+    let mut buffer = Vec::new();
+    write(&mut lb, &mut SourceWriter::new(&mut buffer, b));
+    String::from_utf8(buffer).unwrap().trim_end().to_owned()
+}
+
+pub(crate) fn write<F: Write>(b: &Bindings, out: &mut SourceWriter<F>) {
+    // The documentation is written as Markdown rather than as comments of the declarations.
+    let mut config = b.config.clone();
+    config.documentation = false;
+    let config = &config;
+
+    let mut constants = Group::new("Constants");
+    for constant in &b.constants {
+        if !constant.value.is_valid(b) {
+            continue;
+        }
+        let mut uses = Vec::new();
+        type_names(&constant.ty, &mut uses);
+        let declaration = declaration(b, config, |lb, out| constant.write(config, lb, out, None));
+        constants.push(
+            constant.export_name(),
+            &constant.documentation,
+            &constant.annotations,
+            declaration,
+            uses,
+        );
+    }
+
+    let mut types = Group::new("Types");
+    for item in &b.items {
+        if !item.deref().annotations().should_export() {
+            continue;
+        }
+        let uses = match *item {
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Struct(ref s) => field_type_names(&s.fields),
+            ItemContainer::Union(ref u) => field_type_names(&u.fields),
+            ItemContainer::Enum(ref e) => {
+                let mut names = Vec::new();
+                for variant in &e.variants {
+                    if let VariantBody::Body { ref body, .. } = variant.body {
+                        names.extend(field_type_names(&body.fields));
+                    }
+                }
+                names
+            }
+            ItemContainer::Typedef(ref t) => {
+                let mut names = Vec::new();
+                type_names(&t.aliased, &mut names);
+                names
+            }
+            ItemContainer::OpaqueItem(..) => Vec::new(),
+        };
+        let declaration = declaration(b, config, |lb, out| match *item {
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Enum(ref x) => lb.write_enum(out, x),
+            ItemContainer::Struct(ref x) => lb.write_struct_or_typedef(out, x, b),
+            ItemContainer::Union(ref x) => lb.write_union(out, x),
+            ItemContainer::OpaqueItem(ref x) => lb.write_opaque_item(out, x),
+            ItemContainer::Typedef(ref x) => lb.write_type_def(out, x),
+        });
+        let container = item;
+        let item = item.deref();
+        let index = types.push(
+            item.export_name(),
+            item.documentation(),
+            item.annotations(),
+            declaration,
+            uses,
+        );
+        match *container {
+            ItemContainer::Struct(ref s) => types.add_members(
+                index,
+                s.fields.iter().map(|f| (f.name.as_str(), &f.documentation)),
+            ),
+            ItemContainer::Union(ref u) => types.add_members(
+                index,
+                u.fields.iter().map(|f| (f.name.as_str(), &f.documentation)),
+            ),
+            ItemContainer::Enum(ref e) => types.add_members(
+                index,
+                e.variants
+                    .iter()
+                    .map(|v| (v.export_name.as_str(), &v.documentation)),
+            ),
+            _ => {}
+        }
+    }
+
+    let mut globals = Group::new("Globals");
+    for global in b.globals.iter().filter(|g| g.annotations.should_export()) {
+        let mut uses = Vec::new();
+        type_names(&global.ty, &mut uses);
+        let declaration = declaration(b, config, |lb, out| lb.write_static(out, global));
+        globals.push(
+            global.export_name(),
+            &global.documentation,
+            &global.annotations,
+            declaration,
+            uses,
+        );
+    }
+
+    let mut functions = Group::new("Functions");
+    for function in b.functions.iter().filter(|f| f.annotations.should_export()) {
+        let mut uses = Vec::new();
+        type_names(&function.ret, &mut uses);
+        for arg in &function.args {
+            type_names(&arg.ty, &mut uses);
+        }
+        let declaration = declaration(b, config, |lb, out| {
+            lb.write_function(config, out, function)
+        });
+        functions.push(
+            function.path().name(),
+            &function.documentation,
+            &function.annotations,
+            declaration,
+            uses,
+        );
+    }
+
+    // Only links to the types which have a section, once, and not to the item itself.
+    let documented_types: HashSet<&str> = types.entries.iter().map(|e| e.name).collect();
+    let mut groups = [constants, types, globals, functions];
+    let mut used_by = HashMap::<&str, Vec<&str>>::new();
+    for entry in groups.iter_mut().flat_map(|g| &mut g.entries) {
+        let mut uses = Vec::new();
+        for &name in &entry.uses {
+            if name != entry.name && !uses.contains(&name) && documented_types.contains(name) {
+                uses.push(name);
+            }
+        }
+        for &name in &uses {
+            used_by.entry(name).or_default().push(entry.name);
+        }
+        entry.uses = uses;
+    }
+
+    // The anchors of all the headings, in order, as the ones of items may be taken by others.
+    let mut anchors = Anchors::default();
+    let mut item_anchors = HashMap::<&str, String>::new();
+    anchors.add("API Reference");
+    for group in groups.iter().filter(|g| !g.entries.is_empty()) {
+        anchors.add(group.title);
+        for entry in &group.entries {
+            let anchor = anchors.add(entry.name);
+            item_anchors.entry(entry.name).or_insert(anchor);
+            for (line, is_heading) in documentation_lines(entry.documentation) {
+                if is_heading {
+                    anchors.add(line.trim_start_matches('#'));
+                }
+            }
+        }
+    }
+
+    let fence = match b.config.language {
+        Language::Cxx => "cpp",
+        _ => "c",
+    };
+
+    out.write("# API Reference");
+    out.new_line();
+    if !b.package_version.is_empty() {
+        out.new_line();
+        write!(out, "Version {}.", b.package_version);
+        out.new_line();
+    }
+    for group in &groups {
+        if group.entries.is_empty() {
+            continue;
+        }
+        out.new_line();
+        write!(out, "## {}", group.title);
+        out.new_line();
+        for entry in &group.entries {
+            out.new_line();
+            write!(out, "### `{}`", entry.name);
+            out.new_line();
+            for declaration in &entry.declarations {
+                out.new_line();
+                write!(out, "```{fence}");
+                out.new_line();
+                for line in declaration.lines() {
+                    write!(out, "{line}");
+                    out.new_line();
+                }
+                out.write("```");
+                out.new_line();
+            }
+            if let Some(ref note) = entry.annotations.deprecated {
+                out.new_line();
+                if note.is_empty() {
+                    out.write("> **Deprecated**");
+                } else {
+                    write!(out, "> **Deprecated**: {note}");
+                }
+                out.new_line();
+            }
+            write_documentation(out, entry.documentation);
+            if !entry.members.is_empty() {
+                out.new_line();
+                for &(name, documentation) in &entry.members {
+                    write!(out, "- `{name}`:");
+                    for line in &documentation.doc_comment {
+                        let line = line.trim();
+                        if !line.is_empty() {
+                            write!(out, " {line}");
+                        }
+                    }
+                    out.new_line();
+                }
+            }
+            write_links(out, "Uses", &entry.uses, &item_anchors);
+            if let Some(used_by) = used_by.get(entry.name) {
+                write_links(out, "Used by", used_by, &item_anchors);
+            }
+        }
+    }
+}
+
+/// The lines of the documentation, with whether each one is a heading rather than in a code block.
+fn documentation_lines(documentation: &Documentation) -> impl Iterator<Item = (&str, bool)> {
+    let mut in_code = false;
+    documentation.doc_comment.iter().map(move |line| {
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        (line, !in_code && line.starts_with('#'))
+    })
+}
+
+/// Writes the Markdown of the documentation, with its headings below the ones of the item.
+fn write_documentation<F: Write>(out: &mut SourceWriter<F>, documentation: &Documentation) {
+    if documentation.doc_comment.is_empty() {
+        return;
+    }
+    out.new_line();
+    for (line, is_heading) in documentation_lines(documentation) {
+        if is_heading {
+            write!(out, "###{line}");
+        } else {
+            write!(out, "{line}");
+        }
+        out.new_line();
+    }
+}
+
+fn write_links<F: Write>(
+    out: &mut SourceWriter<F>,
+    title: &str,
+    names: &[&str],
+    anchors: &HashMap<&str, String>,
+) {
+    if names.is_empty() {
+        return;
+    }
+    out.new_line();
+    let links: Vec<_> = names
+        .iter()
+        .map(|name| format!("[`{name}`](#{})", anchors[name]))
+        .collect();
+    write!(out, "{title}: {}.", links.join(", "));
+    out.new_line();
+}
@@ -4,21 +4,31 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::{BufWriter, Read, Write};
 use std::path;
 use std::rc::Rc;
 
+use crate::bindgen::api_docs;
+use crate::bindgen::compat::{self, CompatChange};
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
     Constant, Enum, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct, Type,
     Typedef,
 };
+use crate::bindgen::json;
 use crate::bindgen::language_backend::{
-    CLikeLanguageBackend, CythonLanguageBackend, LanguageBackend,
+    CLikeLanguageBackend, CSharpLanguageBackend, CffiLanguageBackend, CythonLanguageBackend,
+    JavaLanguageBackend, LanguageBackend, RustLanguageBackend, ZigLanguageBackend,
 };
+use crate::bindgen::layout_tests;
+use crate::bindgen::lint::Lint;
+use crate::bindgen::loader;
+use crate::bindgen::symfile;
+use crate::bindgen::type_layout::{self, TypeLayout};
 use crate::bindgen::writer::SourceWriter;
 
 /// A bindings header that can be written.
@@ -41,6 +51,13 @@ pub struct Bindings {
     /// and shouldn't do anything when written anywhere.
     noop: bool,
     pub package_version: String,
+    /// The layouts of the structs, unions, enums and typedefs on `config.target`, by export name.
+    type_layouts: HashMap<String, TypeLayout>,
+    /// The paths of the items instantiated from generic types, which have no Rust definition of
+    /// their own.
+    monomorph_paths: HashSet<BindgenPath>,
+    /// The exported items which couldn't be bound as they are.
+    lints: Vec<Lint>,
 }
 
 impl Bindings {
@@ -57,8 +74,10 @@ impl Bindings {
         source_files: Vec<path::PathBuf>,
         noop: bool,
         package_version: String,
+        monomorph_paths: HashSet<BindgenPath>,
+        lints: Vec<Lint>,
     ) -> Bindings {
-        Bindings {
+        let mut bindings = Bindings {
             config,
             struct_map,
             enum_map,
@@ -71,7 +90,29 @@ impl Bindings {
             source_files,
             noop,
             package_version,
-        }
+            type_layouts: HashMap::new(),
+            monomorph_paths,
+            lints,
+        };
+        bindings.type_layouts = type_layout::compute(&bindings);
+        bindings
+    }
+
+    /// Whether the item at `path` was instantiated from a generic type.
+    pub(crate) fn is_monomorph(&self, path: &BindgenPath) -> bool {
+        self.monomorph_paths.contains(path)
+    }
+
+    /// Returns the size, alignment and field offsets of the type exported as `name` on the
+    /// configured target, if they can be computed.
+    pub fn type_layout(&self, name: &str) -> Option<&TypeLayout> {
+        self.type_layouts.get(name)
+    }
+
+    /// Returns the exported functions which were skipped or pass an opaque type by value, and the
+    /// types used by the bindings which ended up opaque, sorted by location.
+    pub fn lints(&self) -> &[Lint] {
+        &self.lints
     }
 
     // FIXME(emilio): What to do when the configuration doesn't match?
@@ -114,8 +155,8 @@ impl Bindings {
 
     /// Returns how the variant `variant_name` of the enum at `path` should be
     /// referenced in generated code: `Enum::Variant` for a C++ `enum class`,
-    /// or the bare (possibly prefixed) variant name otherwise. Returns `None`
-    /// if the enum or the variant isn't known.
+    /// `Tag.Variant` for Zig and C#, or the bare (possibly prefixed) variant name
+    /// otherwise. Returns `None` if the enum or the variant isn't known.
     pub fn enum_variant_reference(&self, path: &BindgenPath, variant_name: &str) -> Option<String> {
         let config = &self.config;
         let mut result = None;
@@ -126,17 +167,36 @@ impl Bindings {
             let Some(variant) = e.variants.iter().find(|v| v.name == variant_name) else {
                 return;
             };
-            let qualify =
-                config.language == Language::Cxx && config.enumeration.enum_class(&e.annotations);
-            result = Some(if qualify {
-                format!("{}::{}", e.export_name, variant.export_name)
-            } else {
-                variant.export_name.clone()
+            result = Some(match config.language {
+                Language::Cxx if config.enumeration.enum_class(&e.annotations) => {
+                    format!("{}::{}", e.export_name, variant.export_name)
+                }
+                Language::Zig | Language::CSharp | Language::Java => {
+                    format!("{}.{}", e.tag_name(), variant.export_name)
+                }
+                Language::Rust => format!("{}::{}", e.export_name, variant.export_name),
+                _ => variant.export_name.clone(),
             });
         });
         result
     }
 
+    /// The type of the field `name` of the struct at `path`, if there is one.
+    pub fn struct_field_type(&self, path: &BindgenPath, name: &str) -> Option<Type> {
+        let mut ty = None;
+        self.struct_map
+            .for_items(&self.resolved_struct_path(path), |st| {
+                if ty.is_none() {
+                    ty = st
+                        .fields
+                        .iter()
+                        .find(|f| f.name == name)
+                        .map(|f| f.ty.clone());
+                }
+            });
+        ty
+    }
+
     pub fn struct_field_names(&self, path: &BindgenPath) -> Rc<Vec<String>> {
         let mut memos = self.struct_fileds_memo.borrow_mut();
         if let Some(memo) = memos.get(path) {
@@ -183,16 +243,17 @@ impl Bindings {
         function_names.chain(global_names)
     }
 
+    /// Writes the dynamic symbols in the `symfile.format`: a list for `--dynamic-list`, a
+    /// version script, or a module-definition file.
+    pub fn write_symfile<F: Write>(&self, mut file: F) {
+        symfile::write(self, &mut file).expect("writing symbol file failed");
+    }
+
     pub fn generate_symfile<P: AsRef<path::Path>>(&self, symfile_path: P) {
         if let Some(dir) = symfile_path.as_ref().parent() {
             std::fs::create_dir_all(dir).unwrap();
         }
-        let mut writer = BufWriter::new(File::create(symfile_path).unwrap());
-        writeln!(&mut writer, "{{").expect("writing symbol file header failed");
-        for symbol in self.dynamic_symbols_names() {
-            writeln!(&mut writer, "{symbol};").expect("writing symbol failed");
-        }
-        write!(&mut writer, "}};").expect("writing symbol file footer failed");
+        self.write_symfile(BufWriter::new(File::create(symfile_path).unwrap()));
     }
 
     pub fn generate_depfile<P: AsRef<path::Path>>(&self, header_path: P, depfile_path: P) {
@@ -252,6 +313,30 @@ impl Bindings {
 
         let mut new_file_contents = Vec::new();
         self.write(&mut new_file_contents);
+        Self::update_file(path, &new_file_contents)
+    }
+
+    /// Like `write_to_file`, but writes the bindings as JSON like `write_json` does.
+    pub fn write_json_to_file<P: AsRef<path::Path>>(&self, path: P) -> Result<bool, Error> {
+        if self.noop {
+            return Ok(false);
+        }
+
+        let mut new_file_contents = Vec::new();
+        self.write_json(&mut new_file_contents)?;
+        Ok(Self::update_file(path, &new_file_contents))
+    }
+
+    /// Replaces the contents of the file at `path` with `new_file_contents`, and returns whether
+    /// they changed.
+    fn update_file<P: AsRef<path::Path>>(path: P, new_file_contents: &[u8]) -> bool {
+        if !path.as_ref().is_file() {
+            if let Some(parent) = path.as_ref().parent() {
+                fs::create_dir_all(parent).unwrap();
+            }
+            fs::write(path, new_file_contents).unwrap();
+            return true;
+        }
 
         let mut old_file_contents = Vec::new();
         {
@@ -261,7 +346,7 @@ impl Bindings {
 
         if old_file_contents != new_file_contents {
             let mut new_file = File::create(&path).unwrap();
-            new_file.write_all(&new_file_contents).unwrap();
+            new_file.write_all(new_file_contents).unwrap();
             true
         } else {
             false
@@ -276,7 +361,186 @@ impl Bindings {
             Language::Cython => {
                 self.write_with_backend(file, &mut CythonLanguageBackend::new(&self.config))
             }
+            Language::Zig => {
+                self.write_with_backend(file, &mut ZigLanguageBackend::new(&self.config))
+            }
+            Language::CSharp => {
+                self.write_with_backend(file, &mut CSharpLanguageBackend::new(&self.config))
+            }
+            Language::Cffi => {
+                self.write_with_backend(file, &mut CffiLanguageBackend::new(&self.config))
+            }
+            Language::Java => {
+                self.write_with_backend(file, &mut JavaLanguageBackend::new(&self.config))
+            }
+            Language::Rust => {
+                self.write_with_backend(file, &mut RustLanguageBackend::new(&self.config))
+            }
+        }
+    }
+
+    /// Writes the bindings as JSON, in the schema described in `docs.md`, whatever
+    /// the configured language is.
+    pub fn write_json<F: Write>(&self, mut file: F) -> Result<(), Error> {
+        if self.noop {
+            return Ok(());
+        }
+
+        serde_json::to_writer_pretty(&mut file, &json::bindings(self)).map_err(Error::Json)?;
+        file.write_all(b"\n")
+            .map_err(|e| Error::Json(serde_json::Error::io(e)))
+    }
+
+    /// Writes a Markdown reference of the C or C++ API, with a section per exported item holding
+    /// its declaration and documentation. Does nothing for other languages.
+    pub fn write_api_docs<F: Write>(&self, file: F) {
+        if self.noop || !matches!(self.config.language, Language::C | Language::Cxx) {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        api_docs::write(self, &mut out);
+    }
+
+    pub fn generate_api_docs<P: AsRef<path::Path>>(&self, path: P) {
+        if !matches!(self.config.language, Language::C | Language::Cxx) {
+            warn!("API references can only be generated for C and C++.");
+            return;
+        }
+        if let Some(dir) = path.as_ref().parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        self.write_api_docs(BufWriter::new(File::create(path).unwrap()));
+    }
+
+    /// Lists the changes from the `old` bindings which break their API or ABI: removed or renamed
+    /// items, changed signatures and values, and changed layouts.
+    // Only the library calls it, the binary compares against snapshots.
+    #[allow(dead_code)]
+    pub fn compat_changes(&self, old: &Bindings) -> Result<Vec<CompatChange>, Error> {
+        compat::changes(&json::bindings(old), &json::bindings(self)).map_err(Error::Compat)
+    }
+
+    /// Like `compat_changes`, but with the old bindings written as JSON to `snapshot`, like
+    /// `write_json` does.
+    pub fn compat_changes_from_snapshot<P: AsRef<path::Path>>(
+        &self,
+        snapshot: P,
+    ) -> Result<Vec<CompatChange>, Error> {
+        let snapshot = snapshot.as_ref();
+        let text = fs::read_to_string(snapshot).map_err(|e| {
+            Error::Compat(format!(
+                "couldn't open snapshot {}: {e}.",
+                snapshot.display()
+            ))
+        })?;
+        let old = serde_json::from_str(&text).map_err(|e| {
+            Error::Compat(format!(
+                "couldn't parse snapshot {}: {e}.",
+                snapshot.display()
+            ))
+        })?;
+        compat::changes(&old, &json::bindings(self)).map_err(Error::Compat)
+    }
+
+    /// Writes a Rust test module checking the size, alignment and field offsets of the Rust
+    /// types against the layout computed for the configured target.
+    pub fn write_rust_layout_tests<F: Write>(&self, file: F) {
+        if self.noop {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        layout_tests::write_rust(self, &mut out);
+    }
+
+    /// Writes a C or C++ translation unit including `header`, which checks the size, alignment
+    /// and field offsets of its types against the layout computed for the configured target.
+    /// Does nothing for other languages.
+    pub fn write_c_layout_tests<F: Write>(&self, file: F, header: &str) {
+        if self.noop || !matches!(self.config.language, Language::C | Language::Cxx) {
+            return;
         }
+
+        let mut out = SourceWriter::new(file, self);
+        CLikeLanguageBackend::new(&self.config).write_layout_tests(&mut out, self, header);
+    }
+
+    /// Writes the Rust layout tests at `tests_path` with an `rs` extension, and the C or C++
+    /// ones checking the header at `header_path` with a `c` or `cpp` extension.
+    pub fn generate_layout_tests<P: AsRef<path::Path>>(&self, header_path: P, tests_path: P) {
+        let tests_path = tests_path.as_ref();
+        if let Some(dir) = tests_path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        let rust_path = tests_path.with_extension("rs");
+        self.write_rust_layout_tests(BufWriter::new(File::create(rust_path).unwrap()));
+
+        let extension = match self.config.language {
+            Language::C => "c",
+            Language::Cxx => "cpp",
+            _ => return,
+        };
+        let header = header_path.as_ref().file_name().unwrap().to_string_lossy();
+        let c_path = tests_path.with_extension(extension);
+        self.write_c_layout_tests(BufWriter::new(File::create(c_path).unwrap()), &header);
+    }
+
+    /// Writes a C header including `header`, which declares `{name}_functions`, a table of
+    /// pointers to the exported functions and globals, and `{name}_load` to fill it from a
+    /// library opened with `dlopen`. Does nothing for other languages than C.
+    pub fn write_loader_header<F: Write>(&self, file: F, name: &str, header: &str) {
+        if self.noop || self.config.language != Language::C {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        loader::write_header(self, &mut out, name, header);
+    }
+
+    /// Writes the C source defining the functions declared by `write_loader_header` in
+    /// `loader_header`. Does nothing for other languages than C.
+    pub fn write_loader_source<F: Write>(&self, file: F, name: &str, loader_header: &str) {
+        if self.noop || self.config.language != Language::C {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        loader::write_source(self, &mut out, name, loader_header);
+    }
+
+    /// Writes the loader of the library whose bindings are at `header_path` at `loader_path`,
+    /// with an `h` and a `c` extension. Its functions are prefixed with the file name of
+    /// `loader_path`.
+    pub fn generate_loader<P: AsRef<path::Path>>(&self, header_path: P, loader_path: P) {
+        if self.config.language != Language::C {
+            warn!("Dynamic loaders can only be generated for C.");
+            return;
+        }
+        let loader_path = loader_path.as_ref();
+        if let Some(dir) = loader_path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        let name: String = loader_path
+            .file_stem()
+            .unwrap()
+            .to_string_lossy()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let header = header_path.as_ref().file_name().unwrap().to_string_lossy();
+        let loader_header = loader_path.with_extension("h");
+        self.write_loader_header(
+            BufWriter::new(File::create(&loader_header).unwrap()),
+            &name,
+            &header,
+        );
+        let loader_header = loader_header.file_name().unwrap().to_string_lossy();
+        self.write_loader_source(
+            BufWriter::new(File::create(loader_path.with_extension("c")).unwrap()),
+            &name,
+            &loader_header,
+        );
     }
 
     fn write_with_backend<F: Write, LB: LanguageBackend>(
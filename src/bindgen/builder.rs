@@ -368,6 +368,8 @@ impl Builder {
                 Default::default(),
                 true,
                 String::new(),
+                Default::default(),
+                Default::default(),
             ));
         }
 
@@ -413,6 +415,9 @@ impl Builder {
             result.functions,
             result.source_files,
             result.package_version,
+            result.lints,
+            result.name_conflicts,
+            result.reexports,
         )
         .generate()
     }
@@ -39,6 +39,8 @@ struct CDecl {
     type_qualifers: String,
     type_name: String,
     type_generic_args: Vec<GenericArgument>,
+    // Whether the generic arguments are const, for shared slices.
+    type_generic_args_const: bool,
     declarators: Vec<CDeclarator>,
     type_ctype: Option<DeclarationType>,
     deprecated: Option<String>,
@@ -50,6 +52,7 @@ impl CDecl {
             type_qualifers: String::new(),
             type_name: String::new(),
             type_generic_args: Vec::new(),
+            type_generic_args_const: false,
             declarators: Vec::new(),
             type_ctype: None,
             deprecated: None,
@@ -127,6 +130,7 @@ impl CDecl {
                     "error generating cdecl for {t:?}"
                 );
                 generic.generics().clone_into(&mut self.type_generic_args);
+                self.type_generic_args_const = generic.is_slice() && !generic.is_mut_slice();
                 self.type_ctype = generic.ctype().cloned();
             }
             Type::Primitive(ref p) => {
@@ -214,6 +218,11 @@ impl CDecl {
                 &self.type_generic_args,
                 ListType::Join(", "),
                 |language_backend, out, g| match *g {
+                    GenericArgument::Type(ref ty) if self.type_generic_args_const => {
+                        let mut cdecl = CDecl::new();
+                        cdecl.build_type(ty, true, config);
+                        cdecl.write(language_backend, out, None, config);
+                    }
                     GenericArgument::Type(ref ty) => language_backend.write_type(out, ty),
                     GenericArgument::Const(ref expr) => write!(out, "{}", expr.as_str()),
                 },
@@ -242,7 +251,7 @@ impl CDecl {
                     if is_const {
                         out.write("const ");
                     }
-                    if config.language != Language::Cython {
+                    if !matches!(config.language, Language::Cython | Language::Cffi) {
                         if !is_nullable && !is_ref {
                             if let Some(attr) = &config.pointer.non_null_attribute {
                                 write!(out, "{attr} ");
@@ -300,7 +309,7 @@ impl CDecl {
                     }
 
                     out.write("(");
-                    if args.is_empty() && config.language == Language::C {
+                    if args.is_empty() && matches!(config.language, Language::C | Language::Cffi) {
                         out.write("void");
                     }
 
@@ -358,7 +367,8 @@ impl CDecl {
                     }
                     out.write(")");
 
-                    if never_return && config.language != Language::Cython {
+                    if never_return && !matches!(config.language, Language::Cython | Language::Cffi)
+                    {
                         if let Some(ref no_return_attr) = config.function.no_return {
                             out.write_fmt(format_args!(" {no_return_attr}"));
                         }
@@ -381,6 +391,25 @@ pub fn write_func<F: Write, LB: LanguageBackend>(
     CDecl::from_func(f, layout, config).write(language_backend, out, Some(f.path().name()), config);
 }
 
+/// Writes a pointer named `ident` to a function with the signature of `f`.
+pub fn write_func_ptr<F: Write, LB: LanguageBackend>(
+    language_backend: &mut LB,
+    out: &mut SourceWriter<F>,
+    f: &Function,
+    ident: &str,
+    layout: Layout,
+    config: &Config,
+) {
+    let mut cdecl = CDecl::new();
+    cdecl.declarators.push(CDeclarator::Ptr {
+        is_const: false,
+        is_nullable: true,
+        is_ref: false,
+    });
+    cdecl.build_func(f, layout, config);
+    cdecl.write(language_backend, out, Some(ident), config);
+}
+
 pub fn write_field<F: Write, LB: LanguageBackend>(
     language_backend: &mut LB,
     out: &mut SourceWriter<F>,
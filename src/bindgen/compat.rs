@@ -0,0 +1,488 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Compares the JSON dumps of two versions of the bindings, to find the changes breaking the C
+//! API or ABI of the newer one.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::bindgen::json::JSON_SCHEMA_VERSION;
+
+/// A change breaking the API or ABI of the bindings, found by `Bindings::compat_changes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatChange {
+    /// The exported name of the changed item, followed by the changed field or variant, if any,
+    /// like `Foo.bar`.
+    pub item: String,
+    /// What changed, like "removed" or "size changed from 8 to 16 bytes".
+    pub description: String,
+}
+
+impl fmt::Display for CompatChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}`: {}", self.item, self.description)
+    }
+}
+
+/// Lists the breaking changes from the `old` dump to the `new` one, in the order of the old one.
+pub(crate) fn changes(old: &Value, new: &Value) -> Result<Vec<CompatChange>, String> {
+    for dump in [old, new] {
+        let version = dump["schema_version"].as_u64();
+        if version != Some(JSON_SCHEMA_VERSION.into()) {
+            return Err(format!(
+                "expected a JSON dump with a schema_version of {JSON_SCHEMA_VERSION}, found {}.",
+                dump["schema_version"]
+            ));
+        }
+    }
+
+    let mut checker = Checker::default();
+    checker.constants(&constants(old), &constants(new));
+    checker.globals(list(&old["globals"]), list(&new["globals"]));
+    checker.items(list(&old["items"]), list(&new["items"]));
+    checker.functions(list(&old["functions"]), list(&new["functions"]));
+    Ok(checker.changes)
+}
+
+fn list(value: &Value) -> &[Value] {
+    value.as_array().map_or(&[], |list| list)
+}
+
+fn name(value: &Value) -> &str {
+    value["name"].as_str().unwrap_or_default()
+}
+
+/// The constants, including the ones associated to structs.
+fn constants(dump: &Value) -> Vec<&Value> {
+    let associated = list(&dump["items"])
+        .iter()
+        .flat_map(|item| list(&item["associated_constants"]));
+    list(&dump["constants"]).iter().chain(associated).collect()
+}
+
+/// Finds the counterpart of `old` in `new`: the item with the same name and cfg, or else the only
+/// one with the same name.
+fn find<'a>(new: &HashMap<&str, Vec<&'a Value>>, old: &Value) -> Option<&'a Value> {
+    let candidates = new.get(name(old))?;
+    match candidates.iter().find(|new| new["cfg"] == old["cfg"]) {
+        Some(new) => Some(new),
+        None if candidates.len() == 1 => Some(candidates[0]),
+        None => None,
+    }
+}
+
+fn by_name<'a>(values: impl IntoIterator<Item = &'a Value>) -> HashMap<&'a str, Vec<&'a Value>> {
+    let mut map = HashMap::<_, Vec<_>>::new();
+    for value in values {
+        map.entry(name(value)).or_default().push(value);
+    }
+    map
+}
+
+/// The type without what doesn't change its meaning in C: Rust names, whether pointers are
+/// references or nullable, and the names of the arguments of function pointers.
+fn c_type(ty: &Value) -> Value {
+    let Some(map) = ty.as_object() else {
+        return ty.clone();
+    };
+    let is_fn_ptr = ty["kind"] == "function_pointer";
+    map.iter()
+        .filter(|(key, _)| {
+            !matches!(
+                key.as_str(),
+                "rust_name" | "is_ref" | "is_nullable" | "nonzero" | "never_return"
+            )
+        })
+        .map(|(key, value)| {
+            let value = match key.as_str() {
+                "args" if is_fn_ptr => list(value).iter().map(|arg| c_type(&arg["type"])).collect(),
+                "generics" => list(value).iter().map(c_type).collect(),
+                "pointee" | "element" | "return" => c_type(value),
+                _ => value.clone(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+/// Writes a type with the Rust syntax, as the JSON dump has the Rust names of primitives.
+fn type_name(ty: &Value) -> String {
+    match ty["kind"].as_str() {
+        Some("primitive") => name(ty).to_owned(),
+        Some("pointer") => {
+            let mutability = if ty["is_const"] == true {
+                "const"
+            } else {
+                "mut"
+            };
+            format!("*{mutability} {}", type_name(&ty["pointee"]))
+        }
+        Some("path") => {
+            let generics = list(&ty["generics"]);
+            if generics.is_empty() {
+                name(ty).to_owned()
+            } else {
+                let generics: Vec<_> = generics.iter().map(type_name).collect();
+                format!("{}<{}>", name(ty), generics.join(", "))
+            }
+        }
+        Some("array") => format!(
+            "[{}; {}]",
+            type_name(&ty["element"]),
+            ty["length"]["value"]
+                .as_str()
+                .or_else(|| ty["length"]["name"].as_str())
+                .unwrap_or("_")
+        ),
+        Some("function_pointer") => {
+            let args: Vec<_> = list(&ty["args"])
+                .iter()
+                .map(|arg| type_name(&arg["type"]))
+                .collect();
+            format!(
+                "extern fn({}) -> {}",
+                args.join(", "),
+                type_name(&ty["return"])
+            )
+        }
+        // Only `value` is set for constant expressions.
+        _ => ty["value"].as_str().unwrap_or("_").to_owned(),
+    }
+}
+
+/// Parses the value of an integer literal, like `-1`, `0x10` or `1ull`.
+fn integer(literal: &str) -> Option<i128> {
+    let literal = literal.trim_start_matches('(').trim_end_matches(')');
+    let (negative, literal) = match literal.strip_prefix('-') {
+        Some(literal) => (true, literal),
+        None => (false, literal),
+    };
+    let literal = literal.trim_end_matches(['u', 'U', 'l', 'L']);
+    let value = if let Some(hex) = literal.strip_prefix("0x") {
+        i128::from_str_radix(hex, 16).ok()?
+    } else if let Some(binary) = literal.strip_prefix("0b") {
+        i128::from_str_radix(binary, 2).ok()?
+    } else {
+        literal.parse().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// The discriminant of a variant: the value of the last explicit discriminant, or the discriminant
+/// itself if it isn't an integer literal, plus the number of variants since.
+#[derive(Clone, Copy, PartialEq)]
+enum Discriminant<'a> {
+    Value(i128),
+    Offset(&'a Value, i128),
+}
+
+impl fmt::Display for Discriminant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Discriminant::Value(value) => write!(f, "{value}"),
+            Discriminant::Offset(base, 0) => write!(f, "`{}`", literal(base)),
+            Discriminant::Offset(base, offset) => write!(f, "`{} + {offset}`", literal(base)),
+        }
+    }
+}
+
+fn discriminants(variants: &[Value]) -> Vec<Discriminant<'_>> {
+    let mut previous = Discriminant::Value(-1);
+    variants
+        .iter()
+        .map(|variant| {
+            let discriminant = &variant["discriminant"];
+            let value = if !discriminant.is_null() {
+                let value = match discriminant["kind"].as_str() {
+                    Some("expr") => discriminant["value"].as_str().and_then(integer),
+                    _ => None,
+                };
+                match value {
+                    Some(value) => Discriminant::Value(value),
+                    None => Discriminant::Offset(discriminant, 0),
+                }
+            } else {
+                match previous {
+                    Discriminant::Value(value) => Discriminant::Value(value.saturating_add(1)),
+                    Discriminant::Offset(base, offset) => Discriminant::Offset(base, offset + 1),
+                }
+            };
+            previous = value;
+            value
+        })
+        .collect()
+}
+
+/// Writes a constant expression with the Rust syntax.
+fn literal(value: &Value) -> String {
+    match value["kind"].as_str() {
+        Some("expr") => value["value"].as_str().unwrap_or("_").to_owned(),
+        Some("path") => match value["associated_to"].as_str() {
+            Some(associated_to) => format!("{associated_to}::{}", name(value)),
+            None => name(value).to_owned(),
+        },
+        Some("unary_op") => format!(
+            "{}{}",
+            value["op"].as_str().unwrap_or_default(),
+            literal(&value["value"])
+        ),
+        Some("binary_op") => format!(
+            "({} {} {})",
+            literal(&value["left"]),
+            value["op"].as_str().unwrap_or_default(),
+            literal(&value["right"])
+        ),
+        Some("field_access") => format!(
+            "{}.{}",
+            literal(&value["base"]),
+            value["field"].as_str().unwrap_or_default()
+        ),
+        Some("cast") => format!(
+            "{} as {}",
+            literal(&value["value"]),
+            type_name(&value["type"])
+        ),
+        _ => "_".to_owned(),
+    }
+}
+
+fn kind_name(item: &Value) -> &str {
+    match item["kind"].as_str() {
+        Some("opaque") => "an opaque item",
+        Some("enum") => "an enum",
+        Some("struct") => "a struct",
+        Some("union") => "a union",
+        Some("typedef") => "a typedef",
+        _ => "an item",
+    }
+}
+
+#[derive(Default)]
+struct Checker {
+    changes: Vec<CompatChange>,
+}
+
+impl Checker {
+    fn push(&mut self, item: &str, description: String) {
+        self.changes.push(CompatChange {
+            item: item.to_owned(),
+            description,
+        });
+    }
+
+    fn check_type(&mut self, item: &str, what: &str, old: &Value, new: &Value) {
+        if c_type(old) != c_type(new) {
+            self.push(
+                item,
+                format!(
+                    "{what} changed from `{}` to `{}`",
+                    type_name(old),
+                    type_name(new)
+                ),
+            );
+        }
+    }
+
+    /// Reports the removed constants, or the renamed ones when a new constant has the same value.
+    fn constants(&mut self, old: &[&Value], new: &[&Value]) {
+        let old_names = by_name(old.iter().copied());
+        let new_names = by_name(new.iter().copied());
+        for old in old {
+            let name = name(old);
+            let Some(new) = find(&new_names, old) else {
+                let renamed = new.iter().find(|new| {
+                    !old_names.contains_key(self::name(new))
+                        && new["value"] == old["value"]
+                        && c_type(&new["type"]) == c_type(&old["type"])
+                });
+                match renamed {
+                    Some(new) => self.push(name, format!("renamed to `{}`", self::name(new))),
+                    None => self.push(name, "removed".to_owned()),
+                }
+                continue;
+            };
+            self.check_type(name, "type", &old["type"], &new["type"]);
+            if old["value"] != new["value"] {
+                let description =
+                    match (old["value"]["kind"].as_str(), new["value"]["kind"].as_str()) {
+                        (Some("expr"), Some("expr")) => format!(
+                            "value changed from `{}` to `{}`",
+                            old["value"]["value"].as_str().unwrap_or_default(),
+                            new["value"]["value"].as_str().unwrap_or_default()
+                        ),
+                        _ => "value changed".to_owned(),
+                    };
+                self.push(name, description);
+            }
+        }
+    }
+
+    fn globals(&mut self, old: &[Value], new: &[Value]) {
+        let new = by_name(new);
+        for old in old {
+            let name = name(old);
+            let Some(new) = find(&new, old) else {
+                self.push(name, "removed".to_owned());
+                continue;
+            };
+            self.check_type(name, "type", &old["type"], &new["type"]);
+            if old["mutable"] == true && new["mutable"] == false {
+                self.push(name, "no longer mutable".to_owned());
+            }
+        }
+    }
+
+    fn functions(&mut self, old: &[Value], new: &[Value]) {
+        let new = by_name(new);
+        for old in old {
+            let name = name(old);
+            let Some(new) = find(&new, old) else {
+                self.push(name, "removed".to_owned());
+                continue;
+            };
+            let (old_args, new_args) = (list(&old["args"]), list(&new["args"]));
+            if old_args.len() != new_args.len() {
+                self.push(
+                    name,
+                    format!(
+                        "number of arguments changed from {} to {}",
+                        old_args.len(),
+                        new_args.len()
+                    ),
+                );
+            } else {
+                for (old_arg, new_arg) in old_args.iter().zip(new_args) {
+                    let what = format!("type of argument `{}`", self::name(new_arg));
+                    self.check_type(name, &what, &old_arg["type"], &new_arg["type"]);
+                }
+            }
+            self.check_type(name, "return type", &old["return"], &new["return"]);
+        }
+    }
+
+    fn items(&mut self, old: &[Value], new: &[Value]) {
+        let new = by_name(new);
+        for old in old {
+            let name = name(old);
+            let Some(new) = find(&new, old) else {
+                self.push(name, "removed".to_owned());
+                continue;
+            };
+            if old["kind"] != new["kind"] {
+                self.push(
+                    name,
+                    format!("changed from {} to {}", kind_name(old), kind_name(new)),
+                );
+                continue;
+            }
+            self.layout(name, &old["layout"], &new["layout"]);
+            match old["kind"].as_str() {
+                Some("struct") | Some("union") => {
+                    self.fields(name, list(&old["fields"]), list(&new["fields"]));
+                    self.offsets(name, &old["layout"], &new["layout"]);
+                }
+                Some("enum") => self.variants(name, list(&old["variants"]), list(&new["variants"])),
+                Some("typedef") => {
+                    self.check_type(name, "aliased type", &old["aliased"], &new["aliased"])
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn layout(&mut self, name: &str, old: &Value, new: &Value) {
+        if old.is_null() || new.is_null() {
+            return;
+        }
+        for (key, what) in [("size", "size"), ("align", "alignment")] {
+            if old[key] != new[key] {
+                self.push(
+                    name,
+                    format!("{what} changed from {} to {} bytes", old[key], new[key]),
+                );
+            }
+        }
+    }
+
+    /// Reports the removed fields, the ones whose type changed, and fields put in another order.
+    fn fields(&mut self, name: &str, old: &[Value], new: &[Value]) {
+        let mut kept = Vec::new();
+        for old_field in old {
+            let field = format!("{name}.{}", self::name(old_field));
+            match new
+                .iter()
+                .position(|new| self::name(new) == self::name(old_field))
+            {
+                Some(position) => {
+                    self.check_type(&field, "type", &old_field["type"], &new[position]["type"]);
+                    kept.push(position);
+                }
+                None => self.push(&field, "removed".to_owned()),
+            }
+        }
+        if kept.windows(2).any(|pair| pair[0] > pair[1]) {
+            self.push(name, "fields reordered".to_owned());
+        }
+    }
+
+    fn offsets(&mut self, name: &str, old: &Value, new: &Value) {
+        let new_fields = list(&new["fields"]);
+        for old_field in list(&old["fields"]) {
+            let Some(new_field) = new_fields
+                .iter()
+                .find(|new| self::name(new) == self::name(old_field))
+            else {
+                continue;
+            };
+            if old_field["offset"] != new_field["offset"] {
+                self.push(
+                    &format!("{name}.{}", self::name(old_field)),
+                    format!(
+                        "offset changed from {} to {}",
+                        old_field["offset"], new_field["offset"]
+                    ),
+                );
+            }
+        }
+    }
+
+    fn variants(&mut self, name: &str, old: &[Value], new: &[Value]) {
+        let old_values = discriminants(old);
+        let new_values = discriminants(new);
+        for (old_variant, old_value) in old.iter().zip(old_values) {
+            let variant = format!("{name}.{}", self::name(old_variant));
+            let Some(position) = new
+                .iter()
+                .position(|new| self::name(new) == self::name(old_variant))
+            else {
+                self.push(&variant, "removed".to_owned());
+                continue;
+            };
+            let new_value = new_values[position];
+            if old_value != new_value {
+                // Offsets can only be compared from the same expression.
+                let description = match (old_value, new_value) {
+                    (Discriminant::Value(_), Discriminant::Value(_)) => "changed",
+                    (Discriminant::Offset(old, _), Discriminant::Offset(new, _)) if old == new => {
+                        "changed"
+                    }
+                    _ => "may have changed",
+                };
+                self.push(
+                    &variant,
+                    format!("discriminant {description} from {old_value} to {new_value}"),
+                );
+            }
+            let (old_body, new_body) = (&old_variant["body"], &new[position]["body"]);
+            self.fields(
+                &variant,
+                list(&old_body["fields"]),
+                list(&new_body["fields"]),
+            );
+        }
+    }
+}
@@ -23,6 +23,11 @@ pub enum Language {
     Cxx,
     C,
     Cython,
+    Zig,
+    CSharp,
+    Cffi,
+    Java,
+    Rust,
 }
 
 impl FromStr for Language {
@@ -42,6 +47,18 @@ impl FromStr for Language {
             "C" => Ok(Language::C),
             "cython" => Ok(Language::Cython),
             "Cython" => Ok(Language::Cython),
+            "zig" => Ok(Language::Zig),
+            "Zig" => Ok(Language::Zig),
+            "csharp" => Ok(Language::CSharp),
+            "CSharp" => Ok(Language::CSharp),
+            "c#" => Ok(Language::CSharp),
+            "C#" => Ok(Language::CSharp),
+            "cffi" => Ok(Language::Cffi),
+            "Cffi" => Ok(Language::Cffi),
+            "java" => Ok(Language::Java),
+            "Java" => Ok(Language::Java),
+            "rust" => Ok(Language::Rust),
+            "Rust" => Ok(Language::Rust),
             _ => Err(format!("Unrecognized Language: '{s}'.")),
         }
     }
@@ -52,8 +69,16 @@ deserialize_enum_str!(Language);
 impl Language {
     pub(crate) fn typedef(self) -> &'static str {
         match self {
-            Language::Cxx | Language::C => "typedef",
+            Language::Cxx | Language::C | Language::Cffi => "typedef",
             Language::Cython => "ctypedef",
+            // Zig aliases are plain `pub const` declarations, written by the Zig backend itself.
+            Language::Zig => unreachable!("Zig has no typedef keyword"),
+            // C# has no aliases usable across files, the C# backend resolves typedefs itself.
+            Language::CSharp => unreachable!("C# has no typedef keyword"),
+            // Java has no aliases, the Java backend resolves typedefs itself.
+            Language::Java => unreachable!("Java has no typedef keyword"),
+            // Rust aliases are `pub type` items, written by the Rust backend itself.
+            Language::Rust => unreachable!("Rust has no typedef keyword"),
         }
     }
 }
@@ -316,6 +341,64 @@ impl FromStr for SortKey {
 
 deserialize_enum_str!(SortKey);
 
+/// How to export the items defined under the same name in several modules, when several of
+/// them are used by the bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameConflicts {
+    /// Prefix the names of the items which aren't at the root of the binding crate with their
+    /// module path.
+    #[default]
+    Prefix,
+    /// Fail, unless the items are renamed in `export.rename` by their module path.
+    Rename,
+    /// Fail.
+    Error,
+}
+
+impl FromStr for NameConflicts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prefix" => Ok(NameConflicts::Prefix),
+            "rename" => Ok(NameConflicts::Rename),
+            "error" => Ok(NameConflicts::Error),
+            _ => Err(format!("Unrecognized name conflict strategy: '{s}'.")),
+        }
+    }
+}
+
+deserialize_enum_str!(NameConflicts);
+
+/// How to export the types re-exported under another name by `pub use ... as` in the binding
+/// crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reexports {
+    /// Export the types under the name they're re-exported as.
+    #[default]
+    Rename,
+    /// Export the types under their name, along with a typedef for the name they're re-exported
+    /// as.
+    Typedef,
+    /// Export the types as if they weren't re-exported.
+    Ignore,
+}
+
+impl FromStr for Reexports {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rename" => Ok(Reexports::Rename),
+            "typedef" => Ok(Reexports::Typedef),
+            "ignore" => Ok(Reexports::Ignore),
+            _ => Err(format!("Unrecognized re-export strategy: '{s}'.")),
+        }
+    }
+}
+
+deserialize_enum_str!(Reexports);
+
 /// Settings to apply when exporting items.
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -341,6 +424,12 @@ pub struct ExportConfig {
     pub renaming_overrides_prefixing: bool,
     /// Mangling configuration.
     pub mangle: MangleConfig,
+    /// Table of the storage to give to opaque items, so they can be allocated from C.
+    pub opaque_storage: HashMap<String, OpaqueStorage>,
+    /// How to export the items defined under the same name in several modules.
+    pub name_conflicts: NameConflicts,
+    /// How to export the types re-exported under another name.
+    pub reexports: Reexports,
 }
 
 /// Mangling-specific configuration.
@@ -355,6 +444,45 @@ pub struct MangleConfig {
     pub remove_underscores: bool,
 }
 
+/// The size and alignment, in bytes, of the storage to emit for an opaque item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpaqueStorage {
+    pub size: u64,
+    pub align: u64,
+}
+
+impl OpaqueStorage {
+    /// Checks that C will lay the storage out with this exact size and alignment.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if !self.align.is_power_of_two() {
+            return Err(format!("alignment {} isn't a power of two", self.align));
+        }
+        if self.size == 0 || self.size % self.align != 0 {
+            return Err(format!(
+                "size {} isn't a non-zero multiple of the alignment {}",
+                self.size, self.align
+            ));
+        }
+        Ok(())
+    }
+
+    /// The size of the unsigned integers to split the storage of `name` in for languages that
+    /// can't align an array of bytes, which is its alignment up to 8, and their number. Warns if
+    /// the storage is more aligned than `language` can express.
+    pub(crate) fn words(&self, name: &str, language: &str) -> (u64, u64) {
+        if self.align > 8 {
+            warn!(
+                "The storage of {name} is aligned to {} bytes, but {language} can only align it \
+                 to 8 bytes.",
+                self.align
+            );
+        }
+        let word = self.align.min(8);
+        (word, self.size / word)
+    }
+}
+
 impl ExportConfig {
     pub(crate) fn should_generate(&self, item_type: ItemType) -> bool {
         self.item_types.is_empty() || self.item_types.contains(&item_type)
@@ -368,6 +496,10 @@ impl ExportConfig {
         self.body.get(path.name()).map(|s| s.trim_matches('\n'))
     }
 
+    pub(crate) fn opaque_storage(&self, path: &Path) -> Option<OpaqueStorage> {
+        self.opaque_storage.get(path.name()).copied()
+    }
+
     pub(crate) fn rename(&self, item_name: &mut String) {
         if let Some(name) = self.rename.get(item_name) {
             item_name.clone_from(name);
@@ -392,6 +524,22 @@ pub struct LayoutConfig {
     /// The way to annotate C types as #[repr(align(...))]. This is assumed to be a functional
     /// macro which takes a single argument (the alignment).
     pub aligned_n: Option<String>,
+    /// Whether to generate structs with fields `_0`, `_1`, ... for tuple types. Rust doesn't
+    /// specify the layout of tuples, so this acknowledges that they're laid out like the
+    /// equivalent #[repr(C)] struct.
+    pub tuples: bool,
+    /// Whether to generate structs with a `ptr` and a `len` for slice and `&str` types. Rust
+    /// doesn't specify the layout of fat pointers, so this acknowledges that they're laid out as
+    /// such a struct.
+    pub slices: bool,
+    /// The C++ template to use for slices instead of the generated structs, such as `std::span`.
+    pub cpp_slice: Option<String>,
+    /// Whether to pass `&CStr` as a `const char *`. `&CStr` is a fat pointer for now, so this
+    /// acknowledges that the Rust side only ever uses thin pointers to C strings.
+    pub c_str: bool,
+    /// Whether to check the size, alignment and field offsets of structs, unions and enums in C
+    /// and C++ with `static_assert`s, against the ones computed for `target`.
+    pub static_asserts: bool,
 }
 
 impl LayoutConfig {
@@ -716,6 +864,9 @@ pub struct ConstantConfig {
     pub allow_constexpr: bool,
     /// Sort key for constants
     pub sort_by: Option<SortKey>,
+    /// Whether to evaluate the values of constants and the discriminants of enums to literals,
+    /// even if their expressions are valid in C.
+    pub evaluate: bool,
 }
 
 impl Default for ConstantConfig {
@@ -724,6 +875,7 @@ impl Default for ConstantConfig {
             allow_static_const: true,
             allow_constexpr: true,
             sort_by: None,
+            evaluate: false,
         }
     }
 }
@@ -886,6 +1038,108 @@ pub struct PtrConfig {
     pub nullable_attribute: Option<String>,
 }
 
+/// The C spellings of the primitive types C has no standard name for.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct PrimitiveConfig {
+    /// The type to use for `i128`.
+    pub int128: String,
+    /// The type to use for `u128`.
+    pub uint128: String,
+    /// The type to use for `f16`.
+    pub float16: String,
+    /// The type to use for `f128`.
+    pub float128: String,
+}
+
+impl Default for PrimitiveConfig {
+    fn default() -> PrimitiveConfig {
+        PrimitiveConfig {
+            int128: "__int128".to_owned(),
+            uint128: "unsigned __int128".to_owned(),
+            float16: "_Float16".to_owned(),
+            float128: "__float128".to_owned(),
+        }
+    }
+}
+
+/// The size and alignment of a primitive type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrimitiveLayout {
+    pub size: u64,
+    pub align: u64,
+}
+
+/// Settings to compute the layout of types with.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct TargetConfig {
+    /// The target triple, such as `x86_64-unknown-linux-gnu`. Defaults to the host.
+    pub triple: Option<String>,
+    /// The layouts of primitive types by their Rust name (`c_long`, `u64`, ...), or `pointer`,
+    /// overriding the ones derived from the triple.
+    pub primitives: HashMap<String, PrimitiveLayout>,
+}
+
+/// The format of the symbol file listing the dynamic symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymfileFormat {
+    /// A `{ symbol; ... };` list, for the `--dynamic-list` option of GNU ld.
+    #[default]
+    DynamicList,
+    /// A GNU ld version script, with a version node per `cbindgen:since` version.
+    VersionScript,
+    /// An MSVC module-definition file.
+    Def,
+}
+
+impl FromStr for SymfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SymfileFormat, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_ref() {
+            "dynamic-list" => Ok(SymfileFormat::DynamicList),
+            "version-script" => Ok(SymfileFormat::VersionScript),
+            "def" => Ok(SymfileFormat::Def),
+            _ => Err(format!("Unrecognized symfile format: '{s}'.")),
+        }
+    }
+}
+
+deserialize_enum_str!(SymfileFormat);
+
+/// Settings to apply to the symbol file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SymfileConfig {
+    /// The format of the symbol file.
+    pub format: SymfileFormat,
+    /// The prefix of the names of the version nodes of a version script.
+    pub version_prefix: String,
+    /// The version of the symbols without a `cbindgen:since` annotation.
+    pub base_version: String,
+    /// The name of the library in the `LIBRARY` statement of a module-definition file.
+    pub library: Option<String>,
+}
+
+impl Default for SymfileConfig {
+    fn default() -> Self {
+        SymfileConfig {
+            format: SymfileFormat::default(),
+            version_prefix: "VERS_".to_owned(),
+            base_version: "1.0".to_owned(),
+            library: None,
+        }
+    }
+}
+
 /// Settings specific to Cython bindings.
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -899,6 +1153,94 @@ pub struct CythonConfig {
     pub cimports: BTreeMap<String, Vec<String>>,
 }
 
+/// The attribute used to import functions in C# bindings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum CSharpImportAttribute {
+    /// `[DllImport]`, supported by every .NET runtime, including Mono and Unity.
+    #[default]
+    DllImport,
+    /// `[LibraryImport]`, which generates the marshalling code at compile time (.NET 7 or later).
+    LibraryImport,
+}
+
+impl FromStr for CSharpImportAttribute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "DllImport" => Ok(Self::DllImport),
+            "dll_import" => Ok(Self::DllImport),
+            "LibraryImport" => Ok(Self::LibraryImport),
+            "library_import" => Ok(Self::LibraryImport),
+            _ => Err(format!("Unrecognized CSharpImportAttribute: '{s}'.")),
+        }
+    }
+}
+
+deserialize_enum_str!(CSharpImportAttribute);
+
+/// Settings specific to C# bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CSharpConfig {
+    /// The namespace to put the bindings in, if any.
+    pub namespace: Option<String>,
+    /// The name of the `static partial class` holding functions and constants.
+    pub class_name: Option<String>,
+    /// The name of the native library to import functions from.
+    pub library_name: Option<String>,
+    /// The attribute used to import functions.
+    pub import_attribute: CSharpImportAttribute,
+}
+
+impl CSharpConfig {
+    pub(crate) fn class_name(&self) -> &str {
+        self.class_name.as_deref().unwrap_or("NativeMethods")
+    }
+
+    pub(crate) fn library_name(&self) -> &str {
+        self.library_name.as_deref().unwrap_or("native")
+    }
+}
+
+/// Settings specific to cffi `cdef` output.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CffiConfig {
+    /// The defines (the values of the `[defines]` table) considered to be set when
+    /// resolving `#[cfg]` conditions, as cffi doesn't understand `#if`.
+    pub defines: Vec<String>,
+}
+
+/// Settings specific to Java bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct JavaConfig {
+    /// The package to put the bindings class in, if any.
+    pub package: Option<String>,
+    /// The name of the class holding the bindings.
+    pub class_name: Option<String>,
+    /// The name of the native library to look symbols up in, as passed to
+    /// `System.loadLibrary`. If unset, the libraries already loaded by the class
+    /// loader are searched.
+    pub library_name: Option<String>,
+    /// The defines (the values of the `[defines]` table) considered to be set when
+    /// resolving `#[cfg]` conditions, as Java has no preprocessor.
+    pub defines: Vec<String>,
+}
+
+impl JavaConfig {
+    pub(crate) fn class_name(&self) -> &str {
+        self.class_name.as_deref().unwrap_or("Bindings")
+    }
+}
+
 /// A collection of settings to customize the generated bindings.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -983,9 +1325,18 @@ pub struct Config {
     pub documentation_style: DocumentationStyle,
     /// How much of the documentation should be output for each item.
     pub documentation_length: DocumentationLength,
+    /// Translate the rustdoc markup of the documentation: intra-doc links to the exported names,
+    /// and sections and code blocks to Doxygen commands with the doxy style.
+    pub documentation_translate: bool,
     /// Configuration options for pointers
     #[serde(rename = "ptr")]
     pub pointer: PtrConfig,
+    /// The C spellings of the 128-bit and half precision primitive types
+    pub primitive: PrimitiveConfig,
+    /// The target to compute the size and alignment of types for
+    pub target: TargetConfig,
+    /// The symbol file to list the dynamic symbols in
+    pub symfile: SymfileConfig,
     /// Only download sources for dependencies needed for the target platform.
     ///
     /// By default, cbindgen will fetch sources for dependencies used on any platform so that if a
@@ -1023,6 +1374,12 @@ pub struct Config {
     pub only_target_dependencies: bool,
     /// Configuration options specific to Cython.
     pub cython: CythonConfig,
+    /// Configuration options specific to C#.
+    pub csharp: CSharpConfig,
+    /// Configuration options specific to cffi.
+    pub cffi: CffiConfig,
+    /// Configuration options specific to Java.
+    pub java: JavaConfig,
     #[doc(hidden)]
     #[serde(skip)]
     /// Internal field for tracking from which file the config was loaded.
@@ -1072,9 +1429,16 @@ impl Default for Config {
             documentation: true,
             documentation_style: DocumentationStyle::Auto,
             documentation_length: DocumentationLength::Full,
+            documentation_translate: false,
             pointer: PtrConfig::default(),
+            primitive: PrimitiveConfig::default(),
+            target: TargetConfig::default(),
+            symfile: SymfileConfig::default(),
             only_target_dependencies: false,
             cython: CythonConfig::default(),
+            csharp: CSharpConfig::default(),
+            cffi: CffiConfig::default(),
+            java: JavaConfig::default(),
             config_path: None,
         }
     }
@@ -1086,26 +1450,38 @@ impl Config {
     }
 
     pub(crate) fn include_guard(&self) -> Option<&str> {
-        if self.language == Language::Cython {
-            None
-        } else {
-            self.include_guard.as_deref()
+        match self.language {
+            Language::Cxx | Language::C => self.include_guard.as_deref(),
+            Language::Cython
+            | Language::Zig
+            | Language::CSharp
+            | Language::Cffi
+            | Language::Java
+            | Language::Rust => None,
         }
     }
 
     pub(crate) fn includes(&self) -> &[String] {
-        if self.language == Language::Cython {
-            &[]
-        } else {
-            &self.includes
+        match self.language {
+            Language::Cxx | Language::C => &self.includes,
+            Language::Cython
+            | Language::Zig
+            | Language::CSharp
+            | Language::Cffi
+            | Language::Java
+            | Language::Rust => &[],
         }
     }
 
     pub(crate) fn sys_includes(&self) -> &[String] {
-        if self.language == Language::Cython {
-            &[]
-        } else {
-            &self.sys_includes
+        match self.language {
+            Language::Cxx | Language::C => &self.sys_includes,
+            Language::Cython
+            | Language::Zig
+            | Language::CSharp
+            | Language::Cffi
+            | Language::Java
+            | Language::Rust => &[],
         }
     }
 
@@ -0,0 +1,673 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A compile-time evaluator of the constant expressions of the parsed crates.
+//!
+//! The lengths of arrays, the discriminants of enums and the values of constants are folded into
+//! literals before the items are loaded, when they're written with expressions that aren't valid
+//! in C, like calls to `const fn`s, `size_of::<u32>()` or `u32::BITS`, or everywhere with
+//! `const.evaluate`.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use syn::ext::IdentExt;
+use syn::fold::Fold;
+
+use crate::bindgen::ir::PrimitiveType;
+
+/// The maximum number of constants and calls followed to evaluate an expression, as they can be
+/// recursive.
+const MAX_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Bool(bool),
+    /// An integer, with `u128` values above `i128::MAX` held as their bit pattern.
+    Int(i128),
+    Float(f64),
+}
+
+/// Applies the binary operator `op` to integers, wrapping around like `u128` would on overflow.
+pub(crate) fn int_binary_op(left: i128, op: &str, right: i128) -> Option<i128> {
+    match op {
+        "+" => Some(left.wrapping_add(right)),
+        "-" => Some(left.wrapping_sub(right)),
+        "*" => Some(left.wrapping_mul(right)),
+        "/" => left.checked_div(right),
+        "%" => left.checked_rem(right),
+        "<<" => left.checked_shl(u32::try_from(right).ok()?),
+        ">>" => left.checked_shr(u32::try_from(right).ok()?),
+        "&" => Some(left & right),
+        "|" => Some(left | right),
+        "^" => Some(left ^ right),
+        "==" => Some((left == right) as i128),
+        "!=" => Some((left != right) as i128),
+        "<" => Some((left < right) as i128),
+        "<=" => Some((left <= right) as i128),
+        ">" => Some((left > right) as i128),
+        ">=" => Some((left >= right) as i128),
+        "&&" => Some((left != 0 && right != 0) as i128),
+        "||" => Some((left != 0 || right != 0) as i128),
+        _ => None,
+    }
+}
+
+fn binary_op(op: &syn::BinOp) -> Option<&'static str> {
+    Some(match *op {
+        syn::BinOp::Add(..) => "+",
+        syn::BinOp::Sub(..) => "-",
+        syn::BinOp::Mul(..) => "*",
+        syn::BinOp::Div(..) => "/",
+        syn::BinOp::Rem(..) => "%",
+        syn::BinOp::And(..) => "&&",
+        syn::BinOp::Or(..) => "||",
+        syn::BinOp::BitXor(..) => "^",
+        syn::BinOp::BitAnd(..) => "&",
+        syn::BinOp::BitOr(..) => "|",
+        syn::BinOp::Shl(..) => "<<",
+        syn::BinOp::Shr(..) => ">>",
+        syn::BinOp::Eq(..) => "==",
+        syn::BinOp::Lt(..) => "<",
+        syn::BinOp::Le(..) => "<=",
+        syn::BinOp::Ne(..) => "!=",
+        syn::BinOp::Ge(..) => ">=",
+        syn::BinOp::Gt(..) => ">",
+        _ => return None,
+    })
+}
+
+/// The primitive type named by `ty`, like `u32`.
+fn primitive(ty: &syn::Type) -> Option<PrimitiveType> {
+    match *ty {
+        syn::Type::Path(ref path) if path.qself.is_none() => {
+            PrimitiveType::maybe(&path.path.get_ident()?.unraw().to_string())
+        }
+        syn::Type::Paren(ref paren) => primitive(&paren.elem),
+        syn::Type::Group(ref group) => primitive(&group.elem),
+        _ => None,
+    }
+}
+
+/// The size of the primitive type `name`, if it's the same on all targets.
+fn size_of(name: &str) -> Option<i128> {
+    Some(match name {
+        "u8" | "i8" | "bool" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "f32" | "char" => 4,
+        "u64" | "i64" | "f64" => 8,
+        "u128" | "i128" => 16,
+        _ => return None,
+    })
+}
+
+/// The associated constant `name` of the primitive type `ty`, like `u32::BITS`.
+fn primitive_constant(ty: &str, name: &str) -> Option<Value> {
+    match (ty, name) {
+        ("f32", "MAX") => return Some(Value::Float(f32::MAX.into())),
+        ("f32", "MIN") => return Some(Value::Float(f32::MIN.into())),
+        ("f32", "EPSILON") => return Some(Value::Float(f32::EPSILON.into())),
+        ("f64", "MAX") => return Some(Value::Float(f64::MAX)),
+        ("f64", "MIN") => return Some(Value::Float(f64::MIN)),
+        ("f64", "EPSILON") => return Some(Value::Float(f64::EPSILON)),
+        _ => {}
+    }
+    let (bits, signed) = match PrimitiveType::maybe(ty)? {
+        prim @ PrimitiveType::Integer { .. } => prim.integer_bits()?,
+        _ => return None,
+    };
+    // Computed on `u128`, as `u128::MAX` is only representable as its bits.
+    let max = u128::MAX >> (128 - bits);
+    Some(Value::Int(match (name, signed) {
+        ("MAX", true) => (max >> 1) as i128,
+        ("MAX", false) => max as i128,
+        ("MIN", true) => !((max >> 1) as i128),
+        ("MIN", false) => 0,
+        ("BITS", _) => bits.into(),
+        _ => return None,
+    }))
+}
+
+/// Whether the associated constant `name` of the primitive type `ty` can be written in C.
+fn is_c_constant(ty: &str, name: &str) -> bool {
+    matches!(
+        ty,
+        "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+    ) && matches!(name, "MAX" | "MIN")
+}
+
+/// Converts `value` to the primitive type `ty` like an `as` cast would.
+fn cast(value: Value, ty: &PrimitiveType) -> Option<Value> {
+    match *ty {
+        PrimitiveType::Bool => match value {
+            Value::Bool(..) => Some(value),
+            _ => None,
+        },
+        PrimitiveType::Float | PrimitiveType::Double => {
+            let value = match value {
+                Value::Bool(value) => f64::from(u8::from(value)),
+                Value::Int(value) => value as f64,
+                Value::Float(value) => value,
+            };
+            Some(Value::Float(match *ty {
+                PrimitiveType::Float => f64::from(value as f32),
+                _ => value,
+            }))
+        }
+        _ => {
+            let (bits, signed) = ty.integer_bits()?;
+            let value = match value {
+                Value::Bool(value) => value.into(),
+                Value::Int(value) => value,
+                // Float casts saturate.
+                Value::Float(value) if bits < 128 => {
+                    let max = (u128::MAX >> (128 - bits + u32::from(signed))) as f64;
+                    let min = if signed { -max - 1.0 } else { 0.0 };
+                    value.clamp(min, max) as i128
+                }
+                Value::Float(value) if signed => value as i128,
+                Value::Float(value) => value as u128 as i128,
+            };
+            Some(Value::Int(ty.wrap(value)?))
+        }
+    }
+}
+
+/// A `const fn` whose body is an expression.
+#[derive(Debug, Clone)]
+struct ConstFn {
+    params: Vec<String>,
+    ret: syn::Type,
+    body: syn::Expr,
+}
+
+/// A constant, with whether it's `pub`, as only those are exported.
+#[derive(Debug, Clone)]
+struct ConstItem {
+    ty: syn::Type,
+    expr: syn::Expr,
+    public: bool,
+}
+
+/// The variants of an enum with their discriminant.
+type Variants = Vec<(String, Option<syn::Expr>)>;
+
+/// The constants, enums and `const fn`s of the parsed crates, by name. The names defined by
+/// several items, like items under different cfgs, map to `None`, as their value depends on the
+/// target.
+#[derive(Debug, Default)]
+pub struct ConstEvaluator {
+    constants: HashMap<String, Option<ConstItem>>,
+    enums: HashMap<String, Option<Variants>>,
+    fns: HashMap<String, Option<ConstFn>>,
+}
+
+fn insert<T>(map: &mut HashMap<String, Option<T>>, name: String, value: Option<T>) {
+    match map.entry(name) {
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+        }
+        Entry::Occupied(mut entry) => {
+            entry.insert(None);
+        }
+    }
+}
+
+impl ConstEvaluator {
+    pub fn new<'a>(items: impl IntoIterator<Item = &'a syn::Item>) -> Self {
+        let mut evaluator = ConstEvaluator::default();
+        for item in items {
+            match *item {
+                syn::Item::Const(ref item) => insert(
+                    &mut evaluator.constants,
+                    item.ident.unraw().to_string(),
+                    Some(ConstItem {
+                        ty: (*item.ty).clone(),
+                        expr: (*item.expr).clone(),
+                        public: matches!(item.vis, syn::Visibility::Public(..)),
+                    }),
+                ),
+                syn::Item::Enum(ref item) => {
+                    // The discriminants of the variants after a variant under a cfg depend on
+                    // the target.
+                    let has_cfg = item
+                        .variants
+                        .iter()
+                        .any(|v| v.attrs.iter().any(|attr| attr.path().is_ident("cfg")));
+                    let variants = item
+                        .variants
+                        .iter()
+                        .map(|v| {
+                            let discriminant = v.discriminant.as_ref().map(|(_, e)| e.clone());
+                            (v.ident.unraw().to_string(), discriminant)
+                        })
+                        .collect();
+                    insert(
+                        &mut evaluator.enums,
+                        item.ident.unraw().to_string(),
+                        (!has_cfg).then_some(variants),
+                    );
+                }
+                syn::Item::Fn(ref item) if item.sig.constness.is_some() => {
+                    insert(
+                        &mut evaluator.fns,
+                        item.sig.ident.unraw().to_string(),
+                        ConstFn::load(item),
+                    );
+                }
+                _ => {}
+            }
+        }
+        evaluator
+    }
+
+    /// Folds the constant expressions of `items` into literals, only where they aren't valid in
+    /// C unless `all` is set.
+    pub fn fold(&self, items: Vec<syn::Item>, all: bool) -> Vec<syn::Item> {
+        let mut fold = ConstFold {
+            evaluator: self,
+            all,
+        };
+        items.into_iter().map(|item| fold.fold_item(item)).collect()
+    }
+
+    fn constant(&self, name: &str, depth: usize) -> Option<Value> {
+        let constant = self.constants.get(name)?.as_ref()?;
+        let value = self.evaluate(&constant.expr, &HashMap::new(), depth + 1)?;
+        cast(value, &primitive(&constant.ty)?)
+    }
+
+    fn variant(&self, enum_name: &str, name: &str, depth: usize) -> Option<Value> {
+        let variants = self.enums.get(enum_name)?.as_ref()?;
+        let mut value = 0;
+        for (variant, discriminant) in variants {
+            if let Some(ref discriminant) = *discriminant {
+                match self.evaluate(discriminant, &HashMap::new(), depth + 1)? {
+                    Value::Int(discriminant) => value = discriminant,
+                    _ => return None,
+                }
+            }
+            if variant == name {
+                return Some(Value::Int(value));
+            }
+            value = value.wrapping_add(1);
+        }
+        None
+    }
+
+    /// Evaluates `expr`, with the values of the parameters of the `const fn` being evaluated in
+    /// `params`.
+    fn evaluate(
+        &self,
+        expr: &syn::Expr,
+        params: &HashMap<String, Value>,
+        depth: usize,
+    ) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match *expr {
+            syn::Expr::Lit(syn::ExprLit { ref lit, .. }) => match *lit {
+                syn::Lit::Int(ref value) => {
+                    Some(Value::Int(value.base10_parse::<u128>().ok()? as i128))
+                }
+                syn::Lit::Float(ref value) => Some(Value::Float(value.base10_parse().ok()?)),
+                syn::Lit::Bool(ref value) => Some(Value::Bool(value.value)),
+                syn::Lit::Byte(ref value) => Some(Value::Int(value.value().into())),
+                syn::Lit::Char(ref value) => Some(Value::Int(u32::from(value.value()).into())),
+                _ => None,
+            },
+            syn::Expr::Paren(ref paren) => self.evaluate(&paren.expr, params, depth),
+            syn::Expr::Group(ref group) => self.evaluate(&group.expr, params, depth),
+            syn::Expr::Block(ref block) if block.label.is_none() => {
+                self.evaluate(block_expr(&block.block)?, params, depth)
+            }
+            syn::Expr::Unary(ref unary) => {
+                let value = self.evaluate(&unary.expr, params, depth)?;
+                match (&unary.op, value) {
+                    (syn::UnOp::Neg(..), Value::Int(value)) => {
+                        Some(Value::Int(value.wrapping_neg()))
+                    }
+                    (syn::UnOp::Neg(..), Value::Float(value)) => Some(Value::Float(-value)),
+                    (syn::UnOp::Not(..), Value::Int(value)) => Some(Value::Int(!value)),
+                    (syn::UnOp::Not(..), Value::Bool(value)) => Some(Value::Bool(!value)),
+                    _ => None,
+                }
+            }
+            syn::Expr::Binary(ref binary) => {
+                let op = binary_op(&binary.op)?;
+                let left = self.evaluate(&binary.left, params, depth)?;
+                let right = self.evaluate(&binary.right, params, depth)?;
+                evaluate_binary(left, op, right)
+            }
+            syn::Expr::Cast(ref cast_expr) => {
+                let value = self.evaluate(&cast_expr.expr, params, depth)?;
+                cast(value, &primitive(&cast_expr.ty)?)
+            }
+            syn::Expr::If(ref if_expr) => {
+                let condition = self.evaluate(&if_expr.cond, params, depth)?;
+                let (_, ref else_branch) = *if_expr.else_branch.as_ref()?;
+                match condition {
+                    Value::Bool(true) => {
+                        self.evaluate(block_expr(&if_expr.then_branch)?, params, depth)
+                    }
+                    Value::Bool(false) => self.evaluate(else_branch, params, depth),
+                    _ => None,
+                }
+            }
+            syn::Expr::Path(ref path) if path.qself.is_none() => {
+                self.evaluate_path(&path.path, params, depth)
+            }
+            syn::Expr::Call(ref call) => self.evaluate_call(call, params, depth),
+            _ => None,
+        }
+    }
+
+    fn evaluate_path(
+        &self,
+        path: &syn::Path,
+        params: &HashMap<String, Value>,
+        depth: usize,
+    ) -> Option<Value> {
+        let segments: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+        match segments[..] {
+            [ref name] => match params.get(name) {
+                Some(value) => Some(*value),
+                None => self.constant(name, depth),
+            },
+            [.., ref ty, ref name] if PrimitiveType::maybe(ty).is_some() => {
+                primitive_constant(ty, name)
+            }
+            [.., ref ty, ref name] if self.enums.contains_key(ty) => self.variant(ty, name, depth),
+            // Associated constants of other types aren't known.
+            [.., ref ty, _] if ty == "Self" || ty.starts_with(char::is_uppercase) => None,
+            [.., ref name] => self.constant(name, depth),
+            [] => None,
+        }
+    }
+
+    fn evaluate_call(
+        &self,
+        call: &syn::ExprCall,
+        params: &HashMap<String, Value>,
+        depth: usize,
+    ) -> Option<Value> {
+        let syn::Expr::Path(ref func) = *call.func else {
+            return None;
+        };
+        let last = func.path.segments.last()?;
+        let name = last.ident.unraw().to_string();
+        if name == "size_of" && call.args.is_empty() {
+            let syn::PathArguments::AngleBracketed(ref args) = last.arguments else {
+                return None;
+            };
+            return match args.args.first()? {
+                syn::GenericArgument::Type(ref ty) => {
+                    let syn::Type::Path(ref ty) = *ty else {
+                        return None;
+                    };
+                    size_of(&ty.path.get_ident()?.unraw().to_string()).map(Value::Int)
+                }
+                _ => None,
+            };
+        }
+
+        let function = self.fns.get(&name)?.as_ref()?;
+        if function.params.len() != call.args.len() {
+            return None;
+        }
+        let mut args = HashMap::new();
+        for (param, arg) in function.params.iter().zip(&call.args) {
+            args.insert(param.clone(), self.evaluate(arg, params, depth)?);
+        }
+        let value = self.evaluate(&function.body, &args, depth + 1)?;
+        cast(value, &primitive(&function.ret)?)
+    }
+
+    /// Whether `expr` is written in a way which isn't valid in C, so that it needs to be folded.
+    fn needs_evaluation(&self, expr: &syn::Expr) -> bool {
+        match *expr {
+            syn::Expr::Lit(..) => false,
+            syn::Expr::Paren(ref paren) => self.needs_evaluation(&paren.expr),
+            syn::Expr::Group(ref group) => self.needs_evaluation(&group.expr),
+            // `!` is written as `~`, which isn't the negation of booleans.
+            syn::Expr::Unary(ref unary) if matches!(unary.op, syn::UnOp::Not(..)) => {
+                self.needs_evaluation(&unary.expr)
+                    || matches!(
+                        self.evaluate(&unary.expr, &HashMap::new(), 0),
+                        Some(Value::Bool(..))
+                    )
+            }
+            syn::Expr::Unary(ref unary) => self.needs_evaluation(&unary.expr),
+            syn::Expr::Binary(ref binary) => {
+                self.needs_evaluation(&binary.left) || self.needs_evaluation(&binary.right)
+            }
+            syn::Expr::Cast(ref cast) => self.needs_evaluation(&cast.expr),
+            syn::Expr::Path(ref path) => {
+                let segments: Vec<_> = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.unraw().to_string())
+                    .collect();
+                match segments[..] {
+                    [ref name] => self
+                        .constants
+                        .get(name)
+                        .is_some_and(|c| c.as_ref().is_some_and(|c| !c.public)),
+                    [.., ref ty, ref name] if PrimitiveType::maybe(ty).is_some() => {
+                        !is_c_constant(ty, name)
+                    }
+                    _ => false,
+                }
+            }
+            // Struct literals and tuple struct constructors are written as they are.
+            syn::Expr::Call(ref call) => match *call.func {
+                syn::Expr::Path(ref func) => func.path.segments.last().is_some_and(|last| {
+                    let name = last.ident.unraw().to_string();
+                    name == "size_of" || self.fns.contains_key(&name)
+                }),
+                _ => true,
+            },
+            syn::Expr::Struct(..) | syn::Expr::Array(..) | syn::Expr::Field(..) => false,
+            _ => true,
+        }
+    }
+
+    /// The literal `value` converted to `ty` evaluates to, if it's valid in C.
+    fn literal(&self, value: Value, ty: Option<&syn::Type>) -> Option<syn::Expr> {
+        let ty = ty.and_then(primitive);
+        let value = match ty {
+            Some(ref ty) => cast(value, ty)?,
+            None => value,
+        };
+        let text = match value {
+            Value::Bool(value) => value.to_string(),
+            // Characters are written as such.
+            Value::Int(..) if ty == Some(PrimitiveType::Char32) => return None,
+            Value::Int(value) => match ty.as_ref().and_then(PrimitiveType::integer_bits) {
+                Some((128, false)) => (value as u128).to_string(),
+                Some((128, true)) if value.unsigned_abs() > u128::from(u64::MAX) => {
+                    format!("{value}i128")
+                }
+                _ => value.to_string(),
+            },
+            Value::Float(value) if value.is_finite() => match ty {
+                Some(PrimitiveType::Float) => format!("{:?}", value as f32),
+                _ => format!("{value:?}"),
+            },
+            Value::Float(..) => return None,
+        };
+        syn::parse_str(&text).ok()
+    }
+}
+
+impl ConstFn {
+    fn load(item: &syn::ItemFn) -> Option<ConstFn> {
+        if !item.sig.generics.params.is_empty() {
+            return None;
+        }
+        let params = item
+            .sig
+            .inputs
+            .iter()
+            .map(|arg| match *arg {
+                syn::FnArg::Typed(ref arg) => match *arg.pat {
+                    syn::Pat::Ident(ref pat) => Some(pat.ident.unraw().to_string()),
+                    _ => None,
+                },
+                syn::FnArg::Receiver(..) => None,
+            })
+            .collect::<Option<_>>()?;
+        let syn::ReturnType::Type(_, ref ret) = item.sig.output else {
+            return None;
+        };
+        Some(ConstFn {
+            params,
+            ret: (**ret).clone(),
+            body: block_expr(&item.block)?.clone(),
+        })
+    }
+}
+
+/// The expression of a block made of only an expression, like the bodies of simple functions.
+fn block_expr(block: &syn::Block) -> Option<&syn::Expr> {
+    match block.stmts[..] {
+        [syn::Stmt::Expr(ref expr, None)] => Some(expr),
+        _ => None,
+    }
+}
+
+fn evaluate_binary(left: Value, op: &str, right: Value) -> Option<Value> {
+    let ordering = match (left, right) {
+        (Value::Int(left), Value::Int(right)) => Some(left.cmp(&right)),
+        (Value::Float(left), Value::Float(right)) => left.partial_cmp(&right),
+        (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(&right)),
+        _ => return None,
+    };
+    match op {
+        "==" => return Some(Value::Bool(ordering?.is_eq())),
+        "!=" => return Some(Value::Bool(ordering.map_or(true, |o| o.is_ne()))),
+        "<" => return Some(Value::Bool(ordering?.is_lt())),
+        "<=" => return Some(Value::Bool(ordering?.is_le())),
+        ">" => return Some(Value::Bool(ordering?.is_gt())),
+        ">=" => return Some(Value::Bool(ordering?.is_ge())),
+        _ => {}
+    }
+    match (left, right) {
+        (Value::Int(left), Value::Int(right)) => match op {
+            "&&" | "||" => None,
+            _ => int_binary_op(left, op, right).map(Value::Int),
+        },
+        (Value::Float(left), Value::Float(right)) => Some(Value::Float(match op {
+            "+" => left + right,
+            "-" => left - right,
+            "*" => left * right,
+            "/" => left / right,
+            "%" => left % right,
+            _ => return None,
+        })),
+        (Value::Bool(left), Value::Bool(right)) => Some(Value::Bool(match op {
+            "&&" | "&" => left && right,
+            "||" | "|" => left || right,
+            "^" => left != right,
+            _ => return None,
+        })),
+        _ => None,
+    }
+}
+
+/// Whether the length `expr` is a literal or a constant, possibly cast, like `LEN as usize`.
+fn is_constant_length(expr: &syn::Expr) -> bool {
+    match *expr {
+        syn::Expr::Lit(..) | syn::Expr::Path(..) => true,
+        syn::Expr::Cast(ref cast) => is_constant_length(&cast.expr),
+        _ => false,
+    }
+}
+
+/// Replaces the constant expressions which need it with the literal they evaluate to.
+struct ConstFold<'a> {
+    evaluator: &'a ConstEvaluator,
+    /// Whether to fold the values of constants and enum discriminants which are valid in C.
+    all: bool,
+}
+
+impl ConstFold<'_> {
+    fn fold_value(&self, expr: &mut syn::Expr, ty: Option<&syn::Type>, is_length: bool) {
+        let fold = match *expr {
+            syn::Expr::Lit(..) => false,
+            // Lengths are written as expressions only if they're constants.
+            _ if is_length => !is_constant_length(expr) || self.evaluator.needs_evaluation(expr),
+            _ => self.all || self.evaluator.needs_evaluation(expr),
+        };
+        if !fold {
+            return;
+        }
+        // Lengths and discriminants are integers, the constants are of their type.
+        let value = match self.evaluator.evaluate(expr, &HashMap::new(), 0) {
+            Some(Value::Int(value)) if is_length && value < 0 => return,
+            Some(value @ Value::Int(..)) => value,
+            Some(value) if ty.is_some() => value,
+            _ => return,
+        };
+        if let Some(literal) = self.evaluator.literal(value, ty) {
+            *expr = literal;
+        }
+    }
+}
+
+impl Fold for ConstFold<'_> {
+    fn fold_item_const(&mut self, mut item: syn::ItemConst) -> syn::ItemConst {
+        self.fold_value(&mut item.expr, Some(&item.ty), false);
+        syn::fold::fold_item_const(self, item)
+    }
+
+    fn fold_impl_item_const(&mut self, mut item: syn::ImplItemConst) -> syn::ImplItemConst {
+        self.fold_value(&mut item.expr, Some(&item.ty), false);
+        syn::fold::fold_impl_item_const(self, item)
+    }
+
+    fn fold_variant(&mut self, mut variant: syn::Variant) -> syn::Variant {
+        if let Some((_, ref mut discriminant)) = variant.discriminant {
+            self.fold_value(discriminant, None, false);
+        }
+        syn::fold::fold_variant(self, variant)
+    }
+
+    fn fold_type_array(&mut self, mut array: syn::TypeArray) -> syn::TypeArray {
+        self.fold_value(&mut array.len, None, true);
+        syn::fold::fold_type_array(self, array)
+    }
+
+    fn fold_generic_argument(&mut self, mut arg: syn::GenericArgument) -> syn::GenericArgument {
+        if let syn::GenericArgument::Const(ref mut expr) = arg {
+            self.fold_value(expr, None, true);
+        }
+        syn::fold::fold_generic_argument(self, arg)
+    }
+
+    fn fold_item_mod(&mut self, item: syn::ItemMod) -> syn::ItemMod {
+        // Nested modules are folded on their own.
+        item
+    }
+
+    fn fold_expr(&mut self, expr: syn::Expr) -> syn::Expr {
+        // The expressions are folded as a whole, or not at all.
+        expr
+    }
+
+    fn fold_block(&mut self, block: syn::Block) -> syn::Block {
+        // Function bodies aren't part of the bindings.
+        block
+    }
+
+    fn fold_attribute(&mut self, attr: syn::Attribute) -> syn::Attribute {
+        attr
+    }
+}
@@ -25,6 +25,12 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    NameConflict {
+        name: String,
+        module_paths: Vec<String>,
+    },
+    Compat(String),
+    Json(serde_json::Error),
 }
 
 impl fmt::Display for Error {
@@ -66,6 +72,16 @@ impl fmt::Display for Error {
                 f,
                 "Parsing crate `{crate_name}`: cannot open file `{src_path}`."
             ),
+            Error::NameConflict {
+                ref name,
+                ref module_paths,
+            } => write!(
+                f,
+                "`{}` are all used by the bindings, and exported as `{name}`.",
+                module_paths.join("`, `")
+            ),
+            Error::Compat(ref reason) => write!(f, "Couldn't compare the bindings: {reason}"),
+            Error::Json(ref error) => write!(f, "Couldn't write the bindings as JSON: {error}"),
         }
     }
 }
@@ -78,6 +94,9 @@ impl error::Error for Error {
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::NameConflict { .. } => None,
+            Error::Compat(_) => None,
+            Error::Json(ref error) => Some(error),
         }
     }
 }
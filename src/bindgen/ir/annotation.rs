@@ -175,6 +175,10 @@ impl AnnotationSet {
         }
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&String, &AnnotationValue)> {
+        self.annotations.iter()
+    }
+
     pub fn list(&self, name: &str) -> Option<Vec<String>> {
         match self.annotations.get(name) {
             Some(AnnotationValue::List(x)) => Some(x.clone()),
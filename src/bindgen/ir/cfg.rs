@@ -269,7 +269,8 @@ impl Condition {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         match *self {
             Condition::Define(ref define) => {
-                if config.language == Language::Cython {
+                // Cython and C# only know about defined symbols, which are tested by name.
+                if matches!(config.language, Language::Cython | Language::CSharp) {
                     write!(out, "{define}");
                 } else {
                     out.write("defined(");
@@ -317,6 +318,18 @@ impl Condition {
     }
 }
 
+impl Condition {
+    /// Evaluates the condition, considering only the given defines to be set.
+    pub fn evaluate(&self, defines: &[String]) -> bool {
+        match *self {
+            Condition::Define(ref define) => defines.contains(define),
+            Condition::Any(ref conditions) => conditions.iter().any(|c| c.evaluate(defines)),
+            Condition::All(ref conditions) => conditions.iter().all(|c| c.evaluate(defines)),
+            Condition::Not(ref condition) => !condition.evaluate(defines),
+        }
+    }
+}
+
 pub trait ConditionWrite {
     fn write_before<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>);
     fn write_after<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>);
@@ -325,10 +338,20 @@ pub trait ConditionWrite {
 impl ConditionWrite for Option<Condition> {
     fn write_before<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if let Some(ref cfg) = *self {
-            if config.language == Language::Cython {
+            if matches!(config.language, Language::Cffi | Language::Java) {
+                // cffi and Java have no preprocessor, so their backends only write
+                // items whose conditions hold for their `defines`.
+            } else if config.language == Language::Rust {
+                // The Rust backend writes the original `#[cfg]` attributes instead.
+            } else if config.language == Language::Cython {
                 out.write("IF ");
                 cfg.write(config, out);
                 out.open_brace();
+            } else if config.language == Language::Zig {
+                // Zig has no preprocessor, so the condition is only kept as a comment.
+                out.write("// #if ");
+                cfg.write(config, out);
+                out.new_line();
             } else {
                 out.push_set_spaces(0);
                 out.write("#if ");
@@ -341,8 +364,15 @@ impl ConditionWrite for Option<Condition> {
 
     fn write_after<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if self.is_some() {
-            if config.language == Language::Cython {
+            if matches!(
+                config.language,
+                Language::Cffi | Language::Java | Language::Rust
+            ) {
+            } else if config.language == Language::Cython {
                 out.close_brace(false);
+            } else if config.language == Language::Zig {
+                out.new_line();
+                out.write("// #endif");
             } else {
                 out.new_line();
                 out.push_set_spaces(0);
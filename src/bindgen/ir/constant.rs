@@ -10,11 +10,12 @@ use syn::ext::IdentExt;
 use syn::UnOp;
 
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::const_eval::int_binary_op;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, ConstExpr, Documentation, GenericParams, IntKind, Item,
+    ItemContainer, Path, PrimitiveType, Struct, ToCondition, Type,
 };
 use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::library::Library;
@@ -31,8 +32,6 @@ fn member_to_ident(member: &syn::Member) -> String {
 
 // TODO: Maybe add support to more std associated constants.
 pub(crate) fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
-    use crate::bindgen::ir::{IntKind, PrimitiveType};
-
     if name != "MAX" && name != "MIN" {
         return None;
     }
@@ -79,6 +78,24 @@ pub(crate) fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Optio
     Some(format!("{prefix}_{name}"))
 }
 
+/// Escapes `value` for a string literal, writing the bytes which aren't printable ASCII with
+/// `escape`, like `\x00` or `\000`.
+pub(crate) fn escape_bytes(value: &[u8], escape: fn(u8) -> String) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for &byte in value {
+        match byte {
+            b'"' => escaped.push_str("\\\""),
+            b'\\' => escaped.push_str("\\\\"),
+            b'\n' => escaped.push_str("\\n"),
+            b'\r' => escaped.push_str("\\r"),
+            b'\t' => escaped.push_str("\\t"),
+            b' '..=b'~' => escaped.push(byte.into()),
+            _ => escaped.push_str(&escape(byte)),
+        }
+    }
+    escaped
+}
+
 #[derive(Debug, Clone)]
 pub struct LiteralStructField {
     pub value: Literal,
@@ -117,6 +134,11 @@ pub enum Literal {
     Array {
         items: Vec<Literal>,
     },
+    /// A byte string, like `b"RIFF"`, or a C string, like `c"hello"`, without its nul terminator.
+    Bytes {
+        value: Vec<u8>,
+        c_str: bool,
+    },
 }
 
 impl Literal {
@@ -190,13 +212,13 @@ impl Literal {
                     item.replace_self_with(self_ty);
                 }
             }
-            Literal::Expr(..) => {}
+            Literal::Expr(..) | Literal::Bytes { .. } => {}
         }
     }
 
-    fn is_valid(&self, bindings: &Bindings) -> bool {
+    pub(crate) fn is_valid(&self, bindings: &Bindings) -> bool {
         match *self {
-            Literal::Expr(..) => true,
+            Literal::Expr(..) | Literal::Bytes { .. } => true,
             Literal::Array { ref items } => items.iter().all(|i| i.is_valid(bindings)),
             Literal::Path {
                 ref associated_to,
@@ -221,6 +243,81 @@ impl Literal {
         }
     }
 
+    /// Evaluates an integer constant expression, for languages that only accept
+    /// integer literals in places. Only the constants and enum variants whose
+    /// `#[cfg]` holds for `defines` are considered.
+    pub(crate) fn evaluate(&self, b: &Bindings, defines: &[String]) -> Option<i128> {
+        let is_enabled = |cfg: Option<&Cfg>| {
+            cfg.and_then(|cfg| cfg.to_condition(&b.config))
+                .map_or(true, |condition| condition.evaluate(defines))
+        };
+        match *self {
+            Literal::Expr(ref v) => evaluate_c_literal(v),
+            Literal::Path {
+                associated_to: None,
+                ref name,
+            } => {
+                let constant = b
+                    .constants
+                    .iter()
+                    .find(|c| c.export_name() == name && is_enabled(c.cfg.as_ref()))?;
+                let value = constant.value.evaluate(b, defines)?;
+                match constant.ty {
+                    Type::Primitive(ref prim) => prim.wrap(value),
+                    _ => None,
+                }
+            }
+            Literal::Path {
+                associated_to: Some((ref path, _)),
+                ref name,
+            } => {
+                let Some(prim) = PrimitiveType::maybe(path.name()) else {
+                    return evaluate_enum_variant(b, defines, path, name);
+                };
+                let (bits, signed) = prim.integer_bits()?;
+                // Computed on `u128`, as `u128::MAX` is only representable as its bits.
+                let max = u128::MAX >> (128 - bits);
+                match (name.as_str(), signed) {
+                    ("MAX", true) => Some((max >> 1) as i128),
+                    ("MAX", false) => Some(max as i128),
+                    ("MIN", true) => Some(!((max >> 1) as i128)),
+                    ("MIN", false) => Some(0),
+                    _ => None,
+                }
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                let value = value.evaluate(b, defines)?;
+                // Overflowing `i128` is fine, it wraps like `u128` would.
+                match op {
+                    "-" => Some(value.wrapping_neg()),
+                    "~" => Some(!value),
+                    "!" => Some((value == 0) as i128),
+                    _ => None,
+                }
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let left = left.evaluate(b, defines)?;
+                let right = right.evaluate(b, defines)?;
+                int_binary_op(left, op, right)
+            }
+            Literal::Cast { ref ty, ref value } => {
+                let value = value.evaluate(b, defines)?;
+                match *ty {
+                    Type::Primitive(ref prim) => prim.wrap(value),
+                    _ => None,
+                }
+            }
+            Literal::FieldAccess { .. }
+            | Literal::Array { .. }
+            | Literal::Struct { .. }
+            | Literal::Bytes { .. } => None,
+        }
+    }
+
     pub(crate) fn can_be_constexpr(&self) -> bool {
         !self.has_pointer_casts()
     }
@@ -230,7 +327,7 @@ impl Literal {
             return false;
         }
         match self {
-            Literal::Expr(..) | Literal::Path { .. } => true,
+            Literal::Expr(..) | Literal::Path { .. } | Literal::Bytes { .. } => true,
             Literal::PostfixUnaryOp { ref value, .. } => value.visit(visitor),
             Literal::BinOp {
                 ref left,
@@ -285,6 +382,73 @@ impl Literal {
     }
 }
 
+/// Evaluates the discriminant of the variant `name` of the enum at `path`, which is
+/// one more than the previous variant's if it isn't given.
+fn evaluate_enum_variant(
+    b: &Bindings,
+    defines: &[String],
+    path: &Path,
+    name: &str,
+) -> Option<i128> {
+    let is_enabled = |cfg: Option<&Cfg>| {
+        cfg.and_then(|cfg| cfg.to_condition(&b.config))
+            .map_or(true, |condition| condition.evaluate(defines))
+    };
+    let e = b.items.iter().find_map(|item| match *item {
+        ItemContainer::Enum(ref e) if e.path == *path && is_enabled(e.cfg.as_ref()) => Some(e),
+        _ => None,
+    })?;
+    let mut value = 0;
+    for variant in &e.variants {
+        if !is_enabled(variant.cfg.as_ref()) {
+            continue;
+        }
+        if let Some(ref discriminant) = variant.discriminant {
+            value = discriminant.evaluate(b, defines)?;
+        }
+        if variant.name == name {
+            return Some(value);
+        }
+        value += 1;
+    }
+    None
+}
+
+/// Evaluates the integer, boolean and character literals cbindgen writes for C.
+fn evaluate_c_literal(v: &str) -> Option<i128> {
+    match v {
+        "true" => return Some(1),
+        "false" => return Some(0),
+        _ => {}
+    }
+    if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+        return i128::from_str_radix(code, 16).ok();
+    }
+    if let Some(c) = v.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        let code = match c {
+            "\\t" => '\t' as u32,
+            "\\r" => '\r' as u32,
+            "\\n" => '\n' as u32,
+            "\\'" => '\'' as u32,
+            "\\\"" => '"' as u32,
+            "\\\\" => '\\' as u32,
+            _ => match c.strip_prefix("\\u{").and_then(|c| c.strip_suffix('}')) {
+                Some(code) => u32::from_str_radix(code, 16).ok()?,
+                None => {
+                    let mut chars = c.chars();
+                    let c = chars.next()?;
+                    if chars.next().is_some() {
+                        return None;
+                    }
+                    c as u32
+                }
+            },
+        };
+        return Some(code.into());
+    }
+    v.trim_end_matches(['u', 'l']).parse().ok()
+}
+
 impl Literal {
     pub fn rename_for_config(&mut self, config: &Config) {
         match self {
@@ -327,7 +491,7 @@ impl Literal {
                     item.rename_for_config(config);
                 }
             }
-            Literal::Expr(_) => {}
+            Literal::Expr(_) | Literal::Bytes { .. } => {}
             Literal::Cast {
                 ref mut ty,
                 ref mut value,
@@ -338,6 +502,34 @@ impl Literal {
         }
     }
 
+    // C has no 128-bit integer literals, so build the value from its two halves,
+    // i.e. `((u128)high << 64) | low`, cast to `i128` if `signed`.
+    fn int128(value: u128, signed: bool) -> Literal {
+        let cast = |signed, value| Literal::Cast {
+            ty: Type::Primitive(PrimitiveType::Integer {
+                zeroable: true,
+                signed,
+                kind: IntKind::B128,
+            }),
+            value: Box::new(value),
+        };
+        let half = |v: u128| Box::new(Literal::Expr(format!("{v}ull")));
+        let bits = Literal::BinOp {
+            left: Box::new(Literal::BinOp {
+                left: Box::new(cast(false, *half(value >> 64))),
+                op: "<<",
+                right: Box::new(Literal::Expr("64".to_owned())),
+            }),
+            op: "|",
+            right: half(value & u128::from(u64::MAX)),
+        };
+        if signed {
+            cast(true, bits)
+        } else {
+            bits
+        }
+    }
+
     // Translate from full blown `syn::Expr` into a simpler `Literal` type
     pub fn load(expr: &syn::Expr) -> Result<Literal, String> {
         match *expr {
@@ -396,6 +588,17 @@ impl Literal {
                         other_code => format!(r"U'\U{other_code:08X}'"),
                     })),
                     syn::Lit::Int(ref value) => {
+                        if value.base10_parse::<u64>().is_err() {
+                            return match value.suffix() {
+                                "i128" => value
+                                    .base10_parse::<i128>()
+                                    .map(|v| Literal::int128(v as u128, true)),
+                                _ => value
+                                    .base10_parse::<u128>()
+                                    .map(|v| Literal::int128(v, false)),
+                            }
+                            .map_err(|e| e.to_string());
+                        }
                         let suffix = match value.suffix() {
                             "u64" => "ull",
                             "i64" => "ll",
@@ -413,7 +616,15 @@ impl Literal {
                         Ok(Literal::Expr(value.base10_digits().to_string()))
                     }
                     syn::Lit::Bool(ref value) => Ok(Literal::Expr(format!("{}", value.value))),
-                    // TODO: Add support for byte string and Verbatim
+                    syn::Lit::ByteStr(ref value) => Ok(Literal::Bytes {
+                        value: value.value(),
+                        c_str: false,
+                    }),
+                    syn::Lit::CStr(ref value) => Ok(Literal::Bytes {
+                        value: value.value().into_bytes(),
+                        c_str: true,
+                    }),
+                    // TODO: Add support for Verbatim
                     _ => Err(format!("Unsupported literal expression. {:?}", *lit)),
                 }
             }
@@ -484,12 +695,30 @@ impl Literal {
                     })
                 }
                 UnOp::Neg(_) => {
+                    // `-170141183460469231731687303715884105728` is `i128::MIN`, but
+                    // its literal doesn't fit an `i128`, so negate it here.
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(ref value),
+                        ..
+                    }) = **expr
+                    {
+                        if let Ok(value) = value.base10_parse::<u128>() {
+                            if value > u128::from(u64::MAX) {
+                                return Ok(Literal::int128(value.wrapping_neg(), true));
+                            }
+                        }
+                    }
                     let val = Self::load(expr)?;
                     Ok(Literal::PostfixUnaryOp {
                         op: "-",
                         value: Box::new(val),
                     })
                 }
+                // `*b"RIFF"`, for constants of type `[u8; 4]`.
+                UnOp::Deref(_) => match Self::load(expr)? {
+                    bytes @ Literal::Bytes { c_str: false, .. } => Ok(bytes),
+                    _ => Err(format!("Unsupported Unary expression. {:?}", *op)),
+                },
                 _ => Err(format!("Unsupported Unary expression. {:?}", *op)),
             },
 
@@ -504,9 +733,18 @@ impl Literal {
                     },
                     2 => {
                         let struct_name = path.segments[0].ident.to_string();
+                        let name = path.segments[1].ident.to_string();
+                        // There are no macros for these in C.
+                        match (struct_name.as_str(), name.as_str()) {
+                            ("u128", "MAX") => return Ok(Literal::int128(u128::MAX, false)),
+                            ("u128", "MIN") => return Ok(Literal::Expr("0".to_owned())),
+                            ("i128", "MAX") => return Ok(Literal::int128(i128::MAX as u128, true)),
+                            ("i128", "MIN") => return Ok(Literal::int128(i128::MIN as u128, true)),
+                            _ => {}
+                        }
                         Literal::Path {
                             associated_to: Some((Path::new(&struct_name), struct_name)),
-                            name: path.segments[1].ident.to_string(),
+                            name,
                         }
                     }
                     _ => return Err(format!("Unsupported path expression. {path:?}")),
@@ -562,7 +800,12 @@ impl Constant {
         attrs: &[syn::Attribute],
         associated_to: Option<Path>,
     ) -> Result<Constant, String> {
-        let ty = Type::load(ty)?;
+        let mut lit = Literal::load(expr)?;
+
+        let ty = match lit {
+            Literal::Bytes { ref value, c_str } => Some(Self::bytes_type(ty, value.len(), c_str)),
+            _ => Type::load(ty)?,
+        };
         let mut ty = match ty {
             Some(ty) => ty,
             None => {
@@ -570,8 +813,6 @@ impl Constant {
             }
         };
 
-        let mut lit = Literal::load(expr)?;
-
         if let Some(ref associated_to) = associated_to {
             ty.replace_self_with(associated_to);
             lit.replace_self_with(associated_to);
@@ -588,6 +829,37 @@ impl Constant {
         ))
     }
 
+    /// The type of a byte string or C string constant declared with `ty`: byte strings are
+    /// `[u8; N]` if they're declared as an array, and `&[u8; N]` otherwise, even when they're
+    /// declared as a slice, and C strings are `const char *`, as C has no `CStr`.
+    fn bytes_type(ty: &syn::Type, len: usize, c_str: bool) -> Type {
+        if c_str {
+            return Type::Ptr {
+                ty: Box::new(Type::Primitive(PrimitiveType::Char)),
+                is_const: true,
+                is_nullable: false,
+                is_ref: false,
+            };
+        }
+        let array = Type::Array(
+            Box::new(Type::Primitive(PrimitiveType::Integer {
+                zeroable: true,
+                signed: false,
+                kind: IntKind::B8,
+            })),
+            ConstExpr::Value(len.to_string()),
+        );
+        match *ty {
+            syn::Type::Array(..) => array,
+            _ => Type::Ptr {
+                ty: Box::new(array),
+                is_const: true,
+                is_nullable: false,
+                is_ref: false,
+            },
+        }
+    }
+
     pub fn new(
         path: Path,
         ty: Type,
@@ -706,35 +978,22 @@ impl Constant {
         condition.write_after(config, out);
     }
 
-    pub fn write<F: Write, LB: LanguageBackend>(
-        &self,
-        config: &Config,
-        language_backend: &mut LB,
-        out: &mut SourceWriter<F>,
-        associated_to_struct: Option<&Struct>,
-    ) {
-        if let Some(assoc) = associated_to_struct {
-            if assoc.is_generic() {
-                return; // Not tested / implemented yet, so bail out.
-            }
-        }
-
-        if !self.value.is_valid(out.bindings()) {
-            return;
-        }
-
-        let associated_to_transparent = associated_to_struct.is_some_and(|s| s.is_transparent);
-
-        let in_body = associated_to_struct.is_some()
+    /// Whether the constant is declared in the body of the struct it's associated to.
+    fn in_body(&self, config: &Config, associated_to_struct: Option<&Struct>) -> bool {
+        associated_to_struct.is_some_and(|s| !s.is_transparent)
             && config.language == Language::Cxx
             && config.structure.associated_constants_in_body
             && config.constant.allow_static_const
-            && !associated_to_transparent;
-
-        let condition = self.cfg.to_condition(config);
-        condition.write_before(config, out);
+    }
 
-        let name = if in_body {
+    /// The name the constant is declared with, which is prefixed with the name of the struct
+    /// it's associated to, if any.
+    pub(crate) fn declared_name<'a>(
+        &'a self,
+        config: &Config,
+        associated_to_struct: Option<&'a Struct>,
+    ) -> Cow<'a, str> {
+        if self.in_body(config, associated_to_struct) {
             Cow::Owned(format!(
                 "{}::{}",
                 associated_to_struct.unwrap().export_name(),
@@ -767,7 +1026,32 @@ impl Constant {
             };
 
             Cow::Owned(format!("{}_{}", associated_name, self.export_name()))
-        };
+        }
+    }
+
+    pub fn write<F: Write, LB: LanguageBackend>(
+        &self,
+        config: &Config,
+        language_backend: &mut LB,
+        out: &mut SourceWriter<F>,
+        associated_to_struct: Option<&Struct>,
+    ) {
+        if let Some(assoc) = associated_to_struct {
+            if assoc.is_generic() {
+                return; // Not tested / implemented yet, so bail out.
+            }
+        }
+
+        if !self.value.is_valid(out.bindings()) {
+            return;
+        }
+
+        let in_body = self.in_body(config, associated_to_struct);
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        let name = self.declared_name(config, associated_to_struct);
 
         let mut value = &self.value;
         while let Literal::Struct { path, fields, .. } = value {
@@ -777,10 +1061,39 @@ impl Constant {
             value = &fields.iter().next().unwrap().1.value
         }
 
+        // Variants imported with `use Enum::*` are referenced without their enum, which
+        // only C allows.
+        let qualified;
+        if let (
+            Literal::Path {
+                associated_to: None,
+                name: variant,
+            },
+            Type::Path(generic),
+        ) = (value, &self.ty)
+        {
+            if out
+                .bindings()
+                .enum_variant_reference(generic.path(), variant)
+                .is_some()
+            {
+                qualified = Literal::Path {
+                    associated_to: Some((generic.path().clone(), generic.export_name().to_owned())),
+                    name: variant.clone(),
+                };
+                value = &qualified;
+            }
+        }
+
         language_backend.write_documentation(out, self.documentation());
 
         let allow_constexpr = config.constant.allow_constexpr && self.value.can_be_constexpr();
         match config.language {
+            Language::C | Language::Cxx | Language::Cython | Language::CSharp | Language::Zig
+                if matches!(*value, Literal::Bytes { .. }) =>
+            {
+                self.write_bytes(config, language_backend, out, &name, in_body);
+            }
             Language::Cxx if config.constant.allow_static_const || allow_constexpr => {
                 if allow_constexpr {
                     out.write("constexpr ")
@@ -812,8 +1125,216 @@ impl Constant {
                 write!(out, " # = ");
                 language_backend.write_literal(out, value);
             }
+            Language::CSharp => {
+                // Only primitive types can be compile-time constants in C#, and
+                // `Int128`, `UInt128` and `Half` are structs.
+                match self.ty {
+                    Type::Primitive(
+                        PrimitiveType::Float16
+                        | PrimitiveType::Float128
+                        | PrimitiveType::Integer {
+                            kind: IntKind::B128,
+                            ..
+                        },
+                    ) => {
+                        out.write("public static readonly ");
+                        language_backend.write_type(out, &self.ty);
+                        write!(out, " {name} = (");
+                        // Nothing converts to them implicitly.
+                        language_backend.write_type(out, &self.ty);
+                        out.write(")(");
+                        language_backend.write_literal(out, value);
+                        out.write(")");
+                    }
+                    // Pointers can't be constant, so neither can values cast from them.
+                    Type::Primitive(ref prim) if value.can_be_constexpr() => {
+                        out.write("public const ");
+                        language_backend.write_type(out, &self.ty);
+                        write!(out, " {name} = ");
+                        match (prim, value) {
+                            // Unsuffixed floating point literals are doubles in C#.
+                            (PrimitiveType::Float, _) => {
+                                out.write("(float)");
+                                language_backend.write_literal(out, value);
+                            }
+                            // Integer expressions have the type of their operands, which
+                            // doesn't always convert to the constant's type implicitly.
+                            (
+                                PrimitiveType::Integer { .. },
+                                Literal::Expr(..)
+                                | Literal::PostfixUnaryOp { op: "-", .. }
+                                | Literal::Cast { .. },
+                            ) => language_backend.write_literal(out, value),
+                            (PrimitiveType::Integer { .. }, _) => {
+                                let value = Literal::Cast {
+                                    ty: self.ty.clone(),
+                                    value: Box::new(value.clone()),
+                                };
+                                language_backend.write_literal(out, &value);
+                            }
+                            _ => language_backend.write_literal(out, value),
+                        }
+                    }
+                    _ => {
+                        out.write("public static readonly ");
+                        language_backend.write_type(out, &self.ty);
+                        write!(out, " {name} = ");
+                        // The backend writes array and struct literals with the types
+                        // of their items and fields.
+                        let value = match *value {
+                            Literal::Cast { .. } => value.clone(),
+                            _ => Literal::Cast {
+                                ty: self.ty.clone(),
+                                value: Box::new(value.clone()),
+                            },
+                        };
+                        language_backend.write_literal(out, &value);
+                    }
+                }
+                write!(out, ";");
+            }
+            Language::Cffi => {
+                // The backend evaluates the value, which it needs the type of to wrap
+                // around negative values of unsigned constants.
+                write!(out, "#define {name} ");
+                let value = Literal::Cast {
+                    ty: self.ty.clone(),
+                    value: Box::new(value.clone()),
+                };
+                language_backend.write_literal(out, &value);
+            }
+            Language::Java => {
+                // Java has no unsigned types, so the backend evaluates the value to
+                // reinterpret it as the signed type of the same width.
+                out.write("public static final ");
+                language_backend.write_type(out, &self.ty);
+                write!(out, " {name} = ");
+                let value = Literal::Cast {
+                    ty: self.ty.clone(),
+                    value: Box::new(value.clone()),
+                };
+                language_backend.write_literal(out, &value);
+                write!(out, ";");
+            }
+            Language::Rust => {
+                // The backend evaluates integer values, as Rust doesn't convert
+                // between integer types implicitly like C.
+                write!(out, "pub const {name}: ");
+                language_backend.write_type(out, &self.ty);
+                write!(out, " = ");
+                let value = Literal::Cast {
+                    ty: self.ty.clone(),
+                    value: Box::new(value.clone()),
+                };
+                language_backend.write_literal(out, &value);
+                write!(out, ";");
+            }
+            Language::Zig => {
+                write!(out, "pub const {name}: ");
+                language_backend.write_type(out, &self.ty);
+                write!(out, " = ");
+                language_backend.write_literal(out, value);
+                write!(out, ";");
+            }
         }
 
         condition.write_after(config, out);
     }
+
+    /// Writes a byte string or C string constant, as a string literal or as an array of bytes,
+    /// as C can't initialize an array of `uint8_t` from a string literal.
+    fn write_bytes<F: Write, LB: LanguageBackend>(
+        &self,
+        config: &Config,
+        language_backend: &mut LB,
+        out: &mut SourceWriter<F>,
+        name: &str,
+        in_body: bool,
+    ) {
+        let Literal::Bytes { ref value, c_str } = self.value else {
+            unreachable!("Not a byte string")
+        };
+        let bytes = || Literal::Array {
+            items: value
+                .iter()
+                .map(|byte| Literal::Expr(byte.to_string()))
+                .collect(),
+        };
+        let is_array = matches!(self.ty, Type::Array(..));
+        match config.language {
+            Language::Cxx
+                if config.constant.allow_static_const || config.constant.allow_constexpr =>
+            {
+                if config.constant.allow_constexpr {
+                    out.write("constexpr ");
+                }
+                if config.constant.allow_static_const {
+                    out.write(if in_body { "inline " } else { "static " });
+                }
+                out.write("const ");
+                if c_str {
+                    write!(out, "std::string_view {name} = ");
+                    language_backend.write_literal(out, &self.value);
+                } else {
+                    let array = match self.ty {
+                        Type::Ptr { ref ty, .. } => ty,
+                        ref ty => ty,
+                    };
+                    crate::bindgen::cdecl::write_field(language_backend, out, array, name, config);
+                    write!(out, " = ");
+                    language_backend.write_literal(out, &bytes());
+                }
+                write!(out, ";");
+            }
+            Language::C if is_array => {
+                out.write("static const ");
+                crate::bindgen::cdecl::write_field(language_backend, out, &self.ty, name, config);
+                write!(out, " = ");
+                language_backend.write_literal(out, &bytes());
+                write!(out, ";");
+            }
+            Language::C | Language::Cxx => {
+                write!(out, "#define {name} ");
+                language_backend.write_literal(out, &self.value);
+            }
+            Language::Cython => {
+                if is_array {
+                    out.write("const ");
+                    crate::bindgen::cdecl::write_field(
+                        language_backend,
+                        out,
+                        &self.ty,
+                        name,
+                        config,
+                    );
+                } else {
+                    write!(out, "const char *{name}");
+                }
+                write!(out, " # = ");
+                language_backend.write_literal(out, &self.value);
+            }
+            Language::CSharp => {
+                write!(out, "public static readonly byte[] {name} = ");
+                language_backend.write_literal(out, &self.value);
+                write!(out, ";");
+            }
+            Language::Zig => {
+                // String literals are pointers to nul-terminated arrays, which coerce to
+                // pointers to arrays and to C strings.
+                write!(out, "pub const {name}: ");
+                if c_str {
+                    out.write("[*:0]const u8");
+                } else {
+                    language_backend.write_type(out, &self.ty);
+                }
+                write!(out, " = ");
+                language_backend.write_literal(out, &self.value);
+                if is_array {
+                    out.write(".*");
+                }
+                write!(out, ";");
+            }
+            _ => unreachable!("Byte strings are written as casts in {:?}", config.language),
+        }
+    }
 }
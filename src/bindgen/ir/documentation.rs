@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::HashMap;
+
 use crate::bindgen::utilities::SynAttributeHelpers;
 
 #[derive(Debug, Clone)]
@@ -31,4 +33,351 @@ impl Documentation {
             doc_comment: Vec::new(),
         }
     }
+
+    /// Replaces the rustdoc intra-doc links, like ``[`Foo::bar`]``, by their text, where Rust paths
+    /// are replaced by the exported names of `names`, which maps paths like `Foo` or `Foo::bar`
+    /// to them. The definitions of reference links to Rust paths are removed. `self_name` is the
+    /// Rust name of the type `Self` refers to, if any.
+    pub fn resolve_links(&mut self, names: &HashMap<String, String>, self_name: Option<&str>) {
+        let mut links = Links {
+            names,
+            self_name,
+            definitions: HashMap::new(),
+        };
+        for (line, in_code) in with_code_blocks(&self.doc_comment) {
+            if let (false, Some((label, target))) = (in_code, reference_definition(line)) {
+                links
+                    .definitions
+                    .insert(label.to_owned(), target.to_owned());
+            }
+        }
+
+        let mut lines = Vec::with_capacity(self.doc_comment.len());
+        for (line, in_code) in with_code_blocks(&self.doc_comment) {
+            if in_code {
+                lines.push(line.clone());
+            } else if reference_definition(line).is_none() {
+                lines.push(links.resolve_line(line));
+            }
+        }
+        if !links.definitions.is_empty() {
+            while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+        }
+        self.doc_comment = lines;
+    }
+
+    /// The lines of the documentation with Doxygen commands: the items of an `# Arguments`
+    /// section become `@param`s, `# Returns` and `# Safety` sections become `@return` and
+    /// `@warning`, and code blocks are put between `@code` and `@endcode`, without the lines
+    /// rustdoc hides.
+    pub fn to_doxygen(&self) -> Vec<String> {
+        #[derive(PartialEq)]
+        enum Section {
+            Arguments,
+            Returns,
+            Safety,
+            Other,
+        }
+
+        let mut lines = Vec::with_capacity(self.doc_comment.len());
+        let mut section = Section::Other;
+        // Whether the section has no text yet, after its heading.
+        let mut section_start = false;
+        // Whether the lines are in a code block, and whether it's Rust code.
+        let mut code_block = None;
+        for line in &self.doc_comment {
+            let text = line.strip_prefix(' ').unwrap_or(line);
+            if let Some(is_rust) = code_block {
+                if text.trim_start().starts_with("```") {
+                    lines.push(" @endcode".to_owned());
+                    code_block = None;
+                } else if !(is_rust && is_hidden_line(text)) {
+                    lines.push(line.clone());
+                }
+                continue;
+            }
+            if let Some(info) = text.trim_start().strip_prefix("```") {
+                lines.push(" @code".to_owned());
+                code_block = Some(is_rust_code_block(info));
+                section_start = false;
+                continue;
+            }
+            if text.starts_with('#') {
+                section = match text.trim_start_matches('#').trim() {
+                    "Arguments" | "Parameters" => Section::Arguments,
+                    "Returns" => Section::Returns,
+                    "Safety" => Section::Safety,
+                    _ => Section::Other,
+                };
+                if section != Section::Other {
+                    section_start = true;
+                    continue;
+                }
+            }
+            if section_start && text.trim().is_empty() {
+                continue;
+            }
+            let command = match section {
+                Section::Returns if section_start => "@return",
+                Section::Safety if section_start => "@warning",
+                Section::Arguments => match argument(text) {
+                    Some((name, description)) => {
+                        lines.push(format!(" @param {name} {description}"));
+                        section_start = false;
+                        continue;
+                    }
+                    None => "",
+                },
+                _ => "",
+            };
+            section_start = false;
+            if command.is_empty() {
+                lines.push(line.clone());
+            } else {
+                lines.push(format!(" {command} {}", text.trim()));
+            }
+        }
+        lines
+    }
+}
+
+/// The lines, along with whether they're part of a code block.
+fn with_code_blocks(lines: &[String]) -> impl Iterator<Item = (&String, bool)> {
+    let mut in_code = false;
+    lines.iter().map(move |line| {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            return (line, true);
+        }
+        (line, in_code)
+    })
+}
+
+/// The kinds rustdoc accepts as a prefix of links to disambiguate them, like `struct@Foo`.
+const DISAMBIGUATORS: &[&str] = &[
+    "struct",
+    "enum",
+    "union",
+    "trait",
+    "type",
+    "fn",
+    "function",
+    "method",
+    "mod",
+    "module",
+    "const",
+    "constant",
+    "static",
+    "value",
+    "variant",
+    "field",
+    "macro",
+    "prim",
+    "primitive",
+    "derive",
+];
+
+/// The Rust path an intra-doc link points to, like `crate::Foo` for `struct@crate::Foo`, if
+/// it's one rather than a URL.
+fn link_path(target: &str) -> Option<&str> {
+    let target = match target.split_once('@') {
+        Some((kind, path)) if DISAMBIGUATORS.contains(&kind) => path,
+        Some(..) => return None,
+        None => target,
+    };
+    let target = target
+        .strip_suffix("()")
+        .or_else(|| target.strip_suffix('!'))
+        .unwrap_or(target);
+    let is_path = target.split("::").all(|segment| {
+        segment.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+    });
+    is_path.then_some(target)
+}
+
+/// The label and target of a definition of a reference link to a Rust path, like
+/// ``[`Foo`]: crate::Foo``.
+fn reference_definition(line: &str) -> Option<(&str, &str)> {
+    let (label, target) = line.trim_start().strip_prefix('[')?.split_once("]:")?;
+    let target = target.trim();
+    link_path(target.trim_matches('`'))?;
+    Some((label, target))
+}
+
+/// Whether a line of a Rust code block is hidden by rustdoc, like `# use foo::Bar;`.
+fn is_hidden_line(text: &str) -> bool {
+    let text = text.trim_start();
+    text == "#" || text.starts_with("# ")
+}
+
+/// Whether a code block with the given info string is Rust code, which is the default.
+fn is_rust_code_block(info: &str) -> bool {
+    info.split([',', ' '])
+        .filter(|a| !a.is_empty())
+        .all(|attribute| {
+            matches!(
+                attribute,
+                "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail" | "test_harness"
+            ) || attribute.starts_with("edition")
+        })
+}
+
+/// The name and description of an item of an `# Arguments` list, like ``* `x` - The abscissa.``
+fn argument(text: &str) -> Option<(&str, &str)> {
+    let item = text
+        .trim_start()
+        .strip_prefix(['*', '-', '+'])?
+        .trim_start();
+    let (name, rest) = match item.strip_prefix('`') {
+        Some(item) => item.split_once('`')?,
+        None => item.split_at(
+            item.find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(item.len()),
+        ),
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    let description = rest
+        .trim_start()
+        .trim_start_matches(['-', ':'])
+        .trim_start();
+    Some((name, description))
+}
+
+struct Links<'a> {
+    names: &'a HashMap<String, String>,
+    self_name: Option<&'a str>,
+    /// The targets of reference links, by label.
+    definitions: HashMap<String, String>,
+}
+
+impl Links<'_> {
+    /// The exported name of the item at `path`. Modules are ignored, as items are exported
+    /// under their own name.
+    fn resolve(&self, path: &str) -> Option<&str> {
+        let mut segments: Vec<_> = path
+            .split("::")
+            .skip_while(|s| matches!(*s, "crate" | "self" | "super"))
+            .collect();
+        if segments.first() == Some(&"Self") {
+            segments[0] = self.self_name?;
+        }
+        if let [.., parent, member] = segments[..] {
+            if let Some(name) = self.names.get(&format!("{parent}::{member}")) {
+                return Some(name);
+            }
+            // Members of types which aren't exported, like methods.
+            if parent.starts_with(char::is_uppercase) {
+                return None;
+            }
+        }
+        self.names.get(*segments.last()?).map(String::as_str)
+    }
+
+    /// The text of a link to `target`, with a Rust path replaced by its exported name. A text
+    /// which is the end of the target path, like `len` for `Self::len`, is resolved as the
+    /// target.
+    fn text(&self, text: &str, target: &str) -> String {
+        let (is_code, inner) = match text.strip_prefix('`').and_then(|t| t.strip_suffix('`')) {
+            Some(inner) => (true, inner),
+            None => (false, text),
+        };
+        let Some(path) = link_path(inner) else {
+            return text.to_owned();
+        };
+        let path = match link_path(target) {
+            Some(target) if target.ends_with(&format!("::{path}")) => target,
+            _ => path,
+        };
+        let name = self.resolve(path).unwrap_or(path);
+        if is_code {
+            format!("`{name}`")
+        } else {
+            name.to_owned()
+        }
+    }
+
+    /// The text and length of the intra-doc link at the start of `tail`, if there's one.
+    /// `after_word` is whether it directly follows a word, like an index in `a[i]`.
+    fn link(&self, tail: &str, after_word: bool) -> Option<(String, usize)> {
+        let close = closing_bracket(tail)?;
+        let text = &tail[1..close];
+        let after = &tail[close + 1..];
+        let (target, len) = if let Some(inner) = after.strip_prefix('(') {
+            let end = inner.find(')')?;
+            (inner[..end].trim(), close + end + 3)
+        } else if let Some(inner) = after.strip_prefix('[') {
+            let end = inner.find(']')?;
+            let label = if end == 0 { text } else { &inner[..end] };
+            let target = self.definitions.get(label).map_or(label, String::as_str);
+            (target, close + end + 3)
+        } else if after_word {
+            return None;
+        } else {
+            let target = self.definitions.get(text).map_or(text, String::as_str);
+            (target, close + 1)
+        };
+        let target = target.trim_matches('`');
+        link_path(target)?;
+        Some((self.text(text, target), len))
+    }
+
+    fn resolve_line(&self, line: &str) -> String {
+        let mut out = String::with_capacity(line.len());
+        let mut rest = line;
+        while let Some(start) = rest.find(['[', '`']) {
+            out.push_str(&rest[..start]);
+            let tail = &rest[start..];
+            if tail.starts_with('`') {
+                // Code spans are kept as they are.
+                let end = code_span_end(tail);
+                out.push_str(&tail[..end]);
+                rest = &tail[end..];
+                continue;
+            }
+            let after_word = out
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | ']' | '!'));
+            match self.link(tail, after_word) {
+                Some((text, len)) => {
+                    out.push_str(&text);
+                    rest = &tail[len..];
+                }
+                None => {
+                    out.push('[');
+                    rest = &tail[1..];
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// The length of the code span at the start of `text`, or of `text` if it isn't closed.
+fn code_span_end(text: &str) -> usize {
+    let ticks = text.len() - text.trim_start_matches('`').len();
+    match text[ticks..].find(&text[..ticks]) {
+        Some(end) => ticks + end + ticks,
+        None => text.len(),
+    }
+}
+
+/// The position of the bracket closing the one at the start of `text`, skipping code spans.
+fn closing_bracket(text: &str) -> Option<usize> {
+    let mut i = 1;
+    while i < text.len() {
+        match text.as_bytes()[i] {
+            b']' => return Some(i),
+            b'[' => return None,
+            b'`' => i += code_span_end(&text[i..]),
+            _ => i += 1,
+        }
+    }
+    None
 }
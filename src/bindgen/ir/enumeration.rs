@@ -131,14 +131,14 @@ impl EnumVariant {
             for (i, field) in fields.iter().enumerate() {
                 if let Some(mut ty) = Type::load(&field.ty)? {
                     ty.replace_self_with(self_path);
+                    let rust_name = match field.ident {
+                        Some(ref ident) => ident.unraw().to_string(),
+                        None => i.to_string(),
+                    };
                     res.push(Field {
-                        name: inline_name.map_or_else(
-                            || match field.ident {
-                                Some(ref ident) => ident.unraw().to_string(),
-                                None => i.to_string(),
-                            },
-                            |name| name.to_string(),
-                        ),
+                        name: inline_name
+                            .map_or_else(|| rust_name.clone(), |name| name.to_string()),
+                        rust_name: Some(rust_name),
                         ty,
                         cfg: Cfg::load(&field.attrs),
                         annotations: AnnotationSet::load(&field.attrs)?,
@@ -744,6 +744,12 @@ impl Enum {
                     write!(out, "{}enum {}", config.style.cython_def(), tag_name);
                 }
             }
+            Language::Zig | Language::CSharp | Language::Cffi | Language::Java | Language::Rust => {
+                unreachable!(
+                    "{:?} enums are written by their own backend",
+                    config.language
+                )
+            }
         }
         out.open_brace();
 
@@ -820,6 +826,12 @@ impl Enum {
             Language::C if config.style.generate_typedef() => out.write("typedef "),
             Language::C | Language::Cxx => {}
             Language::Cython => out.write(config.style.cython_def()),
+            Language::Zig | Language::CSharp | Language::Cffi | Language::Java | Language::Rust => {
+                unreachable!(
+                    "{:?} enums are written by their own backend",
+                    config.language
+                )
+            }
         }
 
         out.write(if inline_tag_field { "union" } else { "struct" });
@@ -6,6 +6,9 @@ use crate::bindgen::ir::{Documentation, Path, Type};
 #[derive(Debug, Clone)]
 pub struct Field {
     pub name: String,
+    /// The name of the field in the Rust source, before any renaming, or `None` for fields
+    /// cbindgen makes up, like the tags of enums.
+    pub rust_name: Option<String>,
     pub ty: Type,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
@@ -16,6 +19,7 @@ impl Field {
     pub fn from_name_and_type(name: String, ty: Type) -> Field {
         Field {
             name,
+            rust_name: None,
             ty,
             cfg: None,
             annotations: AnnotationSet::new(),
@@ -26,13 +30,15 @@ impl Field {
     pub fn load(field: &syn::Field, self_path: &Path) -> Result<Option<Field>, String> {
         Ok(if let Some(mut ty) = Type::load(&field.ty)? {
             ty.replace_self_with(self_path);
+            let name = field
+                .ident
+                .as_ref()
+                .ok_or_else(|| "field is missing identifier".to_string())?
+                .unraw()
+                .to_string();
             Some(Field {
-                name: field
-                    .ident
-                    .as_ref()
-                    .ok_or_else(|| "field is missing identifier".to_string())?
-                    .unraw()
-                    .to_string(),
+                rust_name: Some(name.clone()),
+                name,
                 ty,
                 cfg: Cfg::load(&field.attrs),
                 annotations: AnnotationSet::load(&field.attrs)?,
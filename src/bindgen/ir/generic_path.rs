@@ -234,6 +234,14 @@ impl GenericArgument {
     }
 }
 
+/// The path tuple types are rewritten to when `layout.tuples` is enabled, so
+/// that `(A, B)` is loaded as `__cbindgen_tuple<A, B>`.
+pub const TUPLE_PATH: &str = "__cbindgen_tuple";
+/// The paths slices are rewritten to when `layout.slices` is enabled, so that
+/// `&[T]` is loaded as `__cbindgen_slice<T>`, and `&str` as `__cbindgen_slice<u8>`.
+pub const SLICE_PATH: &str = "__cbindgen_slice";
+pub const MUT_SLICE_PATH: &str = "__cbindgen_slice_mut";
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct GenericPath {
     path: Path,
@@ -244,7 +252,13 @@ pub struct GenericPath {
 
 impl GenericPath {
     pub fn new(path: Path, generics: Vec<GenericArgument>) -> Self {
-        let export_name = path.name().to_owned();
+        let export_name = match path.name() {
+            TUPLE_PATH => "Tuple",
+            SLICE_PATH => "Slice",
+            MUT_SLICE_PATH => "SliceMut",
+            name => name,
+        }
+        .to_owned();
         Self {
             path,
             export_name,
@@ -292,10 +306,31 @@ impl GenericPath {
         self.generics.is_empty()
     }
 
+    /// Whether this is a tuple type, which gets instantiated as a `Tuple_*` struct.
+    pub fn is_tuple(&self) -> bool {
+        self.path.name() == TUPLE_PATH
+    }
+
+    /// Whether this is a slice type, which gets instantiated as a `Slice_*` or
+    /// `SliceMut_*` struct.
+    pub fn is_slice(&self) -> bool {
+        self.path.name() == SLICE_PATH || self.is_mut_slice()
+    }
+
+    pub fn is_mut_slice(&self) -> bool {
+        self.path.name() == MUT_SLICE_PATH
+    }
+
     pub fn rename_for_config(&mut self, config: &Config, generic_params: &GenericParams) {
         for generic in &mut self.generics {
             generic.rename_for_config(config, generic_params);
         }
+        if self.is_slice() && config.language == Language::Cxx {
+            if let Some(ref cpp_slice) = config.layout.cpp_slice {
+                cpp_slice.clone_into(&mut self.export_name);
+                return;
+            }
+        }
         if !generic_params.iter().any(|param| param.name == self.path) {
             config.export.rename(&mut self.export_name);
         }
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use crate::bindgen::config::Config;
+use crate::bindgen::config::{Config, OpaqueStorage};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
@@ -20,6 +20,9 @@ pub struct OpaqueItem {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    /// The size and alignment to give the item, so that it can be allocated from C without
+    /// knowing its fields, from `cbindgen:opaque-storage=[size, align]` or the config.
+    pub storage: Option<OpaqueStorage>,
 }
 
 impl OpaqueItem {
@@ -46,6 +49,7 @@ impl OpaqueItem {
         documentation: Documentation,
     ) -> OpaqueItem {
         let export_name = path.name().to_owned();
+        let storage = Self::load_storage(&path, &annotations);
         Self {
             path,
             export_name,
@@ -53,6 +57,35 @@ impl OpaqueItem {
             cfg,
             annotations,
             documentation,
+            storage,
+        }
+    }
+
+    fn load_storage(path: &Path, annotations: &AnnotationSet) -> Option<OpaqueStorage> {
+        let list = annotations.list("opaque-storage")?;
+        let storage = match list[..] {
+            [ref size, ref align] => match (size.parse(), align.parse()) {
+                (Ok(size), Ok(align)) => OpaqueStorage { size, align },
+                _ => {
+                    warn!("Ignoring opaque storage of {path}: expected integers, got {list:?}.");
+                    return None;
+                }
+            },
+            _ => {
+                warn!("Ignoring opaque storage of {path}: expected `[size, align]`, got {list:?}.");
+                return None;
+            }
+        };
+        Self::checked_storage(path, storage)
+    }
+
+    fn checked_storage(path: &Path, storage: OpaqueStorage) -> Option<OpaqueStorage> {
+        match storage.validate() {
+            Ok(()) => Some(storage),
+            Err(msg) => {
+                warn!("Ignoring opaque storage of {path}: {msg}.");
+                None
+            }
         }
     }
 }
@@ -95,6 +128,9 @@ impl Item for OpaqueItem {
     }
 
     fn rename_for_config(&mut self, config: &Config) {
+        if let Some(storage) = config.export.opaque_storage(&self.path) {
+            self.storage = Self::checked_storage(&self.path, storage);
+        }
         config.export.rename(&mut self.export_name);
     }
 
@@ -31,6 +31,7 @@ impl ReprType {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ReprAlign {
     Packed,
+    PackedN(u64),
     Align(u64),
 }
 
@@ -97,11 +98,13 @@ impl Repr {
                 ("u16", None) => (IntKind::B16, false),
                 ("u32", None) => (IntKind::B32, false),
                 ("u64", None) => (IntKind::B64, false),
+                ("u128", None) => (IntKind::B128, false),
                 ("usize", None) => (IntKind::Size, false),
                 ("i8", None) => (IntKind::B8, true),
                 ("i16", None) => (IntKind::B16, true),
                 ("i32", None) => (IntKind::B32, true),
                 ("i64", None) => (IntKind::B64, true),
+                ("i128", None) => (IntKind::B128, true),
                 ("isize", None) => (IntKind::Size, true),
                 ("C", None) => {
                     repr.style = ReprStyle::C;
@@ -112,14 +115,28 @@ impl Repr {
                     continue;
                 }
                 ("packed", args) => {
-                    // #[repr(packed(n))] not supported because of some open questions about how
-                    // to calculate the native alignment of types. See mozilla/cbindgen#433.
-                    if args.is_some() {
-                        return Err(
-                            "Not-yet-implemented #[repr(packed(...))] encountered.".to_string()
-                        );
-                    }
-                    let align = ReprAlign::Packed;
+                    let align = match args {
+                        None => ReprAlign::Packed,
+                        Some(arg) => {
+                            // Must be a positive integer.
+                            let pack = match arg.parse::<u64>() {
+                                Ok(pack) => pack,
+                                Err(_) => {
+                                    return Err(format!("Non-unsigned #[repr(packed({arg}))]."))
+                                }
+                            };
+                            // Must be a power of 2.
+                            if !pack.is_power_of_two() {
+                                return Err(format!("Invalid packing to #[repr(packed({pack}))]."));
+                            }
+                            // #[repr(packed(1))] is the same as #[repr(packed)].
+                            if pack == 1 {
+                                ReprAlign::Packed
+                            } else {
+                                ReprAlign::PackedN(pack)
+                            }
+                        }
+                    };
                     // Only permit a single alignment-setting repr.
                     if let Some(old_align) = repr.align {
                         return Err(format!(
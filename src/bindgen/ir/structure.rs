@@ -80,11 +80,12 @@ impl Struct {
             syn::Fields::Unnamed(ref fields) => {
                 let mut out = Vec::new();
                 let mut current = 0;
-                for field in fields.unnamed.iter() {
+                for (i, field) in fields.unnamed.iter().enumerate() {
                     if let Some(mut ty) = Type::load(&field.ty)? {
                         ty.replace_self_with(&path);
                         out.push(Field {
                             name: format!("{current}"),
+                            rust_name: Some(format!("{i}")),
                             ty,
                             cfg: Cfg::load(&field.attrs),
                             annotations: AnnotationSet::load(&field.attrs)?,
@@ -206,6 +207,7 @@ impl Struct {
                 .iter()
                 .map(|field| Field {
                     name: field.name.clone(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.specialize(mappings),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
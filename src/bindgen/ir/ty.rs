@@ -4,6 +4,7 @@
 
 use std::borrow::Cow;
 
+use quote::ToTokens;
 use syn::ext::IdentExt;
 
 use crate::bindgen::config::{Config, Language};
@@ -24,6 +25,8 @@ pub enum PrimitiveType {
     Char32,
     Float,
     Double,
+    Float16,
+    Float128,
     VaList,
     PtrDiffT,
     Integer {
@@ -45,6 +48,7 @@ pub enum IntKind {
     B16,
     B32,
     B64,
+    B128,
 }
 
 impl PrimitiveType {
@@ -61,8 +65,10 @@ impl PrimitiveType {
             "bool" => PrimitiveType::Bool,
             "char" => PrimitiveType::Char32,
 
+            "f16" => PrimitiveType::Float16,
             "f32" => PrimitiveType::Float,
             "f64" => PrimitiveType::Double,
+            "f128" => PrimitiveType::Float128,
 
             _ => return Self::maybe_integer(path),
         })
@@ -90,10 +96,12 @@ impl PrimitiveType {
             "u16" | "uint16_t" => (IntKind::B16, false),
             "u32" | "uint32_t" => (IntKind::B32, false),
             "u64" | "uint64_t" => (IntKind::B64, false),
+            "u128" => (IntKind::B128, false),
             "i8" | "int8_t" => (IntKind::B8, true),
             "i16" | "int16_t" => (IntKind::B16, true),
             "i32" | "int32_t" => (IntKind::B32, true),
             "i64" | "int64_t" => (IntKind::B64, true),
+            "i128" => (IntKind::B128, true),
 
             _ => return Self::maybe_nonzero_integer(path),
         };
@@ -114,11 +122,13 @@ impl PrimitiveType {
             "NonZeroU16" => (IntKind::B16, false),
             "NonZeroU32" => (IntKind::B32, false),
             "NonZeroU64" => (IntKind::B64, false),
+            "NonZeroU128" => (IntKind::B128, false),
             "NonZeroUSize" => (IntKind::Size, false),
             "NonZeroI8" => (IntKind::B8, true),
             "NonZeroI16" => (IntKind::B16, true),
             "NonZeroI32" => (IntKind::B32, true),
             "NonZeroI64" => (IntKind::B64, true),
+            "NonZeroI128" => (IntKind::B128, true),
             "NonZeroISize" => (IntKind::Size, true),
 
             _ => return None,
@@ -163,15 +173,19 @@ impl PrimitiveType {
                 (IntKind::B32, false) => "u32",
                 (IntKind::B64, true) => "i64",
                 (IntKind::B64, false) => "u64",
+                (IntKind::B128, true) => "i128",
+                (IntKind::B128, false) => "u128",
             },
             PrimitiveType::Float => "f32",
             PrimitiveType::Double => "f64",
+            PrimitiveType::Float16 => "f16",
+            PrimitiveType::Float128 => "f128",
             PrimitiveType::PtrDiffT => "ptrdiff_t",
             PrimitiveType::VaList => "va_list",
         }
     }
 
-    pub fn to_repr_c(&self, config: &Config) -> &'static str {
+    pub fn to_repr_c<'a>(&self, config: &'a Config) -> &'a str {
         match *self {
             PrimitiveType::Void => "void",
             PrimitiveType::Bool => "bool",
@@ -214,14 +228,177 @@ impl PrimitiveType {
                 (IntKind::B32, false) => "uint32_t",
                 (IntKind::B64, true) => "int64_t",
                 (IntKind::B64, false) => "uint64_t",
+                // There's no standard spelling for these, and Cython can't parse the usual ones,
+                // so it gets them from a `ctypedef`, see `CythonLanguageBackend::write_headers`.
+                (IntKind::B128, true) if config.language == Language::Cython => "int128_t",
+                (IntKind::B128, false) if config.language == Language::Cython => "uint128_t",
+                (IntKind::B128, true) => &config.primitive.int128,
+                (IntKind::B128, false) => &config.primitive.uint128,
             },
             PrimitiveType::Float => "float",
             PrimitiveType::Double => "double",
+            PrimitiveType::Float16 if config.language == Language::Cython => "float16_t",
+            PrimitiveType::Float128 if config.language == Language::Cython => "float128_t",
+            PrimitiveType::Float16 => &config.primitive.float16,
+            PrimitiveType::Float128 => &config.primitive.float128,
             PrimitiveType::PtrDiffT => "ptrdiff_t",
             PrimitiveType::VaList => "...",
         }
     }
 
+    pub fn to_repr_zig(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "c_char",
+            PrimitiveType::SChar => "i8",
+            PrimitiveType::UChar => "u8",
+            PrimitiveType::Char32 => "u32",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match (kind, signed) {
+                (IntKind::Short, true) => "c_short",
+                (IntKind::Short, false) => "c_ushort",
+                (IntKind::Int, true) => "c_int",
+                (IntKind::Int, false) => "c_uint",
+                (IntKind::Long, true) => "c_long",
+                (IntKind::Long, false) => "c_ulong",
+                (IntKind::LongLong, true) => "c_longlong",
+                (IntKind::LongLong, false) => "c_ulonglong",
+                (IntKind::SizeT, true) | (IntKind::Size, true) => "isize",
+                (IntKind::SizeT, false) | (IntKind::Size, false) => "usize",
+                (IntKind::B8, true) => "i8",
+                (IntKind::B8, false) => "u8",
+                (IntKind::B16, true) => "i16",
+                (IntKind::B16, false) => "u16",
+                (IntKind::B32, true) => "i32",
+                (IntKind::B32, false) => "u32",
+                (IntKind::B64, true) => "i64",
+                (IntKind::B64, false) => "u64",
+                (IntKind::B128, true) => "i128",
+                (IntKind::B128, false) => "u128",
+            },
+            PrimitiveType::Float => "f32",
+            PrimitiveType::Double => "f64",
+            PrimitiveType::Float16 => "f16",
+            PrimitiveType::Float128 => "f128",
+            PrimitiveType::PtrDiffT => "isize",
+            PrimitiveType::VaList => "std.builtin.VaList",
+        }
+    }
+
+    pub fn to_repr_csharp(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "byte",
+            PrimitiveType::SChar => "sbyte",
+            PrimitiveType::UChar => "byte",
+            PrimitiveType::Char32 => "uint",
+            PrimitiveType::Integer {
+                kind,
+                signed,
+                zeroable: _,
+            } => match (kind, signed) {
+                (IntKind::Short, true) | (IntKind::B16, true) => "short",
+                (IntKind::Short, false) | (IntKind::B16, false) => "ushort",
+                (IntKind::Int, true) | (IntKind::B32, true) => "int",
+                (IntKind::Int, false) | (IntKind::B32, false) => "uint",
+                (IntKind::Long, true) => "CLong",
+                (IntKind::Long, false) => "CULong",
+                (IntKind::LongLong, true) | (IntKind::B64, true) => "long",
+                (IntKind::LongLong, false) | (IntKind::B64, false) => "ulong",
+                (IntKind::SizeT, true) | (IntKind::Size, true) => "nint",
+                (IntKind::SizeT, false) | (IntKind::Size, false) => "nuint",
+                (IntKind::B8, true) => "sbyte",
+                (IntKind::B8, false) => "byte",
+                (IntKind::B128, true) => "Int128",
+                (IntKind::B128, false) => "UInt128",
+            },
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::Float16 => "Half",
+            // C# has no 128-bit floating point type, so only the bits are kept.
+            PrimitiveType::Float128 => "UInt128",
+            PrimitiveType::PtrDiffT => "nint",
+            PrimitiveType::VaList => "__arglist",
+        }
+    }
+
+    /// Returns the Java type holding values of this type, assuming an LP64 target.
+    ///
+    /// Java has no unsigned types, so unsigned integers are held in the signed type
+    /// of the same width. It has no 128-bit types or `f16` either, so 128-bit values
+    /// are held in a segment and `f16` values in a `short`, see `Float.float16ToFloat`.
+    pub fn to_repr_java(&self) -> &'static str {
+        match *self {
+            PrimitiveType::Void => "void",
+            PrimitiveType::Bool => "boolean",
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::Float16 => "short",
+            PrimitiveType::VaList | PrimitiveType::Float128 => "MemorySegment",
+            _ => match self.integer_bits() {
+                Some((8, _)) => "byte",
+                Some((16, _)) => "short",
+                Some((32, _)) => "int",
+                Some((128, _)) => "MemorySegment",
+                _ => "long",
+            },
+        }
+    }
+
+    /// Returns the width and signedness of an integer type, assuming an LP64 target.
+    pub(crate) fn integer_bits(&self) -> Option<(u32, bool)> {
+        Some(match *self {
+            PrimitiveType::Bool => (1, false),
+            PrimitiveType::Char | PrimitiveType::SChar => (8, true),
+            PrimitiveType::UChar => (8, false),
+            PrimitiveType::Char32 => (32, false),
+            PrimitiveType::PtrDiffT => (64, true),
+            PrimitiveType::Integer { kind, signed, .. } => {
+                let bits = match kind {
+                    IntKind::B8 => 8,
+                    IntKind::Short | IntKind::B16 => 16,
+                    IntKind::Int | IntKind::B32 => 32,
+                    IntKind::Long
+                    | IntKind::LongLong
+                    | IntKind::SizeT
+                    | IntKind::Size
+                    | IntKind::B64 => 64,
+                    IntKind::B128 => 128,
+                };
+                (bits, signed)
+            }
+            PrimitiveType::Void
+            | PrimitiveType::Float
+            | PrimitiveType::Double
+            | PrimitiveType::Float16
+            | PrimitiveType::Float128
+            | PrimitiveType::VaList => return None,
+        })
+    }
+
+    /// Converts `value` to this integer type like a C cast would.
+    ///
+    /// `u128` values above `i128::MAX` don't fit, so they're held as their bit pattern.
+    pub(crate) fn wrap(&self, value: i128) -> Option<i128> {
+        match self.integer_bits()? {
+            // Both are held as their bits, which casting between them keeps.
+            (128, _) => Some(value),
+            (bits, signed) => {
+                let value = value.rem_euclid(1 << bits);
+                if signed && value >= 1 << (bits - 1) {
+                    Some(value - (1 << bits))
+                } else {
+                    Some(value)
+                }
+            }
+        }
+    }
+
     fn can_cmp_order(&self) -> bool {
         !matches!(*self, PrimitiveType::Bool)
     }
@@ -452,10 +629,11 @@ impl Type {
                 }
                 return Err("Tuples are not supported types.".to_owned());
             }
+            syn::Type::Slice(..) => return Err("Slices are not supported types.".to_owned()),
             syn::Type::Verbatim(ref tokens) if tokens.to_string() == "..." => {
                 Type::Primitive(PrimitiveType::VaList)
             }
-            _ => return Err(format!("Unsupported type: {ty:?}")),
+            _ => return Err(format!("Unsupported type: `{}`", ty.to_token_stream())),
         };
 
         Ok(Some(converted))
@@ -519,6 +697,21 @@ impl Type {
     fn simplified_type(&self, config: &Config) -> Option<Self> {
         let path = match *self {
             Type::Path(ref p) => p,
+            // With `layout.c_str`, `&CStr` is passed as a pointer to its first character, and is
+            // nullable as an `Option<&CStr>`.
+            Type::Ptr {
+                ref ty,
+                is_const,
+                is_nullable: false,
+                ..
+            } if config.layout.c_str && ty.is_c_str() => {
+                return Some(Type::Ptr {
+                    ty: Box::new(Type::Primitive(PrimitiveType::Char)),
+                    is_const,
+                    is_nullable: false,
+                    is_ref: false,
+                })
+            }
             _ => return None,
         };
 
@@ -564,6 +757,10 @@ impl Type {
         }
     }
 
+    fn is_c_str(&self) -> bool {
+        matches!(*self, Type::Path(ref path) if path.name() == "CStr" && path.generics().is_empty())
+    }
+
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.visit_types(|ty| ty.simplify_standard_types(config));
         if let Some(ty) = self.simplified_type(config) {
@@ -578,6 +775,32 @@ impl Type {
         self.visit_types(|ty| ty.replace_self_with(self_ty))
     }
 
+    /// Calls `visitor` with this type and every type it's made of, like the pointee of a pointer
+    /// or the arguments of a function pointer.
+    pub(crate) fn for_each_type(&self, visitor: &mut impl FnMut(&Type)) {
+        visitor(self);
+        match *self {
+            Type::Array(ref ty, ..) | Type::Ptr { ref ty, .. } => ty.for_each_type(visitor),
+            Type::Path(ref path) => {
+                for generic in path.generics() {
+                    match *generic {
+                        GenericArgument::Type(ref ty) => ty.for_each_type(visitor),
+                        GenericArgument::Const(_) => {}
+                    }
+                }
+            }
+            Type::Primitive(..) => {}
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => {
+                ret.for_each_type(visitor);
+                for arg in args {
+                    arg.1.for_each_type(visitor);
+                }
+            }
+        }
+    }
+
     fn visit_types(&mut self, mut visitor: impl FnMut(&mut Type)) {
         match *self {
             Type::Array(ref mut ty, ..) | Type::Ptr { ref mut ty, .. } => visitor(ty),
@@ -728,6 +951,22 @@ impl Type {
                 if generic.generics().is_empty() || out.contains(generic) {
                     return;
                 }
+                if !out.instantiates(generic) {
+                    for generic in generic.generics() {
+                        if let GenericArgument::Type(ref ty) = *generic {
+                            ty.add_monomorphs(library, out);
+                        }
+                    }
+                    return;
+                }
+                if generic.is_tuple() {
+                    out.insert_tuple(library, generic);
+                    return;
+                }
+                if generic.is_slice() {
+                    out.insert_slice(library, generic);
+                    return;
+                }
                 let path = generic.path();
                 if let Some(items) = library.get_items(path) {
                     for item in items {
@@ -812,6 +1051,15 @@ impl Type {
                     return;
                 }
 
+                if !monomorphs.instantiates(generic_path) {
+                    for generic in generic_path.generics_mut() {
+                        if let GenericArgument::Type(ref mut ty) = *generic {
+                            ty.mangle_paths(monomorphs);
+                        }
+                    }
+                    return;
+                }
+
                 if let Some(mangled_path) = monomorphs.mangle_path(generic_path) {
                     *generic_path = GenericPath::new(mangled_path.clone(), vec![]);
                 } else {
@@ -180,6 +180,7 @@ impl Item for Union {
                 } else {
                     overriden_fields.push(Field {
                         name: o[i].clone(),
+                        rust_name: field.rust_name.clone(),
                         ty: field.ty.clone(),
                         cfg: field.cfg.clone(),
                         annotations: field.annotations.clone(),
@@ -197,6 +198,7 @@ impl Item for Union {
                     name: r
                         .apply(&field.name, IdentifierType::StructMember)
                         .into_owned(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.clone(),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
@@ -241,6 +243,7 @@ impl Item for Union {
                 .iter()
                 .map(|field| Field {
                     name: field.name.clone(),
+                    rust_name: field.rust_name.clone(),
                     ty: field.ty.specialize(&mappings),
                     cfg: field.cfg.clone(),
                     annotations: field.annotations.clone(),
@@ -0,0 +1,485 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Serialization of the resolved bindings to JSON, see the "JSON output" section of
+//! `docs.md` for the schema.
+
+use serde_json::{json, Map, Value};
+
+use crate::bindgen::ir::{
+    AnnotationSet, AnnotationValue, Cfg, ConstExpr, Constant, Documentation, Enum, EnumVariant,
+    Field, Function, GenericArgument, Item, ItemContainer, Literal, OpaqueItem, PrimitiveType,
+    ReprAlign, ReprStyle, Static, Struct, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::Bindings;
+
+/// The version of the JSON schema, bumped on every incompatible change to it.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+pub(crate) fn bindings(b: &Bindings) -> Value {
+    let constants: Vec<_> = b
+        .constants
+        .iter()
+        .filter(|c| c.value.is_valid(b))
+        .map(|c| constant(c, b))
+        .collect();
+    let globals: Vec<_> = b.globals.iter().map(global).collect();
+    let items: Vec<_> = b
+        .items
+        .iter()
+        .filter(|item| item.deref().annotations().should_export())
+        .map(|item| match *item {
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Enum(ref x) => enumeration(x, b),
+            ItemContainer::Struct(ref x) => structure(x, b),
+            ItemContainer::Union(ref x) => union(x, b),
+            ItemContainer::OpaqueItem(ref x) => opaque_item(x, b),
+            ItemContainer::Typedef(ref x) => typedef(x),
+        })
+        .collect();
+    let functions: Vec<_> = b.functions.iter().map(function).collect();
+
+    json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "package_version": b.package_version,
+        "constants": constants,
+        "globals": globals,
+        "items": items,
+        "functions": functions,
+    })
+}
+
+/// The members shared by items, fields and variants: their kind and names, when
+/// relevant, `#[cfg]`, documentation and annotations.
+fn item(
+    kind: Option<&str>,
+    name: &str,
+    rust_name: Option<&str>,
+    cfg: Option<&Cfg>,
+    annotations: &AnnotationSet,
+    documentation: &Documentation,
+) -> Map<String, Value> {
+    let mut map = Map::new();
+    if let Some(kind) = kind {
+        map.insert("kind".to_owned(), json!(kind));
+    }
+    map.insert("name".to_owned(), json!(name));
+    if let Some(rust_name) = rust_name {
+        map.insert("rust_name".to_owned(), json!(rust_name));
+    }
+    map.insert("cfg".to_owned(), cfg.map_or(Value::Null, self::cfg));
+    map.insert("documentation".to_owned(), json!(documentation.doc_comment));
+    map.insert("annotations".to_owned(), self::annotations(annotations));
+    map.insert("must_use".to_owned(), json!(annotations.must_use));
+    map.insert("deprecated".to_owned(), json!(annotations.deprecated));
+    map
+}
+
+fn cfg(cfg: &Cfg) -> Value {
+    match *cfg {
+        Cfg::Boolean(ref name) => json!({ "name": name }),
+        Cfg::Named(ref name, ref value) => json!({ "name": name, "value": value }),
+        Cfg::Any(ref cfgs) => json!({ "any": cfgs.iter().map(self::cfg).collect::<Vec<_>>() }),
+        Cfg::All(ref cfgs) => json!({ "all": cfgs.iter().map(self::cfg).collect::<Vec<_>>() }),
+        Cfg::Not(ref cfg) => json!({ "not": self::cfg(cfg) }),
+    }
+}
+
+fn annotations(annotations: &AnnotationSet) -> Value {
+    let mut annotations: Vec<_> = annotations.iter().collect();
+    annotations.sort_by_key(|(name, _)| *name);
+    let map: Map<String, Value> = annotations
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match *value {
+                AnnotationValue::List(ref list) => json!(list),
+                AnnotationValue::Atom(ref atom) => json!(atom),
+                AnnotationValue::Bool(b) => json!(b),
+            };
+            (name.clone(), value)
+        })
+        .collect();
+    Value::Object(map)
+}
+
+fn primitive(p: &PrimitiveType) -> Value {
+    let mut value = json!({ "kind": "primitive", "name": p.to_repr_rust() });
+    if let PrimitiveType::Integer { zeroable, .. } = *p {
+        value["nonzero"] = json!(!zeroable);
+    }
+    value
+}
+
+fn ty(t: &Type) -> Value {
+    match *t {
+        Type::Ptr {
+            ref ty,
+            is_const,
+            is_nullable,
+            is_ref,
+        } => json!({
+            "kind": "pointer",
+            "pointee": self::ty(ty),
+            "is_const": is_const,
+            "is_nullable": is_nullable,
+            "is_ref": is_ref,
+        }),
+        Type::Path(ref path) => {
+            let generics: Vec<_> = path
+                .generics()
+                .iter()
+                .map(|generic| match *generic {
+                    GenericArgument::Type(ref t) => self::ty(t),
+                    GenericArgument::Const(ref expr) => const_expr(expr),
+                })
+                .collect();
+            json!({
+                "kind": "path",
+                "name": path.export_name(),
+                "rust_name": path.name(),
+                "generics": generics,
+            })
+        }
+        Type::Primitive(ref p) => primitive(p),
+        Type::Array(ref ty, ref len) => json!({
+            "kind": "array",
+            "element": self::ty(ty),
+            "length": const_expr(len),
+        }),
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            is_nullable,
+            never_return,
+        } => {
+            let args: Vec<_> = args
+                .iter()
+                .map(|(name, t)| json!({ "name": name, "type": self::ty(t) }))
+                .collect();
+            json!({
+                "kind": "function_pointer",
+                "return": self::ty(ret),
+                "args": args,
+                "is_nullable": is_nullable,
+                "never_return": never_return,
+            })
+        }
+    }
+}
+
+fn const_expr(expr: &ConstExpr) -> Value {
+    match *expr {
+        ConstExpr::Path(ref path) => json!({ "kind": "path", "name": path.export_name() }),
+        ConstExpr::Value(ref value) => json!({ "kind": "value", "value": value }),
+    }
+}
+
+fn literal(l: &Literal) -> Value {
+    match *l {
+        Literal::Expr(ref expr) => json!({ "kind": "expr", "value": expr }),
+        Literal::Path {
+            ref associated_to,
+            ref name,
+        } => json!({
+            "kind": "path",
+            "name": name,
+            "associated_to": associated_to.as_ref().map(|(_, export_name)| export_name),
+        }),
+        Literal::PostfixUnaryOp { op, ref value } => json!({
+            "kind": "unary_op",
+            "op": op,
+            "value": literal(value),
+        }),
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => json!({
+            "kind": "binary_op",
+            "left": literal(left),
+            "op": op,
+            "right": literal(right),
+        }),
+        Literal::FieldAccess {
+            ref base,
+            ref field,
+        } => json!({
+            "kind": "field_access",
+            "base": literal(base),
+            "field": field,
+        }),
+        Literal::Struct {
+            ref export_name,
+            ref fields,
+            ..
+        } => {
+            // Sorted, as the fields are kept in a hash map.
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by_key(|(name, _)| *name);
+            let fields: Map<String, Value> = fields
+                .into_iter()
+                .map(|(name, field)| {
+                    let field = json!({
+                        "value": literal(&field.value),
+                        "cfg": field.cfg.as_ref().map_or(Value::Null, cfg),
+                    });
+                    (name.clone(), field)
+                })
+                .collect();
+            json!({ "kind": "struct", "name": export_name, "fields": fields })
+        }
+        Literal::Cast { ref ty, ref value } => json!({
+            "kind": "cast",
+            "type": self::ty(ty),
+            "value": literal(value),
+        }),
+        Literal::Array { ref items } => json!({
+            "kind": "array",
+            "items": items.iter().map(literal).collect::<Vec<_>>(),
+        }),
+        Literal::Bytes { ref value, c_str } => json!({
+            "kind": if c_str { "c_string" } else { "bytes" },
+            "value": value,
+        }),
+    }
+}
+
+fn constant(c: &Constant, b: &Bindings) -> Value {
+    let mut map = item(
+        Some("constant"),
+        c.export_name(),
+        Some(c.path.name()),
+        c.cfg.as_ref(),
+        &c.annotations,
+        &c.documentation,
+    );
+    map.insert("type".to_owned(), ty(&c.ty));
+    map.insert("value".to_owned(), literal(&c.value));
+    let associated_to = c.associated_to.as_ref().map(|path| {
+        let mut name = path.name().to_owned();
+        b.config.export.rename(&mut name);
+        name
+    });
+    map.insert("associated_to".to_owned(), json!(associated_to));
+    Value::Object(map)
+}
+
+fn global(s: &Static) -> Value {
+    let mut map = item(
+        Some("static"),
+        s.export_name(),
+        Some(s.path.name()),
+        s.cfg.as_ref(),
+        &s.annotations,
+        &s.documentation,
+    );
+    map.insert("type".to_owned(), ty(&s.ty));
+    map.insert("mutable".to_owned(), json!(s.mutable));
+    Value::Object(map)
+}
+
+fn function(f: &Function) -> Value {
+    let mut map = item(
+        Some("function"),
+        f.path.name(),
+        Some(f.path.name()),
+        f.cfg.as_ref(),
+        &f.annotations,
+        &f.documentation,
+    );
+    let args: Vec<_> = f
+        .args
+        .iter()
+        .map(|arg| {
+            json!({
+                "name": arg.name,
+                "type": ty(&arg.ty),
+                "array_length": arg.array_length,
+            })
+        })
+        .collect();
+    map.insert("args".to_owned(), json!(args));
+    map.insert("return".to_owned(), ty(&f.ret));
+    map.insert("never_return".to_owned(), json!(f.never_return));
+    Value::Object(map)
+}
+
+fn field(f: &Field) -> Value {
+    let mut map = item(
+        None,
+        &f.name,
+        None,
+        f.cfg.as_ref(),
+        &f.annotations,
+        &f.documentation,
+    );
+    map.insert("type".to_owned(), ty(&f.ty));
+    Value::Object(map)
+}
+
+fn alignment(alignment: Option<ReprAlign>) -> Value {
+    match alignment {
+        None => Value::Null,
+        Some(ReprAlign::Packed) => json!({ "packed": true }),
+        Some(ReprAlign::PackedN(n)) => json!({ "packed": n }),
+        Some(ReprAlign::Align(n)) => json!({ "align": n }),
+    }
+}
+
+/// The layout of the item exported as `name`, if it can be computed.
+fn layout(name: &str, b: &Bindings) -> Value {
+    match b.type_layout(name) {
+        Some(layout) => {
+            let fields: Vec<_> = layout
+                .fields
+                .iter()
+                .map(|f| json!({ "name": f.name, "offset": f.offset, "size": f.size }))
+                .collect();
+            json!({ "size": layout.size, "align": layout.align, "fields": fields })
+        }
+        None => Value::Null,
+    }
+}
+
+fn structure(s: &Struct, b: &Bindings) -> Value {
+    let mut map = item(
+        Some("struct"),
+        s.export_name(),
+        Some(s.path.name()),
+        s.cfg.as_ref(),
+        &s.annotations,
+        &s.documentation,
+    );
+    map.insert(
+        "fields".to_owned(),
+        json!(s.fields.iter().map(field).collect::<Vec<_>>()),
+    );
+    map.insert("is_transparent".to_owned(), json!(s.is_transparent));
+    map.insert("alignment".to_owned(), alignment(s.alignment));
+    map.insert("layout".to_owned(), layout(s.export_name(), b));
+    let associated_constants: Vec<_> = s
+        .associated_constants
+        .iter()
+        .filter(|c| c.value.is_valid(b))
+        .map(|c| constant(c, b))
+        .collect();
+    map.insert(
+        "associated_constants".to_owned(),
+        json!(associated_constants),
+    );
+    Value::Object(map)
+}
+
+fn union(u: &Union, b: &Bindings) -> Value {
+    let mut map = item(
+        Some("union"),
+        u.export_name(),
+        Some(u.path.name()),
+        u.cfg.as_ref(),
+        &u.annotations,
+        &u.documentation,
+    );
+    map.insert(
+        "fields".to_owned(),
+        json!(u.fields.iter().map(field).collect::<Vec<_>>()),
+    );
+    map.insert("alignment".to_owned(), alignment(u.alignment));
+    map.insert("layout".to_owned(), layout(u.export_name(), b));
+    Value::Object(map)
+}
+
+fn variant(v: &EnumVariant) -> Value {
+    let mut map = item(
+        None,
+        &v.export_name,
+        Some(&v.name),
+        v.cfg.as_ref(),
+        v.body.annotations(),
+        &v.documentation,
+    );
+    map.insert(
+        "discriminant".to_owned(),
+        v.discriminant.as_ref().map_or(Value::Null, literal),
+    );
+    let body = match v.body {
+        VariantBody::Empty(..) => Value::Null,
+        VariantBody::Body {
+            ref name,
+            ref body,
+            inline,
+            ..
+        } => json!({
+            "name": name,
+            "struct": body.export_name(),
+            "fields": body.fields.iter().map(field).collect::<Vec<_>>(),
+            "inline": inline,
+        }),
+    };
+    map.insert("body".to_owned(), body);
+    Value::Object(map)
+}
+
+fn enumeration(e: &Enum, b: &Bindings) -> Value {
+    let mut map = item(
+        Some("enum"),
+        e.export_name(),
+        Some(e.path.name()),
+        e.cfg.as_ref(),
+        &e.annotations,
+        &e.documentation,
+    );
+    let style = match e.repr.style {
+        ReprStyle::Rust => "rust",
+        ReprStyle::C => "c",
+        ReprStyle::Transparent => "transparent",
+    };
+    let repr_type = e
+        .repr
+        .ty
+        .map_or(Value::Null, |ty| primitive(&ty.to_primitive()));
+    map.insert(
+        "repr".to_owned(),
+        json!({ "style": style, "type": repr_type, "alignment": alignment(e.repr.align) }),
+    );
+    map.insert("tag".to_owned(), json!(e.tag));
+    map.insert(
+        "variants".to_owned(),
+        json!(e.variants.iter().map(variant).collect::<Vec<_>>()),
+    );
+    map.insert("layout".to_owned(), layout(e.export_name(), b));
+    Value::Object(map)
+}
+
+fn opaque_item(o: &OpaqueItem, b: &Bindings) -> Value {
+    let mut map = item(
+        Some("opaque"),
+        o.export_name(),
+        Some(o.path.name()),
+        o.cfg.as_ref(),
+        &o.annotations,
+        &o.documentation,
+    );
+    map.insert(
+        "storage".to_owned(),
+        match o.storage {
+            Some(storage) => json!({ "size": storage.size, "align": storage.align }),
+            None => Value::Null,
+        },
+    );
+    map.insert("layout".to_owned(), layout(o.export_name(), b));
+    Value::Object(map)
+}
+
+fn typedef(t: &Typedef) -> Value {
+    let mut map = item(
+        Some("typedef"),
+        t.export_name(),
+        Some(t.path.name()),
+        t.cfg.as_ref(),
+        &t.annotations,
+        &t.documentation,
+    );
+    map.insert("aliased".to_owned(), ty(&t.aliased));
+    Value::Object(map)
+}
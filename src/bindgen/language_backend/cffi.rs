@@ -0,0 +1,428 @@
+use crate::bindgen::ir::{
+    Cfg, Constant, Documentation, Enum, EnumVariant, Field, Function, Item, ItemContainer, Literal,
+    OpaqueItem, PrimitiveType, Static, Struct, ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::{ListType, SourceWriter};
+use crate::bindgen::{cdecl, Bindings, Config};
+use std::io::Write;
+
+/// Writes a preprocessor-free header that can be passed to `cffi.FFI().cdef()`.
+///
+/// `#[cfg]` conditions are resolved against `[cffi] defines` instead of being
+/// written, and constants are reduced to the integer `#define`s cffi accepts.
+pub struct CffiLanguageBackend<'a> {
+    config: &'a Config,
+}
+
+impl<'a> CffiLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    fn is_enabled(&self, cfg: Option<&Cfg>) -> bool {
+        cfg.and_then(|cfg| cfg.to_condition(self.config))
+            .map_or(true, |condition| {
+                condition.evaluate(&self.config.cffi.defines)
+            })
+    }
+
+    fn write_field<W: Write>(&mut self, out: &mut SourceWriter<W>, f: &Field) {
+        cdecl::write_field(self, out, &f.ty, &f.name, self.config);
+
+        if let Some(bitfield) = f.annotations.atom("bitfield") {
+            write!(out, ": {}", bitfield.unwrap_or_default());
+        }
+    }
+
+    fn write_fields<W: Write>(&mut self, out: &mut SourceWriter<W>, fields: &[Field]) {
+        let fields: Vec<_> = fields
+            .iter()
+            .filter(|f| self.is_enabled(f.cfg.as_ref()))
+            .cloned()
+            .collect();
+        out.write_vertical_source_list(self, &fields, ListType::Cap(";"), Self::write_field);
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &EnumVariant) {
+        write!(out, "{}", v.export_name);
+        if let Some(discriminant) = &v.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+    }
+
+    /// Writes the tag enum of an enum, which cffi needs as a typedef of its repr when sized.
+    fn write_tag_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let tag_name = e.tag_name();
+        let size = e.repr.ty.map(|ty| ty.to_primitive().to_repr_c(self.config));
+        let variants: Vec<_> = e
+            .variants
+            .iter()
+            .filter(|v| self.is_enabled(v.cfg.as_ref()))
+            .cloned()
+            .collect();
+
+        // cffi can't size an enum, so sized enums are typedefs of their repr, like in C.
+        if size.is_none() {
+            out.write("typedef ");
+        }
+        write!(out, "enum {tag_name}");
+        out.open_brace();
+        out.write_vertical_source_list(self, &variants, ListType::Join(""), |lb, out, v| {
+            lb.write_enum_variant(out, v)
+        });
+        out.close_brace(false);
+        match size {
+            Some(size) => {
+                out.write(";");
+                out.new_line();
+                write!(out, "typedef {size} {tag_name};");
+            }
+            None => write!(out, " {tag_name};"),
+        }
+    }
+
+    /// Writes the members of the data-carrying variants, which are either fields of the
+    /// enum union, or of the payload union when the tag is kept separately.
+    fn write_variant_fields<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let mut first = true;
+        for variant in &e.variants {
+            let VariantBody::Body {
+                ref name,
+                ref body,
+                inline,
+                ..
+            } = variant.body
+            else {
+                continue;
+            };
+            if !self.is_enabled(variant.cfg.as_ref()) {
+                continue;
+            }
+
+            if !first {
+                out.new_line();
+            }
+            first = false;
+
+            if inline {
+                out.write("struct");
+                out.open_brace();
+                self.write_fields(out, &body.fields);
+                out.close_brace(true);
+            } else {
+                write!(out, "{} {};", body.export_name(), name);
+            }
+        }
+    }
+
+    fn write_constants<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        b: &Bindings,
+        constants: &[Constant],
+        associated_to: Option<&Struct>,
+    ) {
+        for constant in constants {
+            if !self.is_enabled(constant.cfg.as_ref()) {
+                continue;
+            }
+            // A `#define` can only hold an integer, or `...` to have the compiler fill it
+            // in, so constants of other types are left out.
+            let is_integer = match constant.ty {
+                Type::Primitive(PrimitiveType::Float | PrimitiveType::Double) => false,
+                Type::Primitive(_) => true,
+                _ => false,
+            };
+            if !is_integer {
+                continue;
+            }
+            out.new_line_if_not_start();
+            constant.write(&b.config, self, out, associated_to);
+            out.new_line();
+        }
+    }
+
+    /// Declares every struct and union upfront, as cffi needs a type to be declared
+    /// before it's used, even behind a pointer.
+    fn write_forward_declarations<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        let mut declarations = Vec::new();
+        for item in &b.items {
+            if !item.deref().annotations().should_export() || !self.is_enabled(item.deref().cfg()) {
+                continue;
+            }
+            match *item {
+                ItemContainer::Struct(ref s) if s.as_typedef().is_none() => {
+                    declarations.push(("struct", s.export_name()))
+                }
+                ItemContainer::Union(ref u) => declarations.push(("union", u.export_name())),
+                ItemContainer::Enum(ref e) if e.tag.is_some() => {
+                    for variant in &e.variants {
+                        if let VariantBody::Body {
+                            ref body,
+                            inline: false,
+                            ..
+                        } = variant.body
+                        {
+                            if self.is_enabled(variant.cfg.as_ref()) {
+                                declarations.push(("struct", body.export_name()));
+                            }
+                        }
+                    }
+                    let kind = if Enum::inline_tag_field(&e.repr) {
+                        "union"
+                    } else {
+                        "struct"
+                    };
+                    declarations.push((kind, e.export_name()));
+                }
+                _ => {}
+            }
+        }
+
+        if declarations.is_empty() {
+            return;
+        }
+        out.new_line_if_not_start();
+        for (kind, name) in declarations {
+            write!(out, "typedef {kind} {name} {name};");
+            out.new_line();
+        }
+    }
+}
+
+/// cffi can only pack or align all the types of a `cdef` at once, so packed and aligned types
+/// are left incomplete, which is better than declaring them with the wrong layout.
+fn write_unsupported_alignment<W: Write>(out: &mut SourceWriter<W>, name: &str) {
+    warn!("Declaring {name} as an incomplete type, as cffi can't express its #[repr(packed)] or #[repr(align)].");
+    write!(
+        out,
+        "// {name} is packed or aligned, which cffi can't declare."
+    );
+}
+
+impl LanguageBackend for CffiLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        // `header`, `autogen_warning` and the includes are meant for a C compiler, so
+        // they are left out.
+        if self.config.package_version {
+            write!(out, "/* Package version: {package_version} */");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "/* Generated with cbindgen:{} */",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn close_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        self.write_tag_enum(out, e);
+
+        if e.tag.is_none() {
+            return;
+        }
+
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                if self.is_enabled(variant.cfg.as_ref()) {
+                    out.new_line();
+                    out.new_line();
+                    self.write_struct(out, body);
+                }
+            }
+        }
+
+        out.new_line();
+        out.new_line();
+
+        let inline_tag_field = Enum::inline_tag_field(&e.repr);
+        if inline_tag_field {
+            write!(out, "union {}", e.export_name());
+        } else {
+            write!(out, "struct {}", e.export_name());
+        }
+        out.open_brace();
+        write!(out, "{} tag;", e.tag_name());
+        out.new_line();
+        if inline_tag_field {
+            self.write_variant_fields(out, e);
+        } else {
+            out.write("union");
+            out.open_brace();
+            self.write_variant_fields(out, e);
+            out.close_brace(true);
+        }
+        out.close_brace(true);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        if s.alignment.is_some() {
+            write_unsupported_alignment(out, s.export_name());
+            return;
+        }
+        write!(out, "struct {}", s.export_name());
+        out.open_brace();
+        self.write_fields(out, &s.fields);
+        out.close_brace(true);
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        if u.alignment.is_some() {
+            write_unsupported_alignment(out, u.export_name());
+            return;
+        }
+        write!(out, "union {}", u.export_name());
+        out.open_brace();
+        self.write_fields(out, &u.fields);
+        out.close_brace(true);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let Some(storage) = o.storage else {
+            write!(
+                out,
+                "typedef struct {} {};",
+                o.export_name(),
+                o.export_name()
+            );
+            return;
+        };
+        // cffi can't parse `_Alignas`, so the storage is made of integers as aligned as the Rust
+        // type, up to 8 bytes.
+        let (word, len) = storage.words(o.export_name(), "cffi");
+        write!(out, "typedef struct {}", o.export_name());
+        out.open_brace();
+        write!(out, "uint{}_t _private[{len}];", word * 8);
+        out.close_brace(false);
+        write!(out, " {};", o.export_name());
+    }
+
+    fn write_type_def<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Typedef) {
+        out.write("typedef ");
+        cdecl::write_field(self, out, &t.aliased, t.export_name(), self.config);
+        out.write(";");
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        // cffi reads globals from the library by name, `extern` is implied.
+        if !s.mutable && !matches!(s.ty, Type::Ptr { is_const: true, .. }) {
+            out.write("const ");
+        }
+        cdecl::write_field(self, out, &s.ty, &s.export_name, self.config);
+        out.write(";");
+    }
+
+    fn write_function<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+    ) {
+        // Prefixes, postfixes and the like are attributes cffi can't parse.
+        cdecl::write_func(self, out, func, config.function.args, config);
+        out.write(";");
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        cdecl::write_type(self, out, t, self.config);
+    }
+
+    fn write_documentation<W: Write>(&mut self, _out: &mut SourceWriter<W>, _d: &Documentation) {
+        // Documentation is of no use in a `cdef`, which nobody reads.
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l.evaluate(out.bindings(), &self.config.cffi.defines) {
+            Some(value) => write!(out, "{value}"),
+            // Let cffi ask the C compiler, which only works in API mode.
+            None => out.write("..."),
+        }
+    }
+
+    fn write_primitive_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.write_constants(out, b, &b.constants, None);
+        for item in &b.items {
+            if let ItemContainer::Struct(ref s) = *item {
+                if s.annotations.should_export() && self.is_enabled(s.cfg.as_ref()) {
+                    self.write_constants(out, b, &s.associated_constants, Some(s));
+                }
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.write_forward_declarations(out, b);
+
+        for item in &b.items {
+            let enabled = self.is_enabled(item.deref().cfg());
+            if !item.deref().annotations().should_export() || !enabled {
+                continue;
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => match x.as_typedef() {
+                    Some(typedef) => self.write_type_def(out, &typedef),
+                    None => self.write_struct(out, x),
+                },
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                ItemContainer::Typedef(ref x) => self.write_type_def(out, x),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_non_primitive_constants<W: Write>(
+        &mut self,
+        _out: &mut SourceWriter<W>,
+        _b: &Bindings,
+    ) {
+        // Every constant cffi can represent was written with the primitive ones.
+    }
+
+    fn write_globals<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for global in &b.globals {
+            if !global.annotations.should_export() || !self.is_enabled(global.cfg.as_ref()) {
+                continue;
+            }
+            out.new_line_if_not_start();
+            self.write_static(out, global);
+            out.new_line();
+        }
+    }
+
+    fn write_functions<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for function in &b.functions {
+            if !function.annotations.should_export() || !self.is_enabled(function.cfg.as_ref()) {
+                continue;
+            }
+            out.new_line_if_not_start();
+            self.write_function(&b.config, out, function);
+            out.new_line();
+        }
+    }
+
+    fn write_trailer<W: Write>(&mut self, _out: &mut SourceWriter<W>, _b: &Bindings) {}
+}
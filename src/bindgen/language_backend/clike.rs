@@ -1,7 +1,8 @@
+use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::ir::{
-    to_known_assoc_constant, ConditionWrite, DeprecatedNoteKind, Documentation, Enum, EnumVariant,
-    Field, GenericParams, Item, Literal, OpaqueItem, ReprAlign, Static, Struct, ToCondition, Type,
-    Typedef, Union,
+    escape_bytes, to_known_assoc_constant, ConditionWrite, DeprecatedNoteKind, Documentation, Enum,
+    EnumVariant, Field, GenericParams, Item, ItemContainer, Literal, OpaqueItem, ReprAlign, Static,
+    Struct, ToCondition, Type, Typedef, Union,
 };
 use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::rename::IdentifierType;
@@ -12,11 +13,19 @@ use std::io::Write;
 
 pub struct CLikeLanguageBackend<'a> {
     config: &'a Config,
+    /// Whether an opaque item has storage, which needs `alignas`.
+    has_opaque_storage: bool,
+    /// Whether a C string constant is written as a `std::string_view`.
+    has_string_views: bool,
 }
 
 impl<'a> CLikeLanguageBackend<'a> {
     pub fn new(config: &'a Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            has_opaque_storage: false,
+            has_string_views: false,
+        }
     }
 
     fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &EnumVariant) {
@@ -116,6 +125,117 @@ impl<'a> CLikeLanguageBackend<'a> {
         self.config.language == Language::C && self.config.style.generate_typedef()
     }
 
+    /// The name to refer to `item` with in expressions like `sizeof`, which needs the `struct`,
+    /// `union` or `enum` keyword in C if there's no typedef for it.
+    fn item_type_name(&self, item: &ItemContainer) -> String {
+        let name = item.deref().export_name();
+        if self.config.language != Language::C || self.config.style.generate_typedef() {
+            return name.to_owned();
+        }
+        // Fieldless enums with a fixed size are always typedef'd to their integer type.
+        if let ItemContainer::Enum(ref e) = *item {
+            if e.tag.is_none() && e.repr.ty.is_some() {
+                return name.to_owned();
+            }
+        }
+        let mut resolver = DeclarationTypeResolver::default();
+        item.deref().collect_declaration_types(&mut resolver);
+        match resolver.type_for(item.deref().path()) {
+            Some(ctype) => format!("{} {name}", ctype.to_str()),
+            None => name.to_owned(),
+        }
+    }
+
+    /// Whether `item` is a struct, union, enum or sized opaque item whose layout is known.
+    fn has_layout_asserts(item: &ItemContainer, b: &Bindings) -> bool {
+        matches!(
+            *item,
+            ItemContainer::Enum(..)
+                | ItemContainer::Struct(..)
+                | ItemContainer::Union(..)
+                | ItemContainer::OpaqueItem(..)
+        ) && b.type_layout(item.deref().export_name()).is_some()
+    }
+
+    /// Writes `static_assert`s checking the size, alignment and field offsets of `item` against
+    /// the ones computed for the target.
+    fn write_layout_asserts<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        item: &ItemContainer,
+        b: &Bindings,
+    ) {
+        let Some(layout) = b.type_layout(item.deref().export_name()) else {
+            return;
+        };
+        let name = self.item_type_name(item);
+
+        let condition = item
+            .deref()
+            .cfg()
+            .and_then(|cfg| cfg.to_condition(self.config));
+        condition.write_before(self.config, out);
+        write!(
+            out,
+            "static_assert(sizeof({name}) == {}, \"unexpected size of {name}\");",
+            layout.size
+        );
+        out.new_line();
+        write!(
+            out,
+            "static_assert(alignof({name}) == {}, \"unexpected alignment of {name}\");",
+            layout.align
+        );
+        for field in &layout.fields {
+            out.new_line();
+            write!(
+                out,
+                "static_assert(offsetof({name}, {field}) == {}, \"unexpected offset of {name}.{field}\");",
+                field.offset,
+                field = field.name,
+            );
+        }
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes a translation unit including `header`, with the `static_assert`s that
+    /// `layout.static_asserts` would add to it.
+    pub(crate) fn write_layout_tests<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        b: &Bindings,
+        header: &str,
+    ) {
+        if let Some(ref f) = self.config.autogen_warning {
+            write!(out, "{f}");
+            out.new_line();
+            out.new_line();
+        }
+        match self.config.language {
+            Language::C => {
+                out.write("#include <assert.h>");
+                out.new_line();
+                out.write("#include <stdalign.h>");
+                out.new_line();
+                out.write("#include <stddef.h>");
+            }
+            _ => out.write("#include <cstddef>"),
+        }
+        out.new_line();
+        write!(out, "#include \"{header}\"");
+        out.new_line();
+
+        self.open_namespaces(out);
+        for item in &b.items {
+            if item.deref().annotations().should_export() && Self::has_layout_asserts(item, b) {
+                out.new_line_if_not_start();
+                self.write_layout_asserts(out, item, b);
+                out.new_line();
+            }
+        }
+        self.close_namespaces(out);
+    }
+
     fn write_derived_cpp_ops<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
         let mut wrote_start_newline = false;
 
@@ -389,11 +509,21 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
         if !self.config.no_includes {
             match self.config.language {
                 Language::C => {
+                    // For the `static_assert`, `alignof` and `alignas` macros, which are keywords
+                    // in C++.
+                    if self.config.layout.static_asserts {
+                        out.write("#include <assert.h>");
+                        out.new_line();
+                    }
+                    if self.config.layout.static_asserts || self.has_opaque_storage {
+                        out.write("#include <stdalign.h>");
+                        out.new_line();
+                    }
                     out.write("#include <stdarg.h>");
                     out.new_line();
                     out.write("#include <stdbool.h>");
                     out.new_line();
-                    if self.config.usize_is_size_t {
+                    if self.config.usize_is_size_t || self.config.layout.static_asserts {
                         out.write("#include <stddef.h>");
                         out.new_line();
                     }
@@ -405,7 +535,7 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
                 Language::Cxx => {
                     out.write("#include <cstdarg>");
                     out.new_line();
-                    if self.config.usize_is_size_t {
+                    if self.config.usize_is_size_t || self.config.layout.static_asserts {
                         out.write("#include <cstddef>");
                         out.new_line();
                     }
@@ -424,6 +554,10 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
                         out.write("#include <cassert>");
                         out.new_line();
                     }
+                    if self.has_string_views {
+                        out.write("#include <string_view>");
+                        out.new_line();
+                    }
                 }
                 _ => {}
             }
@@ -543,6 +677,11 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
         let condition = s.cfg.to_condition(self.config);
         condition.write_before(self.config, out);
 
+        if let Some(ReprAlign::PackedN(n)) = s.alignment {
+            write!(out, "#pragma pack(push, {n})");
+            out.new_line();
+        }
+
         self.write_documentation(out, &s.documentation);
 
         if !s.is_enum_variant_body {
@@ -569,6 +708,8 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
                         write!(out, " {anno}");
                     }
                 }
+                // Written as a `#pragma pack` around the declaration.
+                ReprAlign::PackedN(_) => {}
                 ReprAlign::Align(n) => {
                     if let Some(ref anno) = self.config.layout.aligned_n {
                         write!(out, " {anno}({n})");
@@ -631,6 +772,11 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
             out.close_brace(true);
         }
 
+        if let Some(ReprAlign::PackedN(_)) = s.alignment {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         for constant in &s.associated_constants {
             out.new_line();
             constant.write(self.config, self, out, Some(s));
@@ -643,6 +789,11 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
         let condition = u.cfg.to_condition(self.config);
         condition.write_before(self.config, out);
 
+        if let Some(ReprAlign::PackedN(n)) = u.alignment {
+            write!(out, "#pragma pack(push, {n})");
+            out.new_line();
+        }
+
         self.write_documentation(out, &u.documentation);
 
         self.write_generic_param(out, &u.generic_params);
@@ -667,6 +818,8 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
                         write!(out, " {anno}");
                     }
                 }
+                // Written as a `#pragma pack` around the declaration.
+                ReprAlign::PackedN(_) => {}
                 ReprAlign::Align(n) => {
                     if let Some(ref anno) = self.config.layout.aligned_n {
                         write!(out, " {anno}({n})");
@@ -702,6 +855,11 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
             out.close_brace(true);
         }
 
+        if let Some(ReprAlign::PackedN(_)) = u.alignment {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         condition.write_after(self.config, out);
     }
 
@@ -713,7 +871,26 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
 
         o.generic_params.write_with_default(self, self.config, out);
 
-        if self.generate_typedef() {
+        if let Some(storage) = o.storage {
+            // An array of bytes as big and aligned as the Rust type, so that it can be allocated
+            // from C without being able to look inside.
+            if self.generate_typedef() {
+                out.write("typedef ");
+            }
+            write!(out, "struct {}", o.export_name());
+            out.open_brace();
+            write!(
+                out,
+                "alignas({}) unsigned char _private[{}];",
+                storage.align, storage.size
+            );
+            if self.generate_typedef() {
+                out.close_brace(false);
+                write!(out, " {};", o.export_name());
+            } else {
+                out.close_brace(true);
+            }
+        } else if self.generate_typedef() {
             write!(
                 out,
                 "typedef struct {} {};",
@@ -776,11 +953,6 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
             return;
         }
 
-        let end = match self.config.documentation_length {
-            DocumentationLength::Short => 1,
-            DocumentationLength::Full => d.doc_comment.len(),
-        };
-
         let style = match self.config.documentation_style {
             DocumentationStyle::Auto if self.config.language == Language::C => {
                 DocumentationStyle::Doxy
@@ -792,6 +964,18 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
             other => other,
         };
 
+        let translated;
+        let lines = if self.config.documentation_translate && style == DocumentationStyle::Doxy {
+            translated = d.to_doxygen();
+            &translated[..]
+        } else {
+            &d.doc_comment[..]
+        };
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1.min(lines.len()),
+            DocumentationLength::Full => lines.len(),
+        };
+
         // Following these documents for style conventions:
         // https://en.wikibooks.org/wiki/C++_Programming/Code/Style_Conventions/Comments
         // https://www.cs.cmu.edu/~410/doc/doxygen.html
@@ -809,7 +993,7 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
             _ => (),
         }
 
-        for line in &d.doc_comment[..end] {
+        for line in &lines[..end] {
             match style {
                 DocumentationStyle::C => out.write(""),
                 DocumentationStyle::Doxy => out.write(" *"),
@@ -840,6 +1024,11 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
     fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
         match l {
             Literal::Expr(v) => write!(out, "{v}"),
+            // Octal escapes have at most three digits, unlike the hexadecimal ones, so they
+            // can be followed by any character.
+            Literal::Bytes { value, .. } => {
+                write!(out, "\"{}\"", escape_bytes(value, |b| format!("\\{b:03o}")))
+            }
             Literal::Array { items } => {
                 write!(out, "{{ ");
                 for item in items {
@@ -963,6 +1152,36 @@ impl LanguageBackend for CLikeLanguageBackend<'_> {
         }
     }
 
+    fn prepare(&mut self, b: &Bindings) {
+        self.has_opaque_storage = b.items.iter().any(|item| match *item {
+            ItemContainer::OpaqueItem(ref o) => o.storage.is_some(),
+            _ => false,
+        });
+        let associated_constants = b.items.iter().flat_map(|item| match *item {
+            ItemContainer::Struct(ref s) => &s.associated_constants[..],
+            _ => &[],
+        });
+        self.has_string_views = (self.config.constant.allow_static_const
+            || self.config.constant.allow_constexpr)
+            && b.constants
+                .iter()
+                .chain(associated_constants)
+                .any(|c| matches!(c.value, Literal::Bytes { c_str: true, .. }));
+    }
+
+    fn write_item_epilogue<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        item: &ItemContainer,
+        b: &Bindings,
+    ) {
+        if self.config.layout.static_asserts && Self::has_layout_asserts(item, b) {
+            out.new_line();
+            self.write_layout_asserts(out, item, b);
+            out.new_line();
+        }
+    }
+
     fn write_globals<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
         // Override default method to open various blocs containing both globals and functions
         // these blocks are closed in [`write_functions`] that is also overridden
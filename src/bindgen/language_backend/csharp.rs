@@ -0,0 +1,959 @@
+use crate::bindgen::config::CSharpImportAttribute;
+use crate::bindgen::ir::{
+    ConditionWrite, ConstExpr, Documentation, Enum, EnumVariant, Field, Function, FunctionArgument,
+    Item, ItemContainer, Literal, OpaqueItem, PrimitiveType, ReprAlign, ReprType, Static, Struct,
+    ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::{collect_typedefs, resolve_typedefs, LanguageBackend};
+use crate::bindgen::reserved;
+use crate::bindgen::writer::{ListType, SourceWriter};
+use crate::bindgen::{Bindings, DocumentationLength};
+use crate::bindgen::{Config, Layout};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// The primitive types that can be the element type of a `fixed` size buffer.
+const FIXED_BUFFER_TYPES: &[&str] = &[
+    "bool", "byte", "sbyte", "short", "ushort", "int", "uint", "long", "ulong", "float", "double",
+];
+
+pub struct CSharpLanguageBackend<'a> {
+    config: &'a Config,
+    /// C# has no type aliases that can be shared between files, so typedefs
+    /// are replaced by the type they alias wherever they are used.
+    typedefs: HashMap<String, Type>,
+}
+
+impl<'a> CSharpLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            typedefs: HashMap::new(),
+        }
+    }
+
+    fn write_ident<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        if reserved::is_csharp_reserved(name) {
+            write!(out, "@{name}");
+        } else {
+            write!(out, "{name}");
+        }
+    }
+
+    fn write_struct_layout<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        explicit: bool,
+        alignment: Option<ReprAlign>,
+    ) {
+        out.write(if explicit {
+            "[StructLayout(LayoutKind.Explicit"
+        } else {
+            "[StructLayout(LayoutKind.Sequential"
+        });
+        // `StructLayout` can only lower the alignment, so `#[repr(align(N))]` is not represented.
+        match alignment {
+            Some(ReprAlign::Packed) => out.write(", Pack = 1"),
+            Some(ReprAlign::PackedN(n)) => write!(out, ", Pack = {n}"),
+            _ => {}
+        }
+        out.write(")]");
+        out.new_line();
+    }
+
+    fn write_struct_decl<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        out.write("public unsafe partial struct ");
+        self.write_ident(out, name);
+    }
+
+    fn write_array_length<W: Write>(&mut self, out: &mut SourceWriter<W>, len: &ConstExpr) {
+        match len {
+            ConstExpr::Path(path) => write!(
+                out,
+                "{}.{}",
+                self.config.csharp.class_name(),
+                path.export_name()
+            ),
+            ConstExpr::Value(value) => write!(out, "{value}"),
+        }
+    }
+
+    /// Writes a literal as a value of type `ty`, where C# wouldn't convert it implicitly:
+    /// unsuffixed floating point literals are doubles, and array literals would get the
+    /// type of their items.
+    fn write_typed_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, ty: &Type, l: &Literal) {
+        match (resolve_typedefs(&self.typedefs, ty), l) {
+            (Type::Primitive(PrimitiveType::Float), Literal::Expr(v))
+                if v.starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                write!(out, "{v}f");
+            }
+            (Type::Primitive(PrimitiveType::Float), _) => {
+                out.write("(float)(");
+                self.write_literal(out, l);
+                out.write(")");
+            }
+            (Type::Array(ty, _), Literal::Array { items }) => {
+                out.write("new ");
+                self.write_type(out, &ty);
+                out.write("[] { ");
+                for item in items {
+                    self.write_typed_literal(out, &ty, item);
+                    out.write(", ");
+                }
+                out.write("}");
+            }
+            _ => self.write_literal(out, l),
+        }
+    }
+
+    /// Writes the declaration of a field, without its documentation or condition.
+    ///
+    /// Arrays of primitives become `fixed` size buffers. Other arrays can't be
+    /// inlined in a C# struct, so every element gets its own `name_N` field.
+    fn write_field_decl<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        name: &str,
+        ty: &Type,
+        explicit: bool,
+    ) {
+        let offset = if explicit { "[FieldOffset(0)] " } else { "" };
+        match resolve_typedefs(&self.typedefs, ty) {
+            Type::Array(ty, len) => {
+                let ty = resolve_typedefs(&self.typedefs, &ty);
+                let elements = match len {
+                    ConstExpr::Value(ref value) => value.parse::<usize>().ok(),
+                    ConstExpr::Path(..) => None,
+                };
+                match ty {
+                    Type::Primitive(ref prim)
+                        if FIXED_BUFFER_TYPES.contains(&prim.to_repr_csharp()) =>
+                    {
+                        write!(out, "{offset}public fixed {} ", prim.to_repr_csharp());
+                        self.write_ident(out, name);
+                        out.write("[");
+                        self.write_array_length(out, &len);
+                        out.write("];");
+                    }
+                    // The elements of a union field must all share the same offset.
+                    _ if elements.is_some() && !explicit => {
+                        for i in 0..elements.unwrap() {
+                            if i != 0 {
+                                out.new_line();
+                            }
+                            self.write_field_decl(out, &format!("{name}_{i}"), &ty, explicit);
+                        }
+                    }
+                    _ => {
+                        write!(
+                            out,
+                            "{offset}[MarshalAs(UnmanagedType.ByValArray, SizeConst = "
+                        );
+                        self.write_array_length(out, &len);
+                        out.write(")] public ");
+                        self.write_type(out, &ty);
+                        out.write("[] ");
+                        self.write_ident(out, name);
+                        out.write(";");
+                    }
+                }
+            }
+            ty => {
+                write!(out, "{offset}");
+                if let Type::Primitive(PrimitiveType::Bool) = ty {
+                    out.write("[MarshalAs(UnmanagedType.U1)] ");
+                }
+                out.write("public ");
+                self.write_type(out, &ty);
+                out.write(" ");
+                self.write_ident(out, name);
+                out.write(";");
+            }
+        }
+    }
+
+    fn write_field<W: Write>(&mut self, out: &mut SourceWriter<W>, f: &Field, explicit: bool) {
+        let condition = f.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &f.documentation);
+        self.write_field_decl(out, &f.name, &f.ty, explicit);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_fields<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        fields: &[Field],
+        explicit: bool,
+    ) {
+        out.write_vertical_source_list(self, fields, ListType::Join(""), |lb, out, f| {
+            lb.write_field(out, f, explicit)
+        });
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &EnumVariant) {
+        let condition = v.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &v.documentation);
+        self.write_ident(out, &v.export_name);
+        if let Some(discriminant) = &v.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes the tag enum of an enum as a C# `enum` with the repr as its underlying type.
+    fn write_tag_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        out.write("public enum ");
+        self.write_ident(out, e.tag_name());
+        if let Some(ty) = e.repr.ty {
+            write!(out, " : {}", enum_underlying_type(ty));
+        }
+        out.open_brace();
+        out.write_vertical_source_list(self, &e.variants, ListType::Join(""), |lb, out, v| {
+            lb.write_enum_variant(out, v)
+        });
+        out.close_brace(false);
+    }
+
+    /// Writes the members of the data-carrying variants, which all overlap the tag.
+    fn write_variant_fields<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let mut first = true;
+        for variant in &e.variants {
+            let VariantBody::Body {
+                ref name, ref body, ..
+            } = variant.body
+            else {
+                continue;
+            };
+
+            if !first {
+                out.new_line();
+            }
+            first = false;
+
+            let condition = variant.cfg.to_condition(self.config);
+            condition.write_before(self.config, out);
+            out.write("[FieldOffset(0)] public ");
+            self.write_ident(out, body.export_name());
+            out.write(" ");
+            self.write_ident(out, name);
+            out.write(";");
+            condition.write_after(self.config, out);
+        }
+    }
+
+    /// C# has no anonymous members, so the bodies of inlined variants become
+    /// nested types of the enum struct.
+    fn write_inline_variant_bodies<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: true,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(self.config);
+                condition.write_before(self.config, out);
+                self.write_struct_layout(out, false, None);
+                self.write_struct_decl(out, body.export_name());
+                out.open_brace();
+                self.write_fields(out, &body.fields, false);
+                out.close_brace(false);
+                condition.write_after(self.config, out);
+            }
+        }
+    }
+
+    fn write_argument<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        (i, arg): &(usize, &FunctionArgument),
+    ) {
+        let ty = resolve_typedefs(&self.typedefs, &arg.ty);
+        match ty {
+            Type::Primitive(PrimitiveType::VaList) => {
+                out.write("__arglist");
+                return;
+            }
+            Type::Primitive(PrimitiveType::Bool) => out.write("[MarshalAs(UnmanagedType.U1)] "),
+            _ => {}
+        }
+        match ty {
+            // Like in C, array arguments decay to a pointer to their first element.
+            Type::Array(ref ty, _) => {
+                self.write_type(out, ty);
+                out.write("*");
+            }
+            _ => self.write_type(out, &ty),
+        }
+        out.write(" ");
+        match arg.name {
+            Some(ref name) => self.write_ident(out, name),
+            None => write!(out, "_{i}"),
+        }
+    }
+
+    fn write_associated_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for item in &b.items {
+            let ItemContainer::Struct(ref s) = *item else {
+                continue;
+            };
+            if !s.annotations.should_export() {
+                continue;
+            }
+            let condition = s.cfg.to_condition(self.config);
+            for constant in &s.associated_constants {
+                out.new_line_if_not_start();
+                condition.write_before(self.config, out);
+                constant.write(self.config, self, out, Some(s));
+                condition.write_after(self.config, out);
+                out.new_line();
+            }
+        }
+    }
+
+    /// Functions, constants and globals can't be declared at the top level, so
+    /// they are all members of a single static class.
+    fn write_native_class<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        out.new_line_if_not_start();
+        out.write("public static unsafe partial class ");
+        self.write_ident(out, self.config.csharp.class_name());
+        out.open_brace();
+        write!(
+            out,
+            "private const string LibraryName = \"{}\";",
+            self.config.csharp.library_name()
+        );
+        out.new_line();
+
+        self.write_primitive_constants(out, b);
+        self.write_associated_constants(out, b);
+        self.write_non_primitive_constants(out, b);
+        self.write_globals(out, b);
+        self.write_functions(out, b);
+
+        // Every member ends its own line, so close the brace on the current one.
+        out.pop_tab();
+        out.write("}");
+    }
+}
+
+/// Returns the C# integral type with the same layout as `ty`, for use as the
+/// underlying type of an enum.
+fn enum_underlying_type(ty: ReprType) -> &'static str {
+    use crate::bindgen::ir::IntKind;
+
+    match (ty.kind, ty.signed) {
+        (IntKind::B8, true) => "sbyte",
+        (IntKind::B8, false) => "byte",
+        (IntKind::Short | IntKind::B16, true) => "short",
+        (IntKind::Short | IntKind::B16, false) => "ushort",
+        (IntKind::Int | IntKind::B32, true) => "int",
+        (IntKind::Int | IntKind::B32, false) => "uint",
+        // Enums can't be backed by `nint` or `CLong`, so assume a 64-bit target.
+        // Nor by `Int128`, so 128-bit enums don't have the right size.
+        (
+            IntKind::Long
+            | IntKind::LongLong
+            | IntKind::B64
+            | IntKind::SizeT
+            | IntKind::Size
+            | IntKind::B128,
+            true,
+        ) => "long",
+        (
+            IntKind::Long
+            | IntKind::LongLong
+            | IntKind::B64
+            | IntKind::SizeT
+            | IntKind::Size
+            | IntKind::B128,
+            false,
+        ) => "ulong",
+    }
+}
+
+impl LanguageBackend for CSharpLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {package_version}");
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            out.write("using System;");
+            out.new_line();
+            if self.config.csharp.import_attribute == CSharpImportAttribute::LibraryImport {
+                // Needed for `CallConvCdecl`.
+                out.write("using System.Runtime.CompilerServices;");
+                out.new_line();
+            }
+            out.write("using System.Runtime.InteropServices;");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{line}");
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        if let Some(ref namespace) = self.config.csharp.namespace {
+            out.new_line_if_not_start();
+            write!(out, "namespace {namespace}");
+            out.open_brace();
+        }
+    }
+
+    fn close_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        if self.config.csharp.namespace.is_some() {
+            out.close_brace(false);
+        }
+    }
+
+    fn write_footers<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        out.new_line();
+    }
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let condition = e.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        if e.tag.is_none() {
+            self.write_documentation(out, &e.documentation);
+            self.write_tag_enum(out, e);
+            condition.write_after(self.config, out);
+            return;
+        }
+
+        self.write_tag_enum(out, e);
+
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(self.config);
+                condition.write_before(self.config, out);
+                self.write_struct(out, body);
+                condition.write_after(self.config, out);
+            }
+        }
+
+        out.new_line();
+        out.new_line();
+        self.write_documentation(out, &e.documentation);
+
+        let inline_tag = Enum::inline_tag_field(&e.repr);
+        // With an inline tag every variant starts with the tag, so they can all overlap it.
+        self.write_struct_layout(out, inline_tag, None);
+        self.write_struct_decl(out, e.export_name());
+        out.open_brace();
+        if let Some(body) = self.config.export.pre_body(&e.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        if inline_tag {
+            out.write("[FieldOffset(0)] public ");
+            self.write_ident(out, e.tag_name());
+            out.write(" tag;");
+            out.new_line();
+            self.write_variant_fields(out, e);
+        } else {
+            out.write("public ");
+            self.write_ident(out, e.tag_name());
+            out.write(" tag;");
+            out.new_line();
+            out.write("public Payload payload;");
+            out.new_line();
+            out.new_line();
+            self.write_struct_layout(out, true, None);
+            out.write("public unsafe struct Payload");
+            out.open_brace();
+            self.write_variant_fields(out, e);
+            out.close_brace(false);
+        }
+
+        self.write_inline_variant_bodies(out, e);
+
+        if let Some(body) = self.config.export.post_body(&e.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+
+        self.write_struct_layout(out, false, s.alignment);
+        self.write_struct_decl(out, s.export_name());
+        out.open_brace();
+
+        if let Some(body) = self.config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &s.fields, false);
+
+        if let Some(body) = self.config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+
+        self.write_struct_layout(out, true, u.alignment);
+        self.write_struct_decl(out, &u.export_name);
+        out.open_brace();
+
+        if let Some(body) = self.config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &u.fields, true);
+
+        if let Some(body) = self.config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &o.documentation);
+
+        match o.storage {
+            Some(storage) => {
+                // `StructLayout` can't raise the alignment, so the storage is made of integers
+                // as aligned as the Rust type, up to 8 bytes.
+                let (word, len) = storage.words(o.export_name(), "C#");
+                let ty = match word {
+                    1 => "byte",
+                    2 => "ushort",
+                    4 => "uint",
+                    _ => "ulong",
+                };
+                self.write_struct_layout(out, false, None);
+                self.write_struct_decl(out, o.export_name());
+                out.open_brace();
+                write!(out, "private fixed {ty} _private[{len}];");
+                out.close_brace(false);
+            }
+            None => {
+                out.write("public partial struct ");
+                self.write_ident(out, o.export_name());
+                out.write(" { }");
+            }
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_type_def<W: Write>(&mut self, _out: &mut SourceWriter<W>, _t: &Typedef) {
+        // Typedefs are substituted where they're used, see `resolve_typedefs`.
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        write!(
+            out,
+            "// `{}` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.",
+            s.export_name
+        );
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        let condition = func.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_documentation(out, &func.documentation);
+
+        let ret = resolve_typedefs(&self.typedefs, &func.ret);
+        match config.csharp.import_attribute {
+            CSharpImportAttribute::DllImport => {
+                out.write("[DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]");
+            }
+            CSharpImportAttribute::LibraryImport => {
+                out.write("[LibraryImport(LibraryName)]");
+                out.new_line();
+                out.write("[UnmanagedCallConv(CallConvs = new[] { typeof(CallConvCdecl) })]");
+            }
+        }
+        out.new_line();
+        if let Type::Primitive(PrimitiveType::Bool) = ret {
+            out.write("[return: MarshalAs(UnmanagedType.U1)]");
+            out.new_line();
+        }
+        out.write(match config.csharp.import_attribute {
+            CSharpImportAttribute::DllImport => "public static extern ",
+            CSharpImportAttribute::LibraryImport => "public static partial ",
+        });
+        self.write_type(out, &ret);
+        out.write(" ");
+        self.write_ident(out, func.path().name());
+        out.write("(");
+        let args: Vec<_> = func.args.iter().enumerate().collect();
+        match layout {
+            Layout::Vertical => out.write_vertical_source_list(
+                self,
+                &args,
+                ListType::Join(","),
+                Self::write_argument,
+            ),
+            _ => out.write_horizontal_source_list(
+                self,
+                &args,
+                ListType::Join(", "),
+                Self::write_argument,
+            ),
+        }
+        out.write(");");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match resolve_typedefs(&self.typedefs, t) {
+            // C# pointers carry neither constness nor nullability.
+            // A pointer to an array points to its first element.
+            Type::Ptr { ty, .. } => {
+                match resolve_typedefs(&self.typedefs, &ty) {
+                    Type::Array(ty, _) => self.write_type(out, &ty),
+                    ty => self.write_type(out, &ty),
+                }
+                out.write("*");
+            }
+            Type::Path(generic) => self.write_ident(out, generic.export_name()),
+            Type::Primitive(primitive) => out.write(primitive.to_repr_csharp()),
+            // Fields inline their arrays, see `write_field_decl`, anywhere else they are
+            // managed arrays, which P/Invoke passes as a pointer to their first element.
+            Type::Array(ty, _) => {
+                self.write_type(out, &ty);
+                out.write("[]");
+            }
+            Type::FuncPtr { ret, args, .. } => {
+                out.write("delegate* unmanaged[Cdecl]<");
+                for (_, ty) in &args {
+                    match resolve_typedefs(&self.typedefs, ty) {
+                        // Variadic function pointers can't be represented.
+                        Type::Primitive(PrimitiveType::VaList) => continue,
+                        // Nothing is marshalled through function pointers, so use
+                        // a type with the same size as a C `bool`.
+                        Type::Primitive(PrimitiveType::Bool) => out.write("byte"),
+                        // Managed arrays can't be passed through function pointers.
+                        Type::Array(ty, _) => {
+                            self.write_type(out, &ty);
+                            out.write("*");
+                        }
+                        ty => self.write_type(out, &ty),
+                    }
+                    out.write(", ");
+                }
+                match resolve_typedefs(&self.typedefs, &ret) {
+                    Type::Primitive(PrimitiveType::Bool) => out.write("byte"),
+                    ret => self.write_type(out, &ret),
+                }
+                out.write(">");
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // C# doc comments are XML, so `documentation_style` is not relevant.
+        out.write("/// <summary>");
+        out.new_line();
+        for line in &d.doc_comment[..end] {
+            let line = line
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            write!(out, "///{line}");
+            out.new_line();
+        }
+        out.write("/// </summary>");
+        out.new_line();
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => {
+                if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+                    // C# characters are UTF-16 code units, so use the code point instead.
+                    write!(out, "0x{}", code.trim_start_matches('0'));
+                } else if let Some(code) =
+                    v.strip_prefix("'\\u{").and_then(|v| v.strip_suffix("}'"))
+                {
+                    write!(out, "'\\u{code:0>4}'");
+                } else if v.starts_with(|c: char| c.is_ascii_digit()) {
+                    let digits = v.trim_end_matches(['u', 'l']);
+                    let suffix = &v[digits.len()..];
+                    write!(out, "{digits}");
+                    if suffix.contains('u') {
+                        out.write("U");
+                    }
+                    if suffix.contains('l') {
+                        out.write("L");
+                    }
+                } else {
+                    write!(out, "{v}");
+                }
+            }
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(prim @ PrimitiveType::Integer { .. }) =
+                        PrimitiveType::maybe(path.name())
+                    {
+                        match name.as_str() {
+                            "MAX" => return write!(out, "{}.MaxValue", prim.to_repr_csharp()),
+                            "MIN" => return write!(out, "{}.MinValue", prim.to_repr_csharp()),
+                            _ => {}
+                        }
+                    }
+                    if let Some(variant) = out.bindings().enum_variant_reference(path, name) {
+                        return write!(out, "{variant}");
+                    }
+                    write!(
+                        out,
+                        "{}.{export_name}_{name}",
+                        self.config.csharp.class_name()
+                    );
+                    return;
+                }
+                // Constants live in the native class, which may not be the enclosing type.
+                write!(out, "{}.{name}", self.config.csharp.class_name())
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                out.write("(");
+                self.write_literal(out, base);
+                out.write(").");
+                self.write_ident(out, field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{op}");
+                match **value {
+                    // C# can't negate unsigned literals, like the `9223372036854775808UL`
+                    // of `i64::MIN`, but a negated literal is signed anyway.
+                    Literal::Expr(ref v)
+                        if *op == "-" && v.starts_with(|c: char| c.is_ascii_digit()) =>
+                    {
+                        self.write_literal(out, &Literal::Expr(v.replace('u', "")));
+                    }
+                    _ => self.write_literal(out, value),
+                }
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                out.write("(");
+                self.write_literal(out, left);
+                write!(out, " {op} ");
+                // Shift counts must be `int`s, which unsuffixed literals already are.
+                let is_int = matches!(**right, Literal::Expr(ref v) if v.bytes().all(|c| c.is_ascii_digit()));
+                if matches!(*op, "<<" | ">>") && !is_int {
+                    out.write("(int)(");
+                    self.write_literal(out, right);
+                    out.write(")");
+                } else {
+                    self.write_literal(out, right);
+                }
+                out.write(")");
+            }
+            // Constants of non-primitive types are wrapped in a cast to their type, which
+            // their literal needs to be written as, but can't be cast to.
+            Literal::Cast { ref ty, ref value }
+                if matches!(ty, Type::Array(..) | Type::Path(..)) =>
+            {
+                self.write_typed_literal(out, ty, value);
+            }
+            Literal::Cast { ref ty, ref value } => {
+                // Constant integer conversions are checked, but Rust's `as` truncates.
+                let is_integer = matches!(
+                    resolve_typedefs(&self.typedefs, ty),
+                    Type::Primitive(PrimitiveType::Integer { .. })
+                );
+                if is_integer {
+                    out.write("unchecked(");
+                }
+                // Parenthesize the value, so that `(Foo)-1` isn't parsed as a subtraction.
+                out.write("(");
+                self.write_type(out, ty);
+                out.write(")(");
+                self.write_literal(out, value);
+                out.write(")");
+                if is_integer {
+                    out.write(")");
+                }
+            }
+            // C strings are passed to native code with their nul terminator.
+            Literal::Bytes { ref value, c_str } => {
+                out.write("new byte[] { ");
+                for byte in value.iter().chain(c_str.then_some(&0)) {
+                    write!(out, "{byte}, ");
+                }
+                out.write("}");
+            }
+            // Without knowing their type, let the compiler infer it from the items.
+            Literal::Array { ref items } => {
+                out.write("new[] { ");
+                for item in items {
+                    self.write_literal(out, item);
+                    out.write(", ");
+                }
+                out.write("}");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                out.write("new ");
+                self.write_ident(out, export_name);
+                out.write(" { ");
+                let ordered_fields = out.bindings().struct_field_names(path);
+                let mut is_first_field = true;
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            out.write(", ");
+                        }
+                        is_first_field = false;
+                        self.write_ident(out, ordered_key);
+                        out.write(" = ");
+                        match out.bindings().struct_field_type(path, ordered_key) {
+                            Some(ty) => self.write_typed_literal(out, &ty, &lit.value),
+                            None => self.write_literal(out, &lit.value),
+                        }
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for item in &b.items {
+            if !item.deref().annotations().should_export() {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Typedef(..) => continue,
+                ItemContainer::Struct(ref x) if x.is_transparent => continue,
+                _ => {}
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => self.write_struct(out, x),
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                _ => unreachable!(),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_bindings<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.typedefs = collect_typedefs(b);
+
+        self.write_headers(out, &b.package_version);
+        self.open_namespaces(out);
+        self.write_items(out, b);
+        self.write_native_class(out, b);
+        self.close_namespaces(out);
+        self.write_footers(out);
+        self.write_trailer(out, b);
+    }
+}
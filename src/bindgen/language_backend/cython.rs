@@ -1,20 +1,39 @@
 use crate::bindgen::ir::{
-    to_known_assoc_constant, ConditionWrite, DeprecatedNoteKind, Documentation, Enum, EnumVariant,
-    Field, Item, Literal, OpaqueItem, ReprAlign, Static, Struct, ToCondition, Type, Typedef, Union,
+    escape_bytes, to_known_assoc_constant, ConditionWrite, DeprecatedNoteKind, Documentation, Enum,
+    EnumVariant, Field, IntKind, Item, ItemContainer, Literal, OpaqueItem, PrimitiveType,
+    ReprAlign, Static, Struct, ToCondition, Type, Typedef, Union, VariantBody,
 };
 use crate::bindgen::language_backend::LanguageBackend;
 use crate::bindgen::writer::{ListType, SourceWriter};
 use crate::bindgen::DocumentationLength;
 use crate::bindgen::{cdecl, Bindings, Config};
+use std::collections::BTreeSet;
 use std::io::Write;
 
 pub struct CythonLanguageBackend<'a> {
     config: &'a Config,
+    /// The primitives used by the bindings which Cython has no type for.
+    extra_primitives: BTreeSet<PrimitiveType>,
 }
 
 impl<'a> CythonLanguageBackend<'a> {
     pub fn new(config: &'a Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            extra_primitives: BTreeSet::new(),
+        }
+    }
+
+    /// Whether the C type of `prim` needs a `ctypedef`, as it has no Cython equivalent.
+    fn is_extra_primitive(prim: &PrimitiveType) -> bool {
+        matches!(
+            *prim,
+            PrimitiveType::Integer {
+                kind: IntKind::B128,
+                ..
+            } | PrimitiveType::Float16
+                | PrimitiveType::Float128
+        )
     }
 
     fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &EnumVariant) {
@@ -41,7 +60,56 @@ impl<'a> CythonLanguageBackend<'a> {
     }
 }
 
+fn warn_unsupported_alignment(name: &str) {
+    warn!("Declaring {name} without its fields, as Cython can't express its #[repr(packed(N))] or #[repr(align(N))].");
+}
+
 impl LanguageBackend for CythonLanguageBackend<'_> {
+    fn prepare(&mut self, b: &Bindings) {
+        let mut primitives = BTreeSet::new();
+        let mut visit = |ty: &Type| {
+            ty.for_each_type(&mut |ty| match *ty {
+                Type::Primitive(ref prim) if Self::is_extra_primitive(prim) => {
+                    primitives.insert(prim.clone());
+                }
+                _ => {}
+            })
+        };
+        let visit_fields = |visit: &mut dyn FnMut(&Type), fields: &[Field]| {
+            fields.iter().for_each(|field| visit(&field.ty))
+        };
+        for function in &b.functions {
+            visit(&function.ret);
+            function.args.iter().for_each(|arg| visit(&arg.ty));
+        }
+        b.globals.iter().for_each(|global| visit(&global.ty));
+        b.constants.iter().for_each(|constant| visit(&constant.ty));
+        for item in &b.items {
+            match *item {
+                ItemContainer::Struct(ref s) => {
+                    visit_fields(&mut visit, &s.fields);
+                    s.associated_constants.iter().for_each(|c| visit(&c.ty));
+                }
+                ItemContainer::Union(ref u) => visit_fields(&mut visit, &u.fields),
+                ItemContainer::Enum(ref e) => {
+                    if let Some(ty) = e.repr.ty {
+                        visit(&Type::Primitive(ty.to_primitive()));
+                    }
+                    for variant in &e.variants {
+                        if let VariantBody::Body { ref body, .. } = variant.body {
+                            visit_fields(&mut visit, &body.fields);
+                        }
+                    }
+                }
+                ItemContainer::Typedef(ref t) => visit(&t.aliased),
+                ItemContainer::Constant(..)
+                | ItemContainer::Static(..)
+                | ItemContainer::OpaqueItem(..) => {}
+            }
+        }
+        self.extra_primitives = primitives;
+    }
+
     fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
         if self.config.package_version {
             write!(out, "''' Package version: {package_version} '''");
@@ -90,6 +158,38 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
             out.new_line();
             out.write("ctypedef struct va_list");
             out.new_line();
+            let primitive = &self.config.primitive;
+            for prim in &self.extra_primitives {
+                let name = prim.to_repr_c(self.config);
+                match *prim {
+                    // Cython has no 128-bit integers, so they're opaque rather than converted
+                    // to and from Python integers of another width.
+                    PrimitiveType::Integer { signed, .. } => {
+                        let c_name = if signed {
+                            &primitive.int128
+                        } else {
+                            &primitive.uint128
+                        };
+                        write!(out, "ctypedef struct {name} \"{c_name}\"");
+                        out.open_brace();
+                        out.write("pass");
+                        out.close_brace(false);
+                        out.new_line();
+                    }
+                    PrimitiveType::Float16 => {
+                        write!(out, "ctypedef float {name} \"{}\"", primitive.float16);
+                        out.new_line();
+                    }
+                    _ => {
+                        write!(
+                            out,
+                            "ctypedef long double {name} \"{}\"",
+                            primitive.float128
+                        );
+                        out.new_line();
+                    }
+                }
+            }
             out.close_brace(false);
         }
 
@@ -167,14 +267,20 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
         out.write(self.config.style.cython_def());
 
         // Cython extern declarations don't manage layouts, layouts are defined entierly by the
-        // corresponding C code. So this `packed` is only for documentation, and missing
-        // `aligned(n)` is also not a problem.
-        if let Some(align) = s.alignment {
-            match align {
-                ReprAlign::Packed => out.write("packed "),
-                ReprAlign::Align(_) => {} // Not supported
+        // corresponding C code. So this `packed` is only for documentation, but as Cython can't
+        // spell `packed(N)` or `aligned(N)`, such structs are declared without their fields
+        // rather than with a layout that doesn't match.
+        let fields: &[Field] = match s.alignment {
+            Some(ReprAlign::Packed) => {
+                out.write("packed ");
+                &s.fields
             }
-        }
+            Some(ReprAlign::PackedN(_) | ReprAlign::Align(_)) => {
+                warn_unsupported_alignment(s.export_name());
+                &[]
+            }
+            None => &s.fields,
+        };
 
         out.write("struct");
 
@@ -201,8 +307,8 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
             out.new_line();
         }
 
-        out.write_vertical_source_list(self, &s.fields, ListType::Cap(";"), Self::write_field);
-        if s.fields.is_empty() {
+        out.write_vertical_source_list(self, fields, ListType::Cap(";"), Self::write_field);
+        if fields.is_empty() {
             out.write("pass");
         }
 
@@ -233,6 +339,15 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
 
         write!(out, " {}", u.export_name);
 
+        // Like for structs, but Cython can't spell `packed` unions either.
+        let fields: &[Field] = match u.alignment {
+            Some(..) => {
+                warn_unsupported_alignment(&u.export_name);
+                &[]
+            }
+            None => &u.fields,
+        };
+
         out.open_brace();
 
         // Emit the pre_body section, if relevant
@@ -241,8 +356,8 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
             out.new_line();
         }
 
-        out.write_vertical_source_list(self, &u.fields, ListType::Cap(";"), Self::write_field);
-        if u.fields.is_empty() {
+        out.write_vertical_source_list(self, fields, ListType::Cap(";"), Self::write_field);
+        if fields.is_empty() {
             out.write("pass");
         }
 
@@ -272,7 +387,11 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
             o.export_name()
         );
         out.open_brace();
-        out.write("pass");
+        match o.storage {
+            // Cython only needs the size, the alignment comes from the C header.
+            Some(storage) => write!(out, "unsigned char _private[{}];", storage.size),
+            None => out.write("pass"),
+        }
         out.close_brace(false);
 
         condition.write_after(self.config, out);
@@ -340,6 +459,13 @@ impl LanguageBackend for CythonLanguageBackend<'_> {
                 "false" => write!(out, "False"),
                 v => write!(out, "{v}"),
             },
+            Literal::Bytes { value, .. } => {
+                write!(
+                    out,
+                    "b\"{}\"",
+                    escape_bytes(value, |b| format!("\\x{b:02x}"))
+                )
+            }
             Literal::Path {
                 ref associated_to,
                 ref name,
@@ -0,0 +1,902 @@
+use crate::bindgen::ir::{
+    Cfg, ConstExpr, Constant, Documentation, Enum, Field, Function, Item, ItemContainer, Literal,
+    OpaqueItem, Path, PrimitiveType, ReprAlign, Static, Struct, ToCondition, Type, Typedef, Union,
+    VariantBody,
+};
+use crate::bindgen::language_backend::{collect_typedefs, resolve_typedefs, LanguageBackend};
+use crate::bindgen::reserved;
+use crate::bindgen::writer::{ListType, SourceWriter};
+use crate::bindgen::{Bindings, Config, DocumentationLength, Layout};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+
+/// The private helpers every bindings class ends with, indented by four spaces
+/// per level.
+///
+/// `MemoryLayout.structLayout` rejects misaligned members instead of padding
+/// them, and neither it nor `unionLayout` pads their size to a multiple of their
+/// alignment, so the generated layouts go through these to match C's.
+const HELPERS: &str = "\
+private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+}
+
+private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+}
+
+private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+}
+
+private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+}
+
+private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+}
+
+private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+}
+
+private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+        long padding = paddingTo(size, member.byteAlignment());
+        if (padding != 0) {
+            padded.add(MemoryLayout.paddingLayout(padding));
+        }
+        padded.add(member);
+        size += padding + member.byteSize();
+        alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+}
+
+private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+}
+
+private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+        size = Math.max(size, member.byteSize());
+        alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+        return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+}
+
+private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+        case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+        case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+        case SequenceLayout sequence ->
+            MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+        default -> layout;
+    };
+    if (layout.name().isPresent()) {
+        result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+}
+
+private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+        result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+}
+";
+
+/// Writes a class using the Foreign Function & Memory API (`java.lang.foreign`).
+///
+/// Every struct, union and enum becomes a nested class holding its `LAYOUT`,
+/// functions become `MethodHandle`s and constants `static final` fields. Like
+/// for cffi, `#[cfg]` conditions are resolved against `[java] defines`.
+pub struct JavaLanguageBackend<'a> {
+    config: &'a Config,
+    /// Java has no type aliases, so typedefs are replaced by the type they alias
+    /// wherever they are used.
+    typedefs: HashMap<String, Type>,
+    /// The integer type of each enum tag, which is what values of the enum are
+    /// passed around as.
+    enums: HashMap<String, PrimitiveType>,
+    /// The opaque items, which have no `LAYOUT` as their size is unknown.
+    opaque_items: HashSet<String>,
+}
+
+impl<'a> JavaLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            typedefs: HashMap::new(),
+            enums: HashMap::new(),
+            opaque_items: HashSet::new(),
+        }
+    }
+
+    fn is_enabled(&self, cfg: Option<&Cfg>) -> bool {
+        cfg.and_then(|cfg| cfg.to_condition(self.config))
+            .map_or(true, |condition| {
+                condition.evaluate(&self.config.java.defines)
+            })
+    }
+
+    fn ident<'n>(&self, name: &'n str) -> Cow<'n, str> {
+        if reserved::is_java_reserved(name) {
+            Cow::Owned(format!("{name}_"))
+        } else {
+            Cow::Borrowed(name)
+        }
+    }
+
+    fn write_ident<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        write!(out, "{}", self.ident(name));
+    }
+
+    fn is_opaque(&self, ty: &Type) -> bool {
+        match resolve_typedefs(&self.typedefs, ty) {
+            Type::Path(ref path) => self.opaque_items.contains(path.export_name()),
+            _ => false,
+        }
+    }
+
+    /// Returns the expression of the `MemoryLayout` of `ty`.
+    fn layout(&self, ty: &Type) -> String {
+        match resolve_typedefs(&self.typedefs, ty) {
+            Type::Primitive(ref prim) => value_layout(prim).to_owned(),
+            Type::Ptr { .. } | Type::FuncPtr { .. } => "ADDRESS".to_owned(),
+            Type::Path(ref path) => format!("{}.LAYOUT", self.ident(path.export_name())),
+            Type::Array(ref ty, ref len) => {
+                let len = match len {
+                    ConstExpr::Path(ref path) => self.ident(path.export_name()).into_owned(),
+                    ConstExpr::Value(ref value) => value.clone(),
+                };
+                format!("MemoryLayout.sequenceLayout({len}, {})", self.layout(ty))
+            }
+        }
+    }
+
+    /// Returns the layout of a function argument, where arrays decay to pointers.
+    fn argument_layout(&self, ty: &Type) -> String {
+        match resolve_typedefs(&self.typedefs, ty) {
+            Type::Array(..) => "ADDRESS".to_owned(),
+            ty => self.layout(&ty),
+        }
+    }
+
+    fn field_layouts(&self, fields: &[Field], alignment: Option<ReprAlign>) -> Vec<String> {
+        fields
+            .iter()
+            .filter(|f| self.is_enabled(f.cfg.as_ref()))
+            .map(|f| {
+                let layout = format!("{}.withName(\"{}\")", self.layout(&f.ty), f.name);
+                match alignment {
+                    Some(ReprAlign::Packed) => format!("packed(1, {layout})"),
+                    Some(ReprAlign::PackedN(n)) => format!("packed({n}, {layout})"),
+                    _ => layout,
+                }
+            })
+            .collect()
+    }
+
+    /// Writes a call to one of the layout helpers, with a member per line.
+    fn write_layout_call<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        function: &str,
+        alignment: Option<ReprAlign>,
+        members: &[String],
+    ) {
+        write!(out, "{function}(");
+        let mut args = Vec::new();
+        if let Some(ReprAlign::Align(n)) = alignment {
+            args.push(n.to_string());
+        }
+        args.extend_from_slice(members);
+        if args.is_empty() {
+            out.write(")");
+            return;
+        }
+        out.push_tab();
+        for (i, arg) in args.iter().enumerate() {
+            out.new_line();
+            write!(out, "{arg}");
+            if i != args.len() - 1 {
+                out.write(",");
+            }
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write(")");
+    }
+
+    /// Opens a nested class, which can't be instantiated.
+    fn open_class<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        let name = self.ident(name).into_owned();
+        write!(out, "public static final class {name}");
+        out.open_brace();
+        write!(out, "private {name}() {{}}");
+        out.new_line();
+        out.new_line();
+    }
+
+    fn write_layout_class<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        path: &Path,
+        name: &str,
+        write_layout: impl FnOnce(&mut Self, &mut SourceWriter<W>),
+    ) {
+        self.open_class(out, name);
+        if let Some(body) = self.config.export.pre_body(path) {
+            out.write_raw_block(body);
+            out.new_line();
+            out.new_line();
+        }
+        write_layout(self, out);
+        write!(out, ".withName(\"{name}\");");
+        if let Some(body) = self.config.export.post_body(path) {
+            out.new_line();
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+    }
+
+    fn write_integer<W: Write>(&mut self, out: &mut SourceWriter<W>, value: i128, bits: u32) {
+        match bits {
+            1 => write!(out, "{}", value != 0),
+            // Java has no 128-bit integers, so only the low 64 bits are kept.
+            64 | 128 => write!(out, "{}L", to_signed(value, 64)),
+            _ => write!(out, "{}", to_signed(value, bits)),
+        }
+    }
+
+    /// Writes the tag enum of an enum as a class of `static final` constants, one per variant.
+    fn write_tag_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let carrier = enum_carrier(e);
+        let (bits, _) = carrier.integer_bits().unwrap();
+
+        self.open_class(out, e.tag_name());
+        let java_type = if bits == 128 {
+            // The values of 128-bit enums are written as their low 64 bits.
+            out.write("public static final MemoryLayout LAYOUT = int128();");
+            "long"
+        } else {
+            let java_type = carrier.to_repr_java();
+            write!(
+                out,
+                "public static final ValueLayout.Of{}{} LAYOUT = {};",
+                java_type[..1].to_uppercase(),
+                &java_type[1..],
+                value_layout(&carrier)
+            );
+            java_type
+        };
+
+        // The value of a variant without a discriminant is one more than the previous
+        // one's, which is left to Java if the previous one couldn't be evaluated.
+        let mut previous: Option<Result<i128, Cow<str>>> = None;
+        for variant in &e.variants {
+            if !self.is_enabled(variant.cfg.as_ref()) {
+                continue;
+            }
+            if previous.is_none() {
+                out.new_line();
+            }
+            out.new_line();
+            self.write_documentation(out, &variant.documentation);
+            write!(out, "public static final {java_type} ");
+            self.write_ident(out, &variant.export_name);
+            out.write(" = ");
+            let value = match (&variant.discriminant, &previous) {
+                (Some(discriminant), _) => {
+                    discriminant.evaluate(out.bindings(), &self.config.java.defines)
+                }
+                (None, None) => Some(0),
+                (None, Some(Ok(previous))) => Some(previous + 1),
+                (None, Some(Err(_))) => None,
+            };
+            match (value, &variant.discriminant, &previous) {
+                (Some(value), _, _) => self.write_integer(out, value, bits),
+                (None, Some(discriminant), _) => {
+                    write!(out, "({java_type}) (");
+                    self.write_literal(out, discriminant);
+                    out.write(")");
+                }
+                (None, None, Some(Err(previous))) => {
+                    write!(out, "({java_type}) ({previous} + 1)")
+                }
+                (None, None, _) => unreachable!(),
+            }
+            out.write(";");
+            previous = Some(value.ok_or_else(|| self.ident(&variant.export_name)));
+        }
+        out.close_brace(false);
+    }
+
+    fn write_constants<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        b: &Bindings,
+        constants: &[Constant],
+        associated_to: Option<&Struct>,
+    ) {
+        for constant in constants {
+            if !self.is_enabled(constant.cfg.as_ref()) {
+                continue;
+            }
+            // Only primitives can be compile-time constants, and Java has no 128-bit
+            // or half precision ones.
+            match resolve_typedefs(&self.typedefs, &constant.ty) {
+                Type::Primitive(ref prim) if prim.to_repr_java() == "MemorySegment" => continue,
+                Type::Primitive(PrimitiveType::Void | PrimitiveType::Float16) => continue,
+                Type::Primitive(_) => {}
+                _ => continue,
+            }
+            out.new_line_if_not_start();
+            constant.write(&b.config, self, out, associated_to);
+            out.new_line();
+        }
+    }
+}
+
+/// Returns the `ValueLayout` constant with the layout of `prim`, assuming an LP64 target.
+fn value_layout(prim: &PrimitiveType) -> &'static str {
+    match *prim {
+        PrimitiveType::Bool => "JAVA_BOOLEAN",
+        PrimitiveType::Float => "JAVA_FLOAT",
+        PrimitiveType::Double => "JAVA_DOUBLE",
+        PrimitiveType::Void | PrimitiveType::VaList => "ADDRESS",
+        PrimitiveType::Float16 => "JAVA_SHORT",
+        // Only the bits of `f128` values are kept, which isn't how they're passed by value.
+        PrimitiveType::Float128 => "int128()",
+        _ => match prim.integer_bits() {
+            Some((8, _)) => "JAVA_BYTE",
+            Some((16, _)) => "JAVA_SHORT",
+            Some((32, _)) => "JAVA_INT",
+            Some((128, _)) => "int128()",
+            _ => "JAVA_LONG",
+        },
+    }
+}
+
+/// Returns the integer type values of `e`'s tag are stored as.
+fn enum_carrier(e: &Enum) -> PrimitiveType {
+    match e.repr.ty {
+        Some(ty) => ty.to_primitive(),
+        None => PrimitiveType::Integer {
+            zeroable: true,
+            signed: true,
+            kind: crate::bindgen::ir::IntKind::Int,
+        },
+    }
+}
+
+/// Reinterprets `value` as a two's complement integer of `bits` bits, as Java
+/// has no unsigned types.
+fn to_signed(value: i128, bits: u32) -> i128 {
+    let value = value.rem_euclid(1 << bits);
+    if value >= 1 << (bits - 1) {
+        value - (1 << bits)
+    } else {
+        value
+    }
+}
+
+impl LanguageBackend for JavaLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {package_version}");
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+
+        if let Some(ref package) = self.config.java.package {
+            out.new_line_if_not_start();
+            write!(out, "package {package};");
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            out.write("import java.lang.foreign.*;");
+            out.new_line();
+            out.write("import java.lang.invoke.MethodHandle;");
+            out.new_line();
+            out.write("import java.util.ArrayList;");
+            out.new_line();
+            out.write("import java.util.Arrays;");
+            out.new_line();
+            out.write("import java.util.List;");
+            out.new_line();
+            out.new_line();
+            out.write("import static java.lang.foreign.ValueLayout.*;");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{line}");
+            out.new_line();
+        }
+    }
+
+    /// Opens the bindings class, as Java can't declare anything at the top level.
+    fn open_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        let class_name = self.config.java.class_name();
+        out.new_line_if_not_start();
+        write!(out, "public final class {class_name}");
+        out.open_brace();
+        write!(out, "private {class_name}() {{}}");
+        out.new_line();
+        out.new_line();
+        out.write("private static final Linker LINKER = Linker.nativeLinker();");
+        out.new_line();
+        match self.config.java.library_name {
+            Some(ref library) => write!(
+                out,
+                "private static final SymbolLookup LOOKUP = SymbolLookup.libraryLookup(System.mapLibraryName(\"{library}\"), Arena.global());"
+            ),
+            None => out.write("private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();"),
+        }
+        out.new_line();
+    }
+
+    fn close_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        out.new_line_if_not_start();
+        for line in HELPERS.lines() {
+            let code = line.trim_start();
+            if !code.is_empty() {
+                let indent = (line.len() - code.len()) / 4 * self.config.tab_width;
+                write!(out, "{:indent$}{code}", "");
+            }
+            out.new_line();
+        }
+        // Every member ends its own line, so close the brace on the current one.
+        out.pop_tab();
+        out.write("}");
+    }
+
+    fn write_footers<W: Write>(&mut self, out: &mut SourceWriter<W>) {
+        out.new_line();
+    }
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        if e.tag.is_none() {
+            self.write_documentation(out, &e.documentation);
+            self.write_tag_enum(out, e);
+            return;
+        }
+
+        self.write_tag_enum(out, e);
+
+        let variants: Vec<_> = e
+            .variants
+            .iter()
+            .filter(|v| self.is_enabled(v.cfg.as_ref()))
+            .collect();
+
+        for variant in &variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                self.write_struct(out, body);
+            }
+        }
+
+        let tag = format!("{}.LAYOUT.withName(\"tag\")", self.ident(e.tag_name()));
+        let mut bodies = Vec::new();
+        for variant in &variants {
+            if let VariantBody::Body {
+                ref name,
+                ref body,
+                inline,
+                ..
+            } = variant.body
+            {
+                bodies.push(if inline {
+                    // Inline bodies are anonymous structs, whose fields are reached directly.
+                    let fields = self.field_layouts(&body.fields, None).join(", ");
+                    format!("structLayout({fields})")
+                } else {
+                    format!(
+                        "{}.LAYOUT.withName(\"{name}\")",
+                        self.ident(body.export_name())
+                    )
+                });
+            }
+        }
+
+        out.new_line();
+        out.new_line();
+        self.write_documentation(out, &e.documentation);
+        let inline_tag = Enum::inline_tag_field(&e.repr);
+        self.write_layout_class(out, &e.path, e.export_name(), |lb, out| {
+            if inline_tag {
+                // Every variant starts with the tag, so they can all overlap it.
+                out.write("public static final UnionLayout LAYOUT = ");
+                let mut members = vec![tag];
+                members.extend(bodies);
+                lb.write_layout_call(out, "unionLayout", None, &members);
+            } else {
+                out.write("public static final StructLayout LAYOUT = ");
+                let payload = format!("unionLayout({})", bodies.join(", "));
+                lb.write_layout_call(out, "structLayout", None, &[tag, payload]);
+            }
+        });
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        self.write_documentation(out, &s.documentation);
+        let members = self.field_layouts(&s.fields, s.alignment);
+        self.write_layout_class(out, &s.path, s.export_name(), |lb, out| {
+            out.write("public static final StructLayout LAYOUT = ");
+            lb.write_layout_call(out, "structLayout", s.alignment, &members);
+        });
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        self.write_documentation(out, &u.documentation);
+        let members = self.field_layouts(&u.fields, u.alignment);
+        self.write_layout_class(out, &u.path, &u.export_name, |lb, out| {
+            out.write("public static final UnionLayout LAYOUT = ");
+            lb.write_layout_call(out, "unionLayout", u.alignment, &members);
+        });
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let Some(storage) = o.storage else {
+            write!(
+                out,
+                "// `{}` is opaque, so it's only ever passed around as an `ADDRESS`.",
+                o.export_name()
+            );
+            return;
+        };
+        self.write_documentation(out, &o.documentation);
+        self.write_layout_class(out, &o.path, o.export_name(), |_, out| {
+            write!(
+                out,
+                "public static final MemoryLayout LAYOUT = MemoryLayout.sequenceLayout({}, JAVA_BYTE).withByteAlignment({})",
+                storage.size, storage.align
+            );
+        });
+    }
+
+    fn write_type_def<W: Write>(&mut self, _out: &mut SourceWriter<W>, _t: &Typedef) {
+        // Typedefs are substituted where they're used, see `resolve_typedefs`.
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        self.write_documentation(out, &s.documentation);
+        out.write("public static final MemorySegment ");
+        self.write_ident(out, &s.export_name);
+        if self.is_opaque(&s.ty) {
+            // Without a size, all that can be known about it is its address.
+            write!(out, " = global(\"{}\");", s.export_name);
+        } else {
+            write!(
+                out,
+                " = global(\"{}\", {});",
+                s.export_name,
+                self.layout(&s.ty)
+            );
+        }
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        _config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        if self.is_opaque(&func.ret) || func.args.iter().any(|arg| self.is_opaque(&arg.ty)) {
+            write!(
+                out,
+                "// `{}` passes an opaque type by value, which can't be described without its layout.",
+                func.path().name()
+            );
+            return;
+        }
+
+        self.write_documentation(out, &func.documentation);
+
+        let mut layouts = Vec::new();
+        let mut first_variadic_arg = None;
+        for (i, arg) in func.args.iter().enumerate() {
+            match resolve_typedefs(&self.typedefs, &arg.ty) {
+                Type::Primitive(PrimitiveType::VaList) => first_variadic_arg = Some(i),
+                ty => layouts.push(self.argument_layout(&ty)),
+            }
+        }
+
+        out.write("public static final MethodHandle ");
+        self.write_ident(out, func.path().name());
+        write!(out, " = downcall(\"{}\", ", func.path().name());
+        match resolve_typedefs(&self.typedefs, &func.ret) {
+            Type::Primitive(PrimitiveType::Void) => out.write("FunctionDescriptor.ofVoid("),
+            ret => {
+                out.write("FunctionDescriptor.of(");
+                layouts.insert(0, self.layout(&ret));
+            }
+        }
+        let write_layout =
+            |_: &mut Self, out: &mut SourceWriter<W>, layout: &String| write!(out, "{layout}");
+        match layout {
+            Layout::Vertical => {
+                out.write_vertical_source_list(self, &layouts, ListType::Join(","), write_layout)
+            }
+            _ => {
+                out.write_horizontal_source_list(self, &layouts, ListType::Join(", "), write_layout)
+            }
+        }
+        out.write(")");
+        if let Some(i) = first_variadic_arg {
+            write!(out, ", Linker.Option.firstVariadicArg({i})");
+        }
+        out.write(");");
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match resolve_typedefs(&self.typedefs, t) {
+            Type::Primitive(ref prim) => out.write(prim.to_repr_java()),
+            Type::Path(ref path) => match self.enums.get(path.export_name()) {
+                Some(carrier) => out.write(carrier.to_repr_java()),
+                None => out.write("MemorySegment"),
+            },
+            _ => out.write("MemorySegment"),
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Javadoc is the only comment style Java tools understand, so
+        // `documentation_style` is not relevant. Unicode escapes are processed even
+        // in comments, so they have to be escaped too.
+        out.write("/**");
+        out.new_line();
+        for line in &d.doc_comment[..end] {
+            let line = line.replace("*/", "*&#47;").replace("\\u", "\\\\u");
+            write!(out, " *{line}");
+            out.new_line();
+        }
+        out.write(" */");
+        out.new_line();
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => {
+                if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+                    // Java characters are UTF-16 code units, so use the code point instead.
+                    write!(out, "0x{}", code.trim_start_matches('0'));
+                } else if let Some(code) =
+                    v.strip_prefix("'\\u{").and_then(|v| v.strip_suffix("}'"))
+                {
+                    write!(out, "'\\u{code:0>4}'");
+                } else if v.starts_with(|c: char| c.is_ascii_digit()) {
+                    let digits = v.trim_end_matches(['u', 'l']);
+                    write!(out, "{digits}");
+                    if v[digits.len()..].contains('l') {
+                        out.write("L");
+                    }
+                } else {
+                    write!(out, "{v}");
+                }
+            }
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(variant) = out.bindings().enum_variant_reference(path, name) {
+                        return write!(out, "{variant}");
+                    }
+                    return write!(out, "{export_name}_{name}");
+                }
+                self.write_ident(out, name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                out.write("(");
+                self.write_literal(out, base);
+                out.write(").");
+                self.write_ident(out, field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{op}");
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                out.write("(");
+                self.write_literal(out, left);
+                write!(out, " {op} ");
+                self.write_literal(out, right);
+                out.write(")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                let ty = resolve_typedefs(&self.typedefs, ty);
+                let Type::Primitive(ref prim) = ty else {
+                    return self.write_literal(out, value);
+                };
+                if let Some((bits, _)) = prim.integer_bits() {
+                    let defines = &self.config.java.defines;
+                    // `ty` may be a typedef, so wrap the value to the type it resolved to.
+                    if let Some(value) = value
+                        .evaluate(out.bindings(), defines)
+                        .and_then(|value| prim.wrap(value))
+                    {
+                        return self.write_integer(out, value, bits);
+                    }
+                }
+                match (prim, &**value) {
+                    // Unsuffixed floating point literals are doubles in Java.
+                    (PrimitiveType::Float, Literal::Expr(v)) => write!(out, "{v}f"),
+                    (PrimitiveType::Double, Literal::Expr(v)) => write!(out, "{v}"),
+                    _ => {
+                        // Parenthesize the value, so that `(int) -1` isn't parsed as a subtraction.
+                        write!(out, "({}) (", prim.to_repr_java());
+                        self.write_literal(out, value);
+                        out.write(")");
+                    }
+                }
+            }
+            // Arrays and structs can't be constants in Java, see `write_constants`.
+            Literal::Array { .. } | Literal::Struct { .. } | Literal::Bytes { .. } => {
+                out.write("null")
+            }
+        }
+    }
+
+    fn write_primitive_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.write_constants(out, b, &b.constants, None);
+        for item in &b.items {
+            if let ItemContainer::Struct(ref s) = *item {
+                if s.annotations.should_export() && self.is_enabled(s.cfg.as_ref()) {
+                    self.write_constants(out, b, &s.associated_constants, Some(s));
+                }
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for item in &b.items {
+            let enabled = self.is_enabled(item.deref().cfg());
+            if !item.deref().annotations().should_export() || !enabled {
+                continue;
+            }
+
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Typedef(..) => continue,
+                ItemContainer::Struct(ref x) if x.as_typedef().is_some() => continue,
+                _ => {}
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => self.write_struct(out, x),
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                _ => unreachable!(),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_non_primitive_constants<W: Write>(
+        &mut self,
+        _out: &mut SourceWriter<W>,
+        _b: &Bindings,
+    ) {
+        // Every constant Java can represent was written with the primitive ones.
+    }
+
+    fn write_globals<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for global in &b.globals {
+            if !global.annotations.should_export() || !self.is_enabled(global.cfg.as_ref()) {
+                continue;
+            }
+            out.new_line_if_not_start();
+            self.write_static(out, global);
+            out.new_line();
+        }
+    }
+
+    fn write_functions<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for function in &b.functions {
+            if !function.annotations.should_export() || !self.is_enabled(function.cfg.as_ref()) {
+                continue;
+            }
+            out.new_line_if_not_start();
+            self.write_function(&b.config, out, function);
+            out.new_line();
+        }
+    }
+
+    fn write_bindings<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.typedefs = collect_typedefs(b);
+        for item in &b.items {
+            match *item {
+                ItemContainer::Enum(ref e) => {
+                    self.enums.insert(e.tag_name().to_owned(), enum_carrier(e));
+                }
+                ItemContainer::OpaqueItem(ref o) if o.storage.is_none() => {
+                    self.opaque_items.insert(o.export_name().to_owned());
+                }
+                _ => {}
+            }
+        }
+
+        self.write_headers(out, &b.package_version);
+        self.open_namespaces(out);
+        self.write_primitive_constants(out, b);
+        self.write_items(out, b);
+        self.write_globals(out, b);
+        self.write_functions(out, b);
+        self.close_namespaces(out);
+        self.write_footers(out);
+        self.write_trailer(out, b);
+    }
+}
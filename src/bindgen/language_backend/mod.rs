@@ -1,18 +1,59 @@
 use crate::bindgen::ir::{
-    cfg::ConditionWrite, DeprecatedNoteKind, Documentation, Enum, Function, ItemContainer, Literal,
-    OpaqueItem, Static, Struct, ToCondition, Type, Typedef, Union,
+    cfg::ConditionWrite, DeprecatedNoteKind, Documentation, Enum, Function, Item, ItemContainer,
+    Literal, OpaqueItem, Static, Struct, ToCondition, Type, Typedef, Union,
 };
 use crate::bindgen::writer::SourceWriter;
 use crate::bindgen::{cdecl, Bindings, Layout};
 use crate::Config;
 
+use std::collections::HashMap;
 use std::io::Write;
 
+mod cffi;
 mod clike;
+mod csharp;
 mod cython;
+mod java;
+mod rust;
+mod zig;
 
+pub use cffi::CffiLanguageBackend;
 pub use clike::CLikeLanguageBackend;
+pub use csharp::CSharpLanguageBackend;
 pub use cython::CythonLanguageBackend;
+pub use java::JavaLanguageBackend;
+pub use rust::RustLanguageBackend;
+pub use zig::ZigLanguageBackend;
+
+/// Collects the type each typedef of `b` aliases, for the languages that write it
+/// wherever the typedef is used as they have no type aliases.
+pub(crate) fn collect_typedefs(b: &Bindings) -> HashMap<String, Type> {
+    let mut typedefs = HashMap::new();
+    for item in &b.items {
+        let typedef = match *item {
+            ItemContainer::Typedef(ref t) => t.clone(),
+            ItemContainer::Struct(ref s) => match s.as_typedef() {
+                Some(t) => t,
+                None => continue,
+            },
+            _ => continue,
+        };
+        typedefs.insert(typedef.export_name().to_owned(), typedef.aliased);
+    }
+    typedefs
+}
+
+/// Peels through `typedefs`, returning the type that should be written in place of `ty`.
+pub(crate) fn resolve_typedefs(typedefs: &HashMap<String, Type>, ty: &Type) -> Type {
+    let mut ty = ty.clone();
+    while let Type::Path(ref path) = ty {
+        match typedefs.get(path.export_name()) {
+            Some(aliased) => ty = aliased.clone(),
+            None => break,
+        }
+    }
+    ty
+}
 
 pub trait LanguageBackend: Sized {
     fn open_namespaces<W: Write>(&mut self, out: &mut SourceWriter<W>);
@@ -116,7 +157,12 @@ pub trait LanguageBackend: Sized {
     fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation);
     fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal);
 
+    /// Gathers what the backend needs to know about the bindings before writing any of them, like
+    /// the headers to include.
+    fn prepare(&mut self, _b: &Bindings) {}
+
     fn write_bindings<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.prepare(b);
         self.write_headers(out, &b.package_version);
         self.open_namespaces(out);
         self.write_primitive_constants(out, b);
@@ -174,9 +220,19 @@ pub trait LanguageBackend: Sized {
                 ItemContainer::Typedef(ref x) => self.write_type_def(out, x),
             }
             out.new_line();
+            self.write_item_epilogue(out, item, b);
         }
     }
 
+    /// Writes what follows the declaration of an item, like checks of its layout.
+    fn write_item_epilogue<W: Write>(
+        &mut self,
+        _out: &mut SourceWriter<W>,
+        _item: &ItemContainer,
+        _b: &Bindings,
+    ) {
+    }
+
     fn write_non_primitive_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
         for constant in &b.constants {
             if !constant.uses_only_primitive_types() {
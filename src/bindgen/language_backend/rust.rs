@@ -0,0 +1,763 @@
+use crate::bindgen::ir::{
+    escape_bytes, AnnotationSet, Cfg, ConstExpr, Constant, Documentation, Enum, EnumVariant, Field,
+    Function, FunctionArgument, Item, ItemContainer, Literal, OpaqueItem, PrimitiveType, ReprAlign,
+    ReprStyle, Static, Struct, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::reserved;
+use crate::bindgen::writer::{ListType, SourceWriter};
+use crate::bindgen::{Bindings, Config, DocumentationLength, Layout};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// Writes Rust declarations of the items, for a crate that links against the
+/// library instead of building it.
+///
+/// Unlike the other languages Rust understands the original `#[cfg]`
+/// attributes, `#[repr]`s and data-carrying enums, so they are written back as is.
+pub struct RustLanguageBackend<'a> {
+    config: &'a Config,
+}
+
+impl<'a> RustLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    fn write_ident<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        match name {
+            // These can't be raw identifiers.
+            "self" | "Self" | "super" | "crate" => write!(out, "{name}_"),
+            _ if reserved::is_rust_reserved(name) => write!(out, "r#{name}"),
+            _ => write!(out, "{name}"),
+        }
+    }
+
+    fn write_cfg<W: Write>(&mut self, out: &mut SourceWriter<W>, cfg: Option<&Cfg>) {
+        if let Some(cfg) = cfg {
+            write!(out, "#[cfg({cfg})]");
+            out.new_line();
+        }
+    }
+
+    fn write_annotations<W: Write>(&mut self, out: &mut SourceWriter<W>, a: &AnnotationSet) {
+        if a.must_use(self.config) {
+            out.write("#[must_use]");
+            out.new_line();
+        }
+        match a.deprecated.as_deref() {
+            Some("") => {
+                out.write("#[deprecated]");
+                out.new_line();
+            }
+            Some(note) => {
+                write!(out, "#[deprecated = {note:?}]");
+                out.new_line();
+            }
+            None => {}
+        }
+    }
+
+    fn write_repr<W: Write>(&mut self, out: &mut SourceWriter<W>, alignment: Option<ReprAlign>) {
+        match alignment {
+            None => out.write("#[repr(C)]"),
+            Some(ReprAlign::Packed) => out.write("#[repr(C, packed)]"),
+            Some(ReprAlign::PackedN(n)) => write!(out, "#[repr(C, packed({n}))]"),
+            Some(ReprAlign::Align(n)) => write!(out, "#[repr(C, align({n}))]"),
+        }
+        out.new_line();
+    }
+
+    /// Writes the fields of a struct or union, or of an enum variant if `is_variant`,
+    /// whose fields can't have a visibility.
+    fn write_fields<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        fields: &[Field],
+        is_variant: bool,
+    ) {
+        out.write_vertical_source_list(self, fields, ListType::Cap(","), |lb, out, f| {
+            lb.write_cfg(out, f.cfg.as_ref());
+            lb.write_documentation(out, &f.documentation);
+            if !is_variant {
+                out.write("pub ");
+            }
+            lb.write_ident(out, &f.name);
+            out.write(": ");
+            lb.write_type(out, &f.ty);
+        });
+    }
+
+    /// Writes a struct-like item, opening with its attributes and ending with its fields.
+    #[allow(clippy::too_many_arguments)]
+    fn write_struct_like<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        keyword: &str,
+        path: &crate::bindgen::ir::Path,
+        name: &str,
+        alignment: Option<ReprAlign>,
+        fields: &[Field],
+        annotations: &AnnotationSet,
+    ) {
+        self.write_repr(out, alignment);
+        out.write("#[derive(Clone, Copy)]");
+        out.new_line();
+        self.write_annotations(out, annotations);
+        write!(out, "pub {keyword} ");
+        self.write_ident(out, name);
+        out.open_brace();
+
+        if let Some(body) = self.config.export.pre_body(path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, fields, false);
+
+        if let Some(body) = self.config.export.post_body(path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &EnumVariant) {
+        self.write_cfg(out, v.cfg.as_ref());
+        self.write_documentation(out, &v.documentation);
+        self.write_ident(out, &v.export_name);
+
+        if let VariantBody::Body {
+            ref body, inline, ..
+        } = v.body
+        {
+            // The tag is implied by the variant.
+            let fields = &body.fields[usize::from(body.has_tag_field)..];
+            let is_tuple = inline
+                || fields
+                    .iter()
+                    .enumerate()
+                    .all(|(i, f)| f.name == format!("_{i}"));
+            if is_tuple {
+                out.write("(");
+                for (i, field) in fields.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_type(out, &field.ty);
+                }
+                out.write(")");
+            } else {
+                out.open_brace();
+                self.write_fields(out, fields, true);
+                out.close_brace(false);
+            }
+        }
+
+        if let Some(discriminant) = &v.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+    }
+
+    fn write_argument<W: Write>(&mut self, out: &mut SourceWriter<W>, arg: &FunctionArgument) {
+        if let Type::Primitive(PrimitiveType::VaList) = arg.ty {
+            out.write("...");
+            return;
+        }
+        match arg.name {
+            Some(ref name) => self.write_ident(out, name),
+            None => out.write("_"),
+        }
+        out.write(": ");
+        self.write_type(out, &arg.ty);
+    }
+
+    fn write_return_type<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        ret: &Type,
+        never_return: bool,
+    ) {
+        if never_return {
+            out.write(" -> !");
+        } else if *ret != Type::Primitive(PrimitiveType::Void) {
+            out.write(" -> ");
+            self.write_type(out, ret);
+        }
+    }
+
+    fn write_constant<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        b: &Bindings,
+        constant: &Constant,
+        associated_to: Option<&Struct>,
+    ) {
+        self.write_cfg(out, constant.cfg.as_ref());
+        constant.write(&b.config, self, out, associated_to);
+    }
+
+    /// Writes a literal with an integer type, which may have been evaluated to `value`.
+    fn write_integer<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        prim: &PrimitiveType,
+        value: i128,
+    ) {
+        match *prim {
+            PrimitiveType::Bool => write!(out, "{}", value != 0),
+            PrimitiveType::Char32 => match u32::try_from(value).ok().and_then(char::from_u32) {
+                Some(c) => write!(out, "{c:?}"),
+                None => write!(out, "'\\u{{{value:x}}}'"),
+            },
+            // `u128` values are held as their bit pattern, see `PrimitiveType::wrap`.
+            _ if prim.integer_bits() == Some((128, false)) => write!(out, "{}", value as u128),
+            _ => write!(out, "{value}"),
+        }
+    }
+
+    /// Writes the globals and functions, which can only be declared in an `extern` block.
+    fn write_extern_block<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        let globals: Vec<_> = b
+            .globals
+            .iter()
+            .filter(|g| g.annotations.should_export())
+            .collect();
+        let functions: Vec<_> = b
+            .functions
+            .iter()
+            .filter(|f| f.annotations.should_export())
+            .collect();
+        if globals.is_empty() && functions.is_empty() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+        out.write("extern \"C\"");
+        out.open_brace();
+        let mut first = true;
+        for global in globals {
+            if !first {
+                out.new_line();
+                out.new_line();
+            }
+            first = false;
+            self.write_static(out, global);
+        }
+        for function in functions {
+            if !first {
+                out.new_line();
+                out.new_line();
+            }
+            first = false;
+            self.write_function(&b.config, out, function);
+        }
+        out.close_brace(false);
+        out.new_line();
+    }
+}
+
+/// Returns the Rust spelling of `prim`, where the C types come from `core::ffi`.
+fn primitive_name(prim: &PrimitiveType) -> Cow<'static, str> {
+    match prim.to_repr_rust() {
+        "size_t" => Cow::Borrowed("usize"),
+        "ssize_t" | "ptrdiff_t" => Cow::Borrowed("isize"),
+        name if name.starts_with("c_") => Cow::Owned(format!("::core::ffi::{name}")),
+        name => Cow::Borrowed(name),
+    }
+}
+
+impl LanguageBackend for RustLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {package_version}");
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+
+        // Everything is referred to by its full path, so there's nothing to import.
+        if let Some(ref line) = self.config.after_includes {
+            out.new_line_if_not_start();
+            write!(out, "{line}");
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn close_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        self.write_cfg(out, e.cfg.as_ref());
+        self.write_documentation(out, &e.documentation);
+
+        let mut repr = Vec::new();
+        match e.repr.style {
+            ReprStyle::C => repr.push(Cow::Borrowed("C")),
+            ReprStyle::Transparent => repr.push(Cow::Borrowed("transparent")),
+            ReprStyle::Rust => {}
+        }
+        if let Some(ty) = e.repr.ty {
+            repr.push(primitive_name(&ty.to_primitive()));
+        }
+        write!(out, "#[repr({})]", repr.join(", "));
+        out.new_line();
+        out.write("#[derive(Clone, Copy)]");
+        out.new_line();
+        self.write_annotations(out, &e.annotations);
+
+        out.write("pub enum ");
+        self.write_ident(out, e.export_name());
+        out.open_brace();
+        if let Some(body) = self.config.export.pre_body(&e.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+        out.write_vertical_source_list(self, &e.variants, ListType::Join(""), |lb, out, v| {
+            lb.write_enum_variant(out, v)
+        });
+        if let Some(body) = self.config.export.post_body(&e.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(false);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        self.write_cfg(out, s.cfg.as_ref());
+        self.write_documentation(out, &s.documentation);
+        self.write_struct_like(
+            out,
+            "struct",
+            &s.path,
+            s.export_name(),
+            s.alignment,
+            &s.fields,
+            &s.annotations,
+        );
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        self.write_cfg(out, u.cfg.as_ref());
+        self.write_documentation(out, &u.documentation);
+        self.write_struct_like(
+            out,
+            "union",
+            &u.path,
+            &u.export_name,
+            u.alignment,
+            &u.fields,
+            &u.annotations,
+        );
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        self.write_cfg(out, o.cfg.as_ref());
+        self.write_documentation(out, &o.documentation);
+
+        if let Some(storage) = o.storage {
+            // As big and aligned as the original type, but still without any way to look inside.
+            // It can be passed by value, so it's as copyable as the structs containing it.
+            self.write_repr(out, Some(ReprAlign::Align(storage.align)));
+            out.write("#[derive(Clone, Copy)]");
+            out.new_line();
+            out.write("pub struct ");
+            self.write_ident(out, o.export_name());
+            out.open_brace();
+            write!(out, "_private: [u8; {}],", storage.size);
+            out.new_line();
+            out.write(
+                "_marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,",
+            );
+            out.close_brace(false);
+            return;
+        }
+
+        // A zero-sized type that can't be constructed, moved out of, or sent between
+        // threads, see https://doc.rust-lang.org/nomicon/ffi.html#representing-opaque-structs
+        out.write("#[repr(C)]");
+        out.new_line();
+        out.write("pub struct ");
+        self.write_ident(out, o.export_name());
+        out.open_brace();
+        out.write("_data: [u8; 0],");
+        out.new_line();
+        out.write(
+            "_marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,",
+        );
+        out.close_brace(false);
+    }
+
+    fn write_type_def<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Typedef) {
+        self.write_cfg(out, t.cfg.as_ref());
+        self.write_documentation(out, &t.documentation);
+        out.write("pub type ");
+        self.write_ident(out, t.export_name());
+        out.write(" = ");
+        self.write_type(out, &t.aliased);
+        out.write(";");
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        self.write_cfg(out, s.cfg.as_ref());
+        self.write_documentation(out, &s.documentation);
+        out.write(if s.mutable {
+            "pub static mut "
+        } else {
+            "pub static "
+        });
+        self.write_ident(out, &s.export_name);
+        out.write(": ");
+        self.write_type(out, &s.ty);
+        out.write(";");
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        _config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        self.write_cfg(out, func.cfg.as_ref());
+        self.write_documentation(out, &func.documentation);
+        self.write_annotations(out, &func.annotations);
+
+        out.write("pub fn ");
+        self.write_ident(out, func.path().name());
+        out.write("(");
+        match layout {
+            Layout::Vertical if !func.args.is_empty() => {
+                out.push_tab();
+                for arg in &func.args {
+                    out.new_line();
+                    self.write_argument(out, arg);
+                    // A trailing comma isn't allowed after `...`.
+                    if arg.ty != Type::Primitive(PrimitiveType::VaList) {
+                        out.write(",");
+                    }
+                }
+                out.pop_tab();
+                out.new_line();
+            }
+            _ => out.write_horizontal_source_list(
+                self,
+                &func.args,
+                ListType::Join(", "),
+                Self::write_argument,
+            ),
+        }
+        out.write(")");
+        self.write_return_type(out, &func.ret, func.never_return);
+        out.write(";");
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match t {
+            Type::Ptr { ty, is_const, .. } => {
+                out.write(if *is_const { "*const " } else { "*mut " });
+                self.write_type(out, ty);
+            }
+            Type::Path(generic) => self.write_ident(out, generic.export_name()),
+            Type::Primitive(prim) => write!(out, "{}", primitive_name(prim)),
+            Type::Array(ty, len) => {
+                out.write("[");
+                self.write_type(out, ty);
+                out.write("; ");
+                match len {
+                    // Constants aren't necessarily `usize`.
+                    ConstExpr::Path(path) => {
+                        self.write_ident(out, path.export_name());
+                        out.write(" as usize");
+                    }
+                    ConstExpr::Value(value) => write!(out, "{value}"),
+                }
+                out.write("]");
+            }
+            Type::FuncPtr {
+                ret,
+                args,
+                is_nullable,
+                never_return,
+            } => {
+                if *is_nullable {
+                    out.write("Option<");
+                }
+                out.write("unsafe extern \"C\" fn(");
+                for (i, (name, ty)) in args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    if let Type::Primitive(PrimitiveType::VaList) = ty {
+                        out.write("...");
+                        continue;
+                    }
+                    if let Some(name) = name {
+                        self.write_ident(out, name);
+                        out.write(": ");
+                    }
+                    self.write_type(out, ty);
+                }
+                out.write(")");
+                self.write_return_type(out, ret, *never_return);
+                if *is_nullable {
+                    out.write(">");
+                }
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // The comments came from Rust, so `documentation_style` is not relevant.
+        for line in &d.doc_comment[..end] {
+            write!(out, "///{line}");
+            out.new_line();
+        }
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => {
+                if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+                    write!(out, "'\\u{{{}}}'", code.trim_start_matches('0'));
+                } else if v.starts_with(|c: char| c.is_ascii_digit()) {
+                    // Drop the C suffixes, Rust infers the type from the context.
+                    write!(out, "{}", v.trim_end_matches(['u', 'l']));
+                } else {
+                    write!(out, "{v}");
+                }
+            }
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if PrimitiveType::maybe(path.name()).is_some() {
+                        return write!(out, "{}::{name}", path.name());
+                    }
+                    if let Some(variant) = out.bindings().enum_variant_reference(path, name) {
+                        return write!(out, "{variant}");
+                    }
+                    return write!(out, "{export_name}_{name}");
+                }
+                self.write_ident(out, name)
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                out.write("(");
+                self.write_literal(out, base);
+                out.write(").");
+                self.write_ident(out, field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                // Rust's bitwise not is `!`.
+                out.write(if *op == "~" { "!" } else { op });
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                out.write("(");
+                self.write_literal(out, left);
+                write!(out, " {op} ");
+                self.write_literal(out, right);
+                out.write(")");
+            }
+            Literal::Cast { ref ty, ref value } => match ty {
+                Type::Primitive(
+                    PrimitiveType::Float
+                    | PrimitiveType::Double
+                    | PrimitiveType::Float16
+                    | PrimitiveType::Float128,
+                ) => match **value {
+                    Literal::Expr(ref v) if !v.contains(['.', 'e', 'E']) => {
+                        write!(out, "{v}.0")
+                    }
+                    Literal::Expr(ref v) => write!(out, "{v}"),
+                    _ => {
+                        out.write("(");
+                        self.write_literal(out, value);
+                        out.write(" as ");
+                        self.write_type(out, ty);
+                        out.write(")");
+                    }
+                },
+                Type::Primitive(ref prim) if prim.integer_bits().is_some() => {
+                    // Rust doesn't convert between integer types implicitly, so write
+                    // the value if it's known, or cast whatever it evaluates to.
+                    match l.evaluate(out.bindings(), &[]) {
+                        Some(v) => self.write_integer(out, prim, v),
+                        None => {
+                            out.write("(");
+                            self.write_literal(out, value);
+                            out.write(" as ");
+                            self.write_type(out, ty);
+                            out.write(")");
+                        }
+                    }
+                }
+                Type::Ptr { .. } | Type::FuncPtr { .. } | Type::Primitive(..) => {
+                    out.write("(");
+                    self.write_literal(out, value);
+                    out.write(" as ");
+                    self.write_type(out, ty);
+                    out.write(")");
+                }
+                Type::Path(generic) => match **value {
+                    // A variant brought into scope with `use Enum::*`.
+                    Literal::Path {
+                        associated_to: None,
+                        ref name,
+                    } => match out.bindings().enum_variant_reference(generic.path(), name) {
+                        Some(variant) => write!(out, "{variant}"),
+                        None => self.write_literal(out, value),
+                    },
+                    _ => self.write_literal(out, value),
+                },
+                // `b"RIFF"` is a reference to the array.
+                Type::Array(..) if matches!(**value, Literal::Bytes { .. }) => {
+                    out.write("*");
+                    self.write_literal(out, value);
+                }
+                Type::Array(..) => self.write_literal(out, value),
+            },
+            Literal::Bytes { ref value, c_str } => {
+                let value = escape_bytes(value, |b| format!("\\x{b:02x}"));
+                if *c_str {
+                    write!(out, "c\"{value}\".as_ptr()");
+                } else {
+                    write!(out, "b\"{value}\"");
+                }
+            }
+            Literal::Array { ref items } => {
+                out.write("[");
+                for (i, item) in items.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    self.write_literal(out, item);
+                }
+                out.write("]");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                self.write_ident(out, export_name);
+                out.write(" { ");
+                let ordered_fields = out.bindings().struct_field_names(path);
+                let mut is_first_field = true;
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            out.write(", ");
+                        }
+                        is_first_field = false;
+                        if let Some(ref cfg) = lit.cfg {
+                            write!(out, "#[cfg({cfg})] ");
+                        }
+                        self.write_ident(out, ordered_key);
+                        out.write(": ");
+                        self.write_literal(out, &lit.value);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+
+    fn write_primitive_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for constant in &b.constants {
+            if constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                self.write_constant(out, b, constant, None);
+                out.new_line();
+            }
+        }
+    }
+
+    fn write_items<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for item in &b.items {
+            if !item.deref().annotations().should_export() {
+                continue;
+            }
+
+            out.new_line_if_not_start();
+            match *item {
+                ItemContainer::Constant(..) => unreachable!(),
+                ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => self.write_enum(out, x),
+                ItemContainer::Struct(ref x) => {
+                    match x.as_typedef() {
+                        Some(typedef) => self.write_type_def(out, &typedef),
+                        None => self.write_struct(out, x),
+                    }
+                    for constant in &x.associated_constants {
+                        out.new_line();
+                        out.new_line();
+                        self.write_constant(out, b, constant, Some(x));
+                    }
+                }
+                ItemContainer::Union(ref x) => self.write_union(out, x),
+                ItemContainer::OpaqueItem(ref x) => self.write_opaque_item(out, x),
+                ItemContainer::Typedef(ref x) => self.write_type_def(out, x),
+            }
+            out.new_line();
+        }
+    }
+
+    fn write_non_primitive_constants<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        for constant in &b.constants {
+            if !constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                self.write_constant(out, b, constant, None);
+                out.new_line();
+            }
+        }
+    }
+
+    fn write_bindings<W: Write>(&mut self, out: &mut SourceWriter<W>, b: &Bindings) {
+        self.write_headers(out, &b.package_version);
+        self.write_primitive_constants(out, b);
+        self.write_items(out, b);
+        self.write_non_primitive_constants(out, b);
+        self.write_extern_block(out, b);
+        self.write_trailer(out, b);
+    }
+}
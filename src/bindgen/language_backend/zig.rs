@@ -0,0 +1,747 @@
+use crate::bindgen::ir::{
+    escape_bytes, ConditionWrite, Documentation, Enum, EnumVariant, Field, Function,
+    FunctionArgument, Item, Literal, OpaqueItem, PrimitiveType, ReprAlign, Static, Struct,
+    ToCondition, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::reserved;
+use crate::bindgen::writer::{ListType, SourceWriter};
+use crate::bindgen::DocumentationLength;
+use crate::bindgen::{Config, Layout};
+use std::io::Write;
+
+pub struct ZigLanguageBackend<'a> {
+    config: &'a Config,
+}
+
+impl<'a> ZigLanguageBackend<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    fn write_ident<W: Write>(&mut self, out: &mut SourceWriter<W>, name: &str) {
+        if reserved::is_zig_reserved(name) {
+            write!(out, "@\"{name}\"");
+        } else {
+            write!(out, "{name}");
+        }
+    }
+
+    fn write_enum_variant<W: Write>(&mut self, out: &mut SourceWriter<W>, v: &EnumVariant) {
+        let condition = v.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &v.documentation);
+        self.write_ident(out, &v.export_name);
+        if let Some(discriminant) = &v.discriminant {
+            out.write(" = ");
+            self.write_literal(out, discriminant);
+        }
+        out.write(",");
+
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes a field including its trailing comma, so that a conditional
+    /// field doesn't end up with the comma inside of its `// #endif` comment.
+    fn write_field<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        f: &Field,
+        align: Option<ReprAlign>,
+    ) {
+        let condition = f.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &f.documentation);
+        self.write_ident(out, &f.name);
+        out.write(": ");
+        self.write_type(out, &f.ty);
+        match align {
+            Some(ReprAlign::Packed) => out.write(" align(1)"),
+            Some(ReprAlign::PackedN(n)) => {
+                out.write(" align(@min(@alignOf(");
+                self.write_type(out, &f.ty);
+                write!(out, "), {n}))");
+            }
+            Some(ReprAlign::Align(n)) => write!(out, " align({n})"),
+            None => {}
+        }
+        out.write(",");
+
+        condition.write_after(self.config, out);
+    }
+
+    /// Writes the fields of an `extern struct` or `extern union`.
+    ///
+    /// Zig has no container-level alignment for extern types, so
+    /// `#[repr(packed)]` lowers every field alignment to 1,
+    /// `#[repr(packed(N))]` caps every field alignment to N, and
+    /// `#[repr(align(N))]` raises the alignment of the first field.
+    fn write_fields<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+    ) {
+        let fields: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let align = match alignment {
+                    Some(ReprAlign::Align(_)) if i != 0 => None,
+                    align => align,
+                };
+                (field, align)
+            })
+            .collect();
+        out.write_vertical_source_list(self, &fields, ListType::Join(""), |lb, out, f| {
+            lb.write_field(out, f.0, f.1)
+        });
+    }
+
+    fn write_container_decl<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        name: &str,
+        container: &str,
+    ) {
+        out.write("pub const ");
+        self.write_ident(out, name);
+        write!(out, " = {container}");
+    }
+
+    fn write_argument<W: Write>(&mut self, out: &mut SourceWriter<W>, arg: &FunctionArgument) {
+        if let Type::Primitive(PrimitiveType::VaList) = arg.ty {
+            out.write("...");
+            return;
+        }
+        match arg.name {
+            Some(ref name) => self.write_ident(out, name),
+            None => out.write("_"),
+        }
+        out.write(": ");
+        self.write_type(out, &arg.ty);
+    }
+
+    fn write_return_type<W: Write>(
+        &mut self,
+        out: &mut SourceWriter<W>,
+        ret: &Type,
+        never_return: bool,
+    ) {
+        if never_return {
+            out.write("noreturn");
+        } else {
+            self.write_type(out, ret);
+        }
+    }
+
+    /// Writes the tag enum of an enum as an `enum(T)`, where `T` defaults to `c_int` like in C.
+    fn write_tag_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let size = match e.repr.ty {
+            Some(ty) => ty.to_primitive().to_repr_zig(),
+            None => "c_int",
+        };
+
+        self.write_container_decl(out, e.tag_name(), "");
+        write!(out, "enum({size})");
+        out.open_brace();
+        out.write_vertical_source_list(self, &e.variants, ListType::Join(""), |lb, out, v| {
+            lb.write_enum_variant(out, v)
+        });
+        out.close_brace(true);
+    }
+
+    /// Writes the members of the data-carrying variants, which are unions fields in both the
+    /// inline and the separate tag representations.
+    fn write_variant_fields<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let mut first = true;
+        for variant in &e.variants {
+            let VariantBody::Body {
+                ref name,
+                ref body,
+                inline,
+                ..
+            } = variant.body
+            else {
+                continue;
+            };
+
+            if !first {
+                out.new_line();
+            }
+            first = false;
+
+            let condition = variant.cfg.to_condition(self.config);
+            condition.write_before(self.config, out);
+
+            self.write_ident(out, name);
+            out.write(": ");
+            if inline {
+                // Zig has no anonymous members, so inlined variants become a
+                // field of an anonymous struct type instead.
+                out.write("extern struct");
+                out.open_brace();
+                self.write_fields(out, &body.fields, None);
+                out.close_brace(false);
+            } else {
+                self.write_ident(out, body.export_name());
+            }
+            out.write(",");
+
+            condition.write_after(self.config, out);
+        }
+    }
+
+    /// What kind of value a type holds, for picking the builtin that casts to or from it.
+    fn cast_kind<W: Write>(&self, out: &SourceWriter<W>, ty: &Type) -> CastKind {
+        match ty {
+            Type::Primitive(PrimitiveType::Bool) => CastKind::Bool,
+            Type::Primitive(
+                PrimitiveType::Float
+                | PrimitiveType::Double
+                | PrimitiveType::Float16
+                | PrimitiveType::Float128,
+            ) => CastKind::Float,
+            Type::Primitive(..) => CastKind::Int,
+            Type::Ptr { .. } | Type::FuncPtr { .. } => CastKind::Pointer,
+            Type::Path(generic) if out.bindings().enum_exists(generic.path()) => CastKind::Enum,
+            Type::Path(..) | Type::Array(..) => CastKind::Other,
+        }
+    }
+
+    /// What kind of value `l` is, or `None` if it is an untyped integer literal or its type
+    /// isn't known, which `@as` coerces.
+    fn value_kind<W: Write>(&self, out: &SourceWriter<W>, l: &Literal) -> Option<CastKind> {
+        match l {
+            Literal::Cast { ty, .. } => Some(self.cast_kind(out, ty)),
+            Literal::Expr(v) if v.starts_with(|c: char| c.is_ascii_digit()) => {
+                let is_float = !v.starts_with("0x") && v.contains(['.', 'e', 'E']);
+                is_float.then_some(CastKind::UntypedFloat)
+            }
+            Literal::Path {
+                associated_to: Some((path, _)),
+                name,
+            } => out
+                .bindings()
+                .enum_variant_reference(path, name)
+                .map(|_| CastKind::Enum),
+            Literal::Path {
+                associated_to: None,
+                name,
+            } => out
+                .bindings()
+                .constants
+                .iter()
+                .find(|c| c.export_name() == name)
+                .map(|c| self.cast_kind(out, &c.ty)),
+            Literal::BinOp { op, .. } if matches!(*op, "==" | "!=" | "<" | ">" | "<=" | ">=") => {
+                Some(CastKind::Bool)
+            }
+            Literal::BinOp { op, left, .. } if matches!(*op, "<<" | ">>") => {
+                self.value_kind(out, left)
+            }
+            Literal::BinOp { left, right, .. } => self
+                .value_kind(out, left)
+                .or_else(|| self.value_kind(out, right)),
+            Literal::PostfixUnaryOp { value, .. } => self.value_kind(out, value),
+            _ => None,
+        }
+    }
+}
+
+/// See `ZigLanguageBackend::cast_kind`.
+#[derive(Clone, Copy)]
+enum CastKind {
+    Int,
+    Float,
+    /// A floating point literal, which coerces to floats of any size.
+    UntypedFloat,
+    Bool,
+    Pointer,
+    Enum,
+    Other,
+}
+
+impl LanguageBackend for ZigLanguageBackend<'_> {
+    fn write_headers<W: Write>(&self, out: &mut SourceWriter<W>, package_version: &str) {
+        if self.config.package_version {
+            write!(out, "// Package version: {package_version}");
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "// Generated with cbindgen:{}",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{f}");
+            out.new_line();
+        }
+
+        if self.config.no_includes && self.config.after_includes.is_none() {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            // Needed for `std.builtin.VaList` and `std.math.maxInt`.
+            out.write("const std = @import(\"std\");");
+            out.new_line();
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{line}");
+            out.new_line();
+        }
+    }
+
+    fn open_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn close_namespaces<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_footers<W: Write>(&mut self, _out: &mut SourceWriter<W>) {}
+
+    fn write_enum<W: Write>(&mut self, out: &mut SourceWriter<W>, e: &Enum) {
+        let condition = e.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        if e.tag.is_none() {
+            self.write_documentation(out, &e.documentation);
+            self.write_tag_enum(out, e);
+            condition.write_after(self.config, out);
+            return;
+        }
+
+        self.write_tag_enum(out, e);
+
+        for variant in &e.variants {
+            if let VariantBody::Body {
+                ref body,
+                inline: false,
+                ..
+            } = variant.body
+            {
+                out.new_line();
+                out.new_line();
+                let condition = variant.cfg.to_condition(self.config);
+                condition.write_before(self.config, out);
+                self.write_struct(out, body);
+                condition.write_after(self.config, out);
+            }
+        }
+
+        out.new_line();
+        out.new_line();
+        self.write_documentation(out, &e.documentation);
+
+        if Enum::inline_tag_field(&e.repr) {
+            // Every variant starts with the tag, so it's accessible through any of them.
+            self.write_container_decl(out, e.export_name(), "extern union");
+            out.open_brace();
+            if let Some(body) = self.config.export.pre_body(&e.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+            out.write("tag: ");
+            self.write_ident(out, e.tag_name());
+            out.write(",");
+            out.new_line();
+            self.write_variant_fields(out, e);
+        } else {
+            self.write_container_decl(out, e.export_name(), "extern struct");
+            out.open_brace();
+            if let Some(body) = self.config.export.pre_body(&e.path) {
+                out.write_raw_block(body);
+                out.new_line();
+            }
+            out.write("tag: ");
+            self.write_ident(out, e.tag_name());
+            out.write(",");
+            out.new_line();
+            out.write("payload: extern union");
+            out.open_brace();
+            self.write_variant_fields(out, e);
+            out.close_brace(false);
+            out.write(",");
+        }
+
+        if let Some(body) = self.config.export.post_body(&e.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(true);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_struct<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Struct) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+
+        self.write_container_decl(out, s.export_name(), "extern struct");
+        out.open_brace();
+
+        if let Some(body) = self.config.export.pre_body(&s.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &s.fields, s.alignment);
+
+        if let Some(body) = self.config.export.post_body(&s.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(true);
+
+        for constant in &s.associated_constants {
+            out.new_line();
+            constant.write(self.config, self, out, Some(s));
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_union<W: Write>(&mut self, out: &mut SourceWriter<W>, u: &Union) {
+        let condition = u.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &u.documentation);
+
+        self.write_container_decl(out, &u.export_name, "extern union");
+        out.open_brace();
+
+        if let Some(body) = self.config.export.pre_body(&u.path) {
+            out.write_raw_block(body);
+            out.new_line();
+        }
+
+        self.write_fields(out, &u.fields, u.alignment);
+
+        if let Some(body) = self.config.export.post_body(&u.path) {
+            out.new_line();
+            out.write_raw_block(body);
+        }
+        out.close_brace(true);
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_opaque_item<W: Write>(&mut self, out: &mut SourceWriter<W>, o: &OpaqueItem) {
+        let condition = o.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &o.documentation);
+
+        match o.storage {
+            Some(storage) => {
+                self.write_container_decl(out, o.export_name(), "extern struct");
+                out.open_brace();
+                write!(
+                    out,
+                    "_private: [{}]u8 align({}),",
+                    storage.size, storage.align
+                );
+                out.close_brace(true);
+            }
+            None => self.write_container_decl(out, o.export_name(), "opaque {};"),
+        }
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_type_def<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Typedef) {
+        let condition = t.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &t.documentation);
+
+        self.write_container_decl(out, t.export_name(), "");
+        self.write_type(out, &t.aliased);
+        out.write(";");
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_static<W: Write>(&mut self, out: &mut SourceWriter<W>, s: &Static) {
+        let condition = s.cfg.to_condition(self.config);
+        condition.write_before(self.config, out);
+
+        self.write_documentation(out, &s.documentation);
+        out.write(if s.mutable {
+            "pub extern var "
+        } else {
+            "pub extern const "
+        });
+        self.write_ident(out, &s.export_name);
+        out.write(": ");
+        self.write_type(out, &s.ty);
+        out.write(";");
+
+        condition.write_after(self.config, out);
+    }
+
+    fn write_function_with_layout<W: Write>(
+        &mut self,
+        config: &Config,
+        out: &mut SourceWriter<W>,
+        func: &Function,
+        layout: Layout,
+    ) {
+        let condition = func.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_documentation(out, &func.documentation);
+
+        out.write("pub extern fn ");
+        self.write_ident(out, func.path().name());
+        out.write("(");
+        match layout {
+            Layout::Vertical => out.write_vertical_source_list(
+                self,
+                &func.args,
+                ListType::Join(","),
+                Self::write_argument,
+            ),
+            _ => out.write_horizontal_source_list(
+                self,
+                &func.args,
+                ListType::Join(", "),
+                Self::write_argument,
+            ),
+        }
+        out.write(") ");
+        self.write_return_type(out, &func.ret, func.never_return);
+        out.write(";");
+
+        condition.write_after(config, out);
+    }
+
+    fn write_type<W: Write>(&mut self, out: &mut SourceWriter<W>, t: &Type) {
+        match t {
+            Type::Ptr {
+                ty,
+                is_const,
+                is_nullable,
+                ..
+            } => {
+                if *is_nullable {
+                    out.write("?");
+                }
+                out.write(if *is_const { "*const " } else { "*" });
+                match **ty {
+                    Type::Primitive(PrimitiveType::Void) => out.write("anyopaque"),
+                    ref ty => self.write_type(out, ty),
+                }
+            }
+            Type::Path(generic) => self.write_ident(out, generic.export_name()),
+            Type::Primitive(primitive) => out.write(primitive.to_repr_zig()),
+            Type::Array(ty, len) => {
+                write!(out, "[{}]", len.as_str());
+                self.write_type(out, ty);
+            }
+            Type::FuncPtr {
+                ret,
+                args,
+                is_nullable,
+                never_return,
+            } => {
+                if *is_nullable {
+                    out.write("?");
+                }
+                out.write("*const fn (");
+                for (i, (name, ty)) in args.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    if let Type::Primitive(PrimitiveType::VaList) = ty {
+                        out.write("...");
+                        continue;
+                    }
+                    if let Some(name) = name {
+                        self.write_ident(out, name);
+                        out.write(": ");
+                    }
+                    self.write_type(out, ty);
+                }
+                out.write(") callconv(.c) ");
+                self.write_return_type(out, ret, *never_return);
+            }
+        }
+    }
+
+    fn write_documentation<W: Write>(&mut self, out: &mut SourceWriter<W>, d: &Documentation) {
+        if d.doc_comment.is_empty() || !self.config.documentation {
+            return;
+        }
+
+        let end = match self.config.documentation_length {
+            DocumentationLength::Short => 1,
+            DocumentationLength::Full => d.doc_comment.len(),
+        };
+
+        // Zig has a single doc comment syntax, so `documentation_style` is not relevant.
+        for line in &d.doc_comment[..end] {
+            write!(out, "///{line}");
+            out.new_line();
+        }
+    }
+
+    fn write_literal<W: Write>(&mut self, out: &mut SourceWriter<W>, l: &Literal) {
+        match l {
+            Literal::Expr(v) => {
+                if let Some(code) = v.strip_prefix("U'\\U").and_then(|v| v.strip_suffix('\'')) {
+                    // Characters outside of Latin-1 are loaded as C `char32_t` literals.
+                    write!(out, "'\\u{{{}}}'", code.trim_start_matches('0'));
+                } else if v.starts_with(|c: char| c.is_ascii_digit()) {
+                    // Zig integer literals are untyped, so drop the C suffixes.
+                    write!(out, "{}", v.trim_end_matches(['u', 'l']));
+                } else {
+                    write!(out, "{v}");
+                }
+            }
+            Literal::Path {
+                ref associated_to,
+                ref name,
+            } => {
+                if let Some((ref path, ref export_name)) = associated_to {
+                    if let Some(prim @ PrimitiveType::Integer { .. }) =
+                        PrimitiveType::maybe(path.name())
+                    {
+                        match name.as_str() {
+                            "MAX" => return write!(out, "std.math.maxInt({})", prim.to_repr_zig()),
+                            "MIN" => return write!(out, "std.math.minInt({})", prim.to_repr_zig()),
+                            _ => {}
+                        }
+                    }
+                    if let Some(variant) = out.bindings().enum_variant_reference(path, name) {
+                        return write!(out, "{variant}");
+                    }
+                    write!(out, "{export_name}_");
+                }
+                write!(out, "{name}")
+            }
+            Literal::FieldAccess {
+                ref base,
+                ref field,
+            } => {
+                out.write("(");
+                self.write_literal(out, base);
+                out.write(").");
+                self.write_ident(out, field);
+            }
+            Literal::PostfixUnaryOp { op, ref value } => {
+                write!(out, "{op}");
+                self.write_literal(out, value);
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let op = match *op {
+                    "&&" => "and",
+                    "||" => "or",
+                    op => op,
+                };
+                out.write("(");
+                self.write_literal(out, left);
+                write!(out, " {op} ");
+                self.write_literal(out, right);
+                out.write(")");
+            }
+            Literal::Cast { ref ty, ref value } => {
+                out.write("@as(");
+                self.write_type(out, ty);
+                out.write(", ");
+                // 128-bit literals are built from their bits, see `Literal::int128`,
+                // but Zig only accepts values that fit the type.
+                if let Type::Primitive(prim) = ty {
+                    if prim.integer_bits() == Some((128, true)) {
+                        if let Some(value) = l.evaluate(out.bindings(), &[]) {
+                            return write!(out, "{value})");
+                        }
+                    }
+                }
+                // `@as` only coerces between compatible types, anything else takes a builtin
+                // inferring its result type from the `@as`.
+                let builtins: &[&str] = match (self.value_kind(out, value), self.cast_kind(out, ty))
+                {
+                    (Some(CastKind::Int), CastKind::Int) => &["@intCast"],
+                    (Some(CastKind::Float | CastKind::UntypedFloat), CastKind::Int) => {
+                        &["@intFromFloat"]
+                    }
+                    (Some(CastKind::Bool), CastKind::Int) => &["@intFromBool"],
+                    (Some(CastKind::Enum), CastKind::Int) => &["@intCast", "@intFromEnum"],
+                    (Some(CastKind::Pointer), CastKind::Int) => &["@intCast", "@intFromPtr"],
+                    (Some(CastKind::Int), CastKind::Float) => &["@floatFromInt"],
+                    (Some(CastKind::Float), CastKind::Float) => &["@floatCast"],
+                    (Some(CastKind::Int) | None, CastKind::Pointer) => &["@ptrFromInt"],
+                    (Some(CastKind::Pointer), CastKind::Pointer) => &["@ptrCast"],
+                    _ => &[],
+                };
+                for builtin in builtins {
+                    write!(out, "{builtin}(");
+                }
+                self.write_literal(out, value);
+                for _ in builtins {
+                    out.write(")");
+                }
+                out.write(")");
+            }
+            Literal::Bytes { ref value, .. } => {
+                write!(
+                    out,
+                    "\"{}\"",
+                    escape_bytes(value, |b| format!("\\x{b:02x}"))
+                )
+            }
+            Literal::Array { ref items } => {
+                out.write(".{ ");
+                for item in items {
+                    self.write_literal(out, item);
+                    out.write(", ");
+                }
+                out.write("}");
+            }
+            Literal::Struct {
+                export_name,
+                fields,
+                path,
+            } => {
+                self.write_ident(out, export_name);
+                out.write("{ ");
+                let ordered_fields = out.bindings().struct_field_names(path);
+                let mut is_first_field = true;
+                for ordered_key in ordered_fields.iter() {
+                    if let Some(lit) = fields.get(ordered_key) {
+                        if !is_first_field {
+                            out.write(", ");
+                        }
+                        is_first_field = false;
+                        out.write(".");
+                        self.write_ident(out, ordered_key);
+                        out.write(" = ");
+                        self.write_literal(out, &lit.value);
+                    }
+                }
+                out.write(" }");
+            }
+        }
+    }
+}
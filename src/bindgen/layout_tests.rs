@@ -0,0 +1,92 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A Rust test module checking that the Rust types have the layout computed for the bindings,
+//! the counterpart of the static assertions checking it on the C side.
+
+use std::io::Write;
+
+use crate::bindgen::ir::{Field, ItemContainer};
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::Bindings;
+
+pub(crate) fn write_rust<F: Write>(b: &Bindings, out: &mut SourceWriter<F>) {
+    if let Some(ref f) = b.config.autogen_warning {
+        out.write_raw_block(f);
+        out.new_line();
+    }
+    out.write(
+        "// Checks the layout of the Rust types, to include in a module where they're in scope.",
+    );
+    out.new_line();
+
+    for item in &b.items {
+        let (fields, checks_fields): (&[Field], bool) = match *item {
+            ItemContainer::Struct(ref s) => (&s.fields, !s.is_transparent),
+            ItemContainer::Union(ref u) => (&u.fields, true),
+            // The fields of tagged enums are variant fields, which `offset_of!` can't reach.
+            ItemContainer::Enum(..) => (&[], false),
+            // Checks the size given to the storage of opaque items, which may be wrong.
+            ItemContainer::OpaqueItem(..) => (&[], false),
+            _ => continue,
+        };
+        let item = item.deref();
+        if !item.annotations().should_export() || b.is_monomorph(item.path()) {
+            continue;
+        }
+        let Some(layout) = b.type_layout(item.export_name()) else {
+            continue;
+        };
+        let name = item.path().name();
+
+        out.new_line();
+        if let Some(cfg) = item.cfg() {
+            write!(out, "#[cfg({cfg})]");
+            out.new_line();
+        }
+        out.write("#[test]");
+        out.new_line();
+        out.write("#[allow(non_snake_case)]");
+        out.new_line();
+        write!(out, "fn layout_of_{name}() {{");
+        out.push_tab();
+        out.new_line();
+        write!(
+            out,
+            "assert_eq!(::core::mem::size_of::<{name}>(), {}, \"size of {name}\");",
+            layout.size
+        );
+        out.new_line();
+        write!(
+            out,
+            "assert_eq!(::core::mem::align_of::<{name}>(), {}, \"alignment of {name}\");",
+            layout.align
+        );
+        if checks_fields {
+            for (field, field_layout) in fields.iter().zip(&layout.fields) {
+                let Some(ref field) = field.rust_name else {
+                    continue;
+                };
+                // Keywords need to be raw identifiers, unlike tuple indices.
+                let raw = if syn::parse_str::<syn::Ident>(field).is_err()
+                    && !field.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    "r#"
+                } else {
+                    ""
+                };
+                out.new_line();
+                write!(
+                    out,
+                    "assert_eq!(::core::mem::offset_of!({name}, {raw}{field}), {}, \"offset of {name}.{field}\");",
+                    field_layout.offset
+                );
+            }
+        }
+        out.pop_tab();
+        out.new_line();
+        out.write("}");
+        out.new_line();
+    }
+}
@@ -2,16 +2,18 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use crate::bindgen::bindings::Bindings;
-use crate::bindgen::config::{Config, Language, SortKey};
+use crate::bindgen::config::{Config, Language, NameConflicts, Reexports, SortKey};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union, VariantBody};
+use crate::bindgen::lint::Lints;
+use crate::bindgen::modules::{NameConflict, Reexport};
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::ItemType;
 
@@ -28,6 +30,9 @@ pub struct Library {
     functions: Vec<Function>,
     source_files: Vec<PathBuf>,
     package_version: String,
+    lints: Lints,
+    name_conflicts: Vec<NameConflict>,
+    reexports: Vec<Reexport>,
 }
 
 impl Library {
@@ -44,6 +49,9 @@ impl Library {
         functions: Vec<Function>,
         source_files: Vec<PathBuf>,
         package_version: String,
+        lints: Lints,
+        name_conflicts: Vec<NameConflict>,
+        reexports: Vec<Reexport>,
     ) -> Library {
         Library {
             config,
@@ -57,6 +65,9 @@ impl Library {
             functions,
             source_files,
             package_version,
+            lints,
+            name_conflicts,
+            reexports,
         }
     }
 
@@ -69,41 +80,34 @@ impl Library {
             SortKey::None => { /* keep input order */ }
         }
 
-        if self.config.language != Language::Cxx {
-            self.instantiate_monomorphs();
-        }
+        let monomorph_paths = if self.config.language != Language::Cxx {
+            self.instantiate_monomorphs(Monomorphs::default())
+        } else if self.config.layout.tuples || self.config.layout.slices {
+            // C++ keeps generic types as templates, but tuples and slices still need a struct.
+            let keep_slices = self.config.layout.cpp_slice.is_some();
+            self.instantiate_monomorphs(Monomorphs::for_templates(keep_slices))
+        } else {
+            HashSet::new()
+        };
         self.remove_excluded();
         if self.config.language == Language::C {
             self.resolve_declaration_types();
         }
 
-        self.rename_items();
-
-        let mut dependencies = Dependencies::new();
-
-        for function in &self.functions {
-            function.add_dependencies(&self, &mut dependencies);
+        if self.config.export.reexports == Reexports::Rename {
+            self.rename_reexports();
         }
-        self.globals.for_all_items(|global| {
-            global.add_dependencies(&self, &mut dependencies);
-        });
-        self.constants.for_all_items(|constant| {
-            constant.add_dependencies(&self, &mut dependencies);
-        });
-        for name in &self.config.export.include {
-            let path = Path::new(name.clone());
-            if let Some(items) = self.get_items(&path) {
-                if dependencies.items.insert(path) {
-                    for item in &items {
-                        item.deref().add_dependencies(&self, &mut dependencies);
-                    }
-                    for item in items {
-                        dependencies.order.push(item);
-                    }
-                }
-            }
+        if !self.name_conflicts.is_empty() {
+            let used = self.dependencies().items;
+            self.resolve_name_conflicts(&used)?;
+        }
+
+        self.rename_items();
+        if self.config.documentation_translate {
+            self.resolve_documentation_links();
         }
 
+        let mut dependencies = self.dependencies();
         dependencies.sort();
 
         let items = dependencies.order;
@@ -134,6 +138,8 @@ impl Library {
             vec![]
         };
 
+        let lints = self.lints.report(&items, &functions);
+
         Ok(Bindings::new(
             self.config,
             self.structs,
@@ -146,9 +152,127 @@ impl Library {
             self.source_files,
             false,
             self.package_version,
+            monomorph_paths,
+            lints,
         ))
     }
 
+    /// The items used by the functions, globals and constants, and the ones included by
+    /// `export.include`.
+    fn dependencies(&self) -> Dependencies {
+        let mut dependencies = Dependencies::new();
+
+        for function in &self.functions {
+            function.add_dependencies(self, &mut dependencies);
+        }
+        self.globals.for_all_items(|global| {
+            global.add_dependencies(self, &mut dependencies);
+        });
+        self.constants.for_all_items(|constant| {
+            constant.add_dependencies(self, &mut dependencies);
+        });
+        for name in &self.config.export.include {
+            self.add_root(Path::new(name.clone()), &mut dependencies);
+        }
+        // The re-exported types are part of the API, unless they're opaque.
+        for reexport in &self.reexports {
+            let is_repr_c = self.get_items(&reexport.path).is_some_and(|items| {
+                items
+                    .iter()
+                    .all(|item| !matches!(item, ItemContainer::OpaqueItem(..)))
+            });
+            if !is_repr_c {
+                continue;
+            }
+            let path = match self.config.export.reexports {
+                Reexports::Typedef => Path::new(reexport.alias.clone()),
+                _ => reexport.path.clone(),
+            };
+            self.add_root(path, &mut dependencies);
+        }
+
+        dependencies
+    }
+
+    fn add_root(&self, path: Path, dependencies: &mut Dependencies) {
+        if let Some(items) = self.get_items(&path) {
+            if dependencies.items.insert(path) {
+                for item in &items {
+                    item.deref().add_dependencies(self, dependencies);
+                }
+                for item in items {
+                    dependencies.order.push(item);
+                }
+            }
+        }
+    }
+
+    /// Exports the re-exported types under the name they're re-exported as, renamed by
+    /// `export.rename` if it is, unless the types are renamed themselves.
+    fn rename_reexports(&mut self) {
+        for reexport in &self.reexports {
+            let rename = &mut self.config.export.rename;
+            let name = rename
+                .get(&reexport.alias)
+                .unwrap_or(&reexport.alias)
+                .clone();
+            rename
+                .entry(reexport.path.name().to_owned())
+                .or_insert(name);
+        }
+    }
+
+    /// Gives their export name to the items defined under the same name in several modules,
+    /// according to `export.name_conflicts`, given the paths of the items used by the bindings.
+    fn resolve_name_conflicts(&mut self, used: &HashSet<Path>) -> Result<(), Error> {
+        let strategy = self.config.export.name_conflicts;
+        for conflict in &self.name_conflicts {
+            let used: Vec<_> = conflict
+                .definitions
+                .iter()
+                .filter(|d| used.contains(&d.path) || self.constants.get_items(&d.path).is_some())
+                .collect();
+            let mut export_names = HashMap::<String, Vec<&str>>::new();
+            for definition in &used {
+                let rename = &self.config.export.rename;
+                let renamed = match strategy {
+                    NameConflicts::Error => None,
+                    _ => rename
+                        .get(&definition.module_path)
+                        .or_else(|| rename.get(definition.path.name())),
+                };
+                let name = match renamed {
+                    Some(name) => name.clone(),
+                    None if strategy == NameConflicts::Prefix && used.len() > 1 => {
+                        definition.path.name().to_owned()
+                    }
+                    None => conflict.name.clone(),
+                };
+                if name != definition.path.name() {
+                    self.config
+                        .export
+                        .rename
+                        .entry(definition.path.name().to_owned())
+                        .or_insert_with(|| name.clone());
+                }
+                export_names
+                    .entry(name)
+                    .or_default()
+                    .push(&definition.module_path);
+            }
+            let mut export_names: Vec<_> = export_names.into_iter().collect();
+            export_names.sort();
+            if let Some((name, module_paths)) = export_names.into_iter().find(|(_, p)| p.len() > 1)
+            {
+                return Err(Error::NameConflict {
+                    name,
+                    module_paths: module_paths.into_iter().map(str::to_owned).collect(),
+                });
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_items(&self, p: &Path) -> Option<Vec<ItemContainer>> {
         macro_rules! find {
             ($field:ident, $kind:ident) => {
@@ -314,6 +438,121 @@ impl Library {
         }
     }
 
+    /// The exported names of the items intra-doc links can point to, by their Rust path, like
+    /// `Foo` or `Foo::Variant`.
+    fn documentation_names(&self) -> HashMap<String, String> {
+        let config = &self.config;
+        let mut names = HashMap::new();
+        let mut insert = |path: String, name: &str| {
+            names.insert(path, name.to_owned());
+        };
+        self.structs.for_all_items(|s| {
+            insert(s.path.name().to_owned(), s.export_name());
+            for field in &s.fields {
+                if let Some(ref rust_name) = field.rust_name {
+                    let path = format!("{}::{}", s.path.name(), rust_name);
+                    insert(path, &format!("{}.{}", s.export_name(), field.name));
+                }
+            }
+            for c in &s.associated_constants {
+                let path = format!("{}::{}", s.path.name(), c.export_name());
+                insert(path, &c.declared_name(config, Some(s)));
+            }
+        });
+        self.unions.for_all_items(|u| {
+            insert(u.path.name().to_owned(), u.export_name());
+            for field in &u.fields {
+                if let Some(ref rust_name) = field.rust_name {
+                    let path = format!("{}::{}", u.path.name(), rust_name);
+                    insert(path, &format!("{}.{}", u.export_name(), field.name));
+                }
+            }
+        });
+        self.enums.for_all_items(|e| {
+            insert(e.path.name().to_owned(), e.export_name());
+            for variant in &e.variants {
+                let path = format!("{}::{}", e.path.name(), variant.name);
+                if config.language == Language::Cxx {
+                    insert(
+                        path,
+                        &format!("{}::{}", e.export_name(), variant.export_name),
+                    );
+                } else {
+                    insert(path, &variant.export_name);
+                }
+            }
+        });
+        self.opaque_items
+            .for_all_items(|o| insert(o.path.name().to_owned(), o.export_name()));
+        self.typedefs
+            .for_all_items(|t| insert(t.path.name().to_owned(), t.export_name()));
+        self.globals
+            .for_all_items(|g| insert(g.path.name().to_owned(), g.export_name()));
+        self.constants.for_all_items(|c| match c.associated_to {
+            Some(ref associated_to) => {
+                let path = format!("{}::{}", associated_to.name(), c.export_name());
+                insert(path, &c.declared_name(config, None));
+            }
+            None => insert(c.path.name().to_owned(), c.export_name()),
+        });
+        for f in &self.functions {
+            insert(f.path.name().to_owned(), f.path.name());
+        }
+        names
+    }
+
+    fn resolve_documentation_links(&mut self) {
+        let names = self.documentation_names();
+
+        self.structs.for_all_items_mut(|s| {
+            let name = s.path.name().to_owned();
+            s.documentation.resolve_links(&names, Some(&name));
+            for field in &mut s.fields {
+                field.documentation.resolve_links(&names, Some(&name));
+            }
+            for c in &mut s.associated_constants {
+                c.documentation.resolve_links(&names, Some(&name));
+            }
+        });
+        self.unions.for_all_items_mut(|u| {
+            let name = u.path.name().to_owned();
+            u.documentation.resolve_links(&names, Some(&name));
+            for field in &mut u.fields {
+                field.documentation.resolve_links(&names, Some(&name));
+            }
+        });
+        self.enums.for_all_items_mut(|e| {
+            let name = e.path.name().to_owned();
+            e.documentation.resolve_links(&names, Some(&name));
+            for variant in &mut e.variants {
+                variant.documentation.resolve_links(&names, Some(&name));
+                if let VariantBody::Body { ref mut body, .. } = variant.body {
+                    for field in &mut body.fields {
+                        field.documentation.resolve_links(&names, Some(&name));
+                    }
+                }
+            }
+        });
+        self.opaque_items.for_all_items_mut(|o| {
+            let name = o.path.name().to_owned();
+            o.documentation.resolve_links(&names, Some(&name));
+        });
+        self.typedefs.for_all_items_mut(|t| {
+            let name = t.path.name().to_owned();
+            t.documentation.resolve_links(&names, Some(&name));
+        });
+        self.globals
+            .for_all_items_mut(|g| g.documentation.resolve_links(&names, None));
+        self.constants.for_all_items_mut(|c| {
+            let self_name = c.associated_to.as_ref().map(|p| p.name().to_owned());
+            c.documentation.resolve_links(&names, self_name.as_deref());
+        });
+        for f in &mut self.functions {
+            let self_name = f.self_type_path.as_ref().map(|p| p.name().to_owned());
+            f.documentation.resolve_links(&names, self_name.as_deref());
+        }
+    }
+
     fn resolve_declaration_types(&mut self) {
         if !self.config.style.generate_tag() {
             return;
@@ -386,9 +625,10 @@ impl Library {
         }
     }
 
-    fn instantiate_monomorphs(&mut self) {
+    /// Instantiates the generic types used with concrete arguments, returning the paths of the
+    /// instantiations.
+    fn instantiate_monomorphs(&mut self, mut monomorphs: Monomorphs) -> HashSet<Path> {
         // Collect a list of monomorphs
-        let mut monomorphs = Monomorphs::default();
 
         self.structs.for_all_items(|x| {
             x.add_monomorphs(self, &mut monomorphs);
@@ -423,12 +663,14 @@ impl Library {
             self.enums.try_insert(monomorph);
         }
 
-        // Remove structs and opaque items that are generic
-        self.opaque_items.filter(|x| x.is_generic());
-        self.structs.filter(|x| x.is_generic());
-        self.unions.filter(|x| x.is_generic());
-        self.enums.filter(|x| x.is_generic());
-        self.typedefs.filter(|x| x.is_generic());
+        // Remove structs and opaque items that are generic, unless they're kept generic
+        if !monomorphs.keeps_generics() {
+            self.opaque_items.filter(|x| x.is_generic());
+            self.structs.filter(|x| x.is_generic());
+            self.unions.filter(|x| x.is_generic());
+            self.enums.filter(|x| x.is_generic());
+            self.typedefs.filter(|x| x.is_generic());
+        }
 
         // Mangle the paths that remain
         self.unions
@@ -442,5 +684,7 @@ impl Library {
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+
+        monomorphs.paths().cloned().collect()
     }
 }
@@ -0,0 +1,157 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The report of `--lint`: the exported functions which were skipped, the ones passing opaque
+//! types by value, and the types which ended up opaque, with their locations.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use proc_macro2::Span;
+
+use crate::bindgen::ir::{Function, Item, ItemContainer, Path, Type};
+
+/// Where an item is defined.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    /// The source file, unknown for expanded crates.
+    pub file: Option<PathBuf>,
+    pub line: usize,
+}
+
+/// An exported item which couldn't be bound as it is.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Lint {
+    pub location: Location,
+    /// The name of the item in Rust, without its module path, like `Handle` or `make_handle`.
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.location.file {
+            Some(ref file) => write!(f, "{}:{}: ", file.display(), self.location.line)?,
+            None => write!(f, "line {}: ", self.location.line)?,
+        }
+        write!(f, "`{}`: {}", self.item, self.message)
+    }
+}
+
+/// The lints found while parsing, which are reported once the bindings are generated, as whether
+/// a type is used by the bindings isn't known until then.
+#[derive(Debug, Clone, Default)]
+pub struct Lints {
+    /// The file the items are loaded from.
+    file: Option<PathBuf>,
+    /// The skipped exported functions.
+    skipped: Vec<Lint>,
+    /// The types which ended up opaque, reported if they are used.
+    opaque: HashMap<Path, Lint>,
+    /// The locations and Rust names of the loaded functions, by exported name.
+    functions: HashMap<Path, (Location, String)>,
+}
+
+impl Lints {
+    /// Sets the file the next items are loaded from, returning the previous one.
+    pub fn set_file(&mut self, file: Option<PathBuf>) -> Option<PathBuf> {
+        std::mem::replace(&mut self.file, file)
+    }
+
+    fn location(&self, span: Span) -> Location {
+        Location {
+            file: self.file.clone(),
+            line: span.start().line,
+        }
+    }
+
+    pub fn skipped_function(&mut self, span: Span, item: String, reason: &str) {
+        let location = self.location(span);
+        self.skipped.push(Lint {
+            location,
+            item,
+            message: format!("skipped ({reason})"),
+        });
+    }
+
+    pub fn opaque_type(&mut self, span: Span, path: &Path, reason: &str) {
+        let location = self.location(span);
+        let lint = Lint {
+            location,
+            item: path.name().to_owned(),
+            message: format!("opaque ({reason})"),
+        };
+        self.opaque.entry(path.clone()).or_insert(lint);
+    }
+
+    pub fn function(&mut self, span: Span, path: &Path, item: String) {
+        let location = self.location(span);
+        self.functions
+            .entry(path.clone())
+            .or_insert((location, item));
+    }
+
+    pub fn extend_with(&mut self, other: &Lints) {
+        self.skipped.extend_from_slice(&other.skipped);
+        for (path, lint) in &other.opaque {
+            self.opaque
+                .entry(path.clone())
+                .or_insert_with(|| lint.clone());
+        }
+        for (path, function) in &other.functions {
+            self.functions
+                .entry(path.clone())
+                .or_insert_with(|| function.clone());
+        }
+    }
+
+    /// The lints of the generated bindings, sorted by location.
+    pub fn report(&self, items: &[ItemContainer], functions: &[Function]) -> Vec<Lint> {
+        let mut lints = self.skipped.clone();
+        // Opaque items with a storage are meant to be.
+        let mut opaque = HashMap::new();
+        for item in items {
+            if let ItemContainer::OpaqueItem(ref o) = *item {
+                if o.storage.is_none() {
+                    opaque.insert(o.export_name(), o.path());
+                }
+            }
+        }
+        let mut reported: Vec<_> = opaque.values().filter_map(|p| self.opaque.get(p)).collect();
+        reported.sort();
+        reported.dedup();
+        lints.extend(reported.into_iter().cloned());
+
+        for function in functions {
+            let Some((location, item)) = self.functions.get(&function.path) else {
+                continue;
+            };
+            let args = function.args.iter().map(|arg| match arg.name {
+                Some(ref name) => (format!("argument `{name}`"), &arg.ty),
+                None => ("an argument".to_owned(), &arg.ty),
+            });
+            let ret = ("return value".to_owned(), &function.ret);
+            for (what, ty) in std::iter::once(ret).chain(args) {
+                let Type::Path(ref path) = *ty else {
+                    continue;
+                };
+                if !opaque.contains_key(path.export_name()) {
+                    continue;
+                }
+                lints.push(Lint {
+                    location: location.clone(),
+                    item: item.clone(),
+                    message: format!(
+                        "{what} is of the opaque type `{}`, which can't be passed by value",
+                        path.export_name()
+                    ),
+                });
+            }
+        }
+
+        lints.sort();
+        lints
+    }
+}
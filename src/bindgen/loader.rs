@@ -0,0 +1,270 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A C table of pointers to the functions and globals of the bindings, and the C code filling it
+//! from a library opened at runtime with `dlopen`, for hosts loading several versions of it.
+
+use std::io::Write;
+
+use crate::bindgen::cdecl;
+use crate::bindgen::config::Layout;
+use crate::bindgen::ir::{ConditionWrite, Item, Static, ToCondition, Type};
+use crate::bindgen::language_backend::{CLikeLanguageBackend, LanguageBackend};
+use crate::bindgen::writer::SourceWriter;
+use crate::bindgen::Bindings;
+
+/// The name of the table of `name`.
+fn table_name(name: &str) -> String {
+    format!("{name}_functions")
+}
+
+fn write_autogen_warning<F: Write>(b: &Bindings, out: &mut SourceWriter<F>) {
+    if let Some(ref f) = b.config.autogen_warning {
+        out.write_raw_block(f);
+        out.new_line();
+        out.new_line();
+    }
+}
+
+/// The exported globals, which are loaded as pointers to them.
+fn globals(b: &Bindings) -> impl Iterator<Item = &Static> {
+    b.globals.iter().filter(|g| g.annotations.should_export())
+}
+
+/// Writes the declaration of the table of `name`, and of the functions loading it, after
+/// including `header`.
+pub(crate) fn write_header<F: Write>(
+    b: &Bindings,
+    out: &mut SourceWriter<F>,
+    name: &str,
+    header: &str,
+) {
+    let config = &b.config;
+    let mut lb = CLikeLanguageBackend::new(config);
+    let table = table_name(name);
+    let guard = format!("{}_H", name.to_uppercase());
+
+    write_autogen_warning(b, out);
+    write!(out, "#ifndef {guard}");
+    out.new_line();
+    write!(out, "#define {guard}");
+    out.new_line();
+    out.new_line();
+    write!(out, "#include \"{header}\"");
+    out.new_line();
+    out.new_line();
+
+    out.write("/**");
+    out.new_line();
+    write!(
+        out,
+        " * The functions and globals of the library, filled by `{name}_load`."
+    );
+    out.new_line();
+    out.write(" */");
+    out.new_line();
+    write!(out, "typedef struct {table}");
+    out.open_brace();
+    out.write("void *dl_handle;");
+    for function in b.functions.iter().filter(|f| f.annotations.should_export()) {
+        out.new_line();
+        let condition = function.cfg.to_condition(config);
+        condition.write_before(config, out);
+        lb.write_documentation(out, &function.documentation);
+        let symbol = function.path().name();
+        let layout = match config.function.args {
+            Layout::Auto => {
+                if out.try_write(
+                    |out| {
+                        cdecl::write_func_ptr(
+                            &mut lb,
+                            out,
+                            function,
+                            symbol,
+                            Layout::Horizontal,
+                            config,
+                        )
+                    },
+                    config.line_length,
+                ) {
+                    None
+                } else {
+                    Some(Layout::Vertical)
+                }
+            }
+            layout => Some(layout),
+        };
+        if let Some(layout) = layout {
+            cdecl::write_func_ptr(&mut lb, out, function, symbol, layout, config);
+        }
+        out.write(";");
+        condition.write_after(config, out);
+    }
+    for global in globals(b) {
+        out.new_line();
+        let condition = global.cfg.to_condition(config);
+        condition.write_before(config, out);
+        lb.write_documentation(out, &global.documentation);
+        let ptr = Type::Ptr {
+            ty: Box::new(global.ty.clone()),
+            is_const: !global.mutable,
+            is_nullable: true,
+            is_ref: false,
+        };
+        cdecl::write_field(&mut lb, out, &ptr, global.export_name(), config);
+        out.write(";");
+        condition.write_after(config, out);
+    }
+    out.close_brace(false);
+    write!(out, " {table};");
+    out.new_line();
+    out.new_line();
+
+    if config.cpp_compatible_c() {
+        out.write("#ifdef __cplusplus");
+        out.new_line();
+        out.write("extern \"C\" {");
+        out.new_line();
+        out.write("#endif  // __cplusplus");
+        out.new_line();
+        out.new_line();
+    }
+
+    out.write("/**");
+    out.new_line();
+    write!(
+        out,
+        " * Opens the library at `path` with `dlopen`, and fills `functions` with its symbols."
+    );
+    out.new_line();
+    out.write(" *");
+    out.new_line();
+    out.write(" * Returns 0 if every symbol was found, -1 if the library can't be opened, or the");
+    out.new_line();
+    out.write(" * number of missing symbols, which are left NULL. Errors are reported on stderr.");
+    out.new_line();
+    out.write(" */");
+    out.new_line();
+    write!(
+        out,
+        "int {name}_load(const char *path, {table} *functions);"
+    );
+    out.new_line();
+    out.new_line();
+    out.write("/**");
+    out.new_line();
+    write!(out, " * Closes the library opened by `{name}_load`.");
+    out.new_line();
+    out.write(" */");
+    out.new_line();
+    write!(out, "void {name}_unload({table} *functions);");
+    out.new_line();
+
+    if config.cpp_compatible_c() {
+        out.new_line();
+        out.write("#ifdef __cplusplus");
+        out.new_line();
+        out.write("}  // extern \"C\"");
+        out.new_line();
+        out.write("#endif  // __cplusplus");
+        out.new_line();
+    }
+
+    out.new_line();
+    write!(out, "#endif  // {guard}");
+    out.new_line();
+}
+
+/// Writes the definition of the functions loading the table of `name`, declared in
+/// `loader_header`.
+pub(crate) fn write_source<F: Write>(
+    b: &Bindings,
+    out: &mut SourceWriter<F>,
+    name: &str,
+    loader_header: &str,
+) {
+    let config = &b.config;
+    let table = table_name(name);
+
+    write_autogen_warning(b, out);
+    out.write("#include <dlfcn.h>");
+    out.new_line();
+    out.write("#include <stdio.h>");
+    out.new_line();
+    out.write("#include <string.h>");
+    out.new_line();
+    write!(out, "#include \"{loader_header}\"");
+    out.new_line();
+    out.new_line();
+
+    // `dlsym` returns a `void *`, which C doesn't allow casting to a function pointer.
+    write!(
+        out,
+        "static void {name}_symbol(void *handle, const char *symbol, void *slot, int *missing)"
+    );
+    out.open_brace();
+    out.write("void *address = dlsym(handle, symbol);");
+    out.new_line();
+    out.write("if (!address)");
+    out.open_brace();
+    write!(
+        out,
+        "fprintf(stderr, \"{name}: missing symbol %s\\n\", symbol);"
+    );
+    out.new_line();
+    out.write("++*missing;");
+    out.close_brace(false);
+    out.new_line();
+    out.write("memcpy(slot, &address, sizeof(address));");
+    out.close_brace(false);
+    out.new_line();
+    out.new_line();
+
+    write!(out, "int {name}_load(const char *path, {table} *functions)");
+    out.open_brace();
+    out.write("int missing = 0;");
+    out.new_line();
+    out.write("memset(functions, 0, sizeof(*functions));");
+    out.new_line();
+    out.write("functions->dl_handle = dlopen(path, RTLD_NOW | RTLD_LOCAL);");
+    out.new_line();
+    out.write("if (!functions->dl_handle)");
+    out.open_brace();
+    write!(out, "fprintf(stderr, \"{name}: %s\\n\", dlerror());");
+    out.new_line();
+    out.write("return -1;");
+    out.close_brace(false);
+    let symbols = b
+        .functions
+        .iter()
+        .filter(|f| f.annotations.should_export())
+        .map(|f| (f.path().name(), &f.cfg))
+        .chain(globals(b).map(|g| (g.export_name(), &g.cfg)));
+    for (symbol, cfg) in symbols {
+        out.new_line();
+        let condition = cfg.to_condition(config);
+        condition.write_before(config, out);
+        write!(
+            out,
+            "{name}_symbol(functions->dl_handle, \"{symbol}\", &functions->{symbol}, &missing);"
+        );
+        condition.write_after(config, out);
+    }
+    out.new_line();
+    out.write("return missing;");
+    out.close_brace(false);
+    out.new_line();
+    out.new_line();
+
+    write!(out, "void {name}_unload({table} *functions)");
+    out.open_brace();
+    out.write("if (functions->dl_handle)");
+    out.open_brace();
+    out.write("dlclose(functions->dl_handle);");
+    out.close_brace(false);
+    out.new_line();
+    out.write("memset(functions, 0, sizeof(*functions));");
+    out.close_brace(false);
+    out.new_line();
+}
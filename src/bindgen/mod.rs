@@ -36,12 +36,15 @@ macro_rules! deserialize_enum_str {
     };
 }
 
+mod api_docs;
 mod bindings;
 mod bitflags;
 mod builder;
 mod cargo;
 mod cdecl;
+mod compat;
 mod config;
+mod const_eval;
 mod declarationtyperesolver;
 mod dependencies;
 mod error;
@@ -49,13 +52,20 @@ mod error;
 pub mod ir;
 #[cfg(not(feature = "unstable_ir"))]
 mod ir;
+mod json;
 mod language_backend;
+mod layout_tests;
 mod library;
+mod lint;
+mod loader;
 mod mangle;
+mod modules;
 mod monomorph;
 mod parser;
 mod rename;
 mod reserved;
+mod symfile;
+mod type_layout;
 mod utilities;
 mod writer;
 
@@ -64,6 +74,12 @@ pub(crate) use self::cargo::*;
 
 pub use self::bindings::Bindings;
 pub use self::builder::Builder;
+#[allow(unused)]
+pub use self::compat::CompatChange;
 pub use self::config::Profile; // disambiguate with cargo::Profile
 pub use self::config::*;
 pub use self::error::Error;
+#[allow(unused)]
+pub use self::lint::{Lint, Location};
+#[allow(unused)]
+pub use self::type_layout::{FieldLayout, TypeLayout};
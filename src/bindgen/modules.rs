@@ -0,0 +1,546 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The modules of the parsed crates, with the items they define and import, so that the paths
+//! to items defined under the same name in several modules resolve to the right one.
+//!
+//! Items are looked up by name only, so such items are given a unique name, prefixed with their
+//! module path, and the paths referring to them are rewritten to it before loading the items.
+
+use std::collections::{HashMap, HashSet};
+
+use syn::ext::IdentExt;
+use syn::fold::Fold;
+
+use crate::bindgen::ir::{Cfg, Path};
+
+/// The maximum number of imports followed to resolve a path, as they can be cyclic.
+const MAX_IMPORT_DEPTH: usize = 16;
+
+/// A name defined by items of several modules.
+#[derive(Debug, Clone)]
+pub struct NameConflict {
+    pub name: String,
+    pub definitions: Vec<NameDefinition>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NameDefinition {
+    /// The module path of the item, like `ffi::Error`, starting with the crate name for the
+    /// dependencies.
+    pub module_path: String,
+    /// The unique name the item is loaded with.
+    pub path: Path,
+}
+
+/// A type re-exported under another name by a `pub use ... as` declaration of the binding crate.
+#[derive(Debug, Clone)]
+pub struct Reexport {
+    /// The path of the re-exporting module.
+    pub module: Vec<String>,
+    /// The name the type is re-exported as.
+    pub alias: String,
+    /// The unique name of the type.
+    pub path: Path,
+    /// The attributes of the `use` declaration.
+    attrs: Vec<syn::Attribute>,
+    import: Import,
+}
+
+/// A path imported by a `use` declaration.
+#[derive(Debug, Clone)]
+struct Import {
+    segments: Vec<String>,
+    /// Whether the path starts with `::`.
+    absolute: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Module {
+    /// The unique names of the items defined in the module, by name.
+    items: HashMap<String, String>,
+    /// The items and modules imported by `use` declarations, by the name they're imported as.
+    imports: HashMap<String, Import>,
+    /// The modules imported by `use path::*` declarations.
+    globs: Vec<Import>,
+    /// The names of the types defined in the module.
+    types: HashSet<String>,
+    /// The paths re-exported under another name by `pub use ... as` declarations, with the
+    /// attributes of the declaration.
+    aliases: Vec<(String, Import, Vec<syn::Attribute>)>,
+}
+
+/// What a path refers to.
+enum Resolution {
+    Module(Vec<String>),
+    /// The unique name of an item.
+    Item(String),
+}
+
+#[derive(Debug, Default)]
+pub struct Modules {
+    /// The modules, by path starting with the crate name.
+    modules: HashMap<Vec<String>, Module>,
+    conflicts: Vec<NameConflict>,
+    /// Whether there are paths to rewrite: paths to items with a unique name, or to items
+    /// imported under another name.
+    needs_fold: bool,
+}
+
+impl Modules {
+    /// Collects the items of the given modules, given with their path, their cfg and their items,
+    /// and the unique names of the conflicting ones. The items of `binding_crate` keep their
+    /// name if they're at the root of the crate.
+    pub fn new<'a, I>(binding_crate: &str, parsed: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a [String], Option<&'a Cfg>, &'a [syn::Item])>,
+    {
+        let mut modules = HashMap::<Vec<String>, Module>::new();
+        // The modules defining each name, and whether all of their definitions have a cfg.
+        let mut definitions = HashMap::<String, Vec<(Vec<String>, bool)>>::new();
+        let mut needs_fold = false;
+        for (path, cfg, items) in parsed {
+            let module = modules.entry(path.to_vec()).or_default();
+            for item in items {
+                let (ident, attrs, is_type) = match *item {
+                    syn::Item::Struct(ref item) => (&item.ident, &item.attrs, true),
+                    syn::Item::Enum(ref item) => (&item.ident, &item.attrs, true),
+                    syn::Item::Union(ref item) => (&item.ident, &item.attrs, true),
+                    syn::Item::Type(ref item) => (&item.ident, &item.attrs, true),
+                    syn::Item::Const(ref item) => (&item.ident, &item.attrs, false),
+                    syn::Item::Use(ref item) => {
+                        let absolute = item.leading_colon.is_some();
+                        add_imports(module, &item.tree, Vec::new(), absolute);
+                        if path.first().map(String::as_str) == Some(binding_crate)
+                            && matches!(item.vis, syn::Visibility::Public(..))
+                        {
+                            let mut aliases = Vec::new();
+                            add_aliases(&mut aliases, &item.tree, Vec::new());
+                            module
+                                .aliases
+                                .extend(aliases.into_iter().map(|(alias, segments)| {
+                                    (alias, Import { segments, absolute }, item.attrs.clone())
+                                }));
+                        }
+                        continue;
+                    }
+                    _ => continue,
+                };
+                let name = ident.unraw().to_string();
+                let has_cfg = cfg.is_some() || Cfg::load(attrs).is_some();
+                module.items.insert(name.clone(), name.clone());
+                if is_type {
+                    module.types.insert(name.clone());
+                }
+                let modules = definitions.entry(name).or_default();
+                match modules.iter_mut().find(|(p, _)| p == path) {
+                    Some((_, all_cfg)) => *all_cfg &= has_cfg,
+                    None => modules.push((path.to_vec(), has_cfg)),
+                }
+            }
+            needs_fold |= module
+                .imports
+                .iter()
+                .any(|(name, import)| import.segments.last() != Some(name));
+        }
+
+        let binding_root = [binding_crate.to_owned()];
+        let mut conflicts = Vec::new();
+        for (name, mut defined_in) in definitions {
+            // Items defined under cfgs are different versions of the same item.
+            if defined_in.len() < 2 || defined_in.iter().all(|&(_, has_cfg)| has_cfg) {
+                continue;
+            }
+            defined_in.sort();
+            let mut conflict = NameConflict {
+                name: name.clone(),
+                definitions: Vec::new(),
+            };
+            for (path, _) in defined_in {
+                let module_path = match path.split_first() {
+                    Some((krate, rest)) if krate == binding_crate => rest,
+                    _ => &path[..],
+                };
+                let unique_name = if path[..] == binding_root {
+                    name.clone()
+                } else {
+                    format!("{}_{}", module_path.join("_"), name)
+                };
+                let mut segments = module_path.to_vec();
+                segments.push(name.clone());
+                conflict.definitions.push(NameDefinition {
+                    module_path: segments.join("::"),
+                    path: Path::new(unique_name.clone()),
+                });
+                modules
+                    .get_mut(&path)
+                    .unwrap()
+                    .items
+                    .insert(name.clone(), unique_name);
+            }
+            conflicts.push(conflict);
+        }
+        conflicts.sort_by(|a, b| a.name.cmp(&b.name));
+        needs_fold |= !conflicts.is_empty();
+
+        Modules {
+            modules,
+            conflicts,
+            needs_fold,
+        }
+    }
+
+    /// The names defined by items of several modules.
+    pub fn conflicts(&self) -> &[NameConflict] {
+        &self.conflicts
+    }
+
+    /// Rewrites the items of the module at `path` to use unique names for the items defined in
+    /// several modules, and the names of the items imported under another name.
+    pub fn fold(&self, path: &[String], items: Vec<syn::Item>) -> Vec<syn::Item> {
+        if !self.needs_fold {
+            return items;
+        }
+        let mut fold = ModuleFold {
+            modules: self,
+            module: path,
+            generics: Vec::new(),
+        };
+        items.into_iter().map(|item| fold.fold_item(item)).collect()
+    }
+
+    /// The types re-exported under another name by the binding crate.
+    pub fn reexports(&self) -> Vec<Reexport> {
+        let mut reexports = Vec::new();
+        for (path, module) in &self.modules {
+            for (alias, import, attrs) in &module.aliases {
+                let Some(Resolution::Item(name)) = self.resolve_import(path, import, 0) else {
+                    continue;
+                };
+                if self.is_type(&name) {
+                    reexports.push(Reexport {
+                        module: path.clone(),
+                        alias: alias.clone(),
+                        path: Path::new(name),
+                        attrs: attrs.clone(),
+                        import: import.clone(),
+                    });
+                }
+            }
+        }
+        reexports.sort_by(|a, b| (&a.module, &a.alias).cmp(&(&b.module, &b.alias)));
+        reexports
+    }
+
+    /// Defines the name a type is re-exported as in the re-exporting module, so that the paths to
+    /// it are kept, and returns the equivalent `pub type` declaration.
+    pub fn define_typedef(&mut self, reexport: &Reexport) -> syn::Item {
+        let module = self.modules.get_mut(&reexport.module).unwrap();
+        module
+            .items
+            .insert(reexport.alias.clone(), reexport.alias.clone());
+        module.types.insert(reexport.alias.clone());
+        self.needs_fold = true;
+
+        let span = proc_macro2::Span::call_site();
+        let mut path = syn::Path {
+            leading_colon: None,
+            segments: Default::default(),
+        };
+        if reexport.import.absolute {
+            path.leading_colon = Some(Default::default());
+        }
+        for segment in &reexport.import.segments {
+            path.segments.push(syn::Ident::new(segment, span).into());
+        }
+        let ident = syn::Ident::new(&reexport.alias, span);
+        let attrs = &reexport.attrs;
+        syn::parse_quote! {
+            #(#attrs)*
+            pub type #ident = #path;
+        }
+    }
+
+    /// Whether the item with the unique name `name` is a type.
+    fn is_type(&self, name: &str) -> bool {
+        self.modules.values().any(|module| {
+            module
+                .items
+                .iter()
+                .any(|(local, unique)| unique == name && module.types.contains(local))
+        })
+    }
+
+    /// Looks up `name` in the scope of `module`.
+    fn lookup(&self, module: &[String], name: &str, depth: usize) -> Option<Resolution> {
+        if depth > MAX_IMPORT_DEPTH {
+            return None;
+        }
+        let scope = self.modules.get(module)?;
+        if let Some(unique_name) = scope.items.get(name) {
+            return Some(Resolution::Item(unique_name.clone()));
+        }
+        let mut child = module.to_vec();
+        child.push(name.to_owned());
+        if self.modules.contains_key(&child) {
+            return Some(Resolution::Module(child));
+        }
+        if let Some(import) = scope.imports.get(name) {
+            return self.resolve_import(module, import, depth + 1);
+        }
+        for glob in &scope.globs {
+            if let Some(Resolution::Module(glob)) = self.resolve_import(module, glob, depth + 1) {
+                if let Some(resolution) = self.lookup(&glob, name, depth + 1) {
+                    return Some(resolution);
+                }
+            }
+        }
+        // Other crates.
+        let root = vec![name.to_owned()];
+        self.modules
+            .contains_key(&root)
+            .then_some(Resolution::Module(root))
+    }
+
+    fn resolve_import(
+        &self,
+        module: &[String],
+        import: &Import,
+        depth: usize,
+    ) -> Option<Resolution> {
+        let segments: Vec<_> = import.segments.iter().map(String::as_str).collect();
+        match self.resolve(module, &segments, import.absolute, depth)? {
+            (index, resolution) if index + 1 == segments.len() => Some(resolution),
+            _ => None,
+        }
+    }
+
+    /// Resolves the path made of `segments` from `module`, to the module or item it refers to
+    /// along with the index of its segment, as paths can go on with the variants of an enum or
+    /// the associated items of a type, like `Shape::Circle`.
+    fn resolve(
+        &self,
+        module: &[String],
+        segments: &[&str],
+        absolute: bool,
+        depth: usize,
+    ) -> Option<(usize, Resolution)> {
+        let (first, rest) = segments.split_first()?;
+        let mut resolution = match *first {
+            _ if absolute => Resolution::Module(vec![(*first).to_owned()]),
+            "crate" => Resolution::Module(module[..1].to_vec()),
+            "self" => Resolution::Module(module.to_vec()),
+            "super" => Resolution::Module(module[..module.len().saturating_sub(1)].to_vec()),
+            "Self" => return None,
+            name => self.lookup(module, name, depth)?,
+        };
+        for (index, segment) in rest.iter().enumerate() {
+            resolution = match resolution {
+                Resolution::Item(..) => return Some((index, resolution)),
+                Resolution::Module(ref module) if *segment == "super" => {
+                    Resolution::Module(module[..module.len().saturating_sub(1)].to_vec())
+                }
+                Resolution::Module(ref module) => self.lookup(module, segment, depth)?,
+            };
+        }
+        Some((rest.len(), resolution))
+    }
+}
+
+/// Adds the imports of a `use` tree, below the path `prefix`.
+fn add_imports(module: &mut Module, tree: &syn::UseTree, mut prefix: Vec<String>, absolute: bool) {
+    let mut import = |name: String, segments: Vec<String>| {
+        if name != "_" {
+            module.imports.insert(name, Import { segments, absolute });
+        }
+    };
+    match *tree {
+        syn::UseTree::Path(ref path) => {
+            prefix.push(path.ident.unraw().to_string());
+            add_imports(module, &path.tree, prefix, absolute);
+        }
+        syn::UseTree::Name(ref name) if name.ident == "self" => {
+            if let Some(last) = prefix.last() {
+                import(last.clone(), prefix);
+            }
+        }
+        syn::UseTree::Name(ref name) => {
+            let name = name.ident.unraw().to_string();
+            prefix.push(name.clone());
+            import(name, prefix);
+        }
+        syn::UseTree::Rename(ref rename) => {
+            if rename.ident != "self" {
+                prefix.push(rename.ident.unraw().to_string());
+            }
+            import(rename.rename.unraw().to_string(), prefix);
+        }
+        syn::UseTree::Glob(..) => module.globs.push(Import {
+            segments: prefix,
+            absolute,
+        }),
+        syn::UseTree::Group(ref group) => {
+            for tree in &group.items {
+                add_imports(module, tree, prefix.clone(), absolute);
+            }
+        }
+    }
+}
+
+/// The crates of the paths re-exported under another name by the `pub use ... as` declarations
+/// of `items`.
+pub fn reexported_crates(items: &[syn::Item]) -> impl Iterator<Item = String> + '_ {
+    items
+        .iter()
+        .filter_map(|item| match *item {
+            syn::Item::Use(ref item) if matches!(item.vis, syn::Visibility::Public(..)) => {
+                let mut aliases = Vec::new();
+                add_aliases(&mut aliases, &item.tree, Vec::new());
+                Some(aliases)
+            }
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|(_, mut segments)| {
+            segments.truncate(1);
+            segments.pop()
+        })
+        .filter(|name| !matches!(name.as_str(), "crate" | "self" | "super"))
+}
+
+/// Adds the paths of a `use` tree imported under another name, below the path `prefix`, with
+/// the name they're imported as.
+fn add_aliases(
+    aliases: &mut Vec<(String, Vec<String>)>,
+    tree: &syn::UseTree,
+    mut prefix: Vec<String>,
+) {
+    match *tree {
+        syn::UseTree::Path(ref path) => {
+            prefix.push(path.ident.unraw().to_string());
+            add_aliases(aliases, &path.tree, prefix);
+        }
+        syn::UseTree::Rename(ref rename) if rename.ident != "self" && rename.rename != "_" => {
+            let name = rename.ident.unraw().to_string();
+            let alias = rename.rename.unraw().to_string();
+            if name != alias {
+                prefix.push(name);
+                aliases.push((alias, prefix));
+            }
+        }
+        syn::UseTree::Group(ref group) => {
+            for tree in &group.items {
+                add_aliases(aliases, tree, prefix.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrites the names of the items of a module, and the paths to items, to their unique names.
+struct ModuleFold<'a> {
+    modules: &'a Modules,
+    module: &'a [String],
+    /// The names of the generic parameters in scope, which shadow the items.
+    generics: Vec<HashSet<String>>,
+}
+
+impl ModuleFold<'_> {
+    fn with_generics<T>(
+        &mut self,
+        generics: &syn::Generics,
+        item: T,
+        fold: impl FnOnce(&mut Self, T) -> T,
+    ) -> T {
+        let params = generics
+            .params
+            .iter()
+            .filter_map(|param| match *param {
+                syn::GenericParam::Type(ref param) => Some(param.ident.unraw().to_string()),
+                syn::GenericParam::Const(ref param) => Some(param.ident.unraw().to_string()),
+                syn::GenericParam::Lifetime(..) => None,
+            })
+            .collect();
+        self.generics.push(params);
+        let item = fold(self, item);
+        self.generics.pop();
+        item
+    }
+
+    fn rename(&self, ident: &mut syn::Ident) {
+        let name = ident.unraw().to_string();
+        let unique_name = self
+            .modules
+            .modules
+            .get(self.module)
+            .and_then(|module| module.items.get(&name));
+        if let Some(unique_name) = unique_name.filter(|unique_name| **unique_name != name) {
+            *ident = syn::Ident::new(unique_name, ident.span());
+        }
+    }
+}
+
+impl Fold for ModuleFold<'_> {
+    fn fold_item(&mut self, mut item: syn::Item) -> syn::Item {
+        match item {
+            syn::Item::Struct(ref mut item) => self.rename(&mut item.ident),
+            syn::Item::Enum(ref mut item) => self.rename(&mut item.ident),
+            syn::Item::Union(ref mut item) => self.rename(&mut item.ident),
+            syn::Item::Type(ref mut item) => self.rename(&mut item.ident),
+            syn::Item::Const(ref mut item) => self.rename(&mut item.ident),
+            // Nested modules are rewritten on their own, and imports keep the names they use.
+            syn::Item::Mod(..) | syn::Item::Use(..) => return item,
+            _ => {}
+        }
+        let generics = match item {
+            syn::Item::Struct(ref item) => item.generics.clone(),
+            syn::Item::Enum(ref item) => item.generics.clone(),
+            syn::Item::Union(ref item) => item.generics.clone(),
+            syn::Item::Type(ref item) => item.generics.clone(),
+            syn::Item::Fn(ref item) => item.sig.generics.clone(),
+            syn::Item::Impl(ref item) => item.generics.clone(),
+            _ => syn::Generics::default(),
+        };
+        self.with_generics(&generics, item, syn::fold::fold_item)
+    }
+
+    fn fold_impl_item_fn(&mut self, item: syn::ImplItemFn) -> syn::ImplItemFn {
+        let generics = item.sig.generics.clone();
+        self.with_generics(&generics, item, syn::fold::fold_impl_item_fn)
+    }
+
+    fn fold_attribute(&mut self, attr: syn::Attribute) -> syn::Attribute {
+        attr
+    }
+
+    fn fold_block(&mut self, block: syn::Block) -> syn::Block {
+        // Function bodies aren't part of the bindings.
+        block
+    }
+
+    fn fold_path(&mut self, path: syn::Path) -> syn::Path {
+        let mut path = syn::fold::fold_path(self, path);
+        let segments: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+        if let [ref name] = segments[..] {
+            if self.generics.iter().any(|params| params.contains(name)) {
+                return path;
+            }
+        }
+        let segments: Vec<_> = segments.iter().map(String::as_str).collect();
+        let absolute = path.leading_colon.is_some();
+        if let Some((index, Resolution::Item(name))) =
+            self.modules.resolve(self.module, &segments, absolute, 0)
+        {
+            if segments[index] != name {
+                let ident = &mut path.segments[index].ident;
+                *ident = syn::Ident::new(&name, ident.span());
+            }
+        }
+        path
+    }
+}
@@ -6,9 +6,11 @@ use std::collections::HashMap;
 use std::mem;
 
 use crate::bindgen::ir::{
-    Enum, GenericArgument, GenericPath, Item, OpaqueItem, Path, Struct, Typedef, Union,
+    AnnotationSet, Documentation, Enum, Field, GenericArgument, GenericParams, GenericPath,
+    IntKind, Item, OpaqueItem, Path, PrimitiveType, Struct, Type, Typedef, Union,
 };
 use crate::bindgen::library::Library;
+use crate::bindgen::mangle;
 
 #[derive(Default, Clone, Debug)]
 pub struct Monomorphs {
@@ -18,13 +20,121 @@ pub struct Monomorphs {
     unions: Vec<Union>,
     typedefs: Vec<Typedef>,
     enums: Vec<Enum>,
+    keep_generics: bool,
+    keep_slices: bool,
 }
 
 impl Monomorphs {
+    /// Monomorphs for languages with templates, which keep generic types
+    /// generic, and only instantiate tuples and, unless `keep_slices`, slices.
+    pub fn for_templates(keep_slices: bool) -> Self {
+        Self {
+            keep_generics: true,
+            keep_slices,
+            ..Self::default()
+        }
+    }
+
+    pub fn keeps_generics(&self) -> bool {
+        self.keep_generics
+    }
+
+    /// Whether the given generic path is instantiated, rather than kept generic.
+    pub fn instantiates(&self, path: &GenericPath) -> bool {
+        if path.is_slice() {
+            !self.keep_slices
+        } else {
+            path.is_tuple() || !self.keep_generics
+        }
+    }
+
     pub fn contains(&self, path: &GenericPath) -> bool {
         self.replacements.contains_key(path)
     }
 
+    /// The paths of all the instantiated items.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.replacements.values()
+    }
+
+    /// Instantiates a tuple as a struct with fields `_0`, `_1`, etc.
+    pub fn insert_tuple(&mut self, library: &Library, tuple: &GenericPath) {
+        debug_assert!(tuple.is_tuple());
+
+        let fields = tuple
+            .generics()
+            .iter()
+            .enumerate()
+            .map(|(i, generic)| {
+                Field::from_name_and_type(format!("_{i}"), Self::element_type(generic))
+            })
+            .collect();
+        self.insert_anonymous(library, tuple, fields);
+    }
+
+    /// Instantiates a slice as a struct with a `ptr` to its elements and a `len`.
+    pub fn insert_slice(&mut self, library: &Library, slice: &GenericPath) {
+        debug_assert!(slice.is_slice());
+
+        let fields = vec![
+            Field::from_name_and_type(
+                "ptr".to_owned(),
+                Type::Ptr {
+                    ty: Box::new(Self::element_type(&slice.generics()[0])),
+                    is_const: !slice.is_mut_slice(),
+                    is_nullable: false,
+                    is_ref: false,
+                },
+            ),
+            Field::from_name_and_type(
+                "len".to_owned(),
+                Type::Primitive(PrimitiveType::Integer {
+                    zeroable: true,
+                    signed: false,
+                    kind: IntKind::Size,
+                }),
+            ),
+        ];
+        self.insert_anonymous(library, slice, fields);
+    }
+
+    fn element_type(generic: &GenericArgument) -> Type {
+        match *generic {
+            GenericArgument::Type(ref ty) => ty.clone(),
+            // Tuple and slice elements are always loaded from types.
+            GenericArgument::Const(ref expr) => unreachable!("Element {expr:?}"),
+        }
+    }
+
+    fn insert_anonymous(&mut self, library: &Library, generic: &GenericPath, fields: Vec<Field>) {
+        debug_assert!(!self.contains(generic));
+
+        let path = mangle::mangle_path(
+            &Path::new(generic.export_name()),
+            generic.generics(),
+            &library.get_config().export.mangle,
+        );
+        let monomorph = Struct::new(
+            path,
+            GenericParams::default(),
+            fields,
+            false,
+            false,
+            None,
+            false,
+            None,
+            AnnotationSet::new(),
+            Documentation::none(),
+        );
+
+        self.replacements
+            .insert(generic.clone(), monomorph.path.clone());
+
+        monomorph.add_monomorphs(library, self);
+
+        self.structs.push(monomorph);
+    }
+
     pub fn insert_struct(
         &mut self,
         library: &Library,
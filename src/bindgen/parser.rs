@@ -9,15 +9,20 @@ use std::io::Read;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
 
 use syn::ext::IdentExt;
+use syn::fold::Fold;
 
 use crate::bindgen::bitflags;
 use crate::bindgen::cargo::{Cargo, PackageRef};
-use crate::bindgen::config::{Config, ParseConfig};
+use crate::bindgen::config::{Config, ParseConfig, Reexports};
+use crate::bindgen::const_eval::ConstEvaluator;
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
     AnnotationSet, AnnotationValue, Cfg, Constant, Documentation, Enum, Function, GenericParam,
-    GenericParams, ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
+    GenericParams, ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union, MUT_SLICE_PATH,
+    SLICE_PATH, TUPLE_PATH,
 };
+use crate::bindgen::lint::Lints;
+use crate::bindgen::modules::{self, Modules, NameConflict, Reexport};
 use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemHelpers};
 
 const STD_CRATES: &[&str] = &[
@@ -48,6 +53,10 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        mod_path: Vec::new(),
+        mod_file: None,
+        parsed_mods: Vec::new(),
+        reexported_crates: HashSet::new(),
         out: Parse::new(),
     };
 
@@ -56,7 +65,9 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         version: None,
     };
 
+    context.mod_path = vec![crate_ident(mod_name)];
     context.parse_mod(&pkg_ref, src_file, 0)?;
+    context.load_parsed_mods();
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     Ok(context.out)
 }
@@ -75,11 +86,16 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        mod_path: Vec::new(),
+        mod_file: None,
+        parsed_mods: Vec::new(),
+        reexported_crates: HashSet::new(),
         out: Parse::new(),
     };
 
     let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
     context.parse_crate(&binding_crate)?;
+    context.load_parsed_mods();
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
     context.out.package_version = context
         .lib
@@ -102,10 +118,29 @@ struct Parser<'a> {
     cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
 
     cfg_stack: Vec<Cfg>,
+    /// The path of the module being parsed, starting with the crate name.
+    mod_path: Vec<String>,
+    /// The file of the module being parsed, unknown for expanded crates.
+    mod_file: Option<FilePathBuf>,
+    /// The modules parsed, which are loaded once all of them are known.
+    parsed_mods: Vec<ParsedMod>,
+    /// The dependencies parsed only for the types re-exported by the binding crate, as
+    /// `parse_deps` is off.
+    reexported_crates: HashSet<String>,
 
     out: Parse,
 }
 
+/// The items of a module, with where they're from.
+#[derive(Debug, Clone)]
+struct ParsedMod {
+    crate_name: String,
+    path: Vec<String>,
+    cfg: Option<Cfg>,
+    file: Option<FilePathBuf>,
+    items: Vec<syn::Item>,
+}
+
 impl Parser<'_> {
     fn should_parse_dependency(&self, pkg_name: &str) -> bool {
         if self.parsed_crates.contains(pkg_name) {
@@ -150,6 +185,7 @@ impl Parser<'_> {
         assert!(self.lib.is_some());
         debug!("Parsing crate {}", pkg.name);
         self.parsed_crates.insert(pkg.name.clone());
+        self.mod_path = vec![crate_ident(&pkg.name)];
 
         // Check if we should use cargo expand for this crate
         if self.config.parse.expand.crates.contains(&pkg.name) {
@@ -174,8 +210,15 @@ impl Parser<'_> {
             }
         }
 
+        let reexported = self.reexported_crates(pkg);
         for (dep_pkg, cfg) in self.lib.as_ref().unwrap().dependencies(pkg) {
-            if !self.should_parse_dependency(&dep_pkg.name) {
+            if reexported.contains(&crate_ident(&dep_pkg.name))
+                && !self.parsed_crates.contains(&dep_pkg.name)
+                && !STD_CRATES.contains(&dep_pkg.name.as_str())
+                && !self.config.parse.exclude.contains(&dep_pkg.name)
+            {
+                self.reexported_crates.insert(dep_pkg.name.clone());
+            } else if !self.should_parse_dependency(&dep_pkg.name) {
                 continue;
             }
 
@@ -193,6 +236,22 @@ impl Parser<'_> {
         Ok(())
     }
 
+    /// The crates the binding crate re-exports types from under another name, which are parsed
+    /// even if `parse_deps` is off.
+    fn reexported_crates(&self, pkg: &PackageRef) -> HashSet<String> {
+        if self.config.parse.parse_deps
+            || self.config.export.reexports == Reexports::Ignore
+            || pkg.name != self.binding_crate_name
+        {
+            return HashSet::new();
+        }
+        self.parsed_mods
+            .iter()
+            .filter(|m| m.crate_name == pkg.name)
+            .flat_map(|m| modules::reexported_crates(&m.items))
+            .collect()
+    }
+
     fn parse_expand_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
         assert!(self.lib.is_some());
 
@@ -215,16 +274,20 @@ impl Parser<'_> {
                     src_path: "".to_owned(),
                     error: x,
                 })?;
+                let i = AnonymousTypeFold::apply(self.config, i);
                 self.cache_expanded_crate.insert(pkg.name.clone(), i.items);
             }
 
             self.cache_expanded_crate.get(&pkg.name).unwrap().clone()
         };
 
-        self.process_mod(
+        let parent_file = self.mod_file.take();
+        let result = self.process_mod(
             pkg, None, None, &mod_items, 0, /* is_mod_rs = */ true,
             /* is_inline = */ false,
-        )
+        );
+        self.mod_file = parent_file;
+        result
     }
 
     fn parse_mod(
@@ -252,6 +315,7 @@ impl Parser<'_> {
                     src_path: mod_path.to_string_lossy().into(),
                     error: x,
                 })?;
+                let i = AnonymousTypeFold::apply(self.config, i);
 
                 vacant_entry.insert(i.items).clone()
             }
@@ -274,7 +338,8 @@ impl Parser<'_> {
             &submod_dir_2018
         };
 
-        self.process_mod(
+        let parent_file = self.mod_file.replace(mod_path.to_path_buf());
+        let result = self.process_mod(
             pkg,
             Some(mod_dir),
             Some(submod_dir),
@@ -282,7 +347,9 @@ impl Parser<'_> {
             depth,
             /* is_inline = */ false,
             is_mod_rs,
-        )
+        );
+        self.out.lints.set_file(parent_file);
+        result
     }
 
     /// `mod_dir` is the path to the current directory of the module. It may be
@@ -303,20 +370,28 @@ impl Parser<'_> {
     ) -> Result<(), Error> {
         debug_assert_eq!(mod_dir.is_some(), submod_dir.is_some());
         // We process the items first then the nested modules.
-        let nested_modules = self.out.load_syn_crate_mod(
-            self.config,
-            &self.binding_crate_name,
-            &pkg.name,
-            Cfg::join(&self.cfg_stack).as_ref(),
-            items,
-        );
+        self.parsed_mods.push(ParsedMod {
+            crate_name: pkg.name.clone(),
+            path: self.mod_path.clone(),
+            cfg: Cfg::join(&self.cfg_stack),
+            file: self.mod_file.clone(),
+            items: items.to_vec(),
+        });
 
+        let nested_modules = items
+            .iter()
+            .filter(|item| !item.should_skip_parsing())
+            .filter_map(|item| match item {
+                syn::Item::Mod(ref item) => Some(item),
+                _ => None,
+            });
         for item in nested_modules {
             let next_mod_name = item.ident.unraw().to_string();
             let cfg = Cfg::load(&item.attrs);
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
             }
+            self.mod_path.push(next_mod_name.clone());
 
             if let Some((_, ref inline_items)) = item.content {
                 // TODO(emilio): This should use #[path] attribute if present,
@@ -399,6 +474,7 @@ impl Parser<'_> {
                 );
             }
 
+            self.mod_path.pop();
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
@@ -406,6 +482,73 @@ impl Parser<'_> {
 
         Ok(())
     }
+
+    /// Loads the items of the parsed modules, with the paths to items defined in several modules
+    /// resolved.
+    fn load_parsed_mods(&mut self) {
+        let mut parsed_mods = std::mem::take(&mut self.parsed_mods);
+        let mut modules = Modules::new(
+            &crate_ident(&self.binding_crate_name),
+            parsed_mods
+                .iter()
+                .map(|m| (&m.path[..], m.cfg.as_ref(), &m.items[..])),
+        );
+        self.out
+            .name_conflicts
+            .extend_from_slice(modules.conflicts());
+
+        let reexports = match self.config.export.reexports {
+            Reexports::Ignore => Vec::new(),
+            _ => modules.reexports(),
+        };
+        if self.config.export.reexports == Reexports::Typedef {
+            for reexport in &reexports {
+                let typedef = modules.define_typedef(reexport);
+                if let Some(parsed_mod) = parsed_mods.iter_mut().find(|m| m.path == reexport.module)
+                {
+                    parsed_mod.items.push(typedef);
+                }
+            }
+        }
+        self.out.reexports.extend(reexports);
+
+        for parsed_mod in &mut parsed_mods {
+            let items = std::mem::take(&mut parsed_mod.items);
+            parsed_mod.items = modules.fold(&parsed_mod.path, items);
+        }
+        let evaluator = ConstEvaluator::new(parsed_mods.iter().flat_map(|m| &m.items));
+
+        for mut parsed_mod in parsed_mods {
+            if self.reexported_crates.contains(&parsed_mod.crate_name) {
+                // Only the types can be re-exported, the functions and constants of the crate
+                // aren't part of the bindings.
+                parsed_mod.items.retain(|item| {
+                    matches!(
+                        item,
+                        syn::Item::Struct(..)
+                            | syn::Item::Enum(..)
+                            | syn::Item::Union(..)
+                            | syn::Item::Type(..)
+                    )
+                });
+            }
+            let items = evaluator.fold(parsed_mod.items, self.config.constant.evaluate);
+            self.out.lints.set_file(parsed_mod.file);
+            self.out.load_syn_crate_mod(
+                self.config,
+                &self.binding_crate_name,
+                &parsed_mod.crate_name,
+                parsed_mod.cfg.as_ref(),
+                &items,
+            );
+        }
+        self.out.lints.set_file(None);
+    }
+}
+
+/// The name of a crate in paths, like `foo_bar` for `foo-bar`.
+fn crate_ident(name: &str) -> String {
+    name.replace('-', "_")
 }
 
 #[derive(Debug, Clone)]
@@ -420,6 +563,9 @@ pub struct Parse {
     pub functions: Vec<Function>,
     pub source_files: Vec<FilePathBuf>,
     pub package_version: String,
+    pub lints: Lints,
+    pub name_conflicts: Vec<NameConflict>,
+    pub reexports: Vec<Reexport>,
 }
 
 impl Parse {
@@ -435,6 +581,9 @@ impl Parse {
             functions: Vec::new(),
             source_files: Vec::new(),
             package_version: String::new(),
+            lints: Lints::default(),
+            name_conflicts: Vec::new(),
+            reexports: Vec::new(),
         }
     }
 
@@ -484,18 +633,20 @@ impl Parse {
         self.functions.extend_from_slice(&other.functions);
         self.source_files.extend_from_slice(&other.source_files);
         self.package_version.clone_from(&other.package_version);
+        self.lints.extend_with(&other.lints);
+        self.name_conflicts.extend_from_slice(&other.name_conflicts);
+        self.reexports.extend_from_slice(&other.reexports);
     }
 
-    fn load_syn_crate_mod<'a>(
+    fn load_syn_crate_mod(
         &mut self,
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
         mod_cfg: Option<&Cfg>,
-        items: &'a [syn::Item],
-    ) -> Vec<&'a syn::ItemMod> {
+        items: &[syn::Item],
+    ) {
         let mut impls_with_assoc_consts = Vec::new();
-        let mut nested_modules = Vec::new();
 
         for item in items {
             if item.should_skip_parsing() {
@@ -564,9 +715,6 @@ impl Parse {
                 syn::Item::Macro(ref item) => {
                     self.load_builtin_macro(config, crate_name, mod_cfg, item);
                 }
-                syn::Item::Mod(ref item) => {
-                    nested_modules.push(item);
-                }
                 _ => {}
             }
         }
@@ -574,8 +722,6 @@ impl Parse {
         for item_impl in impls_with_assoc_consts {
             self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, item_impl)
         }
-
-        nested_modules
     }
 
     fn load_syn_assoc_consts_from_impl(
@@ -728,6 +874,8 @@ impl Parse {
             items.join("::")
         };
 
+        // The lints name items as they're written, without their module path.
+        let rust_name = sig.ident.unraw().to_string();
         let is_extern_c = sig.abi.is_omitted() || sig.abi.is_c() || sig.abi.is_cmse();
         let exported_name = named_symbol.exported_name();
 
@@ -737,10 +885,13 @@ impl Parse {
                 match Function::load(path, self_type, sig, false, attrs, mod_cfg) {
                     Ok(func) => {
                         info!("Take {}.", loggable_item_name());
+                        self.lints.function(sig.ident.span(), &func.path, rust_name);
                         self.functions.push(func);
                     }
                     Err(msg) => {
                         error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                        self.lints
+                            .skipped_function(sig.ident.span(), rust_name, &msg);
                     }
                 }
             }
@@ -752,6 +903,8 @@ impl Parse {
             }
             (false, Some(_exported_name)) => {
                 warn!("Skipping {} - (not `extern \"C\"`)", loggable_item_name());
+                self.lints
+                    .skipped_function(sig.ident.span(), rust_name, "not `extern \"C\"`");
             }
             (false, None) => {}
         }
@@ -921,6 +1074,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.lints.opaque_type(item.ident.span(), &path, &msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -945,6 +1099,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.lints.opaque_type(item.ident.span(), &path, &msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -968,6 +1123,7 @@ impl Parse {
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, item.ident, msg);
                 let path = Path::new(item.ident.unraw().to_string());
+                self.lints.opaque_type(item.ident.span(), &path, &msg);
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
                 );
@@ -1042,3 +1198,106 @@ impl Parse {
         self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, &impl_)
     }
 }
+
+/// Rewrites tuple and slice types to generic paths, so that they're
+/// instantiated as structs like any other generic type.
+struct AnonymousTypeFold {
+    tuples: bool,
+    slices: bool,
+}
+
+impl AnonymousTypeFold {
+    fn apply(config: &Config, file: syn::File) -> syn::File {
+        let mut fold = AnonymousTypeFold {
+            tuples: config.layout.tuples,
+            slices: config.layout.slices,
+        };
+        if fold.tuples || fold.slices {
+            fold.fold_file(file)
+        } else {
+            file
+        }
+    }
+
+    fn generic_path<'a>(name: &str, args: impl Iterator<Item = &'a syn::Type>) -> syn::Type {
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        syn::parse_quote!(#ident<#(#args),*>)
+    }
+
+    fn slice_element(ty: &syn::Type) -> Option<syn::Type> {
+        match *ty {
+            syn::Type::Slice(ref slice) => Some((*slice.elem).clone()),
+            syn::Type::Path(ref path) if path.qself.is_none() && path.path.is_ident("str") => {
+                Some(syn::parse_quote!(u8))
+            }
+            _ => None,
+        }
+    }
+
+    /// The reference in an `Option<&T>`, which is `None` when it's null.
+    fn optional_reference(ty: &syn::Type) -> Option<&syn::TypeReference> {
+        let syn::Type::Path(ref path) = *ty else {
+            return None;
+        };
+        let segment = path.path.segments.last()?;
+        if path.qself.is_some() || segment.ident != "Option" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(ref args) = segment.arguments else {
+            return None;
+        };
+        match (args.args.len(), args.args.first()) {
+            (1, Some(syn::GenericArgument::Type(syn::Type::Reference(reference)))) => {
+                Some(reference)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Fold for AnonymousTypeFold {
+    // Constants are written as literals, which support `&[u8]` byte strings as they are.
+    fn fold_item_const(&mut self, item: syn::ItemConst) -> syn::ItemConst {
+        item
+    }
+
+    fn fold_impl_item_const(&mut self, item: syn::ImplItemConst) -> syn::ImplItemConst {
+        item
+    }
+
+    fn fold_type(&mut self, ty: syn::Type) -> syn::Type {
+        // Like `Option<&T>` is a nullable pointer, `Option<&[T]>` is a slice with a null `ptr`.
+        if self.slices {
+            if let Some(reference) = Self::optional_reference(&ty) {
+                if Self::slice_element(&reference.elem).is_some() {
+                    let reference = syn::Type::Reference(reference.clone());
+                    return self.fold_type(reference);
+                }
+            }
+        }
+        let ty = syn::fold::fold_type(self, ty);
+        let slice = match ty {
+            syn::Type::Tuple(ref tuple) if self.tuples && !tuple.elems.is_empty() => {
+                return Self::generic_path(TUPLE_PATH, tuple.elems.iter());
+            }
+            syn::Type::Reference(ref reference) if self.slices => {
+                Self::slice_element(&reference.elem)
+                    .map(|elem| (elem, reference.mutability.is_some()))
+            }
+            syn::Type::Ptr(ref pointer) if self.slices => {
+                Self::slice_element(&pointer.elem).map(|elem| {
+                    (
+                        elem,
+                        matches!(pointer.mutability, syn::PointerMutability::Mut(_)),
+                    )
+                })
+            }
+            _ => None,
+        };
+        match slice {
+            Some((elem, true)) => Self::generic_path(MUT_SLICE_PATH, std::iter::once(&elem)),
+            Some((elem, false)) => Self::generic_path(SLICE_PATH, std::iter::once(&elem)),
+            None => ty,
+        }
+    }
+}
@@ -89,3 +89,264 @@ pub fn escape(rust_identifier: &mut String) {
         rust_identifier.push('_');
     }
 }
+
+/// Zig keywords, primitive type names and primitive values, which can only be
+/// used as identifiers with the `@"..."` syntax. Arbitrary bit-width integer
+/// types (`u7`, `i128`, ...) are handled separately in `is_zig_reserved`.
+/// Taken from `https://ziglang.org/documentation/master/#Keyword-Reference`
+/// and `https://ziglang.org/documentation/master/#Primitive-Types`.
+const ZIG_RESERVED_IDENTIFIERS: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyerror",
+    "anyframe",
+    "anyopaque",
+    "anytype",
+    "asm",
+    "bool",
+    "break",
+    "c_char",
+    "c_int",
+    "c_long",
+    "c_longdouble",
+    "c_longlong",
+    "c_short",
+    "c_uint",
+    "c_ulong",
+    "c_ulonglong",
+    "c_ushort",
+    "callconv",
+    "catch",
+    "comptime",
+    "comptime_float",
+    "comptime_int",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "f128",
+    "f16",
+    "f32",
+    "f64",
+    "f80",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "isize",
+    "linksection",
+    "noalias",
+    "noinline",
+    "noreturn",
+    "nosuspend",
+    "null",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "true",
+    "try",
+    "type",
+    "undefined",
+    "union",
+    "unreachable",
+    "usingnamespace",
+    "usize",
+    "var",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Whether `identifier` has to be written as `@"identifier"` in Zig.
+pub fn is_zig_reserved(identifier: &str) -> bool {
+    if ZIG_RESERVED_IDENTIFIERS.binary_search(&identifier).is_ok() {
+        return true;
+    }
+    match identifier.strip_prefix(['i', 'u']) {
+        Some(bits) => !bits.is_empty() && bits.bytes().all(|b| b.is_ascii_digit()),
+        None => false,
+    }
+}
+
+/// C# keywords, which can only be used as identifiers with an `@` prefix.
+/// Taken from `https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/`
+const CSHARP_RESERVED_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Whether `identifier` has to be written as `@identifier` in C#.
+pub fn is_csharp_reserved(identifier: &str) -> bool {
+    CSHARP_RESERVED_KEYWORDS.binary_search(&identifier).is_ok()
+}
+
+/// Java keywords and literals, which can't be used as identifiers.
+/// Taken from `https://docs.oracle.com/javase/specs/jls/se22/html/jls-3.html#jls-3.9`
+const JAVA_RESERVED_KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Whether `identifier` can't be used as a Java identifier.
+pub fn is_java_reserved(identifier: &str) -> bool {
+    JAVA_RESERVED_KEYWORDS.binary_search(&identifier).is_ok()
+}
+
+/// Rust keywords, which can only be used as identifiers with an `r#` prefix.
+/// Taken from `https://doc.rust-lang.org/reference/keywords.html`
+const RUST_RESERVED_KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Whether `identifier` has to be written as `r#identifier` in Rust.
+pub fn is_rust_reserved(identifier: &str) -> bool {
+    RUST_RESERVED_KEYWORDS.binary_search(&identifier).is_ok()
+}
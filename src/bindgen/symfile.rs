@@ -0,0 +1,137 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Symbol files listing the dynamic symbols of the bindings, for the linker.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::bindgen::config::SymfileFormat;
+use crate::bindgen::ir::{AnnotationSet, Item};
+use crate::bindgen::Bindings;
+
+struct Symbol<'a> {
+    name: &'a str,
+    annotations: &'a AnnotationSet,
+    is_data: bool,
+}
+
+/// The exported functions and globals, in the order of the header.
+fn symbols(b: &Bindings) -> impl Iterator<Item = Symbol<'_>> {
+    let functions = b.functions.iter().map(|f| Symbol {
+        name: f.path().name(),
+        annotations: &f.annotations,
+        is_data: false,
+    });
+    let globals = b.globals.iter().map(|g| Symbol {
+        name: g.export_name(),
+        annotations: &g.annotations,
+        is_data: true,
+    });
+    functions
+        .chain(globals)
+        .filter(|s| s.annotations.should_export())
+}
+
+/// Parses a version like `1.2.3` into its components, to order versions numerically.
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|c| c.parse().ok()).collect()
+}
+
+/// The symbols by the version they were introduced in, from `cbindgen:since`.
+fn symbols_by_version(b: &Bindings) -> BTreeMap<Vec<u64>, (String, Vec<&str>)> {
+    let base_version = &b.config.symfile.base_version;
+    let base = parse_version(base_version).unwrap_or_else(|| {
+        warn!("Invalid symfile.base_version {base_version:?}, expected numbers separated by dots.");
+        Vec::new()
+    });
+
+    let mut versions = BTreeMap::new();
+    versions.insert(base.clone(), (base_version.clone(), Vec::new()));
+    for symbol in symbols(b) {
+        let key = match symbol.annotations.atom("since") {
+            Some(Some(since)) => match parse_version(&since) {
+                Some(key) => {
+                    versions
+                        .entry(key.clone())
+                        .or_insert_with(|| (since, Vec::new()));
+                    key
+                }
+                None => {
+                    warn!(
+                        "Ignoring invalid version {since:?} of {}, expected numbers separated by \
+                         dots.",
+                        symbol.name
+                    );
+                    base.clone()
+                }
+            },
+            _ => base.clone(),
+        };
+        versions.get_mut(&key).unwrap().1.push(symbol.name);
+    }
+    versions
+}
+
+pub(crate) fn write<F: Write>(b: &Bindings, out: &mut F) -> io::Result<()> {
+    match b.config.symfile.format {
+        SymfileFormat::DynamicList => write_dynamic_list(b, out),
+        SymfileFormat::VersionScript => write_version_script(b, out),
+        SymfileFormat::Def => write_def(b, out),
+    }
+}
+
+fn write_dynamic_list<F: Write>(b: &Bindings, out: &mut F) -> io::Result<()> {
+    writeln!(out, "{{")?;
+    for symbol in b.dynamic_symbols_names() {
+        writeln!(out, "{symbol};")?;
+    }
+    write!(out, "}};")
+}
+
+/// Writes a version node per version, each depending on the previous one. The oldest one also
+/// hides every symbol which isn't listed.
+fn write_version_script<F: Write>(b: &Bindings, out: &mut F) -> io::Result<()> {
+    let prefix = &b.config.symfile.version_prefix;
+    let mut previous: Option<String> = None;
+    for (version, symbols) in symbols_by_version(b).values() {
+        if previous.is_some() {
+            writeln!(out)?;
+        }
+        let node = format!("{prefix}{version}");
+        writeln!(out, "{node} {{")?;
+        if !symbols.is_empty() {
+            writeln!(out, "  global:")?;
+            for symbol in symbols {
+                writeln!(out, "    {symbol};")?;
+            }
+        }
+        match previous {
+            Some(ref previous) => writeln!(out, "}} {previous};")?,
+            None => {
+                writeln!(out, "  local:")?;
+                writeln!(out, "    *;")?;
+                writeln!(out, "}};")?;
+            }
+        }
+        previous = Some(node);
+    }
+    Ok(())
+}
+
+/// Writes the `EXPORTS` of a module-definition file, which has no versions.
+fn write_def<F: Write>(b: &Bindings, out: &mut F) -> io::Result<()> {
+    if let Some(ref library) = b.config.symfile.library {
+        writeln!(out, "LIBRARY {library}")?;
+    }
+    writeln!(out, "EXPORTS")?;
+    for symbol in symbols(b) {
+        if symbol.is_data {
+            writeln!(out, "    {} DATA", symbol.name)?;
+        } else {
+            writeln!(out, "    {}", symbol.name)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,534 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Computes the size, alignment and field offsets of the emitted types for a
+//! target, the same way a C compiler for it would lay them out.
+
+use std::collections::HashMap;
+
+use crate::bindgen::config::{PrimitiveLayout, TargetConfig};
+use crate::bindgen::ir::{
+    ConstExpr, Enum, Field, IntKind, Item, ItemContainer, Literal, PrimitiveType, ReprAlign,
+    Struct, Type, Typedef, Union, VariantBody,
+};
+use crate::bindgen::Bindings;
+
+/// The size and alignment of a type, in bytes, along with the offsets of its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub size: u64,
+    pub align: u64,
+    /// The fields of a struct or union, in declaration order. For tagged enums, these are the
+    /// tag followed by the body of each variant, or its fields if the body is inlined.
+    pub fields: Vec<FieldLayout>,
+}
+
+/// The position of a field within a type, in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+impl TypeLayout {
+    fn primitive(layout: PrimitiveLayout) -> Self {
+        TypeLayout {
+            size: layout.size,
+            align: layout.align,
+            fields: Vec::new(),
+        }
+    }
+
+    fn empty() -> Self {
+        TypeLayout {
+            size: 0,
+            align: 1,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Appends a field after the previous ones, or at offset zero for unions.
+    fn push_field(
+        &mut self,
+        name: &str,
+        field: &TypeLayout,
+        alignment: Option<ReprAlign>,
+        is_union: bool,
+    ) {
+        let align = match alignment {
+            Some(ReprAlign::Packed) => 1,
+            Some(ReprAlign::PackedN(n)) => field.align.min(n),
+            _ => field.align,
+        };
+        let offset = if is_union {
+            0
+        } else {
+            self.size.next_multiple_of(align)
+        };
+        self.fields.push(FieldLayout {
+            name: name.to_owned(),
+            offset,
+            size: field.size,
+        });
+        self.size = self.size.max(offset + field.size);
+        self.align = self.align.max(align);
+    }
+
+    /// Pads the size to a multiple of the alignment, once all fields are pushed.
+    fn finish(mut self, alignment: Option<ReprAlign>) -> Self {
+        if let Some(ReprAlign::Align(n)) = alignment {
+            self.align = self.align.max(n);
+        }
+        self.size = self.size.next_multiple_of(self.align);
+        self
+    }
+}
+
+/// The sizes and alignments of the primitive types of a target.
+#[derive(Debug, Clone)]
+pub(crate) struct Target {
+    pointer: u64,
+    long: u64,
+    int64_align: u64,
+    int128_align: u64,
+    overrides: HashMap<String, PrimitiveLayout>,
+}
+
+impl Target {
+    pub(crate) fn new(config: &TargetConfig) -> Self {
+        let host;
+        let triple = match config.triple {
+            Some(ref triple) => triple.as_str(),
+            None => {
+                host = format!(
+                    "{}-unknown-{}",
+                    std::env::consts::ARCH,
+                    std::env::consts::OS
+                );
+                &host
+            }
+        };
+        let arch = triple.split('-').next().unwrap_or_default();
+        let is_windows = triple.contains("windows");
+        let is_x86 = matches!(arch, "x86" | "i386" | "i586" | "i686");
+
+        let pointer = if arch.ends_with("_32") || triple.ends_with("x32") {
+            4
+        } else if arch.contains("64") || arch == "s390x" || arch.starts_with("bpf") {
+            8
+        } else if arch == "avr" || arch == "msp430" {
+            2
+        } else {
+            4
+        };
+        // `long` is 32 bits on Windows (LLP64), and pointer-sized elsewhere (LP64 / ILP32).
+        let long = if is_windows { 4 } else { pointer.max(4) };
+        // The i386 System V ABI only aligns 64-bit types to 4 bytes.
+        let int64_align = if is_x86 && !is_windows { 4 } else { 8 };
+        let int128_align = if pointer == 8 || is_x86 { 16 } else { 8 };
+
+        Target {
+            pointer,
+            long,
+            int64_align,
+            int128_align,
+            overrides: config.primitives.clone(),
+        }
+    }
+
+    fn pointer(&self) -> PrimitiveLayout {
+        self.overrides
+            .get("pointer")
+            .copied()
+            .unwrap_or(PrimitiveLayout {
+                size: self.pointer,
+                align: self.pointer,
+            })
+    }
+
+    /// The layout of a primitive type, or `None` for the ones without a size.
+    fn primitive(&self, prim: &PrimitiveType) -> Option<PrimitiveLayout> {
+        if let Some(layout) = self.overrides.get(prim.to_repr_rust()) {
+            return Some(*layout);
+        }
+        let (size, align) = match *prim {
+            PrimitiveType::Void | PrimitiveType::VaList => return None,
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar => (1, 1),
+            PrimitiveType::Char32 | PrimitiveType::Float => (4, 4),
+            PrimitiveType::Double => (8, self.int64_align),
+            PrimitiveType::Float16 => (2, 2),
+            PrimitiveType::Float128 => (16, self.int128_align),
+            PrimitiveType::PtrDiffT => return Some(self.pointer()),
+            PrimitiveType::Integer { kind, .. } => match kind {
+                IntKind::B8 => (1, 1),
+                IntKind::Short | IntKind::B16 => (2, 2),
+                IntKind::Int | IntKind::B32 => (4, 4),
+                IntKind::Long => (self.long, self.long.min(self.int64_align)),
+                IntKind::LongLong | IntKind::B64 => (8, self.int64_align),
+                IntKind::B128 => (16, self.int128_align),
+                IntKind::SizeT | IntKind::Size => return Some(self.pointer()),
+            },
+        };
+        Some(PrimitiveLayout { size, align })
+    }
+}
+
+/// Computes the layout of every struct, union, enum, typedef and sized opaque item of `bindings`,
+/// by export name. Types whose layout can't be known, such as other opaque items, templates or
+/// bitfields, are left out.
+pub(crate) fn compute(bindings: &Bindings) -> HashMap<String, TypeLayout> {
+    let mut computer = LayoutComputer {
+        bindings,
+        target: Target::new(&bindings.config.target),
+        items: HashMap::new(),
+        layouts: HashMap::new(),
+    };
+    for item in &bindings.items {
+        computer
+            .items
+            .entry(item.deref().export_name())
+            .or_default()
+            .push(item);
+    }
+    for item in &bindings.items {
+        computer.named(item.deref().export_name());
+    }
+    computer
+        .layouts
+        .into_iter()
+        .filter_map(|(name, layout)| Some((name, layout?)))
+        .collect()
+}
+
+struct LayoutComputer<'a> {
+    bindings: &'a Bindings,
+    target: Target,
+    items: HashMap<&'a str, Vec<&'a ItemContainer>>,
+    /// The memoized layouts by export name. `None` is also recorded while computing a layout,
+    /// so that types containing themselves don't recurse forever.
+    layouts: HashMap<String, Option<TypeLayout>>,
+}
+
+impl LayoutComputer<'_> {
+    fn named(&mut self, name: &str) -> Option<TypeLayout> {
+        if let Some(layout) = self.layouts.get(name) {
+            return layout.clone();
+        }
+        self.layouts.insert(name.to_owned(), None);
+
+        // Items with several definitions (under different cfgs) only have a layout if all of
+        // them agree on it.
+        let items = self.items.get(name).cloned().unwrap_or_default();
+        let mut result = None;
+        for item in &items {
+            let layout = match *item {
+                ItemContainer::Struct(ref s) => self.structure(s),
+                ItemContainer::Union(ref u) => self.union(u),
+                ItemContainer::Enum(ref e) => self.enumeration(e),
+                ItemContainer::Typedef(ref t) => self.typedef(t),
+                ItemContainer::OpaqueItem(ref o) => o.storage.map(|storage| TypeLayout {
+                    size: storage.size,
+                    align: storage.align,
+                    fields: Vec::new(),
+                }),
+                _ => None,
+            };
+            match (layout, &result) {
+                (Some(layout), None) => result = Some(layout),
+                (Some(ref layout), Some(ref other)) if layout == other => {}
+                _ => {
+                    result = None;
+                    break;
+                }
+            }
+        }
+
+        self.layouts.insert(name.to_owned(), result.clone());
+        result
+    }
+
+    fn ty(&mut self, ty: &Type) -> Option<TypeLayout> {
+        match *ty {
+            Type::Primitive(ref prim) => self.target.primitive(prim).map(TypeLayout::primitive),
+            Type::Ptr { .. } | Type::FuncPtr { .. } => {
+                Some(TypeLayout::primitive(self.target.pointer()))
+            }
+            Type::Path(ref generic) => {
+                if !generic.generics().is_empty() {
+                    return None;
+                }
+                self.named(generic.export_name())
+            }
+            Type::Array(ref ty, ref len) => {
+                let literal = match *len {
+                    ConstExpr::Value(ref value) => Literal::Expr(value.clone()),
+                    ConstExpr::Path(ref path) => Literal::Path {
+                        associated_to: None,
+                        name: path.export_name().to_owned(),
+                    },
+                };
+                let len = u64::try_from(literal.evaluate(self.bindings, &[])?).ok()?;
+                let element = self.ty(ty)?;
+                Some(TypeLayout {
+                    size: element.size.checked_mul(len)?,
+                    align: element.align,
+                    fields: Vec::new(),
+                })
+            }
+        }
+    }
+
+    fn fields(
+        &mut self,
+        layout: &mut TypeLayout,
+        fields: &[Field],
+        alignment: Option<ReprAlign>,
+        is_union: bool,
+    ) -> Option<()> {
+        for field in fields {
+            if field.cfg.is_some() || field.annotations.atom("bitfield").is_some() {
+                return None;
+            }
+            let ty = self.ty(&field.ty)?;
+            layout.push_field(&field.name, &ty, alignment, is_union);
+        }
+        Some(())
+    }
+
+    fn structure(&mut self, s: &Struct) -> Option<TypeLayout> {
+        if s.is_generic() {
+            return None;
+        }
+        // Transparent structs are written as a typedef of their field.
+        if s.is_transparent {
+            return self.ty(&s.fields.first()?.ty);
+        }
+        let mut layout = TypeLayout::empty();
+        self.fields(&mut layout, &s.fields, s.alignment, false)?;
+        Some(layout.finish(s.alignment))
+    }
+
+    fn union(&mut self, u: &Union) -> Option<TypeLayout> {
+        if u.is_generic() {
+            return None;
+        }
+        let mut layout = TypeLayout::empty();
+        self.fields(&mut layout, &u.fields, u.alignment, true)?;
+        Some(layout.finish(u.alignment))
+    }
+
+    fn enumeration(&mut self, e: &Enum) -> Option<TypeLayout> {
+        if e.is_generic() {
+            return None;
+        }
+        let tag = self.target.primitive(&match e.repr.ty {
+            Some(ty) => ty.to_primitive(),
+            None => PrimitiveType::Integer {
+                zeroable: true,
+                signed: true,
+                kind: IntKind::Int,
+            },
+        })?;
+        let tag = TypeLayout::primitive(tag);
+        if e.tag.is_none() {
+            return Some(tag);
+        }
+
+        // The bodies of the variants overlap in a union, each starting with the tag when it's
+        // inlined in them.
+        let inline_tag = Enum::inline_tag_field(&e.repr);
+        let mut bodies = TypeLayout::empty();
+        if inline_tag {
+            bodies.push_field("tag", &tag, None, true);
+        }
+        for variant in &e.variants {
+            if variant.cfg.is_some() {
+                return None;
+            }
+            let VariantBody::Body {
+                ref name,
+                ref body,
+                inline,
+                ..
+            } = variant.body
+            else {
+                continue;
+            };
+            let mut layout = TypeLayout::empty();
+            let mut fields = &body.fields[..];
+            if body.has_tag_field {
+                layout.push_field(&fields.first()?.name, &tag, None, false);
+                fields = &fields[1..];
+            }
+            self.fields(&mut layout, fields, None, false)?;
+            let layout = layout.finish(None);
+            if !inline {
+                self.layouts
+                    .insert(body.export_name().to_owned(), Some(layout.clone()));
+            }
+            bodies.push_field(name, &layout, None, true);
+            if inline {
+                // The fields of inlined bodies are members of the enum, through an anonymous
+                // struct.
+                bodies.fields.pop();
+                bodies.fields.extend(layout.fields);
+            }
+        }
+        let bodies = bodies.finish(None);
+        if inline_tag {
+            return Some(bodies);
+        }
+
+        let mut layout = TypeLayout::empty();
+        layout.push_field("tag", &tag, None, false);
+        let offset = layout.size.next_multiple_of(bodies.align);
+        layout
+            .fields
+            .extend(bodies.fields.into_iter().map(|field| FieldLayout {
+                offset: offset + field.offset,
+                ..field
+            }));
+        layout.size = offset + bodies.size;
+        layout.align = layout.align.max(bodies.align);
+        Some(layout.finish(None))
+    }
+
+    fn typedef(&mut self, t: &Typedef) -> Option<TypeLayout> {
+        if t.is_generic() {
+            return None;
+        }
+        self.ty(&t.aliased)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(triple: &str) -> Target {
+        Target::new(&TargetConfig {
+            triple: Some(triple.to_owned()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn primitive_layouts() {
+        let long = PrimitiveType::Integer {
+            zeroable: true,
+            signed: true,
+            kind: IntKind::Long,
+        };
+        let u64 = PrimitiveType::Integer {
+            zeroable: true,
+            signed: false,
+            kind: IntKind::B64,
+        };
+        let layout = |triple, prim: &PrimitiveType| {
+            let layout = target(triple).primitive(prim).unwrap();
+            (layout.size, layout.align)
+        };
+
+        assert_eq!(layout("x86_64-unknown-linux-gnu", &long), (8, 8));
+        assert_eq!(layout("x86_64-pc-windows-msvc", &long), (4, 4));
+        assert_eq!(layout("i686-unknown-linux-gnu", &long), (4, 4));
+        assert_eq!(layout("i686-unknown-linux-gnu", &u64), (8, 4));
+        assert_eq!(layout("i686-pc-windows-msvc", &u64), (8, 8));
+        assert_eq!(target("aarch64-apple-darwin").pointer().size, 8);
+        assert_eq!(target("armv7-unknown-linux-gnueabihf").pointer().size, 4);
+        assert_eq!(target("x86_64-unknown-linux-gnux32").pointer().size, 4);
+    }
+
+    #[test]
+    fn primitive_overrides() {
+        let mut config = TargetConfig {
+            triple: Some("x86_64-unknown-linux-gnu".to_owned()),
+            ..Default::default()
+        };
+        config
+            .primitives
+            .insert("pointer".to_owned(), PrimitiveLayout { size: 4, align: 4 });
+        let target = Target::new(&config);
+        let usize = PrimitiveType::Integer {
+            zeroable: true,
+            signed: false,
+            kind: IntKind::Size,
+        };
+        assert_eq!(
+            target.primitive(&usize),
+            Some(PrimitiveLayout { size: 4, align: 4 })
+        );
+    }
+
+    #[test]
+    fn packed_fields() {
+        let u8 = TypeLayout::primitive(PrimitiveLayout { size: 1, align: 1 });
+        let u32 = TypeLayout::primitive(PrimitiveLayout { size: 4, align: 4 });
+        let offsets = |alignment| {
+            let mut layout = TypeLayout::empty();
+            layout.push_field("a", &u8, alignment, false);
+            layout.push_field("b", &u32, alignment, false);
+            let layout = layout.finish(alignment);
+            (layout.fields[1].offset, layout.size, layout.align)
+        };
+        assert_eq!(offsets(None), (4, 8, 4));
+        assert_eq!(offsets(Some(ReprAlign::Packed)), (1, 5, 1));
+        assert_eq!(offsets(Some(ReprAlign::PackedN(2))), (2, 6, 2));
+        assert_eq!(offsets(Some(ReprAlign::Align(16))), (4, 16, 16));
+    }
+
+    #[test]
+    fn item_layouts() {
+        use std::io::Write;
+
+        let mut src = tempfile::Builder::new().suffix(".rs").tempfile().unwrap();
+        write!(
+            src,
+            r#"
+            pub const LEN: usize = 3;
+
+            #[repr(C)]
+            pub struct Foo {{ a: u8, b: [u16; LEN], c: *const Foo, d: Bar }}
+
+            #[repr(C, u8)]
+            pub enum Bar {{ A(u32), B {{ x: u8, y: u64 }}, C }}
+
+            #[repr(u16)]
+            pub enum Baz {{ D(u8), E(u32) }}
+
+            #[no_mangle]
+            pub extern "C" fn root(foo: Foo, baz: Baz) {{}}
+            "#
+        )
+        .unwrap();
+
+        let config = crate::bindgen::Config {
+            language: crate::bindgen::Language::C,
+            target: TargetConfig {
+                triple: Some("x86_64-unknown-linux-gnu".to_owned()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bindings = crate::bindgen::Builder::new()
+            .with_config(config)
+            .with_src(src.path())
+            .generate()
+            .unwrap();
+        let offsets = |name| {
+            let layout = bindings.type_layout(name).unwrap();
+            let offsets = layout.fields.iter().map(|f| f.offset).collect::<Vec<_>>();
+            (layout.size, layout.align, offsets)
+        };
+
+        assert_eq!(offsets("B_Body"), (16, 8, vec![0, 8]));
+        assert_eq!(offsets("Bar"), (24, 8, vec![0, 8, 8]));
+        assert_eq!(offsets("Baz"), (8, 4, vec![0, 0, 2, 0, 4]));
+        assert_eq!(offsets("Foo"), (40, 8, vec![0, 2, 8, 16]));
+    }
+}
@@ -159,7 +159,13 @@ impl<'a, F: Write> SourceWriter<'a, F> {
 
     pub fn open_brace(&mut self) {
         match self.bindings.config.language {
-            Language::Cxx | Language::C => match self.bindings.config.braces {
+            Language::Cxx
+            | Language::C
+            | Language::Zig
+            | Language::CSharp
+            | Language::Cffi
+            | Language::Java
+            | Language::Rust => match self.bindings.config.braces {
                 Braces::SameLine => {
                     self.write(" {");
                     self.push_tab();
@@ -183,7 +189,13 @@ impl<'a, F: Write> SourceWriter<'a, F> {
     pub fn close_brace(&mut self, semicolon: bool) {
         self.pop_tab();
         match self.bindings.config.language {
-            Language::Cxx | Language::C => {
+            Language::Cxx
+            | Language::C
+            | Language::Zig
+            | Language::CSharp
+            | Language::Cffi
+            | Language::Java
+            | Language::Rust => {
                 self.new_line();
                 if semicolon {
                     self.write("};");
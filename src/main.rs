@@ -27,10 +27,21 @@ mod logging;
 
 use bindgen::{Bindings, Builder, Cargo, Config, Error};
 
+/// Whether `--lang json` asks for the JSON dump of the items resolved for a C header, rather
+/// than bindings.
+fn writes_json(matches: &ArgMatches) -> bool {
+    matches
+        .get_one::<String>("lang")
+        .is_some_and(|lang| lang.eq_ignore_ascii_case("json"))
+}
+
 fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     // We allow specifying a language to override the config default. This is
     // used by compile-tests.
     match matches.try_get_one::<String>("lang") {
+        Ok(Some(_)) if writes_json(matches) => {
+            config.language = bindgen::Language::C;
+        }
         Ok(Some(lang)) => {
             config.language = bindgen::Language::from_str(lang).unwrap();
         }
@@ -75,6 +86,14 @@ fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
         _ => (),
     }
 
+    if let Some(format) = matches.get_one::<String>("symfile-format") {
+        config.symfile.format = bindgen::SymfileFormat::from_str(format).unwrap();
+    }
+
+    if let Some(target) = matches.get_one::<String>("target") {
+        config.target.triple = Some(target.clone());
+    }
+
     if matches.get_flag("d") {
         config.parse.parse_deps = true;
     }
@@ -151,6 +170,30 @@ fn main() {
                 .action(ArgAction::SetTrue)
                 .help("Generate bindings and compare it to the existing bindings file and error if they are different"),
         )
+        .arg(
+            Arg::new("compat")
+                .long("compat")
+                .value_name("SNAPSHOT")
+                .value_parser(value_parser!(PathBuf))
+                .help("Compare the bindings to the ones of a previous version, written with \
+                    `--lang json` to the given path, and error if they break its API or ABI: \
+                    removed or renamed items, changed signatures, values or layouts"),
+        )
+        .arg(
+            Arg::new("lint")
+                .long("lint")
+                .action(ArgAction::SetTrue)
+                .help("Report, instead of writing the bindings, the exported functions which were \
+                    skipped or pass an opaque type by value, and the types used by the bindings \
+                    which ended up opaque, with their locations"),
+        )
+        .arg(
+            Arg::new("deny-lints")
+                .long("deny-lints")
+                .action(ArgAction::SetTrue)
+                .requires("lint")
+                .help("Exit with an error code if `--lint` reports anything"),
+        )
         .arg(
             Arg::new("config")
                 .short('c')
@@ -165,7 +208,10 @@ fn main() {
                 .long("lang")
                 .value_name("LANGUAGE")
                 .help("Specify the language to output bindings in")
-                .value_parser(["c++", "C++", "c", "C", "cython", "Cython"]),
+                .value_parser([
+                    "c++", "C++", "c", "C", "cython", "Cython", "zig", "Zig", "csharp", "CSharp",
+                    "cffi", "Cffi", "java", "Java", "rust", "Rust", "json", "JSON",
+                ]),
         )
         .arg(
             Arg::new("package-version")
@@ -277,6 +323,16 @@ fn main() {
                 )
                 .value_parser(["Debug", "debug", "Release", "release"]),
         )
+        .arg(
+            Arg::new("target")
+                .long("target")
+                .value_name("TRIPLE")
+                .help(
+                    "Specify the target triple to compute the layout of types for. \
+                     Defaults to the host."
+                )
+                .required(false),
+        )
         .arg(
             Arg::new("quiet")
                 .short('q')
@@ -298,6 +354,45 @@ fn main() {
                     This option is ignored if `--out` is missing."
                 )
         )
+        .arg(
+            Arg::new("layout-tests")
+                .value_name("PATH")
+                .long("layout-tests")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate tests checking that the Rust types and the generated \
+                    header agree on the size, alignment and field offsets of each type: \
+                    a Rust test module at the given Path with an `rs` extension, and a C \
+                    or C++ file including the header with a `c` or `cpp` extension. \
+                    This option is ignored if `--out` is missing."
+                )
+        )
+        .arg(
+            Arg::new("loader")
+                .value_name("PATH")
+                .long("loader")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a C loader for the library, which opens it at runtime with \
+                    `dlopen`: a header at the given Path with an `h` extension declaring a \
+                    table of pointers to its functions and globals, and the C source filling \
+                    it with a `c` extension. Its functions are prefixed with the file name. \
+                    This option is ignored if `--out` is missing, or for other languages than C."
+                )
+        )
+        .arg(
+            Arg::new("api-docs")
+                .value_name("PATH")
+                .long("api-docs")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .help("Generate a Markdown reference of the C or C++ API at the given Path, with \
+                    a section per exported item holding its declaration and documentation."
+                )
+        )
         .arg(
             Arg::new("symfile")
                 .value_name("PATH")
@@ -309,6 +404,18 @@ fn main() {
                     dynamic symbols. Useful when creating a plugin system with a C interface."
                 )
         )
+        .arg(
+            Arg::new("symfile-format")
+                .value_name("FORMAT")
+                .long("symfile-format")
+                .num_args(1)
+                .required(false)
+                .value_parser(["dynamic-list", "version-script", "def"])
+                .help("The format of the symbol file: a list for `--dynamic-list` (the \
+                    default), a GNU ld version script, or an MSVC module-definition file. \
+                    Overrides `symfile.format`."
+                )
+        )
         .get_matches();
 
     if matches.get_flag("verify") && !matches.contains_id("out") {
@@ -344,10 +451,27 @@ fn main() {
         }
     };
 
+    if matches.get_flag("lint") {
+        for lint in bindings.lints() {
+            println!("{lint}");
+        }
+        if matches.get_flag("deny-lints") && !bindings.lints().is_empty() {
+            std::process::exit(2);
+        }
+        return;
+    }
+
     // Write the bindings file
     match matches.get_one::<PathBuf>("out") {
         Some(file) => {
-            let changed = bindings.write_to_file(file);
+            let changed = if writes_json(&matches) {
+                bindings.write_json_to_file(file).unwrap_or_else(|msg| {
+                    error!("{msg}");
+                    std::process::exit(1);
+                })
+            } else {
+                bindings.write_to_file(file)
+            };
 
             if matches.get_flag("verify") && changed {
                 error!("Bindings changed: {}", file.display());
@@ -359,9 +483,41 @@ fn main() {
             if let Some(symfile) = matches.get_one::<String>("symfile") {
                 bindings.generate_symfile(symfile);
             }
+            if let Some(layout_tests) = matches.get_one::<PathBuf>("layout-tests") {
+                bindings.generate_layout_tests(file, layout_tests);
+            }
+            if let Some(loader) = matches.get_one::<PathBuf>("loader") {
+                bindings.generate_loader(file, loader);
+            }
+        }
+        _ if writes_json(&matches) => {
+            if let Err(msg) = bindings.write_json(io::stdout()) {
+                error!("{msg}");
+                std::process::exit(1);
+            }
         }
         _ => {
             bindings.write(io::stdout());
         }
     }
+
+    if let Some(api_docs) = matches.get_one::<PathBuf>("api-docs") {
+        bindings.generate_api_docs(api_docs);
+    }
+
+    if let Some(snapshot) = matches.get_one::<PathBuf>("compat") {
+        let changes = match bindings.compat_changes_from_snapshot(snapshot) {
+            Ok(changes) => changes,
+            Err(msg) => {
+                error!("{msg}");
+                std::process::exit(1);
+            }
+        };
+        for change in &changes {
+            error!("Breaking change: {change}");
+        }
+        if !changes.is_empty() {
+            std::process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,57 @@
+//! Helpers for the tests which run cbindgen and compilers on fixtures of their own, and then
+//! what they build. Each test uses some of them.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cbindgen::{Bindings, Builder};
+use tempfile::TempDir;
+
+static CBINDGEN_PATH: &str = env!("CARGO_BIN_EXE_cbindgen");
+
+/// A command running cbindgen.
+pub fn cbindgen() -> Command {
+    Command::new(CBINDGEN_PATH)
+}
+
+/// A command running the tool named by the `var` environment variable, or else `default`, like
+/// the C compiler in `CC`.
+pub fn tool(var: &str, default: &str) -> Command {
+    Command::new(env::var(var).unwrap_or_else(|_| default.to_owned()))
+}
+
+/// Runs `command`, which must succeed.
+pub fn run(command: &mut Command) {
+    let output = command.output().expect("Failed to execute process");
+    assert!(
+        output.status.success(),
+        "{command:?} failed: stdout: `{}`, stderr: `{}`",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+/// The path of a file in the `tests` directory, like `rust/loader.rs`.
+pub fn test_path(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(path)
+}
+
+pub fn tmp_dir(prefix: &str) -> TempDir {
+    tempfile::Builder::new()
+        .prefix(prefix)
+        .tempdir()
+        .expect("Creating tmp dir failed")
+}
+
+/// Generates the bindings of the Rust source at `path` with the default configuration.
+pub fn generate(path: &Path) -> Bindings {
+    Builder::new()
+        .with_src(path)
+        .generate()
+        .expect("generating bindings failed")
+}
@@ -0,0 +1,89 @@
+use cbindgen::CompatChange;
+
+mod common;
+
+use common::{cbindgen, generate, run, test_path, tmp_dir};
+
+fn change(item: &str, description: &str) -> CompatChange {
+    CompatChange {
+        item: item.to_owned(),
+        description: description.to_owned(),
+    }
+}
+
+#[test]
+fn test_compat_changes() {
+    let old = generate(&test_path("compat/old.rs"));
+    let new = generate(&test_path("compat/new.rs"));
+
+    assert_eq!(new.compat_changes(&new).unwrap(), []);
+    assert_eq!(
+        new.compat_changes(&old).unwrap(),
+        [
+            change("MAX_LEN", "value changed from `16` to `32`"),
+            change("OLD_NAME", "renamed to `NEW_NAME`"),
+            change("REMOVED", "removed"),
+            change("COUNTER", "no longer mutable"),
+            change("Shape.Square", "discriminant changed from 1 to 2"),
+            change("Shape.Triangle", "discriminant changed from 2 to 1"),
+            change(
+                "Level.Medium",
+                "discriminant may have changed from `BASE + 1` to 2",
+            ),
+            change(
+                "Level.High",
+                "discriminant changed from `BASE + 2` to `BASE + 1`",
+            ),
+            change("Point", "size changed from 8 to 16 bytes"),
+            change("Point", "alignment changed from 4 to 8 bytes"),
+            change("Point.x", "type changed from `i32` to `i64`"),
+            change("Point.y", "type changed from `i32` to `i64`"),
+            change("Point.y", "offset changed from 4 to 8"),
+            change("Rect", "size changed from 16 to 32 bytes"),
+            change("Rect", "alignment changed from 4 to 8 bytes"),
+            change("Rect", "fields reordered"),
+            change("Rect.origin", "offset changed from 0 to 16"),
+            change("Rect.size", "offset changed from 8 to 0"),
+            change("area", "return type changed from `i32` to `i64`"),
+            change("translate", "number of arguments changed from 2 to 1"),
+            change("reset", "removed"),
+        ]
+    );
+}
+
+/// Checks that `--compat` fails with the breaking changes from a snapshot written with
+/// `--lang json`.
+#[test]
+fn test_compat_snapshot() {
+    let tmp_dir = tmp_dir("cbindgen-compat-");
+    let tmp_dir = tmp_dir.path();
+
+    run(cbindgen()
+        .arg("--lang")
+        .arg("json")
+        .arg("--output")
+        .arg(tmp_dir.join("old.json"))
+        .arg(test_path("compat/old.rs")));
+
+    let output = cbindgen()
+        .arg("--compat")
+        .arg(tmp_dir.join("old.json"))
+        .arg("--output")
+        .arg(tmp_dir.join("new.h"))
+        .arg(test_path("compat/new.rs"))
+        .output()
+        .expect("Failed to execute process");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Breaking change: `reset`: removed"),
+        "stderr: {stderr}"
+    );
+
+    run(cbindgen()
+        .arg("--compat")
+        .arg(tmp_dir.join("old.json"))
+        .arg("--output")
+        .arg(tmp_dir.join("old.h"))
+        .arg(test_path("compat/old.rs")));
+}
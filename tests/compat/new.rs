@@ -0,0 +1,44 @@
+pub const MAX_LEN: u32 = 32;
+pub const NEW_NAME: u32 = 3;
+
+#[repr(C)]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[repr(C)]
+pub struct Rect {
+    size: Point,
+    origin: Point,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle,
+    Triangle,
+    Square,
+    Hexagon,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low = BASE,
+    High,
+    Medium = 2,
+}
+
+#[no_mangle]
+pub static COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn area(rect: &Rect) -> i64 {}
+
+#[no_mangle]
+pub extern "C" fn translate(rect: &mut Rect) {}
+
+#[no_mangle]
+pub extern "C" fn draw(shape: Shape, level: Level, rect: &Rect) {}
+
+#[no_mangle]
+pub extern "C" fn scale(rect: &mut Rect, factor: i64) {}
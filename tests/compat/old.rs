@@ -0,0 +1,44 @@
+pub const MAX_LEN: u32 = 16;
+pub const OLD_NAME: u32 = 3;
+pub const REMOVED: u8 = 1;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle,
+    Square,
+    Triangle,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low = BASE,
+    Medium,
+    High,
+}
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn area(rect: &Rect) -> i32 {}
+
+#[no_mangle]
+pub extern "C" fn translate(rect: &mut Rect, by: Point) {}
+
+#[no_mangle]
+pub extern "C" fn draw(shape: Shape, level: Level, rect: *const Rect) {}
+
+#[no_mangle]
+pub extern "C" fn reset() {}
@@ -0,0 +1,3 @@
+void c(void);
+
+void c_unwind(void);
@@ -0,0 +1,5 @@
+extern "C" {
+  pub fn c();
+
+  pub fn c_unwind();
+}
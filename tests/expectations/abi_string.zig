@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern fn c() void;
+
+pub extern fn c_unwind() void;
@@ -0,0 +1,34 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Status : uint {
+  Ok,
+  Err,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Dep {
+  public int a;
+  public float b;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_i32 {
+  public int a;
+  public int b;
+  public Dep c;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_f64 {
+  public double a;
+  public double b;
+  public Dep c;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_i32 x, Foo_f64 y, int z, Status w);
+}
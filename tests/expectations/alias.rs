@@ -0,0 +1,41 @@
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum Status {
+  Ok,
+  Err,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Dep {
+  pub a: i32,
+  pub b: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_i32 {
+  pub a: i32,
+  pub b: i32,
+  pub c: Dep,
+}
+
+pub type IntFoo = Foo_i32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_f64 {
+  pub a: f64,
+  pub b: f64,
+  pub c: Dep,
+}
+
+pub type DoubleFoo = Foo_f64;
+
+pub type Unit = i32;
+
+pub type SpecialStatus = Status;
+
+extern "C" {
+  pub fn root(x: IntFoo, y: DoubleFoo, z: Unit, w: SpecialStatus);
+}
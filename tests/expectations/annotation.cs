@@ -0,0 +1,80 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum C : uint {
+  X = 2,
+  Y,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct A {
+  public int m0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct B {
+  public int x;
+  public float y;
+}
+
+public enum F_Tag : byte {
+  Foo,
+  Bar,
+  Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar_Body {
+  public F_Tag tag;
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct F {
+  [FieldOffset(0)] public F_Tag tag;
+  [FieldOffset(0)] public Foo_Body foo;
+  [FieldOffset(0)] public Bar_Body bar;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Foo_Body {
+    public F_Tag foo_tag;
+    public short foo;
+  }
+}
+
+public enum H_Tag : byte {
+  Hello,
+  There,
+  Everyone,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct There_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H {
+  public H_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Hello_Body hello;
+    [FieldOffset(0)] public There_Body there;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Hello_Body {
+    public short hello;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A x, B y, C z, F f, H h);
+}
@@ -0,0 +1,188 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class C {
+    private C() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int X = 2;
+    public static final int Y = 3;
+  }
+
+  public static final class A {
+    private A() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("m0")
+    ).withName("A");
+  }
+
+  public static final class B {
+    private B() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("x"),
+      JAVA_FLOAT.withName("y")
+    ).withName("B");
+  }
+
+  public static final class F_Tag {
+    private F_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Foo = 0;
+    public static final byte Bar = 1;
+    public static final byte Baz = 2;
+  }
+
+  public static final class Bar_Body {
+    private Bar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      F_Tag.LAYOUT.withName("tag"),
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("Bar_Body");
+  }
+
+  public static final class F {
+    private F() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      F_Tag.LAYOUT.withName("tag"),
+      structLayout(F_Tag.LAYOUT.withName("foo_tag"), JAVA_SHORT.withName("foo")),
+      Bar_Body.LAYOUT.withName("bar")
+    ).withName("F");
+  }
+
+  public static final class H_Tag {
+    private H_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Hello = 0;
+    public static final byte There = 1;
+    public static final byte Everyone = 2;
+  }
+
+  public static final class There_Body {
+    private There_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("There_Body");
+  }
+
+  public static final class H {
+    private H() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      H_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_SHORT.withName("hello")), There_Body.LAYOUT.withName("there"))
+    ).withName("H");
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(A.LAYOUT,
+                                                                                     B.LAYOUT,
+                                                                                     C.LAYOUT,
+                                                                                     F.LAYOUT,
+                                                                                     H.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,45 @@
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum C {
+  X = 2,
+  Y,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct A {
+  pub m0: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct B {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum F {
+  Foo(i16),
+  Bar {
+    x: u8,
+    y: i16,
+  },
+  Baz,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum H {
+  Hello(i16),
+  There {
+    x: u8,
+    y: i16,
+  },
+  Everyone,
+}
+
+extern "C" {
+  pub fn root(x: A, y: B, z: C, f: F, h: H);
+}
@@ -0,0 +1,28 @@
+#define MAX_LEN 16
+
+typedef struct Point Point;
+
+enum Handle {
+  File,
+  Socket,
+};
+typedef uint8_t Handle;
+
+enum Shape {
+  Circle,
+  Square,
+};
+typedef uint8_t Shape;
+
+typedef struct Canvas Canvas;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+Point point(Handle handle);
+
+void draw(Canvas *canvas, Shape shape, const Point *at);
+
+void draw_circle(Canvas *canvas, Point at);
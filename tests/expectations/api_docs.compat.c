@@ -0,0 +1,120 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The largest length.
+ */
+#define MAX_LEN 16
+
+#if defined(DEFINED)
+enum Handle
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A file descriptor.
+   */
+  File,
+  Socket,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Handle Handle;
+#else
+typedef uint8_t Handle;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+#endif
+
+enum Shape
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A circle around the point.
+   */
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Shape Shape;
+#else
+typedef uint8_t Shape;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef struct Canvas Canvas;
+
+/**
+ * A point in the plane.
+ *
+ * # Examples
+ *
+ * ```
+ * let origin = Point { x: 0, y: 0 };
+ * ```
+ */
+typedef struct {
+  /**
+   * The abscissa.
+   */
+  int32_t x;
+  int32_t y;
+} Point;
+
+#if defined(NOT_DEFINED)
+enum Handle_Tag
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A raw handle.
+   */
+  Raw,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Handle_Tag Handle_Tag;
+#else
+typedef uint8_t Handle_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef union {
+  Handle_Tag tag;
+  struct {
+    Handle_Tag raw_tag;
+    uint32_t raw;
+  };
+} Handle;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The origin.
+ *
+ * # Point
+ *
+ * Not the [`Point`] type.
+ */
+Point point(Handle handle);
+
+/**
+ * Draws a shape.
+ */
+void draw(Canvas *canvas, Shape shape, const Point *at);
+
+void draw_circle(Canvas *canvas, Point at);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
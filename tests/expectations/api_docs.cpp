@@ -0,0 +1,72 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// The largest length.
+constexpr static const uint32_t MAX_LEN = 16;
+
+#if defined(DEFINED)
+enum class Handle : uint8_t {
+  /// A file descriptor.
+  File,
+  Socket,
+};
+#endif
+
+enum class Shape : uint8_t {
+  /// A circle around the point.
+  Circle,
+  Square,
+};
+
+struct Canvas;
+
+/// A point in the plane.
+///
+/// # Examples
+///
+/// ```
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+struct Point {
+  /// The abscissa.
+  int32_t x;
+  int32_t y;
+};
+
+#if defined(NOT_DEFINED)
+union Handle {
+  enum class Tag : uint8_t {
+    /// A raw handle.
+    Raw,
+  };
+
+  struct Raw_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Raw_Body raw;
+};
+#endif
+
+extern "C" {
+
+/// The origin.
+///
+/// # Point
+///
+/// Not the [`Point`] type.
+Point point(Handle handle);
+
+/// Draws a shape.
+void draw(Canvas *canvas, Shape shape, const Point *at);
+
+void draw_circle(Canvas *canvas, Point at);
+
+}  // extern "C"
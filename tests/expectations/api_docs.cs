@@ -0,0 +1,89 @@
+using System;
+using System.Runtime.InteropServices;
+
+#if DEFINED
+public enum Handle : byte {
+  /// <summary>
+  /// A file descriptor.
+  /// </summary>
+  File,
+  Socket,
+}
+#endif
+
+public enum Shape : byte {
+  /// <summary>
+  /// A circle around the point.
+  /// </summary>
+  Circle,
+  Square,
+}
+
+public partial struct Canvas { }
+
+/// <summary>
+/// A point in the plane.
+///
+/// # Examples
+///
+/// ```
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  /// <summary>
+  /// The abscissa.
+  /// </summary>
+  public int x;
+  public int y;
+}
+
+#if NOT_DEFINED
+public enum Handle_Tag : byte {
+  /// <summary>
+  /// A raw handle.
+  /// </summary>
+  Raw,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Handle {
+  [FieldOffset(0)] public Handle_Tag tag;
+  [FieldOffset(0)] public Raw_Body raw;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Raw_Body {
+    public Handle_Tag raw_tag;
+    public uint raw;
+  }
+}
+#endif
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// The largest length.
+  /// </summary>
+  public const uint MAX_LEN = 16;
+
+  /// <summary>
+  /// The origin.
+  ///
+  /// # Point
+  ///
+  /// Not the [`Point`] type.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Point point(Handle handle);
+
+  /// <summary>
+  /// Draws a shape.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void draw(Canvas* canvas, Shape shape, Point* at);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void draw_circle(Canvas* canvas, Point at);
+}
@@ -0,0 +1,63 @@
+/// The largest length.
+pub const MAX_LEN: u32 = 16;
+
+#[cfg(target_os = "linux")]
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Handle {
+  /// A file descriptor.
+  File,
+  Socket,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Shape {
+  /// A circle around the point.
+  Circle,
+  Square,
+}
+
+#[repr(C)]
+pub struct Canvas {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+/// A point in the plane.
+///
+/// # Examples
+///
+/// ```
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  /// The abscissa.
+  pub x: i32,
+  pub y: i32,
+}
+
+#[cfg(target_os = "windows")]
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Handle {
+  /// A raw handle.
+  Raw(u32),
+}
+
+extern "C" {
+  /// The origin.
+  ///
+  /// # Point
+  ///
+  /// Not the [`Point`] type.
+  pub fn point(handle: Handle) -> Point;
+
+  /// Draws a shape.
+  pub fn draw(canvas: *mut Canvas, shape: Shape, at: *const Point);
+
+  #[deprecated = "Use `draw` instead"]
+  pub fn draw_circle(canvas: *mut Canvas, at: Point);
+}
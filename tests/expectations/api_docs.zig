@@ -0,0 +1,60 @@
+const std = @import("std");
+
+/// The largest length.
+pub const MAX_LEN: u32 = 16;
+
+// #if defined(DEFINED)
+pub const Handle = enum(u8) {
+  /// A file descriptor.
+  File,
+  Socket,
+};
+// #endif
+
+pub const Shape = enum(u8) {
+  /// A circle around the point.
+  Circle,
+  Square,
+};
+
+pub const Canvas = opaque {};
+
+/// A point in the plane.
+///
+/// # Examples
+///
+/// ```
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+pub const Point = extern struct {
+  /// The abscissa.
+  x: i32,
+  y: i32,
+};
+
+// #if defined(NOT_DEFINED)
+pub const Handle_Tag = enum(u8) {
+  /// A raw handle.
+  Raw,
+};
+
+pub const Handle = extern union {
+  tag: Handle_Tag,
+  raw: extern struct {
+    raw_tag: Handle_Tag,
+    raw: u32,
+  },
+};
+// #endif
+
+/// The origin.
+///
+/// # Point
+///
+/// Not the [`Point`] type.
+pub extern fn point(handle: Handle) Point;
+
+/// Draws a shape.
+pub extern fn draw(canvas: ?*Canvas, shape: Shape, at: *const Point) void;
+
+pub extern fn draw_circle(canvas: ?*Canvas, at: Point) void;
@@ -0,0 +1,106 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The largest length.
+ */
+#define MAX_LEN 16
+
+#if defined(DEFINED)
+enum Handle
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A file descriptor.
+   */
+  File,
+  Socket,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Handle Handle;
+#else
+typedef uint8_t Handle;
+#endif // __STDC_VERSION__ >= 202311L
+#endif
+
+enum Shape
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A circle around the point.
+   */
+  Circle,
+  Square,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Shape Shape;
+#else
+typedef uint8_t Shape;
+#endif // __STDC_VERSION__ >= 202311L
+
+struct Canvas;
+
+/**
+ * A point in the plane.
+ *
+ * # Examples
+ *
+ * ```
+ * let origin = Point { x: 0, y: 0 };
+ * ```
+ */
+struct Point {
+  /**
+   * The abscissa.
+   */
+  int32_t x;
+  int32_t y;
+};
+
+#if defined(NOT_DEFINED)
+enum Handle_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * A raw handle.
+   */
+  Raw,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Handle_Tag Handle_Tag;
+#else
+typedef uint8_t Handle_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+union Handle {
+  Handle_Tag tag;
+  struct {
+    Handle_Tag raw_tag;
+    uint32_t raw;
+  };
+};
+#endif
+
+/**
+ * The origin.
+ *
+ * # Point
+ *
+ * Not the [`Point`] type.
+ */
+struct Point point(Handle handle);
+
+/**
+ * Draws a shape.
+ */
+void draw(struct Canvas *canvas, Shape shape, const struct Point *at);
+
+void draw_circle(struct Canvas *canvas, struct Point at);
@@ -0,0 +1,60 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # The largest length.
+  const uint32_t MAX_LEN # = 16
+
+  IF DEFINED:
+    cdef enum:
+      # A file descriptor.
+      File,
+      Socket,
+    ctypedef uint8_t Handle;
+
+  cdef enum:
+    # A circle around the point.
+    Circle,
+    Square,
+  ctypedef uint8_t Shape;
+
+  cdef struct Canvas:
+    pass
+
+  # A point in the plane.
+  #
+  # # Examples
+  #
+  # ```
+  # let origin = Point { x: 0, y: 0 };
+  # ```
+  cdef struct Point:
+    # The abscissa.
+    int32_t x;
+    int32_t y;
+
+  IF NOT_DEFINED:
+    cdef enum:
+      # A raw handle.
+      Raw,
+    ctypedef uint8_t Handle_Tag;
+
+    cdef union Handle:
+      Handle_Tag tag;
+      uint32_t raw;
+
+  # The origin.
+  #
+  # # Point
+  #
+  # Not the [`Point`] type.
+  Point point(Handle handle);
+
+  # Draws a shape.
+  void draw(Canvas *canvas, Shape shape, const Point *at);
+
+  void draw_circle(Canvas *canvas, Point at);
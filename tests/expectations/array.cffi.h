@@ -0,0 +1,16 @@
+typedef struct Foo Foo;
+
+typedef enum Foo_Tag {
+  A,
+} Foo_Tag;
+
+struct Foo {
+  Foo_Tag tag;
+  union {
+    struct {
+      float a[20];
+    };
+  };
+};
+
+void root(Foo a);
@@ -0,0 +1,29 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Foo_Tag {
+  A,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+  public Foo_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public A_Body a;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct A_Body {
+    public fixed float a[20];
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+}
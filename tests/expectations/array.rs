@@ -0,0 +1,9 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum Foo {
+  A([f32; 20]),
+}
+
+extern "C" {
+  pub fn root(a: Foo);
+}
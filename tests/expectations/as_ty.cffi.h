@@ -0,0 +1,14 @@
+#define SIZE 4
+
+typedef struct WithoutAs WithoutAs;
+typedef struct WithAs WithAs;
+
+struct WithoutAs {
+  uint32_t items[SIZE];
+};
+
+struct WithAs {
+  uint32_t items[SIZE];
+};
+
+void some_fn(WithoutAs a, WithAs b);
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct WithoutAs {
+  public fixed uint items[NativeMethods.SIZE];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct WithAs {
+  public fixed uint items[NativeMethods.SIZE];
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const nint SIZE = 4;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void some_fn(WithoutAs a, WithAs b);
+}
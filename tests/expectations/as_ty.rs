@@ -0,0 +1,17 @@
+pub const SIZE: isize = 4;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct WithoutAs {
+  pub items: [char; SIZE as usize],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct WithAs {
+  pub items: [char; SIZE as usize],
+}
+
+extern "C" {
+  pub fn some_fn(a: WithoutAs, b: WithAs);
+}
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const SIZE: isize = 4;
+
+pub const WithoutAs = extern struct {
+  items: [SIZE]u32,
+};
+
+pub const WithAs = extern struct {
+  items: [SIZE]u32,
+};
+
+pub extern fn some_fn(a: WithoutAs, b: WithAs) void;
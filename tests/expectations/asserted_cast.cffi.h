@@ -0,0 +1,76 @@
+typedef struct H_Bar_Body H_Bar_Body;
+typedef struct H H;
+typedef struct J_Bar_Body J_Bar_Body;
+typedef struct J J;
+typedef struct K_Bar_Body K_Bar_Body;
+typedef union K K;
+
+typedef struct I I;
+
+enum H_Tag {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+};
+typedef uint8_t H_Tag;
+
+struct H_Bar_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct H {
+  H_Tag tag;
+  union {
+    struct {
+      int16_t foo;
+    };
+    H_Bar_Body bar;
+  };
+};
+
+enum J_Tag {
+  J_Foo,
+  J_Bar,
+  J_Baz,
+};
+typedef uint8_t J_Tag;
+
+struct J_Bar_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct J {
+  J_Tag tag;
+  union {
+    struct {
+      int16_t foo;
+    };
+    J_Bar_Body bar;
+  };
+};
+
+enum K_Tag {
+  K_Foo,
+  K_Bar,
+  K_Baz,
+};
+typedef uint8_t K_Tag;
+
+struct K_Bar_Body {
+  K_Tag tag;
+  uint8_t x;
+  int16_t y;
+};
+
+union K {
+  K_Tag tag;
+  struct {
+    K_Tag foo_tag;
+    int16_t foo;
+  };
+  K_Bar_Body bar;
+};
+
+void foo(H h, I i, J j, K k);
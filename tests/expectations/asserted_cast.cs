@@ -0,0 +1,99 @@
+#define MY_ASSERT(...) do { } while (0)
+#define MY_ATTRS __attribute((noinline))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct I { }
+
+public enum H_Tag : byte {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H_Bar_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H {
+  public H_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public H_Foo_Body foo;
+    [FieldOffset(0)] public H_Bar_Body bar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct H_Foo_Body {
+    public short foo;
+  }
+}
+
+public enum J_Tag : byte {
+  J_Foo,
+  J_Bar,
+  J_Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct J_Bar_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct J {
+  public J_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public J_Foo_Body foo;
+    [FieldOffset(0)] public J_Bar_Body bar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct J_Foo_Body {
+    public short foo;
+  }
+}
+
+public enum K_Tag : byte {
+  K_Foo,
+  K_Bar,
+  K_Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct K_Bar_Body {
+  public K_Tag tag;
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct K {
+  [FieldOffset(0)] public K_Tag tag;
+  [FieldOffset(0)] public K_Foo_Body foo;
+  [FieldOffset(0)] public K_Bar_Body bar;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct K_Foo_Body {
+    public K_Tag foo_tag;
+    public short foo;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(H h, I i, J j, K k);
+}
@@ -0,0 +1,46 @@
+#define MY_ASSERT(...) do { } while (0)
+#define MY_ATTRS __attribute((noinline))
+
+
+#[repr(C)]
+pub struct I {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum H {
+  H_Foo(i16),
+  H_Bar {
+    x: u8,
+    y: i16,
+  },
+  H_Baz,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum J {
+  J_Foo(i16),
+  J_Bar {
+    x: u8,
+    y: i16,
+  },
+  J_Baz,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum K {
+  K_Foo(i16),
+  K_Bar {
+    x: u8,
+    y: i16,
+  },
+  K_Baz,
+}
+
+extern "C" {
+  pub fn foo(h: H, i: I, j: J, k: K);
+}
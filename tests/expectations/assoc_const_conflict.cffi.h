@@ -0,0 +1 @@
+#define Foo_FOO 42
@@ -0,0 +1 @@
+pub const Foo_FOO: u32 = 42;
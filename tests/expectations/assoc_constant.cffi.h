@@ -0,0 +1,9 @@
+#define Foo_GA 10
+
+typedef struct Foo Foo;
+
+struct Foo {
+
+};
+
+void root(Foo x);
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const int Foo_GA = 10;
+
+  public const float Foo_ZO = (float)3.14;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo x);
+}
@@ -0,0 +1,13 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+
+}
+
+pub const Foo_GA: i32 = 10;
+
+pub const Foo_ZO: f32 = 3.14;
+
+extern "C" {
+  pub fn root(x: Foo);
+}
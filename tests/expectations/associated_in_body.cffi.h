@@ -0,0 +1,19 @@
+typedef struct StyleAlignFlags StyleAlignFlags;
+typedef struct StyleNativeSurfaceId StyleNativeSurfaceId;
+typedef struct StyleNativeTileId StyleNativeTileId;
+
+struct StyleAlignFlags {
+  uint8_t bits;
+};
+
+struct StyleNativeSurfaceId {
+  uint64_t _0;
+};
+
+struct StyleNativeTileId {
+  StyleNativeSurfaceId surface_id;
+  int32_t x;
+  int32_t y;
+};
+
+void root(StyleAlignFlags flags, StyleNativeTileId tile);
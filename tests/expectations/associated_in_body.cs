@@ -0,0 +1,90 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct StyleAlignFlags {
+  public byte bits;
+}
+
+/// <summary>
+/// An arbitrary identifier for a native (OS compositor) surface
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct StyleNativeSurfaceId {
+  public ulong _0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct StyleNativeTileId {
+  public StyleNativeSurfaceId surface_id;
+  public int x;
+  public int y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// 'auto'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_AUTO = new StyleAlignFlags { bits = unchecked((byte)(0)) };
+
+  /// <summary>
+  /// 'normal'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_NORMAL = new StyleAlignFlags { bits = unchecked((byte)(1)) };
+
+  /// <summary>
+  /// 'start'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_START = new StyleAlignFlags { bits = unchecked((byte)((1 << 1))) };
+
+  /// <summary>
+  /// 'end'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_END = new StyleAlignFlags { bits = unchecked((byte)((1 << 2))) };
+
+  public static readonly StyleAlignFlags StyleAlignFlags_ALIAS = new StyleAlignFlags { bits = unchecked((byte)((NativeMethods.StyleAlignFlags_END).bits)) };
+
+  /// <summary>
+  /// 'flex-start'
+  /// </summary>
+  public static readonly StyleAlignFlags StyleAlignFlags_FLEX_START = new StyleAlignFlags { bits = unchecked((byte)((1 << 3))) };
+
+  public static readonly StyleAlignFlags StyleAlignFlags_MIXED = new StyleAlignFlags { bits = unchecked((byte)((((1 << 4) | (NativeMethods.StyleAlignFlags_FLEX_START).bits) | (NativeMethods.StyleAlignFlags_END).bits))) };
+
+  public static readonly StyleAlignFlags StyleAlignFlags_MIXED_SELF = new StyleAlignFlags { bits = unchecked((byte)((((1 << 5) | (NativeMethods.StyleAlignFlags_FLEX_START).bits) | (NativeMethods.StyleAlignFlags_END).bits))) };
+
+#if PLATFORM_WIN
+  public static readonly StyleAlignFlags StyleAlignFlags_PLATFORM_BIT = new StyleAlignFlags { bits = unchecked((byte)((1 << 6))) };
+#endif
+
+#if PLATFORM_UNIX
+  public static readonly StyleAlignFlags StyleAlignFlags_PLATFORM_BIT = new StyleAlignFlags { bits = unchecked((byte)((1 << 7))) };
+#endif
+
+  /// <summary>
+  /// A special id for the native surface that is used for debug / profiler overlays.
+  /// </summary>
+  public static readonly StyleNativeSurfaceId StyleNativeSurfaceId_DEBUG_OVERLAY = new StyleNativeSurfaceId { _0 = ulong.MaxValue };
+
+  /// <summary>
+  /// A special id for the native surface that is used for debug / profiler overlays.
+  /// </summary>
+  public static readonly StyleNativeTileId StyleNativeTileId_DEBUG_OVERLAY = new StyleNativeTileId { surface_id = NativeMethods.StyleNativeSurfaceId_DEBUG_OVERLAY, x = 0, y = 0 };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(StyleAlignFlags flags, StyleNativeTileId tile);
+}
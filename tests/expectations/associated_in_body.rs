@@ -0,0 +1,67 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StyleAlignFlags {
+  pub bits: u8,
+}
+
+/// 'auto'
+pub const StyleAlignFlags_AUTO: StyleAlignFlags = StyleAlignFlags { bits: 0 };
+
+/// 'normal'
+pub const StyleAlignFlags_NORMAL: StyleAlignFlags = StyleAlignFlags { bits: 1 };
+
+/// 'start'
+pub const StyleAlignFlags_START: StyleAlignFlags = StyleAlignFlags { bits: 2 };
+
+/// 'end'
+pub const StyleAlignFlags_END: StyleAlignFlags = StyleAlignFlags { bits: 4 };
+
+pub const StyleAlignFlags_ALIAS: StyleAlignFlags = StyleAlignFlags { bits: ((StyleAlignFlags_END).bits as u8) };
+
+/// 'flex-start'
+pub const StyleAlignFlags_FLEX_START: StyleAlignFlags = StyleAlignFlags { bits: 8 };
+
+pub const StyleAlignFlags_MIXED: StyleAlignFlags = StyleAlignFlags { bits: ((((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) as u8) };
+
+pub const StyleAlignFlags_MIXED_SELF: StyleAlignFlags = StyleAlignFlags { bits: ((((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) as u8) };
+
+#[cfg(windows)]
+pub const StyleAlignFlags_PLATFORM_BIT: StyleAlignFlags = StyleAlignFlags { bits: 64 };
+
+#[cfg(unix)]
+pub const StyleAlignFlags_PLATFORM_BIT: StyleAlignFlags = StyleAlignFlags { bits: 128 };
+
+/// An arbitrary identifier for a native (OS compositor) surface
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StyleNativeSurfaceId {
+  pub _0: u64,
+}
+
+/// A special id for the native surface that is used for debug / profiler overlays.
+pub const StyleNativeSurfaceId_DEBUG_OVERLAY: StyleNativeSurfaceId = StyleNativeSurfaceId { _0: u64::MAX };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StyleNativeTileId {
+  pub surface_id: StyleNativeSurfaceId,
+  pub x: i32,
+  pub y: i32,
+}
+
+/// A special id for the native surface that is used for debug / profiler overlays.
+pub const StyleNativeTileId_DEBUG_OVERLAY: StyleNativeTileId = StyleNativeTileId { surface_id: StyleNativeSurfaceId_DEBUG_OVERLAY, x: 0, y: 0 };
+
+extern "C" {
+  pub fn root(flags: StyleAlignFlags, tile: StyleNativeTileId);
+}
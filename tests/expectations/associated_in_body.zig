@@ -0,0 +1,51 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+const std = @import("std");
+
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub const StyleAlignFlags = extern struct {
+  bits: u8,
+};
+/// 'auto'
+pub const StyleAlignFlags_AUTO: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, 0) };
+/// 'normal'
+pub const StyleAlignFlags_NORMAL: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, 1) };
+/// 'start'
+pub const StyleAlignFlags_START: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (1 << 1)) };
+/// 'end'
+pub const StyleAlignFlags_END: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (1 << 2)) };
+pub const StyleAlignFlags_ALIAS: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (StyleAlignFlags_END).bits) };
+/// 'flex-start'
+pub const StyleAlignFlags_FLEX_START: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (1 << 3)) };
+pub const StyleAlignFlags_MIXED: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits)) };
+pub const StyleAlignFlags_MIXED_SELF: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits)) };
+// #if defined(PLATFORM_WIN)
+pub const StyleAlignFlags_PLATFORM_BIT: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (1 << 6)) };
+// #endif
+// #if defined(PLATFORM_UNIX)
+pub const StyleAlignFlags_PLATFORM_BIT: StyleAlignFlags = StyleAlignFlags{ .bits = @as(u8, (1 << 7)) };
+// #endif
+
+/// An arbitrary identifier for a native (OS compositor) surface
+pub const StyleNativeSurfaceId = extern struct {
+  _0: u64,
+};
+/// A special id for the native surface that is used for debug / profiler overlays.
+pub const StyleNativeSurfaceId_DEBUG_OVERLAY: StyleNativeSurfaceId = StyleNativeSurfaceId{ ._0 = std.math.maxInt(u64) };
+
+pub const StyleNativeTileId = extern struct {
+  surface_id: StyleNativeSurfaceId,
+  x: i32,
+  y: i32,
+};
+/// A special id for the native surface that is used for debug / profiler overlays.
+pub const StyleNativeTileId_DEBUG_OVERLAY: StyleNativeTileId = StyleNativeTileId{ .surface_id = StyleNativeSurfaceId_DEBUG_OVERLAY, .x = 0, .y = 0 };
+
+pub extern fn root(flags: StyleAlignFlags, tile: StyleNativeTileId) void;
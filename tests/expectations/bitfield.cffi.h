@@ -0,0 +1,8 @@
+typedef struct HasBitfields HasBitfields;
+
+struct HasBitfields {
+  uint64_t foo: 8;
+  uint64_t bar: 56;
+};
+
+void root(const HasBitfields*);
@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct HasBitfields {
+  public ulong foo;
+  public ulong bar;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(HasBitfields* _0);
+}
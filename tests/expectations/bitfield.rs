@@ -0,0 +1,10 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct HasBitfields {
+  pub foo: u64,
+  pub bar: u64,
+}
+
+extern "C" {
+  pub fn root(_: *const HasBitfields);
+}
@@ -0,0 +1,22 @@
+typedef struct AlignFlags AlignFlags;
+typedef struct DebugFlags DebugFlags;
+typedef struct LargeFlags LargeFlags;
+typedef struct OutOfLine OutOfLine;
+
+struct AlignFlags {
+  uint8_t bits;
+};
+
+struct DebugFlags {
+  uint32_t bits;
+};
+
+struct LargeFlags {
+  uint64_t bits;
+};
+
+struct OutOfLine {
+  uint32_t _0;
+};
+
+void root(AlignFlags flags, DebugFlags bigger_flags, LargeFlags largest_flags, OutOfLine out_of_line);
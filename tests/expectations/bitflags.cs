@@ -0,0 +1,86 @@
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct AlignFlags {
+  public byte bits;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct DebugFlags {
+  public uint bits;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct LargeFlags {
+  public ulong bits;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct OutOfLine {
+  public uint _0;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// 'auto'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_AUTO = new AlignFlags { bits = unchecked((byte)(0)) };
+
+  /// <summary>
+  /// 'normal'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_NORMAL = new AlignFlags { bits = unchecked((byte)(1)) };
+
+  /// <summary>
+  /// 'start'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_START = new AlignFlags { bits = unchecked((byte)((1 << 1))) };
+
+  /// <summary>
+  /// 'end'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_END = new AlignFlags { bits = unchecked((byte)((1 << 2))) };
+
+  public static readonly AlignFlags AlignFlags_ALIAS = new AlignFlags { bits = unchecked((byte)((NativeMethods.AlignFlags_END).bits)) };
+
+  /// <summary>
+  /// 'flex-start'
+  /// </summary>
+  public static readonly AlignFlags AlignFlags_FLEX_START = new AlignFlags { bits = unchecked((byte)((1 << 3))) };
+
+  public static readonly AlignFlags AlignFlags_MIXED = new AlignFlags { bits = unchecked((byte)((((1 << 4) | (NativeMethods.AlignFlags_FLEX_START).bits) | (NativeMethods.AlignFlags_END).bits))) };
+
+  public static readonly AlignFlags AlignFlags_MIXED_SELF = new AlignFlags { bits = unchecked((byte)((((1 << 5) | (NativeMethods.AlignFlags_FLEX_START).bits) | (NativeMethods.AlignFlags_END).bits))) };
+
+  /// <summary>
+  /// Flag with the topmost bit set of the u32
+  /// </summary>
+  public static readonly DebugFlags DebugFlags_BIGGEST_ALLOWED = new DebugFlags { bits = unchecked((uint)((1 << 31))) };
+
+  /// <summary>
+  /// Flag with a very large shift that usually would be narrowed.
+  /// </summary>
+  public static readonly LargeFlags LargeFlags_LARGE_SHIFT = new LargeFlags { bits = unchecked((ulong)((1UL << 44))) };
+
+  public static readonly LargeFlags LargeFlags_INVERTED = new LargeFlags { bits = unchecked((ulong)(~(NativeMethods.LargeFlags_LARGE_SHIFT).bits)) };
+
+  public static readonly OutOfLine OutOfLine_A = new OutOfLine { _0 = unchecked((uint)(1)) };
+
+  public static readonly OutOfLine OutOfLine_B = new OutOfLine { _0 = unchecked((uint)(2)) };
+
+  public static readonly OutOfLine OutOfLine_AB = new OutOfLine { _0 = unchecked((uint)(((NativeMethods.OutOfLine_A)._0 | (NativeMethods.OutOfLine_B)._0))) };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(AlignFlags flags,
+                                 DebugFlags bigger_flags,
+                                 LargeFlags largest_flags,
+                                 OutOfLine out_of_line);
+}
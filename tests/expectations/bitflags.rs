@@ -0,0 +1,70 @@
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AlignFlags {
+  pub bits: u8,
+}
+
+/// 'auto'
+pub const AlignFlags_AUTO: AlignFlags = AlignFlags { bits: 0 };
+
+/// 'normal'
+pub const AlignFlags_NORMAL: AlignFlags = AlignFlags { bits: 1 };
+
+/// 'start'
+pub const AlignFlags_START: AlignFlags = AlignFlags { bits: 2 };
+
+/// 'end'
+pub const AlignFlags_END: AlignFlags = AlignFlags { bits: 4 };
+
+pub const AlignFlags_ALIAS: AlignFlags = AlignFlags { bits: ((AlignFlags_END).bits as u8) };
+
+/// 'flex-start'
+pub const AlignFlags_FLEX_START: AlignFlags = AlignFlags { bits: 8 };
+
+pub const AlignFlags_MIXED: AlignFlags = AlignFlags { bits: ((((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) as u8) };
+
+pub const AlignFlags_MIXED_SELF: AlignFlags = AlignFlags { bits: ((((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) as u8) };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DebugFlags {
+  pub bits: u32,
+}
+
+/// Flag with the topmost bit set of the u32
+pub const DebugFlags_BIGGEST_ALLOWED: DebugFlags = DebugFlags { bits: 2147483648 };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LargeFlags {
+  pub bits: u64,
+}
+
+/// Flag with a very large shift that usually would be narrowed.
+pub const LargeFlags_LARGE_SHIFT: LargeFlags = LargeFlags { bits: 17592186044416 };
+
+pub const LargeFlags_INVERTED: LargeFlags = LargeFlags { bits: (!(LargeFlags_LARGE_SHIFT).bits as u64) };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct OutOfLine {
+  pub _0: u32,
+}
+
+pub const OutOfLine_A: OutOfLine = OutOfLine { _0: 1 };
+
+pub const OutOfLine_B: OutOfLine = OutOfLine { _0: 2 };
+
+pub const OutOfLine_AB: OutOfLine = OutOfLine { _0: (((OutOfLine_A)._0 | (OutOfLine_B)._0) as u32) };
+
+extern "C" {
+  pub fn root(
+    flags: AlignFlags,
+    bigger_flags: DebugFlags,
+    largest_flags: LargeFlags,
+    out_of_line: OutOfLine,
+  );
+}
@@ -0,0 +1,9 @@
+typedef uint8_t AlignFlags;
+
+typedef uint32_t DebugFlags;
+
+typedef uint64_t LargeFlags;
+
+typedef uint32_t OutOfLine;
+
+void root(AlignFlags flags, DebugFlags bigger_flags, LargeFlags largest_flags, OutOfLine out_of_line);
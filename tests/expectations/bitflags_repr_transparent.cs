@@ -0,0 +1,61 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// 'auto'
+  /// </summary>
+  public static readonly byte AlignFlags_AUTO = unchecked((byte)(0));
+
+  /// <summary>
+  /// 'normal'
+  /// </summary>
+  public static readonly byte AlignFlags_NORMAL = unchecked((byte)(1));
+
+  /// <summary>
+  /// 'start'
+  /// </summary>
+  public static readonly byte AlignFlags_START = unchecked((byte)((1 << 1)));
+
+  /// <summary>
+  /// 'end'
+  /// </summary>
+  public static readonly byte AlignFlags_END = unchecked((byte)((1 << 2)));
+
+  public static readonly byte AlignFlags_ALIAS = unchecked((byte)(NativeMethods.AlignFlags_END));
+
+  /// <summary>
+  /// 'flex-start'
+  /// </summary>
+  public static readonly byte AlignFlags_FLEX_START = unchecked((byte)((1 << 3)));
+
+  public static readonly byte AlignFlags_MIXED = unchecked((byte)((((1 << 4) | NativeMethods.AlignFlags_FLEX_START) | NativeMethods.AlignFlags_END)));
+
+  public static readonly byte AlignFlags_MIXED_SELF = unchecked((byte)((((1 << 5) | NativeMethods.AlignFlags_FLEX_START) | NativeMethods.AlignFlags_END)));
+
+  /// <summary>
+  /// Flag with the topmost bit set of the u32
+  /// </summary>
+  public static readonly uint DebugFlags_BIGGEST_ALLOWED = unchecked((uint)((1 << 31)));
+
+  /// <summary>
+  /// Flag with a very large shift that usually would be narrowed.
+  /// </summary>
+  public static readonly ulong LargeFlags_LARGE_SHIFT = unchecked((ulong)((1UL << 44)));
+
+  public static readonly ulong LargeFlags_INVERTED = unchecked((ulong)(~NativeMethods.LargeFlags_LARGE_SHIFT));
+
+  public static readonly uint OutOfLine_A = unchecked((uint)(1));
+
+  public static readonly uint OutOfLine_B = unchecked((uint)(2));
+
+  public static readonly uint OutOfLine_AB = unchecked((uint)((NativeMethods.OutOfLine_A | NativeMethods.OutOfLine_B)));
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(byte flags,
+                                 uint bigger_flags,
+                                 ulong largest_flags,
+                                 uint out_of_line);
+}
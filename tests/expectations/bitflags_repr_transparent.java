@@ -0,0 +1,152 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  /**
+   * 'auto'
+   */
+  public static final byte AlignFlags_AUTO = 0;
+
+  /**
+   * 'normal'
+   */
+  public static final byte AlignFlags_NORMAL = 1;
+
+  /**
+   * 'start'
+   */
+  public static final byte AlignFlags_START = 2;
+
+  /**
+   * 'end'
+   */
+  public static final byte AlignFlags_END = 4;
+
+  public static final byte AlignFlags_ALIAS = (byte) ((byte) (AlignFlags_END));
+
+  /**
+   * 'flex-start'
+   */
+  public static final byte AlignFlags_FLEX_START = 8;
+
+  public static final byte AlignFlags_MIXED = (byte) ((byte) ((((1 << 4) | AlignFlags_FLEX_START) | AlignFlags_END)));
+
+  public static final byte AlignFlags_MIXED_SELF = (byte) ((byte) ((((1 << 5) | AlignFlags_FLEX_START) | AlignFlags_END)));
+
+  /**
+   * Flag with the topmost bit set of the u32
+   */
+  public static final int DebugFlags_BIGGEST_ALLOWED = -2147483648;
+
+  /**
+   * Flag with a very large shift that usually would be narrowed.
+   */
+  public static final long LargeFlags_LARGE_SHIFT = 17592186044416L;
+
+  public static final long LargeFlags_INVERTED = (long) ((long) (~LargeFlags_LARGE_SHIFT));
+
+  public static final int OutOfLine_A = 1;
+
+  public static final int OutOfLine_B = 2;
+
+  public static final int OutOfLine_AB = (int) ((int) ((OutOfLine_A | OutOfLine_B)));
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(JAVA_BYTE,
+                                                                                     JAVA_INT,
+                                                                                     JAVA_LONG,
+                                                                                     JAVA_INT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
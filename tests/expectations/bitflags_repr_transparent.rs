@@ -0,0 +1,54 @@
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub type AlignFlags = u8;
+
+/// 'auto'
+pub const AlignFlags_AUTO: AlignFlags = 0;
+
+/// 'normal'
+pub const AlignFlags_NORMAL: AlignFlags = 1;
+
+/// 'start'
+pub const AlignFlags_START: AlignFlags = 2;
+
+/// 'end'
+pub const AlignFlags_END: AlignFlags = 4;
+
+pub const AlignFlags_ALIAS: AlignFlags = (AlignFlags_END as u8);
+
+/// 'flex-start'
+pub const AlignFlags_FLEX_START: AlignFlags = 8;
+
+pub const AlignFlags_MIXED: AlignFlags = ((((1 << 4) | AlignFlags_FLEX_START) | AlignFlags_END) as u8);
+
+pub const AlignFlags_MIXED_SELF: AlignFlags = ((((1 << 5) | AlignFlags_FLEX_START) | AlignFlags_END) as u8);
+
+pub type DebugFlags = u32;
+
+/// Flag with the topmost bit set of the u32
+pub const DebugFlags_BIGGEST_ALLOWED: DebugFlags = 2147483648;
+
+pub type LargeFlags = u64;
+
+/// Flag with a very large shift that usually would be narrowed.
+pub const LargeFlags_LARGE_SHIFT: LargeFlags = 17592186044416;
+
+pub const LargeFlags_INVERTED: LargeFlags = (!LargeFlags_LARGE_SHIFT as u64);
+
+pub type OutOfLine = u32;
+
+pub const OutOfLine_A: OutOfLine = 1;
+
+pub const OutOfLine_B: OutOfLine = 2;
+
+pub const OutOfLine_AB: OutOfLine = ((OutOfLine_A | OutOfLine_B) as u32);
+
+extern "C" {
+  pub fn root(
+    flags: AlignFlags,
+    bigger_flags: DebugFlags,
+    largest_flags: LargeFlags,
+    out_of_line: OutOfLine,
+  );
+}
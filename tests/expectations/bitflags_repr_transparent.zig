@@ -0,0 +1,38 @@
+const std = @import("std");
+
+/// Constants shared by multiple CSS Box Alignment properties
+///
+/// These constants match Gecko's `NS_STYLE_ALIGN_*` constants.
+pub const AlignFlags = u8;
+/// 'auto'
+pub const AlignFlags_AUTO: AlignFlags = @as(u8, 0);
+/// 'normal'
+pub const AlignFlags_NORMAL: AlignFlags = @as(u8, 1);
+/// 'start'
+pub const AlignFlags_START: AlignFlags = @as(u8, (1 << 1));
+/// 'end'
+pub const AlignFlags_END: AlignFlags = @as(u8, (1 << 2));
+pub const AlignFlags_ALIAS: AlignFlags = @as(u8, AlignFlags_END);
+/// 'flex-start'
+pub const AlignFlags_FLEX_START: AlignFlags = @as(u8, (1 << 3));
+pub const AlignFlags_MIXED: AlignFlags = @as(u8, (((1 << 4) | AlignFlags_FLEX_START) | AlignFlags_END));
+pub const AlignFlags_MIXED_SELF: AlignFlags = @as(u8, (((1 << 5) | AlignFlags_FLEX_START) | AlignFlags_END));
+
+pub const DebugFlags = u32;
+/// Flag with the topmost bit set of the u32
+pub const DebugFlags_BIGGEST_ALLOWED: DebugFlags = @as(u32, (1 << 31));
+
+pub const LargeFlags = u64;
+/// Flag with a very large shift that usually would be narrowed.
+pub const LargeFlags_LARGE_SHIFT: LargeFlags = @as(u64, (1 << 44));
+pub const LargeFlags_INVERTED: LargeFlags = @as(u64, ~LargeFlags_LARGE_SHIFT);
+
+pub const OutOfLine = u32;
+pub const OutOfLine_A: OutOfLine = @as(u32, 1);
+pub const OutOfLine_B: OutOfLine = @as(u32, 2);
+pub const OutOfLine_AB: OutOfLine = @as(u32, (OutOfLine_A | OutOfLine_B));
+
+pub extern fn root(flags: AlignFlags,
+                   bigger_flags: DebugFlags,
+                   largest_flags: LargeFlags,
+                   out_of_line: OutOfLine) void;
@@ -0,0 +1,81 @@
+typedef struct MyFancyStruct MyFancyStruct;
+typedef struct MyFancyEnum MyFancyEnum;
+typedef union MyUnion MyUnion;
+typedef struct MyFancyStruct_Prepended MyFancyStruct_Prepended;
+typedef struct MyFancyEnum_Prepended MyFancyEnum_Prepended;
+typedef union MyUnion_Prepended MyUnion_Prepended;
+
+typedef enum MyCLikeEnum {
+  Foo1,
+  Bar1,
+  Baz1,
+} MyCLikeEnum;
+
+typedef enum MyCLikeEnum_Prepended {
+  Foo1_Prepended,
+  Bar1_Prepended,
+  Baz1_Prepended,
+} MyCLikeEnum_Prepended;
+
+struct MyFancyStruct {
+  int32_t i;
+};
+
+typedef enum MyFancyEnum_Tag {
+  Foo,
+  Bar,
+  Baz,
+} MyFancyEnum_Tag;
+
+struct MyFancyEnum {
+  MyFancyEnum_Tag tag;
+  union {
+    struct {
+      int32_t bar;
+    };
+    struct {
+      int32_t baz;
+    };
+  };
+};
+
+union MyUnion {
+  float f;
+  uint32_t u;
+};
+
+struct MyFancyStruct_Prepended {
+  int32_t i;
+};
+
+typedef enum MyFancyEnum_Prepended_Tag {
+  Foo_Prepended,
+  Bar_Prepended,
+  Baz_Prepended,
+} MyFancyEnum_Prepended_Tag;
+
+struct MyFancyEnum_Prepended {
+  MyFancyEnum_Prepended_Tag tag;
+  union {
+    struct {
+      int32_t bar_prepended;
+    };
+    struct {
+      int32_t baz_prepended;
+    };
+  };
+};
+
+union MyUnion_Prepended {
+  float f;
+  uint32_t u;
+};
+
+void root(MyFancyStruct s,
+          MyFancyEnum e,
+          MyCLikeEnum c,
+          MyUnion u,
+          MyFancyStruct_Prepended sp,
+          MyFancyEnum_Prepended ep,
+          MyCLikeEnum_Prepended cp,
+          MyUnion_Prepended up);
@@ -0,0 +1,120 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum MyCLikeEnum {
+  Foo1,
+  Bar1,
+  Baz1,
+}
+
+public enum MyCLikeEnum_Prepended {
+  Foo1_Prepended,
+  Bar1_Prepended,
+  Baz1_Prepended,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyFancyStruct {
+  public int i;
+#ifdef __cplusplus
+    inline void foo();
+#endif
+}
+
+public enum MyFancyEnum_Tag {
+  Foo,
+  Bar,
+  Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyFancyEnum {
+  public MyFancyEnum_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Bar_Body bar;
+    [FieldOffset(0)] public Baz_Body baz;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Bar_Body {
+    public int bar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Baz_Body {
+    public int baz;
+  }
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct MyUnion {
+  [FieldOffset(0)] public float f;
+  [FieldOffset(0)] public uint u;
+  int32_t extra_member;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyFancyStruct_Prepended {
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+  public int i;
+}
+
+public enum MyFancyEnum_Prepended_Tag {
+  Foo_Prepended,
+  Bar_Prepended,
+  Baz_Prepended,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyFancyEnum_Prepended {
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+  public MyFancyEnum_Prepended_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Bar_Prepended_Body bar_prepended;
+    [FieldOffset(0)] public Baz_Prepended_Body baz_prepended;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Bar_Prepended_Body {
+    public int bar_prepended;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Baz_Prepended_Body {
+    public int baz_prepended;
+  }
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct MyUnion_Prepended {
+    int32_t extra_member;
+  [FieldOffset(0)] public float f;
+  [FieldOffset(0)] public uint u;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(MyFancyStruct s,
+                                 MyFancyEnum e,
+                                 MyCLikeEnum c,
+                                 MyUnion u,
+                                 MyFancyStruct_Prepended sp,
+                                 MyFancyEnum_Prepended ep,
+                                 MyCLikeEnum_Prepended cp,
+                                 MyUnion_Prepended up);
+}
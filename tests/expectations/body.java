@@ -0,0 +1,219 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class MyCLikeEnum {
+    private MyCLikeEnum() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Foo1 = 0;
+    public static final int Bar1 = 1;
+    public static final int Baz1 = 2;
+  }
+
+  public static final class MyCLikeEnum_Prepended {
+    private MyCLikeEnum_Prepended() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Foo1_Prepended = 0;
+    public static final int Bar1_Prepended = 1;
+    public static final int Baz1_Prepended = 2;
+  }
+
+  public static final class MyFancyStruct {
+    private MyFancyStruct() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("i")
+    ).withName("MyFancyStruct");
+
+#ifdef __cplusplus
+    inline void foo();
+#endif
+  }
+
+  public static final class MyFancyEnum_Tag {
+    private MyFancyEnum_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Foo = 0;
+    public static final int Bar = 1;
+    public static final int Baz = 2;
+  }
+
+  public static final class MyFancyEnum {
+    private MyFancyEnum() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      MyFancyEnum_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_INT.withName("bar")), structLayout(JAVA_INT.withName("baz")))
+    ).withName("MyFancyEnum");
+
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+  }
+
+  public static final class MyUnion {
+    private MyUnion() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      JAVA_FLOAT.withName("f"),
+      JAVA_INT.withName("u")
+    ).withName("MyUnion");
+
+  int32_t extra_member;
+  }
+
+  public static final class MyFancyStruct_Prepended {
+    private MyFancyStruct_Prepended() {}
+
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("i")
+    ).withName("MyFancyStruct_Prepended");
+  }
+
+  public static final class MyFancyEnum_Prepended_Tag {
+    private MyFancyEnum_Prepended_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Foo_Prepended = 0;
+    public static final int Bar_Prepended = 1;
+    public static final int Baz_Prepended = 2;
+  }
+
+  public static final class MyFancyEnum_Prepended {
+    private MyFancyEnum_Prepended() {}
+
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+
+    public static final StructLayout LAYOUT = structLayout(
+      MyFancyEnum_Prepended_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_INT.withName("bar_prepended")), structLayout(JAVA_INT.withName("baz_prepended")))
+    ).withName("MyFancyEnum_Prepended");
+  }
+
+  public static final class MyUnion_Prepended {
+    private MyUnion_Prepended() {}
+
+    int32_t extra_member;
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      JAVA_FLOAT.withName("f"),
+      JAVA_INT.withName("u")
+    ).withName("MyUnion_Prepended");
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(MyFancyStruct.LAYOUT,
+                                                                                     MyFancyEnum.LAYOUT,
+                                                                                     MyCLikeEnum.LAYOUT,
+                                                                                     MyUnion.LAYOUT,
+                                                                                     MyFancyStruct_Prepended.LAYOUT,
+                                                                                     MyFancyEnum_Prepended.LAYOUT,
+                                                                                     MyCLikeEnum_Prepended.LAYOUT,
+                                                                                     MyUnion_Prepended.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,86 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum MyCLikeEnum {
+  Foo1,
+  Bar1,
+  Baz1,
+  BogusVariantForSerializationForExample,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum MyCLikeEnum_Prepended {
+  BogusVariantForSerializationForExample,
+  Foo1_Prepended,
+  Bar1_Prepended,
+  Baz1_Prepended,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyFancyStruct {
+  pub i: i32,
+#ifdef __cplusplus
+    inline void foo();
+#endif
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum MyFancyEnum {
+  Foo,
+  Bar(i32),
+  Baz(i32),
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union MyUnion {
+  pub f: f32,
+  pub u: u32,
+  int32_t extra_member;
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyFancyStruct_Prepended {
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+  pub i: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum MyFancyEnum_Prepended {
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+  Foo_Prepended,
+  Bar_Prepended(i32),
+  Baz_Prepended(i32),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union MyUnion_Prepended {
+    int32_t extra_member;
+  pub f: f32,
+  pub u: u32,
+}
+
+extern "C" {
+  pub fn root(
+    s: MyFancyStruct,
+    e: MyFancyEnum,
+    c: MyCLikeEnum,
+    u: MyUnion,
+    sp: MyFancyStruct_Prepended,
+    ep: MyFancyEnum_Prepended,
+    cp: MyCLikeEnum_Prepended,
+    up: MyUnion_Prepended,
+  );
+}
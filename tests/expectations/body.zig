@@ -0,0 +1,90 @@
+const std = @import("std");
+
+pub const MyCLikeEnum = enum(c_int) {
+  Foo1,
+  Bar1,
+  Baz1,
+};
+
+pub const MyCLikeEnum_Prepended = enum(c_int) {
+  Foo1_Prepended,
+  Bar1_Prepended,
+  Baz1_Prepended,
+};
+
+pub const MyFancyStruct = extern struct {
+  i: i32,
+#ifdef __cplusplus
+    inline void foo();
+#endif
+};
+
+pub const MyFancyEnum_Tag = enum(c_int) {
+  Foo,
+  Bar,
+  Baz,
+};
+
+pub const MyFancyEnum = extern struct {
+  tag: MyFancyEnum_Tag,
+  payload: extern union {
+    bar: extern struct {
+      bar: i32,
+    },
+    baz: extern struct {
+      baz: i32,
+    },
+  },
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+};
+
+pub const MyUnion = extern union {
+  f: f32,
+  u: u32,
+  int32_t extra_member;
+};
+
+pub const MyFancyStruct_Prepended = extern struct {
+#ifdef __cplusplus
+    inline void prepended_wohoo();
+#endif
+  i: i32,
+};
+
+pub const MyFancyEnum_Prepended_Tag = enum(c_int) {
+  Foo_Prepended,
+  Bar_Prepended,
+  Baz_Prepended,
+};
+
+pub const MyFancyEnum_Prepended = extern struct {
+#ifdef __cplusplus
+    inline void wohoo();
+#endif
+  tag: MyFancyEnum_Prepended_Tag,
+  payload: extern union {
+    bar_prepended: extern struct {
+      bar_prepended: i32,
+    },
+    baz_prepended: extern struct {
+      baz_prepended: i32,
+    },
+  },
+};
+
+pub const MyUnion_Prepended = extern union {
+    int32_t extra_member;
+  f: f32,
+  u: u32,
+};
+
+pub extern fn root(s: MyFancyStruct,
+                   e: MyFancyEnum,
+                   c: MyCLikeEnum,
+                   u: MyUnion,
+                   sp: MyFancyStruct_Prepended,
+                   ep: MyFancyEnum_Prepended,
+                   cp: MyCLikeEnum_Prepended,
+                   up: MyUnion_Prepended) void;
@@ -0,0 +1,15 @@
+typedef struct MyStruct MyStruct;
+
+typedef struct NotReprC_____i32 NotReprC_____i32;
+
+typedef NotReprC_____i32 Foo;
+
+struct MyStruct {
+  int32_t *number;
+};
+
+void root(const Foo *a, const MyStruct *with_box);
+
+void drop_box(int32_t *x);
+
+void drop_box_opt(int32_t *x);
@@ -0,0 +1,35 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+pub struct NotReprC_____i32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+pub type Foo = NotReprC_____i32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyStruct {
+  pub number: *mut i32,
+}
+
+extern "C" {
+  pub fn root(a: *const Foo, with_box: *const MyStruct);
+
+  pub fn drop_box(x: *mut i32);
+
+  pub fn drop_box_opt(x: *mut i32);
+}
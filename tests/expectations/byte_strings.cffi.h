@@ -0,0 +1 @@
+void read_chunk(const uint8_t *id, uintptr_t len);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define GREETING "hello"
+
+#define PATH "C:\\temp\n"
+
+/**
+ * The magic number of RIFF files.
+ */
+#define MAGIC "RIFF"
+
+#define SIGNATURE "\211PNG\r\n\032\n"
+
+static const uint8_t FORMAT[4] = { 87, 65, 86, 69, };
+
+#define QUOTED "say \"hi\""
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void read_chunk(const uint8_t *id, uintptr_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
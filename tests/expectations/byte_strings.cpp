@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <string_view>
+
+constexpr static const std::string_view GREETING = "hello";
+
+constexpr static const std::string_view PATH = "C:\\temp\n";
+
+/// The magic number of RIFF files.
+constexpr static const uint8_t MAGIC[4] = { 82, 73, 70, 70, };
+
+constexpr static const uint8_t SIGNATURE[8] = { 137, 80, 78, 71, 13, 10, 26, 10, };
+
+constexpr static const uint8_t FORMAT[4] = { 87, 65, 86, 69, };
+
+constexpr static const uint8_t QUOTED[8] = { 115, 97, 121, 32, 34, 104, 105, 34, };
+
+extern "C" {
+
+void read_chunk(const uint8_t *id, uintptr_t len);
+
+}  // extern "C"
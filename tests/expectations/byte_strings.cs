@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly byte[] GREETING = new byte[] { 104, 101, 108, 108, 111, 0, };
+
+  public static readonly byte[] PATH = new byte[] { 67, 58, 92, 116, 101, 109, 112, 10, 0, };
+
+  /// <summary>
+  /// The magic number of RIFF files.
+  /// </summary>
+  public static readonly byte[] MAGIC = new byte[] { 82, 73, 70, 70, };
+
+  public static readonly byte[] SIGNATURE = new byte[] { 137, 80, 78, 71, 13, 10, 26, 10, };
+
+  public static readonly byte[] FORMAT = new byte[] { 87, 65, 86, 69, };
+
+  public static readonly byte[] QUOTED = new byte[] { 115, 97, 121, 32, 34, 104, 105, 34, };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void read_chunk(byte* id, nuint len);
+}
@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const char *GREETING # = b"hello"
+
+  const char *PATH # = b"C:\\temp\n"
+
+  # The magic number of RIFF files.
+  const char *MAGIC # = b"RIFF"
+
+  const char *SIGNATURE # = b"\x89PNG\r\n\x1a\n"
+
+  const uint8_t FORMAT[4] # = b"WAVE"
+
+  const char *QUOTED # = b"say \"hi\""
+
+  void read_chunk(const uint8_t *id, uintptr_t len);
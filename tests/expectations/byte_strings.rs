@@ -0,0 +1,16 @@
+pub const GREETING: *const ::core::ffi::c_char = (c"hello".as_ptr() as *const ::core::ffi::c_char);
+
+pub const PATH: *const ::core::ffi::c_char = (c"C:\\temp\n".as_ptr() as *const ::core::ffi::c_char);
+
+/// The magic number of RIFF files.
+pub const MAGIC: *const [u8; 4] = (b"RIFF" as *const [u8; 4]);
+
+pub const SIGNATURE: *const [u8; 8] = (b"\x89PNG\r\n\x1a\n" as *const [u8; 8]);
+
+pub const FORMAT: [u8; 4] = *b"WAVE";
+
+pub const QUOTED: *const [u8; 8] = (b"say \"hi\"" as *const [u8; 8]);
+
+extern "C" {
+  pub fn read_chunk(id: *const u8, len: usize);
+}
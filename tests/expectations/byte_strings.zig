@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const GREETING: [*:0]const u8 = "hello";
+
+pub const PATH: [*:0]const u8 = "C:\\temp\n";
+
+/// The magic number of RIFF files.
+pub const MAGIC: *const [4]u8 = "RIFF";
+
+pub const SIGNATURE: *const [8]u8 = "\x89PNG\r\n\x1a\n";
+
+pub const FORMAT: [4]u8 = "WAVE".*;
+
+pub const QUOTED: *const [8]u8 = "say \"hi\"";
+
+pub extern fn read_chunk(id: ?*const u8, len: usize) void;
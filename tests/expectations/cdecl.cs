@@ -0,0 +1,26 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern delegate* unmanaged[Cdecl]<void> O();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(delegate* unmanaged[Cdecl]<void> a,
+                                 delegate* unmanaged[Cdecl]<void> b,
+                                 delegate* unmanaged[Cdecl]<int, int, byte> c,
+                                 delegate* unmanaged[Cdecl]<int, delegate* unmanaged[Cdecl]<float, byte>> d,
+                                 delegate* unmanaged[Cdecl]<int*> e,
+                                 int* f,
+                                 int** g,
+                                 int** h,
+                                 int* i,
+                                 delegate* unmanaged[Cdecl]<float, double>* j,
+                                 int* k,
+                                 int** l,
+                                 delegate* unmanaged[Cdecl]<int, int, byte>* m,
+                                 delegate* unmanaged[Cdecl]<int, int, void>* n,
+                                 delegate* unmanaged[Cdecl]<int, byte, byte, int, void> p);
+}
@@ -0,0 +1,51 @@
+pub type A = unsafe extern "C" fn();
+
+pub type B = unsafe extern "C" fn();
+
+pub type C = unsafe extern "C" fn(i32, i32) -> bool;
+
+pub type D = unsafe extern "C" fn(i32) -> unsafe extern "C" fn(f32) -> bool;
+
+pub type E = unsafe extern "C" fn() -> *const [i32; 16];
+
+pub type F = *const i32;
+
+pub type G = *const *const i32;
+
+pub type H = *const *mut i32;
+
+pub type I = *const [i32; 16];
+
+pub type J = *const unsafe extern "C" fn(f32) -> f64;
+
+pub type K = [i32; 16];
+
+pub type L = [*const i32; 16];
+
+pub type M = [unsafe extern "C" fn(i32, i32) -> bool; 16];
+
+pub type N = [unsafe extern "C" fn(i32, i32); 16];
+
+pub type P = unsafe extern "C" fn(named1st: i32, bool, named3rd: bool, _: i32);
+
+extern "C" {
+  pub fn O() -> unsafe extern "C" fn();
+
+  pub fn root(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G,
+    h: H,
+    i: I,
+    j: J,
+    k: K,
+    l: L,
+    m: M,
+    n: N,
+    p: P,
+  );
+}
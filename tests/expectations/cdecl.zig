@@ -0,0 +1,49 @@
+const std = @import("std");
+
+pub const A = *const fn () callconv(.c) void;
+
+pub const B = *const fn () callconv(.c) void;
+
+pub const C = *const fn (i32, i32) callconv(.c) bool;
+
+pub const D = *const fn (i32) callconv(.c) *const fn (f32) callconv(.c) bool;
+
+pub const E = *const fn () callconv(.c) ?*const [16]i32;
+
+pub const F = ?*const i32;
+
+pub const G = ?*const ?*const i32;
+
+pub const H = ?*const ?*i32;
+
+pub const I = ?*const [16]i32;
+
+pub const J = ?*const *const fn (f32) callconv(.c) f64;
+
+pub const K = [16]i32;
+
+pub const L = [16]?*const i32;
+
+pub const M = [16]*const fn (i32, i32) callconv(.c) bool;
+
+pub const N = [16]*const fn (i32, i32) callconv(.c) void;
+
+pub const P = *const fn (named1st: i32, bool, named3rd: bool, _: i32) callconv(.c) void;
+
+pub extern fn O() *const fn () callconv(.c) void;
+
+pub extern fn root(a: A,
+                   b: B,
+                   c: C,
+                   d: D,
+                   e: E,
+                   f: F,
+                   g: G,
+                   h: H,
+                   i: I,
+                   j: J,
+                   k: K,
+                   l: L,
+                   m: M,
+                   n: N,
+                   p: P) void;
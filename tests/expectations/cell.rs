@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct NotReprC_RefCell_i32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+pub type Foo = NotReprC_RefCell_i32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyStruct {
+  pub number: i32,
+}
+
+extern "C" {
+  pub fn root(a: *const Foo, with_cell: *const MyStruct);
+}
@@ -0,0 +1,30 @@
+typedef struct Flags Flags;
+typedef union C C;
+typedef struct ConditionalField ConditionalField;
+typedef struct Normal Normal;
+
+struct Flags {
+  uint8_t _0;
+};
+
+enum C_Tag {
+  C1,
+  C2,
+};
+typedef uint8_t C_Tag;
+
+union C {
+  C_Tag tag;
+
+};
+
+struct ConditionalField {
+
+};
+
+struct Normal {
+  int32_t x;
+  float y;
+};
+
+void cond(ConditionalField a);
@@ -0,0 +1,151 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+DEF X11 = 0
+DEF M_32 = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+using System;
+using System.Runtime.InteropServices;
+
+#if (PLATFORM_UNIX && X11)
+public enum FooType : uint {
+  A,
+  B,
+  C,
+}
+#endif
+
+#if (PLATFORM_WIN || M_32)
+public enum BarType : uint {
+  A,
+  B,
+  C,
+}
+#endif
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Flags {
+  public byte _0;
+}
+
+#if (PLATFORM_UNIX && X11)
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FooHandle {
+  public FooType ty;
+  public Flags flags;
+  public int x;
+  public float y;
+}
+#endif
+
+public enum C_Tag : byte {
+  C1,
+  C2,
+#if PLATFORM_WIN
+  C3,
+#endif
+#if PLATFORM_UNIX
+  C5,
+#endif
+}
+
+#if PLATFORM_UNIX
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct C5_Body {
+  public C_Tag tag;
+  public int int_;
+}
+#endif
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct C {
+  [FieldOffset(0)] public C_Tag tag;
+#if PLATFORM_UNIX
+  [FieldOffset(0)] public C5_Body c5;
+#endif
+}
+
+#if (PLATFORM_WIN || M_32)
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct BarHandle {
+  public BarType ty;
+  public int x;
+  public float y;
+}
+#endif
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ConditionalField {
+#if X11
+  public int field;
+#endif
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Normal {
+  public int x;
+  public float y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// none
+  /// </summary>
+  public static readonly Flags Flags_NONE = new Flags { _0 = unchecked((byte)(0)) };
+
+#if PLATFORM_WIN
+  public static readonly Flags Flags_A = new Flags { _0 = unchecked((byte)((1 << 0))) };
+#endif
+
+#if PLATFORM_UNIX
+  public static readonly Flags Flags_A = new Flags { _0 = unchecked((byte)((1 << 1))) };
+#endif
+
+#if PLATFORM_WIN
+  public static readonly Flags Flags_B = new Flags { _0 = unchecked((byte)(((NativeMethods.Flags_A)._0 | (1 << 3)))) };
+#endif
+
+#if PLATFORM_UNIX
+  public static readonly Flags Flags_B = new Flags { _0 = unchecked((byte)(((NativeMethods.Flags_A)._0 | (1 << 4)))) };
+#endif
+
+  public static readonly ConditionalField ConditionalField_ZERO = new ConditionalField { field = 0 };
+
+  public static readonly ConditionalField ConditionalField_ONE = new ConditionalField { field = 1 };
+
+#if PLATFORM_WIN
+  // `global_array_with_different_sizes` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+#endif
+
+#if PLATFORM_UNIX
+  // `global_array_with_different_sizes` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+#endif
+
+#if (PLATFORM_UNIX && X11)
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FooHandle a, C c);
+#endif
+
+#if (PLATFORM_WIN || M_32)
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(BarHandle a, C c);
+#endif
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void cond(ConditionalField a);
+
+#if PLATFORM_WIN
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int foo();
+#endif
+
+#if PLATFORM_WIN
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(Normal a);
+#endif
+}
@@ -0,0 +1,119 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+DEF X11 = 0
+DEF M_32 = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+#[cfg(all(unix, x11))]
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum FooType {
+  A,
+  B,
+  C,
+}
+
+#[cfg(any(windows, target_pointer_width = "32"))]
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum BarType {
+  A,
+  B,
+  C,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Flags {
+  pub _0: u8,
+}
+
+/// none
+pub const Flags_NONE: Flags = Flags { _0: 0 };
+
+#[cfg(windows)]
+pub const Flags_A: Flags = Flags { _0: 1 };
+
+#[cfg(unix)]
+pub const Flags_A: Flags = Flags { _0: 2 };
+
+#[cfg(windows)]
+pub const Flags_B: Flags = Flags { _0: (((Flags_A)._0 | (1 << 3)) as u8) };
+
+#[cfg(unix)]
+pub const Flags_B: Flags = Flags { _0: (((Flags_A)._0 | (1 << 4)) as u8) };
+
+#[cfg(all(unix, x11))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FooHandle {
+  pub ty: FooType,
+  pub flags: Flags,
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum C {
+  C1,
+  C2,
+  #[cfg(windows)]
+  C3,
+  #[cfg(unix)]
+  C5 {
+    int_: i32,
+  },
+}
+
+#[cfg(any(windows, target_pointer_width = "32"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BarHandle {
+  pub ty: BarType,
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ConditionalField {
+  #[cfg(x11)]
+  pub field: i32,
+}
+
+pub const ConditionalField_ZERO: ConditionalField = ConditionalField { #[cfg(x11)] field: 0 };
+
+pub const ConditionalField_ONE: ConditionalField = ConditionalField { #[cfg(x11)] field: 1 };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Normal {
+  pub x: i32,
+  pub y: f32,
+}
+
+extern "C" {
+  #[cfg(windows)]
+  pub static mut global_array_with_different_sizes: [i32; 2];
+
+  #[cfg(unix)]
+  pub static mut global_array_with_different_sizes: [i32; 1];
+
+  #[cfg(all(unix, x11))]
+  pub fn root(a: FooHandle, c: C);
+
+  #[cfg(any(windows, target_pointer_width = "32"))]
+  pub fn root(a: BarHandle, c: C);
+
+  pub fn cond(a: ConditionalField);
+
+  #[cfg(windows)]
+  pub fn foo() -> i32;
+
+  #[cfg(windows)]
+  pub fn bar(a: Normal);
+}
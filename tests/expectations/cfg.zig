@@ -0,0 +1,125 @@
+#if 0
+DEF PLATFORM_UNIX = 0
+DEF PLATFORM_WIN = 0
+DEF X11 = 0
+DEF M_32 = 0
+#endif
+#define PLATFORM_UNIX 1
+
+
+const std = @import("std");
+
+// #if (defined(PLATFORM_UNIX) && defined(X11))
+pub const FooType = enum(u32) {
+  A,
+  B,
+  C,
+};
+// #endif
+
+// #if (defined(PLATFORM_WIN) || defined(M_32))
+pub const BarType = enum(u32) {
+  A,
+  B,
+  C,
+};
+// #endif
+
+pub const Flags = extern struct {
+  _0: u8,
+};
+/// none
+pub const Flags_NONE: Flags = Flags{ ._0 = @as(u8, 0) };
+// #if defined(PLATFORM_WIN)
+pub const Flags_A: Flags = Flags{ ._0 = @as(u8, (1 << 0)) };
+// #endif
+// #if defined(PLATFORM_UNIX)
+pub const Flags_A: Flags = Flags{ ._0 = @as(u8, (1 << 1)) };
+// #endif
+// #if defined(PLATFORM_WIN)
+pub const Flags_B: Flags = Flags{ ._0 = @as(u8, ((Flags_A)._0 | (1 << 3))) };
+// #endif
+// #if defined(PLATFORM_UNIX)
+pub const Flags_B: Flags = Flags{ ._0 = @as(u8, ((Flags_A)._0 | (1 << 4))) };
+// #endif
+
+// #if (defined(PLATFORM_UNIX) && defined(X11))
+pub const FooHandle = extern struct {
+  ty: FooType,
+  flags: Flags,
+  x: i32,
+  y: f32,
+};
+// #endif
+
+pub const C_Tag = enum(u8) {
+  C1,
+  C2,
+  // #if defined(PLATFORM_WIN)
+  C3,
+  // #endif
+  // #if defined(PLATFORM_UNIX)
+  C5,
+  // #endif
+};
+
+// #if defined(PLATFORM_UNIX)
+pub const C5_Body = extern struct {
+  tag: C_Tag,
+  int_: i32,
+};
+// #endif
+
+pub const C = extern union {
+  tag: C_Tag,
+  // #if defined(PLATFORM_UNIX)
+  c5: C5_Body,
+  // #endif
+};
+
+// #if (defined(PLATFORM_WIN) || defined(M_32))
+pub const BarHandle = extern struct {
+  ty: BarType,
+  x: i32,
+  y: f32,
+};
+// #endif
+
+pub const ConditionalField = extern struct {
+  // #if defined(X11)
+  field: i32,
+  // #endif
+};
+pub const ConditionalField_ZERO: ConditionalField = ConditionalField{ .field = 0 };
+pub const ConditionalField_ONE: ConditionalField = ConditionalField{ .field = 1 };
+
+pub const Normal = extern struct {
+  x: i32,
+  y: f32,
+};
+
+// #if defined(PLATFORM_WIN)
+pub extern var global_array_with_different_sizes: [2]i32;
+// #endif
+
+// #if defined(PLATFORM_UNIX)
+pub extern var global_array_with_different_sizes: [1]i32;
+// #endif
+
+// #if (defined(PLATFORM_UNIX) && defined(X11))
+pub extern fn root(a: FooHandle, c: C) void;
+// #endif
+
+// #if (defined(PLATFORM_WIN) || defined(M_32))
+pub extern fn root(a: BarHandle, c: C) void;
+// #endif
+
+pub extern fn cond(a: ConditionalField) void;
+
+// #if defined(PLATFORM_WIN)
+pub extern fn foo() i32;
+// #endif
+
+// #if defined(PLATFORM_WIN)
+pub extern fn bar(a: Normal) void;
+// #endif
@@ -0,0 +1,19 @@
+#define DEFAULT_X 42
+
+typedef struct Foo Foo;
+typedef struct Bar Bar;
+typedef struct Root Root;
+
+struct Foo {
+  int32_t x;
+};
+
+struct Bar {
+  Foo z;
+};
+
+struct Root {
+  Bar w;
+};
+
+void root(Root a);
@@ -0,0 +1,42 @@
+#if 0
+DEF DEFINED = 1
+DEF NOT_DEFINED = 0
+#endif
+
+
+#[cfg(windows)]
+pub const DEFAULT_X: i32 = 8;
+
+#[cfg(unix)]
+pub const DEFAULT_X: i32 = 42;
+
+#[cfg(any(windows, unix))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: i32,
+}
+
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar {
+  pub y: Foo,
+}
+
+#[cfg(unix)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar {
+  pub z: Foo,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Root {
+  pub w: Bar,
+}
+
+extern "C" {
+  pub fn root(a: Root);
+}
@@ -0,0 +1,7 @@
+typedef struct Foo Foo;
+
+struct Foo {
+  uint32_t a;
+};
+
+void root(Foo a);
@@ -0,0 +1,9 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub a: char,
+}
+
+extern "C" {
+  pub fn root(a: Foo);
+}
@@ -0,0 +1,3 @@
+void foo(void);
+
+void bar(void);
@@ -0,0 +1,5 @@
+extern "C" {
+  pub fn foo();
+
+  pub fn bar();
+}
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern fn foo() void;
+
+pub extern fn bar() void;
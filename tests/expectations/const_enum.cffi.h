@@ -0,0 +1,13 @@
+typedef struct Style Style;
+
+enum FillRule {
+  Nonzero,
+  Evenodd,
+};
+typedef uint8_t FillRule;
+
+struct Style {
+  FillRule rule;
+};
+
+void root(FillRule rule, Style style);
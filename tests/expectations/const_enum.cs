@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum FillRule : byte {
+  Nonzero,
+  Evenodd,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Style {
+  public FillRule rule;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly FillRule Style_DEFAULT_RULE = FillRule.Nonzero;
+
+  public static readonly FillRule[] Style_ALL_RULES = new FillRule[] { FillRule.Nonzero, FillRule.Evenodd, };
+
+  public static readonly FillRule DEFAULT_FILL_RULE = FillRule.Nonzero;
+
+  public static readonly FillRule[] ALL_FILL_RULES = new FillRule[] { FillRule.Nonzero, FillRule.Evenodd, };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FillRule rule, Style style);
+}
@@ -0,0 +1,24 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum FillRule {
+  Nonzero,
+  Evenodd,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Style {
+  pub rule: FillRule,
+}
+
+pub const Style_DEFAULT_RULE: FillRule = FillRule::Nonzero;
+
+pub const Style_ALL_RULES: [FillRule; 2] = [FillRule::Nonzero, FillRule::Evenodd];
+
+pub const DEFAULT_FILL_RULE: FillRule = FillRule::Nonzero;
+
+pub const ALL_FILL_RULES: [FillRule; 2] = [FillRule::Nonzero, FillRule::Evenodd];
+
+extern "C" {
+  pub fn root(rule: FillRule, style: Style);
+}
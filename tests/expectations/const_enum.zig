@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const FillRule = enum(u8) {
+  Nonzero,
+  Evenodd,
+};
+
+pub const Style = extern struct {
+  rule: FillRule,
+};
+pub const Style_DEFAULT_RULE: FillRule = FillRule.Nonzero;
+pub const Style_ALL_RULES: [2]FillRule = .{ FillRule.Nonzero, FillRule.Evenodd, };
+
+pub const DEFAULT_FILL_RULE: FillRule = FillRule.Nonzero;
+
+pub const ALL_FILL_RULES: [2]FillRule = .{ FillRule.Nonzero, FillRule.Evenodd, };
+
+pub extern fn root(rule: FillRule, style: Style) void;
@@ -0,0 +1,38 @@
+#define PAYLOAD_LEN 12
+
+#define BUFFER_SIZE 4096
+
+#define ALIGNED 16
+
+#define LARGEST 3000
+
+#define WORD_BITS 32
+
+#define WORD_SIZE 8
+
+#define HALF_MAX 32767
+
+#define HIDDEN 8
+
+#define FLAG 8
+
+#define ENABLED 1
+
+typedef struct Packet Packet;
+
+enum Size {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = 1048576,
+};
+typedef uint32_t Size;
+
+struct Packet {
+  uint8_t header[4];
+  uint8_t payload[PAYLOAD_LEN];
+  uint8_t trailer[8];
+  uint8_t padding[11];
+};
+
+void send(const Packet *packet, Size size);
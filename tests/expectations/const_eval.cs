@@ -0,0 +1,48 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Size : uint {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = (1 << 20),
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Packet {
+  public fixed byte header[4];
+  public fixed byte payload[NativeMethods.PAYLOAD_LEN];
+  public fixed byte trailer[8];
+  public fixed byte padding[11];
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const nuint PAYLOAD_LEN = 12;
+
+  public const uint BUFFER_SIZE = 4096;
+
+  public const nuint ALIGNED = 16;
+
+  public const uint LARGEST = 3000;
+
+  public const uint WORD_BITS = 32;
+
+  public const nuint WORD_SIZE = 8;
+
+  public const ushort HALF_MAX = unchecked((ushort)((ushort.MaxValue / 2)));
+
+  public const float SCALE = (float)(1.0 / (float)(NativeMethods.WORD_SIZE));
+
+  public const double THRESHOLD = ((double)(NativeMethods.PAYLOAD_LEN) * 0.5);
+
+  public const nuint HIDDEN = 8;
+
+  public const byte FLAG = unchecked((byte)((1 << 3)));
+
+  public const bool ENABLED = true;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void send(Packet* packet, Size size);
+}
@@ -0,0 +1,46 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uintptr_t PAYLOAD_LEN # = 12
+
+  const uint32_t BUFFER_SIZE # = 4096
+
+  const uintptr_t ALIGNED # = 16
+
+  const uint32_t LARGEST # = 3000
+
+  const uint32_t WORD_BITS # = 32
+
+  const uintptr_t WORD_SIZE # = 8
+
+  const uint16_t HALF_MAX # = (UINT16_MAX / 2)
+
+  const float SCALE # = (1.0 / <float>WORD_SIZE)
+
+  const double THRESHOLD # = (<double>PAYLOAD_LEN * 0.5)
+
+  const uintptr_t HIDDEN # = 8
+
+  const uint8_t FLAG # = (1 << 3)
+
+  const bool ENABLED # = True
+
+  cdef enum:
+    Small # = 1024,
+    Medium # = 4100,
+    Large,
+    Huge # = (1 << 20),
+  ctypedef uint32_t Size;
+
+  ctypedef struct Packet:
+    uint8_t header[4];
+    uint8_t payload[PAYLOAD_LEN];
+    uint8_t trailer[8];
+    uint8_t padding[11];
+
+  void send(const Packet *packet, Size size);
@@ -0,0 +1,45 @@
+pub const PAYLOAD_LEN: usize = 12;
+
+pub const BUFFER_SIZE: u32 = 4096;
+
+pub const ALIGNED: usize = 16;
+
+pub const LARGEST: u32 = 3000;
+
+pub const WORD_BITS: u32 = 32;
+
+pub const WORD_SIZE: usize = 8;
+
+pub const HALF_MAX: u16 = 32767;
+
+pub const SCALE: f32 = ((1.0 / (WORD_SIZE as f32)) as f32);
+
+pub const THRESHOLD: f64 = (((PAYLOAD_LEN as f64) * 0.5) as f64);
+
+pub const HIDDEN: usize = 8;
+
+pub const FLAG: u8 = 8;
+
+pub const ENABLED: bool = true;
+
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum Size {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = (1 << 20),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Packet {
+  pub header: [u8; 4],
+  pub payload: [u8; PAYLOAD_LEN as usize],
+  pub trailer: [u8; 8],
+  pub padding: [u8; 11],
+}
+
+extern "C" {
+  pub fn send(packet: *const Packet, size: Size);
+}
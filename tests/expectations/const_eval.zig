@@ -0,0 +1,41 @@
+const std = @import("std");
+
+pub const PAYLOAD_LEN: usize = 12;
+
+pub const BUFFER_SIZE: u32 = 4096;
+
+pub const ALIGNED: usize = 16;
+
+pub const LARGEST: u32 = 3000;
+
+pub const WORD_BITS: u32 = 32;
+
+pub const WORD_SIZE: usize = 8;
+
+pub const HALF_MAX: u16 = (std.math.maxInt(u16) / 2);
+
+pub const SCALE: f32 = (1.0 / @as(f32, @floatFromInt(WORD_SIZE)));
+
+pub const THRESHOLD: f64 = (@as(f64, @floatFromInt(PAYLOAD_LEN)) * 0.5);
+
+pub const HIDDEN: usize = 8;
+
+pub const FLAG: u8 = (1 << 3);
+
+pub const ENABLED: bool = true;
+
+pub const Size = enum(u32) {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = (1 << 20),
+};
+
+pub const Packet = extern struct {
+  header: [4]u8,
+  payload: [PAYLOAD_LEN]u8,
+  trailer: [8]u8,
+  padding: [11]u8,
+};
+
+pub extern fn send(packet: *const Packet, size: Size) void;
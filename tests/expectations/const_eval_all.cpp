@@ -0,0 +1,49 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uintptr_t PAYLOAD_LEN = 12;
+
+constexpr static const uint32_t BUFFER_SIZE = 4096;
+
+constexpr static const uintptr_t ALIGNED = 16;
+
+constexpr static const uint32_t LARGEST = 3000;
+
+constexpr static const uint32_t WORD_BITS = 32;
+
+constexpr static const uintptr_t WORD_SIZE = 8;
+
+constexpr static const uint16_t HALF_MAX = 32767;
+
+constexpr static const float SCALE = 0.125;
+
+constexpr static const double THRESHOLD = 6.0;
+
+constexpr static const uintptr_t HIDDEN = 8;
+
+constexpr static const uint8_t FLAG = 8;
+
+constexpr static const bool ENABLED = true;
+
+enum class Size : uint32_t {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = 1048576,
+};
+
+struct Packet {
+  uint8_t header[4];
+  uint8_t payload[PAYLOAD_LEN];
+  uint8_t trailer[8];
+  uint8_t padding[11];
+};
+
+extern "C" {
+
+void send(const Packet *packet, Size size);
+
+}  // extern "C"
@@ -0,0 +1,41 @@
+const std = @import("std");
+
+pub const PAYLOAD_LEN: usize = 12;
+
+pub const BUFFER_SIZE: u32 = 4096;
+
+pub const ALIGNED: usize = 16;
+
+pub const LARGEST: u32 = 3000;
+
+pub const WORD_BITS: u32 = 32;
+
+pub const WORD_SIZE: usize = 8;
+
+pub const HALF_MAX: u16 = 32767;
+
+pub const SCALE: f32 = 0.125;
+
+pub const THRESHOLD: f64 = 6.0;
+
+pub const HIDDEN: usize = 8;
+
+pub const FLAG: u8 = 8;
+
+pub const ENABLED: bool = true;
+
+pub const Size = enum(u32) {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = 1048576,
+};
+
+pub const Packet = extern struct {
+  header: [4]u8,
+  payload: [PAYLOAD_LEN]u8,
+  trailer: [8]u8,
+  padding: [11]u8,
+};
+
+pub extern fn send(packet: *const Packet, size: Size) void;
@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define PAYLOAD_LEN 12
+
+#define BUFFER_SIZE 4096
+
+#define ALIGNED 16
+
+#define LARGEST 3000
+
+#define WORD_BITS 32
+
+#define WORD_SIZE 8
+
+#define HALF_MAX 32767
+
+#define SCALE 0.125
+
+#define THRESHOLD 6.0
+
+#define HIDDEN 8
+
+#define FLAG 8
+
+#define ENABLED true
+
+enum Size
+#if __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Small = 1024,
+  Medium = 4100,
+  Large,
+  Huge = 1048576,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Size Size;
+#else
+typedef uint32_t Size;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct Packet {
+  uint8_t header[4];
+  uint8_t payload[PAYLOAD_LEN];
+  uint8_t trailer[8];
+  uint8_t padding[11];
+} Packet;
+
+void send(const struct Packet *packet, Size size);
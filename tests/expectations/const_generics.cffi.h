@@ -0,0 +1,14 @@
+#define TITLE_SIZE 80
+
+typedef struct Book Book;
+
+typedef int8_t CArrayString_TITLE_SIZE[TITLE_SIZE];
+
+typedef int8_t CArrayString_40[40];
+
+struct Book {
+  CArrayString_TITLE_SIZE title;
+  CArrayString_40 author;
+};
+
+void root(Book *a);
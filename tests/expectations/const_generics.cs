@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Book {
+  public fixed sbyte title[NativeMethods.TITLE_SIZE];
+  public fixed sbyte author[40];
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const nuint TITLE_SIZE = 80;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Book* a);
+}
@@ -0,0 +1,16 @@
+pub const TITLE_SIZE: usize = 80;
+
+pub type CArrayString_TITLE_SIZE = [i8; TITLE_SIZE as usize];
+
+pub type CArrayString_40 = [i8; 40];
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Book {
+  pub title: CArrayString_TITLE_SIZE,
+  pub author: CArrayString_40,
+}
+
+extern "C" {
+  pub fn root(a: *mut Book);
+}
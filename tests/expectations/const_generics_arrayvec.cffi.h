@@ -0,0 +1,8 @@
+typedef struct ArrayVec_____u8__100 ArrayVec_____u8__100;
+
+struct ArrayVec_____u8__100 {
+  uint8_t *xs[100];
+  uint32_t len;
+};
+
+int32_t push(ArrayVec_____u8__100 *v, uint8_t *elem);
@@ -0,0 +1,10 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArrayVec_____u8__100 {
+  pub xs: [*mut u8; 100],
+  pub len: u32,
+}
+
+extern "C" {
+  pub fn push(v: *mut ArrayVec_____u8__100, elem: *mut u8) -> i32;
+}
@@ -0,0 +1,37 @@
+pub type Str = *const ::core::ffi::c_char;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct HashTable_Str__c_char__false {
+  pub num_buckets: usize,
+  pub capacity: usize,
+  pub occupied: *mut u8,
+  pub keys: *mut Str,
+  pub vals: *mut ::core::ffi::c_char,
+}
+
+pub type MySet = HashTable_Str__c_char__false;
+
+pub type SetCallback = unsafe extern "C" fn(key: Str);
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct HashTable_Str__u64__true {
+  pub num_buckets: usize,
+  pub capacity: usize,
+  pub occupied: *mut u8,
+  pub keys: *mut Str,
+  pub vals: *mut u64,
+}
+
+pub type MapCallback = unsafe extern "C" fn(key: Str, val: u64);
+
+extern "C" {
+  pub fn new_set() -> *mut MySet;
+
+  pub fn set_for_each(set: *const MySet, callback: SetCallback);
+
+  pub fn new_map() -> *mut HashTable_Str__u64__true;
+
+  pub fn map_for_each(map: *const HashTable_Str__u64__true, callback: MapCallback);
+}
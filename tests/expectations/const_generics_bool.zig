@@ -0,0 +1,33 @@
+const std = @import("std");
+
+pub const Str = ?*const c_char;
+
+pub const HashTable_Str__c_char__false = extern struct {
+  num_buckets: usize,
+  capacity: usize,
+  occupied: ?*u8,
+  keys: ?*Str,
+  vals: ?*c_char,
+};
+
+pub const MySet = HashTable_Str__c_char__false;
+
+pub const SetCallback = *const fn (key: Str) callconv(.c) void;
+
+pub const HashTable_Str__u64__true = extern struct {
+  num_buckets: usize,
+  capacity: usize,
+  occupied: ?*u8,
+  keys: ?*Str,
+  vals: ?*u64,
+};
+
+pub const MapCallback = *const fn (key: Str, val: u64) callconv(.c) void;
+
+pub extern fn new_set() ?*MySet;
+
+pub extern fn set_for_each(set: ?*const MySet, callback: SetCallback) void;
+
+pub extern fn new_map() ?*HashTable_Str__u64__true;
+
+pub extern fn map_for_each(map: ?*const HashTable_Str__u64__true, callback: MapCallback) void;
@@ -0,0 +1,18 @@
+typedef struct Parser_40__41 Parser_40__41;
+typedef struct Parser_123__125 Parser_123__125;
+
+struct Parser_40__41 {
+  uint8_t *buf;
+  uintptr_t len;
+};
+
+struct Parser_123__125 {
+  uint8_t *buf;
+  uintptr_t len;
+};
+
+void init_parens_parser(Parser_40__41 *p, uint8_t *buf, uintptr_t len);
+
+void destroy_parens_parser(Parser_40__41 *p);
+
+void init_braces_parser(Parser_123__125 *p, uint8_t *buf, uintptr_t len);
@@ -0,0 +1,21 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Parser_40__41 {
+  pub buf: *mut u8,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Parser_123__125 {
+  pub buf: *mut u8,
+  pub len: usize,
+}
+
+extern "C" {
+  pub fn init_parens_parser(p: *mut Parser_40__41, buf: *mut u8, len: usize);
+
+  pub fn destroy_parens_parser(p: *mut Parser_40__41);
+
+  pub fn init_braces_parser(p: *mut Parser_123__125, buf: *mut u8, len: usize);
+}
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const Parser_40__41 = extern struct {
+  buf: ?*u8,
+  len: usize,
+};
+
+pub const Parser_123__125 = extern struct {
+  buf: ?*u8,
+  len: usize,
+};
+
+pub extern fn init_parens_parser(p: ?*Parser_40__41, buf: ?*u8, len: usize) void;
+
+pub extern fn destroy_parens_parser(p: ?*Parser_40__41) void;
+
+pub extern fn init_braces_parser(p: ?*Parser_123__125, buf: ?*u8, len: usize) void;
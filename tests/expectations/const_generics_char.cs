@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TakeUntil_0 {
+  public byte* start;
+  public nuint len;
+  public nuint point;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern TakeUntil_0 until_nul(byte* start, nuint len);
+}
@@ -0,0 +1,11 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TakeUntil_0 {
+  pub start: *const u8,
+  pub len: usize,
+  pub point: usize,
+}
+
+extern "C" {
+  pub fn until_nul(start: *const u8, len: usize) -> TakeUntil_0;
+}
@@ -0,0 +1,16 @@
+#define FONT_WEIGHT_FRACTION_BITS 6
+
+typedef struct FixedPoint_FONT_WEIGHT_FRACTION_BITS FixedPoint_FONT_WEIGHT_FRACTION_BITS;
+typedef struct FontWeight FontWeight;
+
+struct FixedPoint_FONT_WEIGHT_FRACTION_BITS {
+  uint16_t value;
+};
+
+typedef FixedPoint_FONT_WEIGHT_FRACTION_BITS FontWeightFixedPoint;
+
+struct FontWeight {
+  FontWeightFixedPoint _0;
+};
+
+void root(FontWeight w);
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FixedPoint_FONT_WEIGHT_FRACTION_BITS {
+  public ushort value;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FontWeight {
+  public FixedPoint_FONT_WEIGHT_FRACTION_BITS _0;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const ushort FONT_WEIGHT_FRACTION_BITS = 6;
+
+  public static readonly FontWeight FontWeight_NORMAL = new FontWeight { _0 = new FontWeightFixedPoint { value = (400 << (int)(NativeMethods.FONT_WEIGHT_FRACTION_BITS)) } };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FontWeight w);
+}
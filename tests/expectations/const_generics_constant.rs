@@ -0,0 +1,21 @@
+pub const FONT_WEIGHT_FRACTION_BITS: u16 = 6;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FixedPoint_FONT_WEIGHT_FRACTION_BITS {
+  pub value: u16,
+}
+
+pub type FontWeightFixedPoint = FixedPoint_FONT_WEIGHT_FRACTION_BITS;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FontWeight {
+  pub _0: FontWeightFixedPoint,
+}
+
+pub const FontWeight_NORMAL: FontWeight = FontWeight { _0: FontWeightFixedPoint { value: (400 << FONT_WEIGHT_FRACTION_BITS) } };
+
+extern "C" {
+  pub fn root(w: FontWeight);
+}
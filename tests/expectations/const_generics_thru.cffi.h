@@ -0,0 +1,24 @@
+typedef struct Inner_1 Inner_1;
+typedef struct Outer_1 Outer_1;
+typedef struct Inner_2 Inner_2;
+typedef struct Outer_2 Outer_2;
+
+struct Inner_1 {
+  uint8_t bytes[1];
+};
+
+struct Outer_1 {
+  Inner_1 inner;
+};
+
+struct Inner_2 {
+  uint8_t bytes[2];
+};
+
+struct Outer_2 {
+  Inner_2 inner;
+};
+
+Outer_1 one(void);
+
+Outer_2 two(void);
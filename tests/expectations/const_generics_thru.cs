@@ -0,0 +1,32 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Inner_1 {
+  public fixed byte bytes[1];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Outer_1 {
+  public Inner_1 inner;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Inner_2 {
+  public fixed byte bytes[2];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Outer_2 {
+  public Inner_2 inner;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Outer_1 one();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Outer_2 two();
+}
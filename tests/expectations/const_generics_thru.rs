@@ -0,0 +1,29 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Inner_1 {
+  pub bytes: [u8; 1],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Outer_1 {
+  pub inner: Inner_1,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Inner_2 {
+  pub bytes: [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Outer_2 {
+  pub inner: Inner_2,
+}
+
+extern "C" {
+  pub fn one() -> Outer_1;
+
+  pub fn two() -> Outer_2;
+}
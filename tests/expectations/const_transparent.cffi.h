@@ -0,0 +1,5 @@
+#define TransparentStruct_ASSOC_STRUCT_FOO 1
+
+typedef uint8_t TransparentStruct;
+
+typedef uint8_t TransparentTupleStruct;
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const long TransparentStruct_ASSOC_STRUCT_FOO = 1;
+
+  public static readonly byte TransparentStruct_ASSOC_STRUCT_BAR = 2;
+
+
+
+  public static readonly byte STRUCT_FOO = 4;
+
+  public static readonly byte STRUCT_BAR = 5;
+
+
+
+
+}
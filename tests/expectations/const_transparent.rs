@@ -0,0 +1,17 @@
+pub type TransparentStruct = u8;
+
+pub const TransparentStruct_ASSOC_STRUCT_FOO: i64 = 1;
+
+pub const TransparentStruct_ASSOC_STRUCT_BAR: TransparentStruct = 2;
+
+
+
+pub type TransparentTupleStruct = u8;
+
+pub const STRUCT_FOO: TransparentStruct = 4;
+
+pub const STRUCT_BAR: TransparentTupleStruct = 5;
+
+
+
+
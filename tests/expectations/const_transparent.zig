@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const TransparentStruct = u8;
+pub const TransparentStruct_ASSOC_STRUCT_FOO: i64 = 1;
+pub const TransparentStruct_ASSOC_STRUCT_BAR: TransparentStruct = 2;
+
+
+pub const TransparentTupleStruct = u8;
+
+pub const STRUCT_FOO: TransparentStruct = 4;
+
+pub const STRUCT_BAR: TransparentTupleStruct = 5;
+
+
+
+
@@ -0,0 +1,39 @@
+#define FOO 10
+
+#define DELIMITER 58
+
+#define LEFTCURLY 123
+
+#define QUOTE 39
+
+#define TAB 9
+
+#define NEWLINE 10
+
+#define HEART 10084
+
+#define EQUID 65667
+
+#define POS_ONE 1
+
+#define NEG_ONE -1
+
+#define SHIFT 3
+
+#define XBOOL 1
+
+#define XFALSE 1
+
+#define XTRUE 8
+
+#define CAST 65
+
+#define DOUBLE_CAST ...
+
+typedef struct Foo Foo;
+
+struct Foo {
+  int32_t x[FOO];
+};
+
+void root(Foo x);
@@ -0,0 +1,48 @@
+pub const FOO: i32 = 10;
+
+pub const DELIMITER: char = ':';
+
+pub const LEFTCURLY: char = '{';
+
+pub const QUOTE: char = '\'';
+
+pub const TAB: char = '\t';
+
+pub const NEWLINE: char = '\n';
+
+pub const HEART: char = '❤';
+
+pub const EQUID: char = '𐂃';
+
+pub const ZOM: f32 = 3.14;
+
+/// A single-line doc comment.
+pub const POS_ONE: i8 = 1;
+
+/// A
+/// multi-line
+/// doc
+/// comment.
+pub const NEG_ONE: i8 = -1;
+
+pub const SHIFT: i64 = 3;
+
+pub const XBOOL: i64 = 1;
+
+pub const XFALSE: i64 = 1;
+
+pub const XTRUE: i64 = 8;
+
+pub const CAST: u8 = 65;
+
+pub const DOUBLE_CAST: u32 = ((1.0 as u32) as u32);
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: [i32; FOO as usize],
+}
+
+extern "C" {
+  pub fn root(x: Foo);
+}
@@ -0,0 +1,46 @@
+const std = @import("std");
+
+pub const FOO: i32 = 10;
+
+pub const DELIMITER: u32 = ':';
+
+pub const LEFTCURLY: u32 = '{';
+
+pub const QUOTE: u32 = '\'';
+
+pub const TAB: u32 = '\t';
+
+pub const NEWLINE: u32 = '\n';
+
+pub const HEART: u32 = '\u{2764}';
+
+pub const EQUID: u32 = '\u{10083}';
+
+pub const ZOM: f32 = 3.14;
+
+/// A single-line doc comment.
+pub const POS_ONE: i8 = 1;
+
+/// A
+/// multi-line
+/// doc
+/// comment.
+pub const NEG_ONE: i8 = -1;
+
+pub const SHIFT: i64 = 3;
+
+pub const XBOOL: i64 = 1;
+
+pub const XFALSE: i64 = ((0 << SHIFT) | XBOOL);
+
+pub const XTRUE: i64 = (1 << (SHIFT | XBOOL));
+
+pub const CAST: u8 = @as(u8, 'A');
+
+pub const DOUBLE_CAST: u32 = @as(u32, @intFromFloat(@as(f32, 1)));
+
+pub const Foo = extern struct {
+  x: [FOO]i32,
+};
+
+pub extern fn root(x: Foo) void;
@@ -0,0 +1,7 @@
+#define UNSIGNED_NEEDS_ULL_SUFFIX 9223372036854775808
+
+#define UNSIGNED_DOESNT_NEED_ULL_SUFFIX 8070450532247928832
+
+#define SIGNED_NEEDS_ULL_SUFFIX -9223372036854775808
+
+#define SIGNED_DOESNT_NEED_ULL_SUFFIX -9223372036854775807
@@ -0,0 +1,13 @@
+#define CONSTANT_I64 216
+
+#define DELIMITER 58
+
+#define LEFTCURLY 123
+
+#define Foo_CONSTANT_I64_BODY 216
+
+typedef struct Foo Foo;
+
+struct Foo {
+  int32_t x;
+};
@@ -0,0 +1,7 @@
+#define A 0
+
+#define B 0
+
+const uint8_t C;
+
+const uint8_t D;
@@ -0,0 +1,14 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const byte A = 0;
+
+  public const byte B = 0;
+
+  // `C` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  // `D` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+}
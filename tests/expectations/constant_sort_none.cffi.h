@@ -0,0 +1,7 @@
+#define B 0
+
+#define A 0
+
+const uint8_t D;
+
+const uint8_t C;
@@ -0,0 +1,9 @@
+pub const B: u8 = 0;
+
+pub const A: u8 = 0;
+
+extern "C" {
+  pub static D: u8;
+
+  pub static C: u8;
+}
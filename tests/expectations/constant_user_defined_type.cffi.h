@@ -0,0 +1,11 @@
+typedef struct S S;
+
+typedef enum E {
+  V,
+} E;
+
+struct S {
+  uint8_t field;
+};
+
+typedef uint8_t A;
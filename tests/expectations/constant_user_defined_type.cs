@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum E {
+  V,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct S {
+  public byte field;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly S C1 = new S { field = 0 };
+
+  public static readonly E C2 = E.V;
+
+  public static readonly byte C3 = 0;
+}
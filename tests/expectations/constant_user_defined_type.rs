@@ -0,0 +1,19 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum E {
+  V,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct S {
+  pub field: u8,
+}
+
+pub type A = u8;
+
+pub const C1: S = S { field: 0 };
+
+pub const C2: E = E::V;
+
+pub const C3: A = 0;
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 0
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
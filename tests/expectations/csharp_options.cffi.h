@@ -0,0 +1,12 @@
+#define ORIGIN_ID 0
+
+typedef struct Point Point;
+
+struct Point {
+  float x;
+  float y;
+};
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 0
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
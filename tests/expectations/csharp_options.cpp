@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t ORIGIN_ID = 0;
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
+
+}  // extern "C"
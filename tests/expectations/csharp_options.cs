@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace Geometry {
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Point {
+    public float x;
+    public float y;
+  }
+
+  public static unsafe partial class Native {
+    private const string LibraryName = "geometry";
+
+    public const uint ORIGIN_ID = 0;
+
+    [LibraryImport(LibraryName)]
+    [UnmanagedCallConv(CallConvs = new[] { typeof(CallConvCdecl) })]
+    [return: MarshalAs(UnmanagedType.U1)]
+    public static partial bool point_is_origin(Point* p);
+
+    [LibraryImport(LibraryName)]
+    [UnmanagedCallConv(CallConvs = new[] { typeof(CallConvCdecl) })]
+    public static partial float point_length(Point p);
+  }
+}
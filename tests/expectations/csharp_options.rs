@@ -0,0 +1,14 @@
+pub const ORIGIN_ID: u32 = 0;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: f32,
+  pub y: f32,
+}
+
+extern "C" {
+  pub fn point_is_origin(p: *const Point) -> bool;
+
+  pub fn point_length(p: Point) -> f32;
+}
@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const ORIGIN_ID: u32 = 0;
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub extern fn point_is_origin(p: ?*const Point) bool;
+
+pub extern fn point_length(p: Point) f32;
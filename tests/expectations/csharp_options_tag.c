@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 0
+
+struct Point {
+  float x;
+  float y;
+};
+
+bool point_is_origin(const struct Point *p);
+
+float point_length(struct Point p);
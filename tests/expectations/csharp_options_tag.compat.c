@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 0
+
+struct Point {
+  float x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool point_is_origin(const struct Point *p);
+
+float point_length(struct Point p);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t ORIGIN_ID # = 0
+
+  cdef struct Point:
+    float x;
+    float y;
+
+  bool point_is_origin(const Point *p);
+
+  float point_length(Point p);
@@ -0,0 +1,10 @@
+typedef struct Entry Entry;
+
+struct Entry {
+  const char *name;
+  const char *description;
+};
+
+void set_name(Entry *entry, const char *name);
+
+const char *find(const char *name);
@@ -0,0 +1,30 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <string_view>
+
+constexpr static const std::string_view VERSION = "1.0.0";
+
+struct Entry {
+  const char *CBINDGEN_NONNULL name;
+  const char *CBINDGEN_NULLABLE description;
+};
+
+extern "C" {
+
+void set_name(Entry *CBINDGEN_NONNULL entry, const char *CBINDGEN_NONNULL name);
+
+const char *CBINDGEN_NULLABLE find(const char *CBINDGEN_NONNULL name);
+
+}  // extern "C"
@@ -0,0 +1,29 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Entry {
+  public byte* name;
+  public byte* description;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly byte[] VERSION = new byte[] { 49, 46, 48, 46, 48, 0, };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void set_name(Entry* entry, byte* name);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern byte* find(byte* name);
+}
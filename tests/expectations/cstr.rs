@@ -0,0 +1,23 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+pub const VERSION: *const ::core::ffi::c_char = (c"1.0.0".as_ptr() as *const ::core::ffi::c_char);
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Entry {
+  pub name: *const ::core::ffi::c_char,
+  pub description: *const ::core::ffi::c_char,
+}
+
+extern "C" {
+  pub fn set_name(entry: *mut Entry, name: *const ::core::ffi::c_char);
+
+  pub fn find(name: *const ::core::ffi::c_char) -> *const ::core::ffi::c_char;
+}
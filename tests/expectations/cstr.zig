@@ -0,0 +1,21 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+const std = @import("std");
+
+pub const VERSION: [*:0]const u8 = "1.0.0";
+
+pub const Entry = extern struct {
+  name: *const c_char,
+  description: ?*const c_char,
+};
+
+pub extern fn set_name(entry: *Entry, name: *const c_char) void;
+
+pub extern fn find(name: *const c_char) ?*const c_char;
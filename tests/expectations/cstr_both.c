@@ -0,0 +1,24 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define VERSION "1.0.0"
+
+typedef struct Entry {
+  const char *CBINDGEN_NONNULL name;
+  const char *CBINDGEN_NULLABLE description;
+} Entry;
+
+void set_name(struct Entry *CBINDGEN_NONNULL entry, const char *CBINDGEN_NONNULL name);
+
+const char *CBINDGEN_NULLABLE find(const char *CBINDGEN_NONNULL name);
@@ -0,0 +1 @@
+void root(void);
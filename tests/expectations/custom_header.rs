@@ -0,0 +1,12 @@
+#if 0
+# This file is generated by cbindgen. DO NOT EDIT
+#endif
+
+
+extern "C" {
+  pub fn root();
+}
+
+#if 0
+# This is a simple test to ensure that trailers do not cause extra newlines in files
+#endif
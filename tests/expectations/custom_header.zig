@@ -0,0 +1,10 @@
+#if 0
+# This file is generated by cbindgen. DO NOT EDIT
+#endif
+
+
+pub extern fn root() void;
+
+#if 0
+# This is a simple test to ensure that trailers do not cause extra newlines in files
+#endif
@@ -0,0 +1,13 @@
+typedef struct BindGroupLayoutEntry BindGroupLayoutEntry;
+
+enum BindingType {
+  Buffer = 0,
+  NotBuffer = 1,
+};
+typedef uint32_t BindingType;
+
+struct BindGroupLayoutEntry {
+  BindingType ty;
+};
+
+void root(BindGroupLayoutEntry entry);
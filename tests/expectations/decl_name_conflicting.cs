@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum BindingType : uint {
+  Buffer = 0,
+  NotBuffer = 1,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct BindGroupLayoutEntry {
+  public BindingType ty;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(BindGroupLayoutEntry entry);
+}
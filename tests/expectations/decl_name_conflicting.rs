@@ -0,0 +1,16 @@
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum BindingType {
+  Buffer = 0,
+  NotBuffer = 1,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BindGroupLayoutEntry {
+  pub ty: BindingType,
+}
+
+extern "C" {
+  pub fn root(entry: BindGroupLayoutEntry);
+}
@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const BindingType = enum(u32) {
+  Buffer = 0,
+  NotBuffer = 1,
+};
+
+pub const BindGroupLayoutEntry = extern struct {
+  ty: BindingType,
+};
+
+pub extern fn root(entry: BindGroupLayoutEntry) void;
@@ -0,0 +1,8 @@
+typedef struct dep_struct dep_struct;
+
+struct dep_struct {
+  uint32_t x;
+  double y;
+};
+
+uint32_t get_x(const dep_struct *dep_struct);
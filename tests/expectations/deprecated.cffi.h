@@ -0,0 +1,77 @@
+typedef struct DeprecatedStruct DeprecatedStruct;
+typedef struct DeprecatedStructWithNote DeprecatedStructWithNote;
+typedef struct Bar_Body Bar_Body;
+typedef struct Baz_Body Baz_Body;
+typedef union EnumWithDeprecatedStructVariants EnumWithDeprecatedStructVariants;
+
+enum DeprecatedEnum {
+  A = 0,
+};
+typedef int32_t DeprecatedEnum;
+
+enum DeprecatedEnumWithNote {
+  B = 0,
+};
+typedef int32_t DeprecatedEnumWithNote;
+
+enum EnumWithDeprecatedVariants {
+  C = 0,
+  D = 1,
+  E = 2,
+  F = 3,
+};
+typedef int32_t EnumWithDeprecatedVariants;
+
+struct DeprecatedStruct {
+  int32_t a;
+};
+
+struct DeprecatedStructWithNote {
+  int32_t a;
+};
+
+enum EnumWithDeprecatedStructVariants_Tag {
+  Foo,
+  Bar,
+  Baz,
+};
+typedef uint8_t EnumWithDeprecatedStructVariants_Tag;
+
+struct Bar_Body {
+  EnumWithDeprecatedStructVariants_Tag tag;
+  uint8_t x;
+  int16_t y;
+};
+
+struct Baz_Body {
+  EnumWithDeprecatedStructVariants_Tag tag;
+  uint8_t x;
+  uint8_t y;
+};
+
+union EnumWithDeprecatedStructVariants {
+  EnumWithDeprecatedStructVariants_Tag tag;
+  struct {
+    EnumWithDeprecatedStructVariants_Tag foo_tag;
+    int16_t foo;
+  };
+  Bar_Body bar;
+  Baz_Body baz;
+};
+
+void deprecated_without_note(void);
+
+void deprecated_without_bracket(void);
+
+void deprecated_with_note(void);
+
+void deprecated_with_note_and_since(void);
+
+void deprecated_with_note_which_requires_to_be_escaped(void);
+
+void dummy(DeprecatedEnum a,
+           DeprecatedEnumWithNote b,
+           EnumWithDeprecatedVariants c,
+           DeprecatedStruct d,
+           DeprecatedStructWithNote e,
+           EnumWithDeprecatedStructVariants f);
@@ -0,0 +1,210 @@
+#define DEPRECATED_FUNC __attribute__((deprecated))
+#define DEPRECATED_STRUCT __attribute__((deprecated))
+#define DEPRECATED_ENUM __attribute__((deprecated))
+#define DEPRECATED_ENUM_VARIANT __attribute__((deprecated))
+#define DEPRECATED_FUNC_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_STRUCT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_VARIANT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class DeprecatedEnum {
+    private DeprecatedEnum() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int A = 0;
+  }
+
+  public static final class DeprecatedEnumWithNote {
+    private DeprecatedEnumWithNote() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int B = 0;
+  }
+
+  public static final class EnumWithDeprecatedVariants {
+    private EnumWithDeprecatedVariants() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int C = 0;
+    public static final int D = 1;
+    public static final int E = 2;
+    public static final int F = 3;
+  }
+
+  public static final class DeprecatedStruct {
+    private DeprecatedStruct() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("a")
+    ).withName("DeprecatedStruct");
+  }
+
+  public static final class DeprecatedStructWithNote {
+    private DeprecatedStructWithNote() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("a")
+    ).withName("DeprecatedStructWithNote");
+  }
+
+  public static final class EnumWithDeprecatedStructVariants_Tag {
+    private EnumWithDeprecatedStructVariants_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Foo = 0;
+    public static final byte Bar = 1;
+    public static final byte Baz = 2;
+  }
+
+  public static final class Bar_Body {
+    private Bar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      EnumWithDeprecatedStructVariants_Tag.LAYOUT.withName("tag"),
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("Bar_Body");
+  }
+
+  public static final class Baz_Body {
+    private Baz_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      EnumWithDeprecatedStructVariants_Tag.LAYOUT.withName("tag"),
+      JAVA_BYTE.withName("x"),
+      JAVA_BYTE.withName("y")
+    ).withName("Baz_Body");
+  }
+
+  public static final class EnumWithDeprecatedStructVariants {
+    private EnumWithDeprecatedStructVariants() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      EnumWithDeprecatedStructVariants_Tag.LAYOUT.withName("tag"),
+      structLayout(EnumWithDeprecatedStructVariants_Tag.LAYOUT.withName("foo_tag"), JAVA_SHORT.withName("foo")),
+      Bar_Body.LAYOUT.withName("bar"),
+      Baz_Body.LAYOUT.withName("baz")
+    ).withName("EnumWithDeprecatedStructVariants");
+  }
+
+  public static final MethodHandle deprecated_without_note = downcall("deprecated_without_note", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle deprecated_without_bracket = downcall("deprecated_without_bracket", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle deprecated_with_note = downcall("deprecated_with_note", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle deprecated_with_note_and_since = downcall("deprecated_with_note_and_since", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle deprecated_with_note_which_requires_to_be_escaped = downcall("deprecated_with_note_which_requires_to_be_escaped", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle dummy = downcall("dummy", FunctionDescriptor.of(void_.LAYOUT,
+                                                                                   DeprecatedEnum.LAYOUT,
+                                                                                   DeprecatedEnumWithNote.LAYOUT,
+                                                                                   EnumWithDeprecatedVariants.LAYOUT,
+                                                                                   DeprecatedStruct.LAYOUT,
+                                                                                   DeprecatedStructWithNote.LAYOUT,
+                                                                                   EnumWithDeprecatedStructVariants.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
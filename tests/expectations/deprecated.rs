@@ -0,0 +1,86 @@
+#define DEPRECATED_FUNC __attribute__((deprecated))
+#define DEPRECATED_STRUCT __attribute__((deprecated))
+#define DEPRECATED_ENUM __attribute__((deprecated))
+#define DEPRECATED_ENUM_VARIANT __attribute__((deprecated))
+#define DEPRECATED_FUNC_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_STRUCT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+#define DEPRECATED_ENUM_VARIANT_WITH_NOTE(...) __attribute__((deprecated(__VA_ARGS__)))
+
+
+#[repr(i32)]
+#[derive(Clone, Copy)]
+#[deprecated]
+pub enum DeprecatedEnum {
+  A = 0,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy)]
+#[deprecated = "This is a note"]
+pub enum DeprecatedEnumWithNote {
+  B = 0,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy)]
+pub enum EnumWithDeprecatedVariants {
+  C = 0,
+  D = 1,
+  E = 2,
+  F = 3,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[deprecated]
+pub struct DeprecatedStruct {
+  pub a: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[deprecated = "This is a note"]
+pub struct DeprecatedStructWithNote {
+  pub a: i32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum EnumWithDeprecatedStructVariants {
+  Foo(i16),
+  Bar {
+    x: u8,
+    y: i16,
+  },
+  Baz {
+    x: u8,
+    y: u8,
+  },
+}
+
+extern "C" {
+  #[deprecated]
+  pub fn deprecated_without_note();
+
+  #[deprecated = "This is a note"]
+  pub fn deprecated_without_bracket();
+
+  #[deprecated = "This is a note"]
+  pub fn deprecated_with_note();
+
+  #[deprecated = "This is a note"]
+  pub fn deprecated_with_note_and_since();
+
+  #[deprecated = "This quote \" requires to be quoted, and this [\n] requires to be escaped"]
+  pub fn deprecated_with_note_which_requires_to_be_escaped();
+
+  pub fn dummy(
+    a: DeprecatedEnum,
+    b: DeprecatedEnumWithNote,
+    c: EnumWithDeprecatedVariants,
+    d: DeprecatedStruct,
+    e: DeprecatedStructWithNote,
+    f: EnumWithDeprecatedStructVariants,
+  ) -> void;
+}
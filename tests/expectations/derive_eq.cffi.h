@@ -0,0 +1,44 @@
+typedef struct Foo Foo;
+typedef struct Bazz_Body Bazz_Body;
+typedef struct FooNamed_Body FooNamed_Body;
+typedef struct FooParen_Body FooParen_Body;
+typedef union Bar Bar;
+
+struct Foo {
+  bool a;
+  int32_t b;
+};
+
+enum Bar_Tag {
+  Baz,
+  Bazz,
+  FooNamed,
+  FooParen,
+};
+typedef uint8_t Bar_Tag;
+
+struct Bazz_Body {
+  Bar_Tag tag;
+  Foo named;
+};
+
+struct FooNamed_Body {
+  Bar_Tag tag;
+  int32_t different;
+  uint32_t fields;
+};
+
+struct FooParen_Body {
+  Bar_Tag tag;
+  int32_t _0;
+  Foo _1;
+};
+
+union Bar {
+  Bar_Tag tag;
+  Bazz_Body bazz;
+  FooNamed_Body foo_named;
+  FooParen_Body foo_paren;
+};
+
+Foo root(Bar aBar);
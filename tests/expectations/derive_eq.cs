@@ -0,0 +1,50 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+  [MarshalAs(UnmanagedType.U1)] public bool a;
+  public int b;
+}
+
+public enum Bar_Tag : byte {
+  Baz,
+  Bazz,
+  FooNamed,
+  FooParen,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bazz_Body {
+  public Bar_Tag tag;
+  public Foo named;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FooNamed_Body {
+  public Bar_Tag tag;
+  public int different;
+  public uint fields;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FooParen_Body {
+  public Bar_Tag tag;
+  public int _0;
+  public Foo _1;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Bar {
+  [FieldOffset(0)] public Bar_Tag tag;
+  [FieldOffset(0)] public Bazz_Body bazz;
+  [FieldOffset(0)] public FooNamed_Body foo_named;
+  [FieldOffset(0)] public FooParen_Body foo_paren;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Foo root(Bar aBar);
+}
@@ -0,0 +1,24 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub a: bool,
+  pub b: i32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Bar {
+  Baz,
+  Bazz {
+    named: Foo,
+  },
+  FooNamed {
+    different: i32,
+    fields: u32,
+  },
+  FooParen(i32, Foo),
+}
+
+extern "C" {
+  pub fn root(aBar: Bar) -> Foo;
+}
@@ -0,0 +1,94 @@
+typedef struct A A;
+typedef struct B B;
+typedef struct D D;
+typedef struct Bar_Body Bar_Body;
+typedef union F F;
+typedef struct There_Body There_Body;
+typedef struct H H;
+typedef struct ThereAgain_Body ThereAgain_Body;
+typedef struct I I;
+
+enum C {
+  X = 2,
+  Y,
+};
+typedef uint32_t C;
+
+struct A {
+  int32_t _0;
+};
+
+struct B {
+  int32_t x;
+  float y;
+};
+
+struct D {
+  uint8_t List;
+  uintptr_t Of;
+  B Things;
+};
+
+enum F_Tag {
+  Foo,
+  Bar,
+  Baz,
+};
+typedef uint8_t F_Tag;
+
+struct Bar_Body {
+  F_Tag tag;
+  uint8_t x;
+  int16_t y;
+};
+
+union F {
+  F_Tag tag;
+  struct {
+    F_Tag foo_tag;
+    int16_t foo;
+  };
+  Bar_Body bar;
+};
+
+enum H_Tag {
+  Hello,
+  There,
+  Everyone,
+};
+typedef uint8_t H_Tag;
+
+struct There_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct H {
+  H_Tag tag;
+  union {
+    struct {
+      int16_t hello;
+    };
+    There_Body there;
+  };
+};
+
+enum I_Tag {
+  ThereAgain,
+  SomethingElse,
+};
+typedef uint8_t I_Tag;
+
+struct ThereAgain_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct I {
+  I_Tag tag;
+  union {
+    ThereAgain_Body there_again;
+  };
+};
+
+void root(A a, B b, C c, D d, F f, H h, I i);
@@ -0,0 +1,109 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum C : uint {
+  X = 2,
+  Y,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct A {
+  public int _0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct B {
+  public int x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct D {
+  public byte List;
+  public nuint Of;
+  public B Things;
+}
+
+public enum F_Tag : byte {
+  Foo,
+  Bar,
+  Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar_Body {
+  public F_Tag tag;
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct F {
+  [FieldOffset(0)] public F_Tag tag;
+  [FieldOffset(0)] public Foo_Body foo;
+  [FieldOffset(0)] public Bar_Body bar;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Foo_Body {
+    public F_Tag foo_tag;
+    public short foo;
+  }
+}
+
+public enum H_Tag : byte {
+  Hello,
+  There,
+  Everyone,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct There_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H {
+  public H_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Hello_Body hello;
+    [FieldOffset(0)] public There_Body there;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Hello_Body {
+    public short hello;
+  }
+}
+
+public enum I_Tag : byte {
+  ThereAgain,
+  SomethingElse,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ThereAgain_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct I {
+  public I_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public ThereAgain_Body there_again;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c, D d, F f, H h, I i);
+}
@@ -0,0 +1,63 @@
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum C {
+  X = 2,
+  Y,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct A {
+  pub _0: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct B {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct D {
+  pub List: u8,
+  pub Of: usize,
+  pub Things: B,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum F {
+  Foo(i16),
+  Bar {
+    x: u8,
+    y: i16,
+  },
+  Baz,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum H {
+  Hello(i16),
+  There {
+    x: u8,
+    y: i16,
+  },
+  Everyone,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum I {
+  ThereAgain {
+    x: u8,
+    y: i16,
+  },
+  SomethingElse,
+}
+
+extern "C" {
+  pub fn root(a: A, b: B, c: C, d: D, f: F, h: H, i: I);
+}
@@ -0,0 +1,265 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public enum FillRule : byte {
+  A,
+  B,
+}
+
+/// <summary>
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct OwnedSlice_u32 {
+  public nuint len;
+  public uint* ptr;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Polygon_u32 {
+  public FillRule fill;
+  public OwnedSlice_u32 coordinates;
+}
+
+/// <summary>
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct OwnedSlice_i32 {
+  public nuint len;
+  public int* ptr;
+}
+
+public enum Foo_u32_Tag : byte {
+  Bar_u32,
+  Polygon1_u32,
+  Slice1_u32,
+  Slice2_u32,
+  Slice3_u32,
+  Slice4_u32,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice3_Body_u32 {
+  public FillRule fill;
+  public OwnedSlice_u32 coords;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice4_Body_u32 {
+  public FillRule fill;
+  public OwnedSlice_i32 coords;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_u32 {
+  public Foo_u32_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Polygon1_Body_u32 polygon1;
+    [FieldOffset(0)] public Slice1_Body_u32 slice1;
+    [FieldOffset(0)] public Slice2_Body_u32 slice2;
+    [FieldOffset(0)] public Slice3_Body_u32 slice3;
+    [FieldOffset(0)] public Slice4_Body_u32 slice4;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Polygon1_Body_u32 {
+    public Polygon_u32 polygon1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Slice1_Body_u32 {
+    public OwnedSlice_u32 slice1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Slice2_Body_u32 {
+    public OwnedSlice_i32 slice2;
+  }
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Polygon_i32 {
+  public FillRule fill;
+  public OwnedSlice_i32 coordinates;
+}
+
+public enum Baz_i32_Tag : byte {
+  Bar2_i32,
+  Polygon21_i32,
+  Slice21_i32,
+  Slice22_i32,
+  Slice23_i32,
+  Slice24_i32,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice23_Body_i32 {
+  public Baz_i32_Tag tag;
+  public FillRule fill;
+  public OwnedSlice_i32 coords;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice24_Body_i32 {
+  public Baz_i32_Tag tag;
+  public FillRule fill;
+  public OwnedSlice_i32 coords;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Baz_i32 {
+  [FieldOffset(0)] public Baz_i32_Tag tag;
+  [FieldOffset(0)] public Polygon21_Body_i32 polygon21;
+  [FieldOffset(0)] public Slice21_Body_i32 slice21;
+  [FieldOffset(0)] public Slice22_Body_i32 slice22;
+  [FieldOffset(0)] public Slice23_Body_i32 slice23;
+  [FieldOffset(0)] public Slice24_Body_i32 slice24;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Polygon21_Body_i32 {
+    public Baz_i32_Tag polygon21_tag;
+    public Polygon_i32 polygon21;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Slice21_Body_i32 {
+    public Baz_i32_Tag slice21_tag;
+    public OwnedSlice_i32 slice21;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Slice22_Body_i32 {
+    public Baz_i32_Tag slice22_tag;
+    public OwnedSlice_i32 slice22;
+  }
+}
+
+public enum Taz_Tag : byte {
+  Bar3,
+  Taz1,
+  Taz3,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Taz {
+  [FieldOffset(0)] public Taz_Tag tag;
+  [FieldOffset(0)] public Taz1_Body taz1;
+  [FieldOffset(0)] public Taz3_Body taz3;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz1_Body {
+    public Taz_Tag taz1_tag;
+    public int taz1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz3_Body {
+    public Taz_Tag taz3_tag;
+    public OwnedSlice_i32 taz3;
+  }
+}
+
+public enum Tazz_Tag : byte {
+  Bar4,
+  Taz2,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Tazz {
+  [FieldOffset(0)] public Tazz_Tag tag;
+  [FieldOffset(0)] public Taz2_Body taz2;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz2_Body {
+    public Tazz_Tag taz2_tag;
+    public int taz2;
+  }
+}
+
+public enum Tazzz_Tag : byte {
+  Bar5,
+  Taz5,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Tazzz {
+  [FieldOffset(0)] public Tazzz_Tag tag;
+  [FieldOffset(0)] public Taz5_Body taz5;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz5_Body {
+    public Tazzz_Tag taz5_tag;
+    public int taz5;
+  }
+}
+
+public enum Tazzzz_Tag : byte {
+  Taz6,
+  Taz7,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Tazzzz {
+  [FieldOffset(0)] public Tazzzz_Tag tag;
+  [FieldOffset(0)] public Taz6_Body taz6;
+  [FieldOffset(0)] public Taz7_Body taz7;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz6_Body {
+    public Tazzzz_Tag taz6_tag;
+    public int taz6;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Taz7_Body {
+    public Tazzzz_Tag taz7_tag;
+    public uint taz7;
+  }
+}
+
+public enum Qux_Tag : byte {
+  Qux1,
+  Qux2,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Qux {
+  [FieldOffset(0)] public Qux_Tag tag;
+  [FieldOffset(0)] public Qux1_Body qux1;
+  [FieldOffset(0)] public Qux2_Body qux2;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Qux1_Body {
+    public Qux_Tag qux1_tag;
+    public int qux1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Qux2_Body {
+    public Qux_Tag qux2_tag;
+    public uint qux2;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_u32* a,
+                                 Baz_i32* b,
+                                 Taz* c,
+                                 Tazz d,
+                                 Tazzz* e,
+                                 Tazzzz* f,
+                                 Qux* g);
+}
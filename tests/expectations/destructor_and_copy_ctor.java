@@ -0,0 +1,343 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class FillRule {
+    private FillRule() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte A = 0;
+    public static final byte B = 1;
+  }
+
+  /**
+   * This will have a destructor manually implemented via variant_body, and
+   * similarly a Drop impl in Rust.
+   */
+  public static final class OwnedSlice_u32 {
+    private OwnedSlice_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_LONG.withName("len"),
+      ADDRESS.withName("ptr")
+    ).withName("OwnedSlice_u32");
+  }
+
+  public static final class Polygon_u32 {
+    private Polygon_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_u32.LAYOUT.withName("coordinates")
+    ).withName("Polygon_u32");
+  }
+
+  /**
+   * This will have a destructor manually implemented via variant_body, and
+   * similarly a Drop impl in Rust.
+   */
+  public static final class OwnedSlice_i32 {
+    private OwnedSlice_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_LONG.withName("len"),
+      ADDRESS.withName("ptr")
+    ).withName("OwnedSlice_i32");
+  }
+
+  public static final class Foo_u32_Tag {
+    private Foo_u32_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Bar_u32 = 0;
+    public static final byte Polygon1_u32 = 1;
+    public static final byte Slice1_u32 = 2;
+    public static final byte Slice2_u32 = 3;
+    public static final byte Slice3_u32 = 4;
+    public static final byte Slice4_u32 = 5;
+  }
+
+  public static final class Slice3_Body_u32 {
+    private Slice3_Body_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_u32.LAYOUT.withName("coords")
+    ).withName("Slice3_Body_u32");
+  }
+
+  public static final class Slice4_Body_u32 {
+    private Slice4_Body_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_i32.LAYOUT.withName("coords")
+    ).withName("Slice4_Body_u32");
+  }
+
+  public static final class Foo_u32 {
+    private Foo_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Foo_u32_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(Polygon_u32.LAYOUT.withName("polygon1")), structLayout(OwnedSlice_u32.LAYOUT.withName("slice1")), structLayout(OwnedSlice_i32.LAYOUT.withName("slice2")), Slice3_Body_u32.LAYOUT.withName("slice3"), Slice4_Body_u32.LAYOUT.withName("slice4"))
+    ).withName("Foo_u32");
+  }
+
+  public static final class Polygon_i32 {
+    private Polygon_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_i32.LAYOUT.withName("coordinates")
+    ).withName("Polygon_i32");
+  }
+
+  public static final class Baz_i32_Tag {
+    private Baz_i32_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Bar2_i32 = 0;
+    public static final byte Polygon21_i32 = 1;
+    public static final byte Slice21_i32 = 2;
+    public static final byte Slice22_i32 = 3;
+    public static final byte Slice23_i32 = 4;
+    public static final byte Slice24_i32 = 5;
+  }
+
+  public static final class Slice23_Body_i32 {
+    private Slice23_Body_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Baz_i32_Tag.LAYOUT.withName("tag"),
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_i32.LAYOUT.withName("coords")
+    ).withName("Slice23_Body_i32");
+  }
+
+  public static final class Slice24_Body_i32 {
+    private Slice24_Body_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Baz_i32_Tag.LAYOUT.withName("tag"),
+      FillRule.LAYOUT.withName("fill"),
+      OwnedSlice_i32.LAYOUT.withName("coords")
+    ).withName("Slice24_Body_i32");
+  }
+
+  public static final class Baz_i32 {
+    private Baz_i32() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Baz_i32_Tag.LAYOUT.withName("tag"),
+      structLayout(Baz_i32_Tag.LAYOUT.withName("polygon21_tag"), Polygon_i32.LAYOUT.withName("polygon21")),
+      structLayout(Baz_i32_Tag.LAYOUT.withName("slice21_tag"), OwnedSlice_i32.LAYOUT.withName("slice21")),
+      structLayout(Baz_i32_Tag.LAYOUT.withName("slice22_tag"), OwnedSlice_i32.LAYOUT.withName("slice22")),
+      Slice23_Body_i32.LAYOUT.withName("slice23"),
+      Slice24_Body_i32.LAYOUT.withName("slice24")
+    ).withName("Baz_i32");
+  }
+
+  public static final class Taz_Tag {
+    private Taz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Bar3 = 0;
+    public static final byte Taz1 = 1;
+    public static final byte Taz3 = 2;
+  }
+
+  public static final class Taz {
+    private Taz() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Taz_Tag.LAYOUT.withName("tag"),
+      structLayout(Taz_Tag.LAYOUT.withName("taz1_tag"), JAVA_INT.withName("taz1")),
+      structLayout(Taz_Tag.LAYOUT.withName("taz3_tag"), OwnedSlice_i32.LAYOUT.withName("taz3"))
+    ).withName("Taz");
+  }
+
+  public static final class Tazz_Tag {
+    private Tazz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Bar4 = 0;
+    public static final byte Taz2 = 1;
+  }
+
+  public static final class Tazz {
+    private Tazz() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Tazz_Tag.LAYOUT.withName("tag"),
+      structLayout(Tazz_Tag.LAYOUT.withName("taz2_tag"), JAVA_INT.withName("taz2"))
+    ).withName("Tazz");
+  }
+
+  public static final class Tazzz_Tag {
+    private Tazzz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Bar5 = 0;
+    public static final byte Taz5 = 1;
+  }
+
+  public static final class Tazzz {
+    private Tazzz() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Tazzz_Tag.LAYOUT.withName("tag"),
+      structLayout(Tazzz_Tag.LAYOUT.withName("taz5_tag"), JAVA_INT.withName("taz5"))
+    ).withName("Tazzz");
+  }
+
+  public static final class Tazzzz_Tag {
+    private Tazzzz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Taz6 = 0;
+    public static final byte Taz7 = 1;
+  }
+
+  public static final class Tazzzz {
+    private Tazzzz() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Tazzzz_Tag.LAYOUT.withName("tag"),
+      structLayout(Tazzzz_Tag.LAYOUT.withName("taz6_tag"), JAVA_INT.withName("taz6")),
+      structLayout(Tazzzz_Tag.LAYOUT.withName("taz7_tag"), JAVA_INT.withName("taz7"))
+    ).withName("Tazzzz");
+  }
+
+  public static final class Qux_Tag {
+    private Qux_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Qux1 = 0;
+    public static final byte Qux2 = 1;
+  }
+
+  public static final class Qux {
+    private Qux() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Qux_Tag.LAYOUT.withName("tag"),
+      structLayout(Qux_Tag.LAYOUT.withName("qux1_tag"), JAVA_INT.withName("qux1")),
+      structLayout(Qux_Tag.LAYOUT.withName("qux2_tag"), JAVA_INT.withName("qux2"))
+    ).withName("Qux");
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(ADDRESS,
+                                                                                     ADDRESS,
+                                                                                     ADDRESS,
+                                                                                     Tazz.LAYOUT,
+                                                                                     ADDRESS,
+                                                                                     ADDRESS,
+                                                                                     ADDRESS));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,124 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum FillRule {
+  A,
+  B,
+}
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct OwnedSlice_u32 {
+  pub len: usize,
+  pub ptr: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Polygon_u32 {
+  pub fill: FillRule,
+  pub coordinates: OwnedSlice_u32,
+}
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct OwnedSlice_i32 {
+  pub len: usize,
+  pub ptr: *mut i32,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum Foo_u32 {
+  Bar_u32,
+  Polygon1_u32(Polygon_u32),
+  Slice1_u32(OwnedSlice_u32),
+  Slice2_u32(OwnedSlice_i32),
+  Slice3_u32 {
+    fill: FillRule,
+    coords: OwnedSlice_u32,
+  },
+  Slice4_u32 {
+    fill: FillRule,
+    coords: OwnedSlice_i32,
+  },
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Polygon_i32 {
+  pub fill: FillRule,
+  pub coordinates: OwnedSlice_i32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Baz_i32 {
+  Bar2_i32,
+  Polygon21_i32(Polygon_i32),
+  Slice21_i32(OwnedSlice_i32),
+  Slice22_i32(OwnedSlice_i32),
+  Slice23_i32 {
+    fill: FillRule,
+    coords: OwnedSlice_i32,
+  },
+  Slice24_i32 {
+    fill: FillRule,
+    coords: OwnedSlice_i32,
+  },
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Taz {
+  Bar3,
+  Taz1(i32),
+  Taz3(OwnedSlice_i32),
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Tazz {
+  Bar4,
+  Taz2(i32),
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Tazzz {
+  Bar5,
+  Taz5(i32),
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Tazzzz {
+  Taz6(i32),
+  Taz7(u32),
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Qux {
+  Qux1(i32),
+  Qux2(u32),
+}
+
+extern "C" {
+  pub fn root(
+    a: *const Foo_u32,
+    b: *const Baz_i32,
+    c: *const Taz,
+    d: Tazz,
+    e: *const Tazzz,
+    f: *const Tazzzz,
+    g: *const Qux,
+  );
+}
@@ -0,0 +1,195 @@
+#define NOINLINE __attribute__((noinline))
+#define NODISCARD [[nodiscard]]
+
+
+const std = @import("std");
+
+pub const FillRule = enum(u8) {
+  A,
+  B,
+};
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+pub const OwnedSlice_u32 = extern struct {
+  len: usize,
+  ptr: *u32,
+};
+
+pub const Polygon_u32 = extern struct {
+  fill: FillRule,
+  coordinates: OwnedSlice_u32,
+};
+
+/// This will have a destructor manually implemented via variant_body, and
+/// similarly a Drop impl in Rust.
+pub const OwnedSlice_i32 = extern struct {
+  len: usize,
+  ptr: *i32,
+};
+
+pub const Foo_u32_Tag = enum(u8) {
+  Bar_u32,
+  Polygon1_u32,
+  Slice1_u32,
+  Slice2_u32,
+  Slice3_u32,
+  Slice4_u32,
+};
+
+pub const Slice3_Body_u32 = extern struct {
+  fill: FillRule,
+  coords: OwnedSlice_u32,
+};
+
+pub const Slice4_Body_u32 = extern struct {
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Foo_u32 = extern struct {
+  tag: Foo_u32_Tag,
+  payload: extern union {
+    polygon1: extern struct {
+      polygon1: Polygon_u32,
+    },
+    slice1: extern struct {
+      slice1: OwnedSlice_u32,
+    },
+    slice2: extern struct {
+      slice2: OwnedSlice_i32,
+    },
+    slice3: Slice3_Body_u32,
+    slice4: Slice4_Body_u32,
+  },
+};
+
+pub const Polygon_i32 = extern struct {
+  fill: FillRule,
+  coordinates: OwnedSlice_i32,
+};
+
+pub const Baz_i32_Tag = enum(u8) {
+  Bar2_i32,
+  Polygon21_i32,
+  Slice21_i32,
+  Slice22_i32,
+  Slice23_i32,
+  Slice24_i32,
+};
+
+pub const Slice23_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Slice24_Body_i32 = extern struct {
+  tag: Baz_i32_Tag,
+  fill: FillRule,
+  coords: OwnedSlice_i32,
+};
+
+pub const Baz_i32 = extern union {
+  tag: Baz_i32_Tag,
+  polygon21: extern struct {
+    polygon21_tag: Baz_i32_Tag,
+    polygon21: Polygon_i32,
+  },
+  slice21: extern struct {
+    slice21_tag: Baz_i32_Tag,
+    slice21: OwnedSlice_i32,
+  },
+  slice22: extern struct {
+    slice22_tag: Baz_i32_Tag,
+    slice22: OwnedSlice_i32,
+  },
+  slice23: Slice23_Body_i32,
+  slice24: Slice24_Body_i32,
+};
+
+pub const Taz_Tag = enum(u8) {
+  Bar3,
+  Taz1,
+  Taz3,
+};
+
+pub const Taz = extern union {
+  tag: Taz_Tag,
+  taz1: extern struct {
+    taz1_tag: Taz_Tag,
+    taz1: i32,
+  },
+  taz3: extern struct {
+    taz3_tag: Taz_Tag,
+    taz3: OwnedSlice_i32,
+  },
+};
+
+pub const Tazz_Tag = enum(u8) {
+  Bar4,
+  Taz2,
+};
+
+pub const Tazz = extern union {
+  tag: Tazz_Tag,
+  taz2: extern struct {
+    taz2_tag: Tazz_Tag,
+    taz2: i32,
+  },
+};
+
+pub const Tazzz_Tag = enum(u8) {
+  Bar5,
+  Taz5,
+};
+
+pub const Tazzz = extern union {
+  tag: Tazzz_Tag,
+  taz5: extern struct {
+    taz5_tag: Tazzz_Tag,
+    taz5: i32,
+  },
+};
+
+pub const Tazzzz_Tag = enum(u8) {
+  Taz6,
+  Taz7,
+};
+
+pub const Tazzzz = extern union {
+  tag: Tazzzz_Tag,
+  taz6: extern struct {
+    taz6_tag: Tazzzz_Tag,
+    taz6: i32,
+  },
+  taz7: extern struct {
+    taz7_tag: Tazzzz_Tag,
+    taz7: u32,
+  },
+};
+
+pub const Qux_Tag = enum(u8) {
+  Qux1,
+  Qux2,
+};
+
+pub const Qux = extern union {
+  tag: Qux_Tag,
+  qux1: extern struct {
+    qux1_tag: Qux_Tag,
+    qux1: i32,
+  },
+  qux2: extern struct {
+    qux2_tag: Qux_Tag,
+    qux2: u32,
+  },
+};
+
+pub extern fn root(a: *const Foo_u32,
+                   b: *const Baz_i32,
+                   c: *const Taz,
+                   d: Tazz,
+                   e: *const Tazzz,
+                   f: *const Tazzzz,
+                   g: *const Qux) void;
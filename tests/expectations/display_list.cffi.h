@@ -0,0 +1,46 @@
+typedef struct Rect Rect;
+typedef struct Color Color;
+typedef struct Fill_Body Fill_Body;
+typedef struct Image_Body Image_Body;
+typedef union DisplayItem DisplayItem;
+
+struct Rect {
+  float x;
+  float y;
+  float w;
+  float h;
+};
+
+struct Color {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+  uint8_t a;
+};
+
+enum DisplayItem_Tag {
+  Fill,
+  Image,
+  ClearScreen,
+};
+typedef uint8_t DisplayItem_Tag;
+
+struct Fill_Body {
+  DisplayItem_Tag tag;
+  Rect _0;
+  Color _1;
+};
+
+struct Image_Body {
+  DisplayItem_Tag tag;
+  uint32_t id;
+  Rect bounds;
+};
+
+union DisplayItem {
+  DisplayItem_Tag tag;
+  Fill_Body fill;
+  Image_Body image;
+};
+
+bool push_item(DisplayItem item);
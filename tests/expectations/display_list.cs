@@ -0,0 +1,53 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Rect {
+  public float x;
+  public float y;
+  public float w;
+  public float h;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Color {
+  public byte r;
+  public byte g;
+  public byte b;
+  public byte a;
+}
+
+public enum DisplayItem_Tag : byte {
+  Fill,
+  Image,
+  ClearScreen,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Fill_Body {
+  public DisplayItem_Tag tag;
+  public Rect _0;
+  public Color _1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Image_Body {
+  public DisplayItem_Tag tag;
+  public uint id;
+  public Rect bounds;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct DisplayItem {
+  [FieldOffset(0)] public DisplayItem_Tag tag;
+  [FieldOffset(0)] public Fill_Body fill;
+  [FieldOffset(0)] public Image_Body image;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  [return: MarshalAs(UnmanagedType.U1)]
+  public static extern bool push_item(DisplayItem item);
+}
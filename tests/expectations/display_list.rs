@@ -0,0 +1,32 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Rect {
+  pub x: f32,
+  pub y: f32,
+  pub w: f32,
+  pub h: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Color {
+  pub r: u8,
+  pub g: u8,
+  pub b: u8,
+  pub a: u8,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum DisplayItem {
+  Fill(Rect, Color),
+  Image {
+    id: u32,
+    bounds: Rect,
+  },
+  ClearScreen,
+}
+
+extern "C" {
+  pub fn push_item(item: DisplayItem) -> bool;
+}
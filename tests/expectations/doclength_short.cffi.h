@@ -0,0 +1,3 @@
+void root(void);
+
+void trunk(void);
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// The root of all evil.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+
+  /// <summary>
+  /// A little above the root, and a lot more visible, with a run-on sentence
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void trunk();
+}
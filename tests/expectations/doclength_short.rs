@@ -0,0 +1,7 @@
+extern "C" {
+  /// The root of all evil.
+  pub fn root();
+
+  /// A little above the root, and a lot more visible, with a run-on sentence
+  pub fn trunk();
+}
@@ -0,0 +1,4 @@
+extern "C" {
+  /// The root of all evil.
+  pub fn root();
+}
@@ -0,0 +1,3 @@
+const uint32_t FOO;
+
+void root(void);
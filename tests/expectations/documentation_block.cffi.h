@@ -0,0 +1,5 @@
+const uint32_t FOO;
+
+void root(void);
+
+void block_function(void);
@@ -0,0 +1,20 @@
+#define LibMAX_LEN 16
+
+#define LibBuffer_CAPACITY 64
+
+typedef struct LibBuffer LibBuffer;
+
+enum LibStatus {
+  Ok,
+  TooLong,
+};
+typedef uint8_t LibStatus;
+
+struct LibBuffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+LibBuffer *buffer_new(uintptr_t len, LibStatus *status);
+
+void buffer_free(LibBuffer *buffer);
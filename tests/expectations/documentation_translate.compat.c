@@ -0,0 +1,80 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The largest length of a `LibBuffer`.
+ */
+#define LibMAX_LEN 16
+
+/**
+ * The result of an operation on a LibBuffer.
+ */
+enum LibStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * Everything went well.
+   */
+  Ok,
+  /**
+   * The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+   */
+  TooLong,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum LibStatus LibStatus;
+#else
+typedef uint8_t LibStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+/**
+ * A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+ */
+typedef struct {
+  /**
+   * The bytes, as many as `LibBuffer.len`.
+   */
+  uint8_t *data;
+  uintptr_t len;
+} LibBuffer;
+/**
+ * The capacity of a `LibBuffer`.
+ */
+#define LibBuffer_CAPACITY 64
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a new `LibBuffer`.
+ *
+ * @param len The length of the buffer, at most `LibMAX_LEN`.
+ * @param status Set to `TooLong` if `len` is too large.
+ *
+ * @return The new buffer, to be freed with `buffer_free`, or null.
+ *
+ * # Examples
+ *
+ * @code
+ * let buffer = buffer_new(4, &mut Status::Ok);
+ * @endcode
+ */
+LibBuffer *buffer_new(uintptr_t len, LibStatus *status);
+
+/**
+ * Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+ *
+ * @warning The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+ */
+void buffer_free(LibBuffer *buffer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,66 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/**
+ * The largest length of a `LibBuffer`.
+ */
+constexpr static const uint32_t LibMAX_LEN = 16;
+
+/**
+ * The result of an operation on a LibBuffer.
+ */
+enum class LibStatus : uint8_t {
+  /**
+   * Everything went well.
+   */
+  Ok,
+  /**
+   * The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+   */
+  TooLong,
+};
+
+/**
+ * A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+ */
+struct LibBuffer {
+  /**
+   * The bytes, as many as `LibBuffer.len`.
+   */
+  uint8_t *data;
+  uintptr_t len;
+};
+/**
+ * The capacity of a `LibBuffer`.
+ */
+constexpr static const uintptr_t LibBuffer_CAPACITY = 64;
+
+extern "C" {
+
+/**
+ * Creates a new `LibBuffer`.
+ *
+ * @param len The length of the buffer, at most `LibMAX_LEN`.
+ * @param status Set to `LibStatus::TooLong` if `len` is too large.
+ *
+ * @return The new buffer, to be freed with `buffer_free`, or null.
+ *
+ * # Examples
+ *
+ * @code
+ * let buffer = buffer_new(4, &mut Status::Ok);
+ * @endcode
+ */
+LibBuffer *buffer_new(uintptr_t len, LibStatus *status);
+
+/**
+ * Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+ *
+ * @warning The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+ */
+void buffer_free(LibBuffer *buffer);
+
+}  // extern "C"
@@ -0,0 +1,74 @@
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// The result of an operation on a LibBuffer.
+/// </summary>
+public enum LibStatus : byte {
+  /// <summary>
+  /// Everything went well.
+  /// </summary>
+  Ok,
+  /// <summary>
+  /// The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+  /// </summary>
+  TooLong,
+}
+
+/// <summary>
+/// A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct LibBuffer {
+  /// <summary>
+  /// The bytes, as many as `LibBuffer.len`.
+  /// </summary>
+  public byte* data;
+  public nuint len;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// The largest length of a `LibBuffer`.
+  /// </summary>
+  public const uint LibMAX_LEN = 16;
+
+  /// <summary>
+  /// The capacity of a `LibBuffer`.
+  /// </summary>
+  public const nuint LibBuffer_CAPACITY = 64;
+
+  /// <summary>
+  /// Creates a new `LibBuffer`.
+  ///
+  /// # Arguments
+  ///
+  /// * `len` - The length of the buffer, at most `LibMAX_LEN`.
+  /// * `status` - Set to `TooLong` if `len` is too large.
+  ///
+  /// # Returns
+  ///
+  /// The new buffer, to be freed with `buffer_free`, or null.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use lib::*;
+  /// let buffer = buffer_new(4, &amp;mut Status::Ok);
+  /// ```
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern LibBuffer* buffer_new(nuint len, LibStatus* status);
+
+  /// <summary>
+  /// Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+  ///
+  /// # Safety
+  ///
+  /// The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void buffer_free(LibBuffer* buffer);
+}
@@ -0,0 +1,170 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  /**
+   * The largest length of a `LibBuffer`.
+   */
+  public static final int LibMAX_LEN = 16;
+
+  /**
+   * The capacity of a `LibBuffer`.
+   */
+  public static final long LibBuffer_CAPACITY = 64L;
+
+  /**
+   * The result of an operation on a LibBuffer.
+   */
+  public static final class LibStatus {
+    private LibStatus() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    /**
+     * Everything went well.
+     */
+    public static final byte Ok = 0;
+    /**
+     * The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+     */
+    public static final byte TooLong = 1;
+  }
+
+  /**
+   * A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+   */
+  public static final class LibBuffer {
+    private LibBuffer() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      ADDRESS.withName("data"),
+      JAVA_LONG.withName("len")
+    ).withName("LibBuffer");
+  }
+
+  /**
+   * Creates a new `LibBuffer`.
+   *
+   * # Arguments
+   *
+   * * `len` - The length of the buffer, at most `LibMAX_LEN`.
+   * * `status` - Set to `TooLong` if `len` is too large.
+   *
+   * # Returns
+   *
+   * The new buffer, to be freed with `buffer_free`, or null.
+   *
+   * # Examples
+   *
+   * ```
+   * # use lib::*;
+   * let buffer = buffer_new(4, &mut Status::Ok);
+   * ```
+   */
+  public static final MethodHandle buffer_new = downcall("buffer_new", FunctionDescriptor.of(ADDRESS,
+                                                                                             JAVA_LONG,
+                                                                                             ADDRESS));
+
+  /**
+   * Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+   *
+   * # Safety
+   *
+   * The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+   */
+  public static final MethodHandle buffer_free = downcall("buffer_free", FunctionDescriptor.ofVoid(ADDRESS));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
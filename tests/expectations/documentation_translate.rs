@@ -0,0 +1,52 @@
+/// The largest length of a `LibBuffer`.
+pub const LibMAX_LEN: u32 = 16;
+
+/// The result of an operation on a LibBuffer.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum LibStatus {
+  /// Everything went well.
+  Ok,
+  /// The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+  TooLong,
+}
+
+/// A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibBuffer {
+  /// The bytes, as many as `LibBuffer.len`.
+  pub data: *mut u8,
+  pub len: usize,
+}
+
+/// The capacity of a `LibBuffer`.
+pub const LibBuffer_CAPACITY: usize = 64;
+
+extern "C" {
+  /// Creates a new `LibBuffer`.
+  ///
+  /// # Arguments
+  ///
+  /// * `len` - The length of the buffer, at most `LibMAX_LEN`.
+  /// * `status` - Set to `TooLong` if `len` is too large.
+  ///
+  /// # Returns
+  ///
+  /// The new buffer, to be freed with `buffer_free`, or null.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use lib::*;
+  /// let buffer = buffer_new(4, &mut Status::Ok);
+  /// ```
+  pub fn buffer_new(len: usize, status: *mut LibStatus) -> *mut LibBuffer;
+
+  /// Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+  ///
+  /// # Safety
+  ///
+  /// The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+  pub fn buffer_free(buffer: *mut LibBuffer);
+}
@@ -0,0 +1,47 @@
+const std = @import("std");
+
+/// The largest length of a `LibBuffer`.
+pub const LibMAX_LEN: u32 = 16;
+
+/// The result of an operation on a LibBuffer.
+pub const LibStatus = enum(u8) {
+  /// Everything went well.
+  Ok,
+  /// The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+  TooLong,
+};
+
+/// A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+pub const LibBuffer = extern struct {
+  /// The bytes, as many as `LibBuffer.len`.
+  data: ?*u8,
+  len: usize,
+};
+/// The capacity of a `LibBuffer`.
+pub const LibBuffer_CAPACITY: usize = 64;
+
+/// Creates a new `LibBuffer`.
+///
+/// # Arguments
+///
+/// * `len` - The length of the buffer, at most `LibMAX_LEN`.
+/// * `status` - Set to `TooLong` if `len` is too large.
+///
+/// # Returns
+///
+/// The new buffer, to be freed with `buffer_free`, or null.
+///
+/// # Examples
+///
+/// ```
+/// # use lib::*;
+/// let buffer = buffer_new(4, &mut Status::Ok);
+/// ```
+pub extern fn buffer_new(len: usize, status: *LibStatus) ?*LibBuffer;
+
+/// Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+///
+/// # Safety
+///
+/// The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+pub extern fn buffer_free(buffer: ?*LibBuffer) void;
@@ -0,0 +1,14 @@
+typedef struct Buffer Buffer;
+typedef union Either Either;
+
+struct Buffer {
+  uint8_t *myData;
+  uintptr_t myLen;
+};
+
+union Either {
+  Buffer buffer;
+  uintptr_t byteCount;
+};
+
+uint8_t *buffer_data(const Buffer *buffer, Either either);
@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+struct Buffer {
+  /// The bytes, as many as `Buffer.myLen`.
+  uint8_t *myData;
+  uintptr_t myLen;
+};
+
+/// A buffer or a length, see `Either.byteCount`.
+union Either {
+  Buffer buffer;
+  uintptr_t byteCount;
+};
+
+extern "C" {
+
+/// Reads the `Buffer.myData` of `buffer`.
+uint8_t *buffer_data(const Buffer *buffer, Either either);
+
+}  // extern "C"
@@ -0,0 +1,33 @@
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Buffer {
+  /// <summary>
+  /// The bytes, as many as `Buffer.myLen`.
+  /// </summary>
+  public byte* myData;
+  public nuint myLen;
+}
+
+/// <summary>
+/// A buffer or a length, see `Either.byteCount`.
+/// </summary>
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Either {
+  [FieldOffset(0)] public Buffer buffer;
+  [FieldOffset(0)] public nuint byteCount;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  /// <summary>
+  /// Reads the `Buffer.myData` of `buffer`.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern byte* buffer_data(Buffer* buffer, Either either);
+}
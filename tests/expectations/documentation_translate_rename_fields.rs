@@ -0,0 +1,21 @@
+/// A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Buffer {
+  /// The bytes, as many as `Buffer.myLen`.
+  pub myData: *mut u8,
+  pub myLen: usize,
+}
+
+/// A buffer or a length, see `Either.byteCount`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Either {
+  pub buffer: Buffer,
+  pub byteCount: usize,
+}
+
+extern "C" {
+  /// Reads the `Buffer.myData` of `buffer`.
+  pub fn buffer_data(buffer: *const Buffer, either: Either) -> *mut u8;
+}
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+/// A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+pub const Buffer = extern struct {
+  /// The bytes, as many as `Buffer.myLen`.
+  myData: ?*u8,
+  myLen: usize,
+};
+
+/// A buffer or a length, see `Either.byteCount`.
+pub const Either = extern union {
+  buffer: Buffer,
+  byteCount: usize,
+};
+
+/// Reads the `Buffer.myData` of `buffer`.
+pub extern fn buffer_data(buffer: *const Buffer, either: Either) ?*u8;
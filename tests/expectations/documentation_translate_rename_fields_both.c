@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+ */
+typedef struct Buffer {
+  /**
+   * The bytes, as many as `Buffer.myLen`.
+   */
+  uint8_t *myData;
+  uintptr_t myLen;
+} Buffer;
+
+/**
+ * A buffer or a length, see `Either.byteCount`.
+ */
+typedef union Either {
+  struct Buffer buffer;
+  uintptr_t byteCount;
+} Either;
+
+/**
+ * Reads the `Buffer.myData` of `buffer`.
+ */
+uint8_t *buffer_data(const struct Buffer *buffer, union Either either);
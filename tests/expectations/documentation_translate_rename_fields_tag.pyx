@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # A buffer of bytes, see `Buffer.myLen` and `Buffer.myData`.
+  cdef struct Buffer:
+    # The bytes, as many as `Buffer.myLen`.
+    uint8_t *myData;
+    uintptr_t myLen;
+
+  # A buffer or a length, see `Either.byteCount`.
+  cdef union Either:
+    Buffer buffer;
+    uintptr_t byteCount;
+
+  # Reads the `Buffer.myData` of `buffer`.
+  uint8_t *buffer_data(const Buffer *buffer, Either either);
@@ -0,0 +1,52 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # The largest length of a `LibBuffer`.
+  const uint32_t LibMAX_LEN # = 16
+
+  # The result of an operation on a LibBuffer.
+  cdef enum:
+    # Everything went well.
+    Ok,
+    # The buffer is longer than `LibMAX_LEN`, see `LibBuffer_CAPACITY`.
+    TooLong,
+  ctypedef uint8_t LibStatus;
+
+  # A buffer of bytes, see `buffer_new` and `LibBuffer.len`.
+  cdef struct LibBuffer:
+    # The bytes, as many as `LibBuffer.len`.
+    uint8_t *data;
+    uintptr_t len;
+  # The capacity of a `LibBuffer`.
+  const uintptr_t LibBuffer_CAPACITY # = 64
+
+  # Creates a new `LibBuffer`.
+  #
+  # # Arguments
+  #
+  # * `len` - The length of the buffer, at most `LibMAX_LEN`.
+  # * `status` - Set to `TooLong` if `len` is too large.
+  #
+  # # Returns
+  #
+  # The new buffer, to be freed with `buffer_free`, or null.
+  #
+  # # Examples
+  #
+  # ```
+  # # use lib::*;
+  # let buffer = buffer_new(4, &mut Status::Ok);
+  # ```
+  LibBuffer *buffer_new(uintptr_t len, LibStatus *status);
+
+  # Frees a `LibBuffer`, see the [Rust documentation](https://doc.rust-lang.org/std/).
+  #
+  # # Safety
+  #
+  # The buffer must come from `buffer_new`, and must not be used after `a[0]` is freed.
+  void buffer_free(LibBuffer *buffer);
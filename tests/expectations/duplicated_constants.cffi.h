@@ -0,0 +1,16 @@
+#define Foo_FIELD_RELATED_CONSTANT 0
+
+#define Bar_FIELD_RELATED_CONSTANT 0
+
+typedef struct Foo Foo;
+typedef struct Bar Bar;
+
+struct Foo {
+  uint32_t field;
+};
+
+struct Bar {
+  uint32_t field;
+};
+
+void root(Foo a, Bar b);
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+  public uint field;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar {
+  public uint field;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const uint Foo_FIELD_RELATED_CONSTANT = 0;
+
+  public const uint Bar_FIELD_RELATED_CONSTANT = 0;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a, Bar b);
+}
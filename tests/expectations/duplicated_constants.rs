@@ -0,0 +1,19 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub field: u32,
+}
+
+pub const Foo_FIELD_RELATED_CONSTANT: u32 = 0;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar {
+  pub field: u32,
+}
+
+pub const Bar_FIELD_RELATED_CONSTANT: u32 = 0;
+
+extern "C" {
+  pub fn root(a: Foo, b: Bar);
+}
@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Foo = extern struct {
+  field: u32,
+};
+pub const Foo_FIELD_RELATED_CONSTANT: u32 = 0;
+
+pub const Bar = extern struct {
+  field: u32,
+};
+pub const Bar_FIELD_RELATED_CONSTANT: u32 = 0;
+
+pub extern fn root(a: Foo, b: Bar) void;
@@ -0,0 +1,239 @@
+typedef struct Bar_Body Bar_Body;
+typedef union G G;
+typedef struct H_Bar_Body H_Bar_Body;
+typedef struct H H;
+typedef struct ExI_Bar_Body ExI_Bar_Body;
+typedef struct ExI ExI;
+typedef struct P1_Body P1_Body;
+typedef struct P P;
+typedef struct Q Q;
+typedef struct IRBar_Body IRBar_Body;
+typedef struct R R;
+
+enum A {
+  a1 = 0,
+  a2 = 2,
+  a3,
+  a4 = 5,
+};
+typedef uint64_t A;
+
+enum B {
+  b1 = 0,
+  b2 = 2,
+  b3,
+  b4 = 5,
+};
+typedef uint32_t B;
+
+enum C {
+  c1 = 0,
+  c2 = 2,
+  c3,
+  c4 = 5,
+};
+typedef uint16_t C;
+
+enum D {
+  d1 = 0,
+  d2 = 2,
+  d3,
+  d4 = 5,
+};
+typedef uint8_t D;
+
+enum E {
+  e1 = 0,
+  e2 = 2,
+  e3,
+  e4 = 5,
+};
+typedef uintptr_t E;
+
+enum F {
+  f1 = 0,
+  f2 = 2,
+  f3,
+  f4 = 5,
+};
+typedef intptr_t F;
+
+typedef enum L {
+  l1,
+  l2,
+  l3,
+  l4,
+} L;
+
+enum M {
+  m1 = -1,
+  m2 = 0,
+  m3 = 1,
+};
+typedef int8_t M;
+
+typedef enum N {
+  n1,
+  n2,
+  n3,
+  n4,
+} N;
+
+enum O {
+  o1,
+  o2,
+  o3,
+  o4,
+};
+typedef int8_t O;
+
+typedef struct J J;
+
+typedef struct K K;
+
+typedef struct Opaque Opaque;
+
+enum G_Tag {
+  Foo,
+  Bar,
+  Baz,
+};
+typedef uint8_t G_Tag;
+
+struct Bar_Body {
+  G_Tag tag;
+  uint8_t x;
+  int16_t y;
+};
+
+union G {
+  G_Tag tag;
+  struct {
+    G_Tag foo_tag;
+    int16_t foo;
+  };
+  Bar_Body bar;
+};
+
+typedef enum H_Tag {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+} H_Tag;
+
+struct H_Bar_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct H {
+  H_Tag tag;
+  union {
+    struct {
+      int16_t foo;
+    };
+    H_Bar_Body bar;
+  };
+};
+
+enum ExI_Tag {
+  ExI_Foo,
+  ExI_Bar,
+  ExI_Baz,
+};
+typedef uint8_t ExI_Tag;
+
+struct ExI_Bar_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct ExI {
+  ExI_Tag tag;
+  union {
+    struct {
+      int16_t foo;
+    };
+    ExI_Bar_Body bar;
+  };
+};
+
+enum P_Tag {
+  P0,
+  P1,
+};
+typedef uint8_t P_Tag;
+
+struct P1_Body {
+  uint8_t _0;
+  uint8_t _1;
+  uint8_t _2;
+};
+
+struct P {
+  P_Tag tag;
+  union {
+    struct {
+      uint8_t p0;
+    };
+    P1_Body p1;
+  };
+};
+
+typedef enum Q_Tag {
+  Ok,
+  Err,
+} Q_Tag;
+
+struct Q {
+  Q_Tag tag;
+  union {
+    struct {
+      uint32_t *ok;
+    };
+    struct {
+      uint32_t err;
+    };
+  };
+};
+
+typedef enum R_Tag {
+  IRFoo,
+  IRBar,
+  IRBaz,
+} R_Tag;
+
+struct IRBar_Body {
+  uint8_t x;
+  int16_t y;
+};
+
+struct R {
+  R_Tag tag;
+  union {
+    struct {
+      int16_t IRFoo;
+    };
+    IRBar_Body IRBar;
+  };
+};
+
+void root(Opaque *opaque,
+          A a,
+          B b,
+          C c,
+          D d,
+          E e,
+          F f,
+          G g,
+          H h,
+          ExI i,
+          J j,
+          K k,
+          L l,
+          M m,
+          N n,
+          O o,
+          P p,
+          Q q,
+          R r);
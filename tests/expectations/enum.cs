@@ -0,0 +1,300 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public enum A : ulong {
+  a1 = 0,
+  a2 = 2,
+  a3,
+  a4 = 5,
+}
+
+public enum B : uint {
+  b1 = 0,
+  b2 = 2,
+  b3,
+  b4 = 5,
+}
+
+public enum C : ushort {
+  c1 = 0,
+  c2 = 2,
+  c3,
+  c4 = 5,
+}
+
+public enum D : byte {
+  d1 = 0,
+  d2 = 2,
+  d3,
+  d4 = 5,
+}
+
+public enum E : ulong {
+  e1 = 0,
+  e2 = 2,
+  e3,
+  e4 = 5,
+}
+
+public enum F : long {
+  f1 = 0,
+  f2 = 2,
+  f3,
+  f4 = 5,
+}
+
+public enum L {
+  l1,
+  l2,
+  l3,
+  l4,
+}
+
+public enum M : sbyte {
+  m1 = -1,
+  m2 = 0,
+  m3 = 1,
+}
+
+public enum N {
+  n1,
+  n2,
+  n3,
+  n4,
+}
+
+public enum O : sbyte {
+  o1,
+  o2,
+  o3,
+  o4,
+}
+
+public partial struct J { }
+
+public partial struct K { }
+
+public partial struct Opaque { }
+
+public enum G_Tag : byte {
+  Foo,
+  Bar,
+  Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar_Body {
+  public G_Tag tag;
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct G {
+  [FieldOffset(0)] public G_Tag tag;
+  [FieldOffset(0)] public Foo_Body foo;
+  [FieldOffset(0)] public Bar_Body bar;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Foo_Body {
+    public G_Tag foo_tag;
+    public short foo;
+  }
+}
+
+public enum H_Tag {
+  H_Foo,
+  H_Bar,
+  H_Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H_Bar_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct H {
+  public H_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public H_Foo_Body foo;
+    [FieldOffset(0)] public H_Bar_Body bar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct H_Foo_Body {
+    public short foo;
+  }
+}
+
+public enum ExI_Tag : byte {
+  ExI_Foo,
+  ExI_Bar,
+  ExI_Baz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ExI_Bar_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ExI {
+  public ExI_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public ExI_Foo_Body foo;
+    [FieldOffset(0)] public ExI_Bar_Body bar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct ExI_Foo_Body {
+    public short foo;
+  }
+}
+
+public enum P_Tag : byte {
+  P0,
+  P1,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct P1_Body {
+  public byte _0;
+  public byte _1;
+  public byte _2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct P {
+  public P_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public P0_Body p0;
+    [FieldOffset(0)] public P1_Body p1;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct P0_Body {
+    public byte p0;
+  }
+}
+
+public enum Q_Tag {
+  Ok,
+  Err,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Q {
+  public Q_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Ok_Body ok;
+    [FieldOffset(0)] public Err_Body err;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Ok_Body {
+    public uint* ok;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Err_Body {
+    public uint err;
+  }
+}
+
+public enum R_Tag {
+  IRFoo,
+  IRBar,
+  IRBaz,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct IRBar_Body {
+  public byte x;
+  public short y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct R {
+  public R_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public IRFoo_Body IRFoo;
+    [FieldOffset(0)] public IRBar_Body IRBar;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct IRFoo_Body {
+    public short IRFoo;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Opaque* opaque,
+                                 A a,
+                                 B b,
+                                 C c,
+                                 D d,
+                                 E e,
+                                 F f,
+                                 G g,
+                                 H h,
+                                 ExI i,
+                                 J j,
+                                 K k,
+                                 L l,
+                                 M m,
+                                 N n,
+                                 O o,
+                                 P p,
+                                 Q q,
+                                 R r);
+}
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
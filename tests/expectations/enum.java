@@ -0,0 +1,404 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class A {
+    private A() {}
+
+    public static final ValueLayout.OfLong LAYOUT = JAVA_LONG;
+
+    public static final long a1 = 0L;
+    public static final long a2 = 2L;
+    public static final long a3 = 3L;
+    public static final long a4 = 5L;
+  }
+
+  public static final class B {
+    private B() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int b1 = 0;
+    public static final int b2 = 2;
+    public static final int b3 = 3;
+    public static final int b4 = 5;
+  }
+
+  public static final class C {
+    private C() {}
+
+    public static final ValueLayout.OfShort LAYOUT = JAVA_SHORT;
+
+    public static final short c1 = 0;
+    public static final short c2 = 2;
+    public static final short c3 = 3;
+    public static final short c4 = 5;
+  }
+
+  public static final class D {
+    private D() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte d1 = 0;
+    public static final byte d2 = 2;
+    public static final byte d3 = 3;
+    public static final byte d4 = 5;
+  }
+
+  public static final class E {
+    private E() {}
+
+    public static final ValueLayout.OfLong LAYOUT = JAVA_LONG;
+
+    public static final long e1 = 0L;
+    public static final long e2 = 2L;
+    public static final long e3 = 3L;
+    public static final long e4 = 5L;
+  }
+
+  public static final class F {
+    private F() {}
+
+    public static final ValueLayout.OfLong LAYOUT = JAVA_LONG;
+
+    public static final long f1 = 0L;
+    public static final long f2 = 2L;
+    public static final long f3 = 3L;
+    public static final long f4 = 5L;
+  }
+
+  public static final class L {
+    private L() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int l1 = 0;
+    public static final int l2 = 1;
+    public static final int l3 = 2;
+    public static final int l4 = 3;
+  }
+
+  public static final class M {
+    private M() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte m1 = -1;
+    public static final byte m2 = 0;
+    public static final byte m3 = 1;
+  }
+
+  public static final class N {
+    private N() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int n1 = 0;
+    public static final int n2 = 1;
+    public static final int n3 = 2;
+    public static final int n4 = 3;
+  }
+
+  public static final class O {
+    private O() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte o1 = 0;
+    public static final byte o2 = 1;
+    public static final byte o3 = 2;
+    public static final byte o4 = 3;
+  }
+
+  // `J` is opaque, so it's only ever passed around as an `ADDRESS`.
+
+  // `K` is opaque, so it's only ever passed around as an `ADDRESS`.
+
+  // `Opaque` is opaque, so it's only ever passed around as an `ADDRESS`.
+
+  public static final class G_Tag {
+    private G_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Foo = 0;
+    public static final byte Bar = 1;
+    public static final byte Baz = 2;
+  }
+
+  public static final class Bar_Body {
+    private Bar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      G_Tag.LAYOUT.withName("tag"),
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("Bar_Body");
+  }
+
+  public static final class G {
+    private G() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      G_Tag.LAYOUT.withName("tag"),
+      structLayout(G_Tag.LAYOUT.withName("foo_tag"), JAVA_SHORT.withName("foo")),
+      Bar_Body.LAYOUT.withName("bar")
+    ).withName("G");
+  }
+
+  public static final class H_Tag {
+    private H_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int H_Foo = 0;
+    public static final int H_Bar = 1;
+    public static final int H_Baz = 2;
+  }
+
+  public static final class H_Bar_Body {
+    private H_Bar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("H_Bar_Body");
+  }
+
+  public static final class H {
+    private H() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      H_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_SHORT.withName("foo")), H_Bar_Body.LAYOUT.withName("bar"))
+    ).withName("H");
+  }
+
+  public static final class ExI_Tag {
+    private ExI_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte ExI_Foo = 0;
+    public static final byte ExI_Bar = 1;
+    public static final byte ExI_Baz = 2;
+  }
+
+  public static final class ExI_Bar_Body {
+    private ExI_Bar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("ExI_Bar_Body");
+  }
+
+  public static final class ExI {
+    private ExI() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      ExI_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_SHORT.withName("foo")), ExI_Bar_Body.LAYOUT.withName("bar"))
+    ).withName("ExI");
+  }
+
+  public static final class P_Tag {
+    private P_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte P0 = 0;
+    public static final byte P1 = 1;
+  }
+
+  public static final class P1_Body {
+    private P1_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("_0"),
+      JAVA_BYTE.withName("_1"),
+      JAVA_BYTE.withName("_2")
+    ).withName("P1_Body");
+  }
+
+  public static final class P {
+    private P() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      P_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_BYTE.withName("p0")), P1_Body.LAYOUT.withName("p1"))
+    ).withName("P");
+  }
+
+  public static final class Q_Tag {
+    private Q_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Ok = 0;
+    public static final int Err = 1;
+  }
+
+  public static final class Q {
+    private Q() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Q_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(ADDRESS.withName("ok")), structLayout(JAVA_INT.withName("err")))
+    ).withName("Q");
+  }
+
+  public static final class R_Tag {
+    private R_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int IRFoo = 0;
+    public static final int IRBar = 1;
+    public static final int IRBaz = 2;
+  }
+
+  public static final class IRBar_Body {
+    private IRBar_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("x"),
+      JAVA_SHORT.withName("y")
+    ).withName("IRBar_Body");
+  }
+
+  public static final class R {
+    private R() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      R_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_SHORT.withName("IRFoo")), IRBar_Body.LAYOUT.withName("IRBar"))
+    ).withName("R");
+  }
+
+  // `root` passes an opaque type by value, which can't be described without its layout.
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
@@ -0,0 +1,217 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(u64)]
+#[derive(Clone, Copy)]
+pub enum A {
+  a1 = 0,
+  a2 = 2,
+  a3,
+  a4 = 5,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub enum B {
+  b1 = 0,
+  b2 = 2,
+  b3,
+  b4 = 5,
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub enum C {
+  c1 = 0,
+  c2 = 2,
+  c3,
+  c4 = 5,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum D {
+  d1 = 0,
+  d2 = 2,
+  d3,
+  d4 = 5,
+}
+
+#[repr(usize)]
+#[derive(Clone, Copy)]
+pub enum E {
+  e1 = 0,
+  e2 = 2,
+  e3,
+  e4 = 5,
+}
+
+#[repr(isize)]
+#[derive(Clone, Copy)]
+pub enum F {
+  f1 = 0,
+  f2 = 2,
+  f3,
+  f4 = 5,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum L {
+  l1,
+  l2,
+  l3,
+  l4,
+}
+
+#[repr(i8)]
+#[derive(Clone, Copy)]
+pub enum M {
+  m1 = -1,
+  m2 = 0,
+  m3 = 1,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum N {
+  n1,
+  n2,
+  n3,
+  n4,
+}
+
+#[repr(i8)]
+#[derive(Clone, Copy)]
+pub enum O {
+  o1,
+  o2,
+  o3,
+  o4,
+}
+
+#[repr(C)]
+pub struct J {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct K {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum G {
+  Foo(i16),
+  Bar {
+    x: u8,
+    y: i16,
+  },
+  Baz,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum H {
+  H_Foo(i16),
+  H_Bar {
+    x: u8,
+    y: i16,
+  },
+  H_Baz,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum ExI {
+  ExI_Foo(i16),
+  ExI_Bar {
+    x: u8,
+    y: i16,
+  },
+  ExI_Baz,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum P {
+  P0(u8),
+  P1(u8, u8, u8),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum Q {
+  Ok(*mut u32),
+  Err(u32),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum R {
+  IRFoo(i16),
+  IRBar {
+    x: u8,
+    y: i16,
+  },
+  IRBaz,
+}
+
+extern "C" {
+  pub fn root(
+    opaque: *mut Opaque,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G,
+    h: H,
+    i: ExI,
+    j: J,
+    k: K,
+    l: L,
+    m: M,
+    n: N,
+    o: O,
+    p: P,
+    q: Q,
+    r: R,
+  );
+}
+
+#if 0
+''' '
+#endif
+
+#include <stddef.h>
+#include "testing-helpers.h"
+static_assert(offsetof(CBINDGEN_STRUCT(P), tag) == 0, "unexpected offset for tag");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p0");
+static_assert(offsetof(CBINDGEN_STRUCT(P), p0) == 1, "unexpected offset for p1");
+static_assert(sizeof(CBINDGEN_STRUCT(P)) == 4, "unexpected size for P");
+
+#if 0
+' '''
+#endif
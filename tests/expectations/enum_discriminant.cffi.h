@@ -0,0 +1,14 @@
+#define FOURTY_FOUR 4
+
+enum E {
+  A = 1,
+  B = -1,
+  C = 3,
+  D = 4,
+  F = 5,
+  G = 54,
+  H = 0,
+};
+typedef int8_t E;
+
+void root(const E*);
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum E : sbyte {
+  A = 1,
+  B = -1,
+  C = (1 + 2),
+  D = NativeMethods.FOURTY_FOUR,
+  F = 5,
+  G = unchecked((sbyte)(54)),
+  H = unchecked((sbyte)(false)),
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const sbyte FOURTY_FOUR = 4;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(E* _0);
+}
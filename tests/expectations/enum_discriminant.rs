@@ -0,0 +1,17 @@
+pub const FOURTY_FOUR: i8 = 4;
+
+#[repr(i8)]
+#[derive(Clone, Copy)]
+pub enum E {
+  A = 1,
+  B = -1,
+  C = (1 + 2),
+  D = FOURTY_FOUR,
+  F = 5,
+  G = 54,
+  H = 0,
+}
+
+extern "C" {
+  pub fn root(_: *const E);
+}
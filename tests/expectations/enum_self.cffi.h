@@ -0,0 +1,27 @@
+typedef struct Foo_Bar Foo_Bar;
+typedef union Bar Bar;
+
+struct Foo_Bar {
+  const int32_t *something;
+};
+
+enum Bar_Tag {
+  Min,
+  Max,
+  Other,
+};
+typedef uint8_t Bar_Tag;
+
+union Bar {
+  Bar_Tag tag;
+  struct {
+    Bar_Tag min_tag;
+    Foo_Bar min;
+  };
+  struct {
+    Bar_Tag max_tag;
+    Foo_Bar max;
+  };
+};
+
+void root(Bar b);
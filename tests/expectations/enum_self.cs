@@ -0,0 +1,39 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_Bar {
+  public int* something;
+}
+
+public enum Bar_Tag : byte {
+  Min,
+  Max,
+  Other,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Bar {
+  [FieldOffset(0)] public Bar_Tag tag;
+  [FieldOffset(0)] public Min_Body min;
+  [FieldOffset(0)] public Max_Body max;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Min_Body {
+    public Bar_Tag min_tag;
+    public Foo_Bar min;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Max_Body {
+    public Bar_Tag max_tag;
+    public Foo_Bar max;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
@@ -0,0 +1,17 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_Bar {
+  pub something: *const i32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Bar {
+  Min(Foo_Bar),
+  Max(Foo_Bar),
+  Other,
+}
+
+extern "C" {
+  pub fn root(b: Bar);
+}
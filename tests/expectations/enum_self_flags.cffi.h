@@ -0,0 +1,40 @@
+#define AXIS_SHIFT 3
+
+#define SELF_WM_SHIFT 6
+
+#define SELF_WM 64
+
+enum PositionAreaTrack {
+  Start = 1,
+  SpanStart = 3,
+  End = 4,
+  SpanEnd = 6,
+  Center = 2,
+  SpanAll = 7,
+};
+typedef uint8_t PositionAreaTrack;
+
+enum PositionAreaAxis {
+  Horizontal = 0,
+  Vertical = 1,
+  X = 2,
+  Y = 3,
+  Inline = 6,
+  Block = 7,
+};
+typedef uint8_t PositionAreaAxis;
+
+enum PositionAreaKeyword {
+  None = 0,
+  Center = 2,
+  SpanAll = 7,
+  Start = 1,
+  End = 4,
+  SpanStart = 3,
+  SpanEnd = 6,
+  Top = 9,
+  Bottom = 12,
+};
+typedef uint8_t PositionAreaKeyword;
+
+void root(PositionAreaKeyword, PositionAreaTrack, PositionAreaAxis);
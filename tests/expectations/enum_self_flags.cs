@@ -0,0 +1,92 @@
+#if 0
+''' '
+#endif
+
+// FIXME: Mis-generated in C mode with enum.prefix_with_name = false, and in
+// C++ mode with it set to true...
+#if defined(__cplusplus) && !defined(CBINDGEN_CPP_COMPAT)
+
+
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// Specifies which tracks(s) on the axis that the position-area span occupies.
+/// Represented as 3 bits: start, center, end track.
+/// </summary>
+public enum PositionAreaTrack : byte {
+  /// <summary>
+  /// First track
+  /// </summary>
+  Start = 1,
+  /// <summary>
+  /// First and center.
+  /// </summary>
+  SpanStart = 3,
+  /// <summary>
+  /// Last track.
+  /// </summary>
+  End = 4,
+  /// <summary>
+  /// Last and center.
+  /// </summary>
+  SpanEnd = 6,
+  /// <summary>
+  /// Center track.
+  /// </summary>
+  Center = 2,
+  /// <summary>
+  /// All tracks
+  /// </summary>
+  SpanAll = 7,
+}
+
+/// <summary>
+/// A three-bit value that represents the axis in which position-area operates on.
+/// Represented as 3 bits: axis type (physical or logical), direction type (physical or logical),
+/// axis value.
+/// </summary>
+public enum PositionAreaAxis : byte {
+  Horizontal = 0,
+  Vertical = 1,
+  X = 2,
+  Y = 3,
+  Inline = 6,
+  Block = 7,
+}
+
+/// <summary>
+/// Possible values for the `position-area` property's keywords.
+/// Represented by [0z xxx yyy], where z means "self wm resolution", xxx is the type (as in
+/// PositionAreaAxis and yyy is the PositionAreaTrack
+/// https://drafts.csswg.org/css-anchor-position-1/#propdef-position-area
+/// </summary>
+public enum PositionAreaKeyword : byte {
+  None = 0,
+  Center = unchecked((byte)(PositionAreaTrack.Center)),
+  SpanAll = unchecked((byte)(PositionAreaTrack.SpanAll)),
+  Start = unchecked((byte)(PositionAreaTrack.Start)),
+  End = unchecked((byte)(PositionAreaTrack.End)),
+  SpanStart = unchecked((byte)(PositionAreaTrack.SpanStart)),
+  SpanEnd = unchecked((byte)(PositionAreaTrack.SpanEnd)),
+  Top = ((unchecked((byte)(PositionAreaAxis.Vertical)) << (int)(NativeMethods.AXIS_SHIFT)) | unchecked((byte)(PositionAreaTrack.Start))),
+  Bottom = ((unchecked((byte)(PositionAreaAxis.Vertical)) << (int)(NativeMethods.AXIS_SHIFT)) | unchecked((byte)(PositionAreaTrack.End))),
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const nuint AXIS_SHIFT = 3;
+
+  public const nuint SELF_WM_SHIFT = 6;
+
+  public const byte SELF_WM = unchecked((byte)((1 << 6)));
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(PositionAreaKeyword _0, PositionAreaTrack _1, PositionAreaAxis _2);
+}
+
+#endif
+#if 0
+' '''
+#endif
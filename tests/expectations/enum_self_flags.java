@@ -0,0 +1,197 @@
+#if 0
+''' '
+#endif
+
+// FIXME: Mis-generated in C mode with enum.prefix_with_name = false, and in
+// C++ mode with it set to true...
+#if defined(__cplusplus) && !defined(CBINDGEN_CPP_COMPAT)
+
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final long AXIS_SHIFT = 3L;
+
+  public static final long SELF_WM_SHIFT = 6L;
+
+  public static final byte SELF_WM = 64;
+
+  /**
+   * Specifies which tracks(s) on the axis that the position-area span occupies.
+   * Represented as 3 bits: start, center, end track.
+   */
+  public static final class PositionAreaTrack {
+    private PositionAreaTrack() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    /**
+     * First track
+     */
+    public static final byte Start = 1;
+    /**
+     * First and center.
+     */
+    public static final byte SpanStart = 3;
+    /**
+     * Last track.
+     */
+    public static final byte End = 4;
+    /**
+     * Last and center.
+     */
+    public static final byte SpanEnd = 6;
+    /**
+     * Center track.
+     */
+    public static final byte Center = 2;
+    /**
+     * All tracks
+     */
+    public static final byte SpanAll = 7;
+  }
+
+  /**
+   * A three-bit value that represents the axis in which position-area operates on.
+   * Represented as 3 bits: axis type (physical or logical), direction type (physical or logical),
+   * axis value.
+   */
+  public static final class PositionAreaAxis {
+    private PositionAreaAxis() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Horizontal = 0;
+    public static final byte Vertical = 1;
+    public static final byte X = 2;
+    public static final byte Y = 3;
+    public static final byte Inline = 6;
+    public static final byte Block = 7;
+  }
+
+  /**
+   * Possible values for the `position-area` property's keywords.
+   * Represented by [0z xxx yyy], where z means "self wm resolution", xxx is the type (as in
+   * PositionAreaAxis and yyy is the PositionAreaTrack
+   * https://drafts.csswg.org/css-anchor-position-1/#propdef-position-area
+   */
+  public static final class PositionAreaKeyword {
+    private PositionAreaKeyword() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte None = 0;
+    public static final byte Center = 2;
+    public static final byte SpanAll = 7;
+    public static final byte Start = 1;
+    public static final byte End = 4;
+    public static final byte SpanStart = 3;
+    public static final byte SpanEnd = 6;
+    public static final byte Top = 9;
+    public static final byte Bottom = 12;
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(PositionAreaKeyword.LAYOUT,
+                                                                                     PositionAreaTrack.LAYOUT,
+                                                                                     PositionAreaAxis.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
+
+#endif
+#if 0
+' '''
+#endif
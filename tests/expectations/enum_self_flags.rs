@@ -0,0 +1,74 @@
+#if 0
+''' '
+#endif
+
+// FIXME: Mis-generated in C mode with enum.prefix_with_name = false, and in
+// C++ mode with it set to true...
+#if defined(__cplusplus) && !defined(CBINDGEN_CPP_COMPAT)
+
+
+pub const AXIS_SHIFT: usize = 3;
+
+pub const SELF_WM_SHIFT: usize = 6;
+
+pub const SELF_WM: u8 = 64;
+
+/// Specifies which tracks(s) on the axis that the position-area span occupies.
+/// Represented as 3 bits: start, center, end track.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum PositionAreaTrack {
+  /// First track
+  Start = 1,
+  /// First and center.
+  SpanStart = 3,
+  /// Last track.
+  End = 4,
+  /// Last and center.
+  SpanEnd = 6,
+  /// Center track.
+  Center = 2,
+  /// All tracks
+  SpanAll = 7,
+}
+
+/// A three-bit value that represents the axis in which position-area operates on.
+/// Represented as 3 bits: axis type (physical or logical), direction type (physical or logical),
+/// axis value.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum PositionAreaAxis {
+  Horizontal = 0,
+  Vertical = 1,
+  X = 2,
+  Y = 3,
+  Inline = 6,
+  Block = 7,
+}
+
+/// Possible values for the `position-area` property's keywords.
+/// Represented by [0z xxx yyy], where z means "self wm resolution", xxx is the type (as in
+/// PositionAreaAxis and yyy is the PositionAreaTrack
+/// https://drafts.csswg.org/css-anchor-position-1/#propdef-position-area
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum PositionAreaKeyword {
+  None = 0,
+  Center = 2,
+  SpanAll = 7,
+  Start = 1,
+  End = 4,
+  SpanStart = 3,
+  SpanEnd = 6,
+  Top = ((1 << AXIS_SHIFT) | 1),
+  Bottom = ((1 << AXIS_SHIFT) | 4),
+}
+
+extern "C" {
+  pub fn root(_: PositionAreaKeyword, _: PositionAreaTrack, _: PositionAreaAxis);
+}
+
+#endif
+#if 0
+' '''
+#endif
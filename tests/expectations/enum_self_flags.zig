@@ -0,0 +1,68 @@
+#if 0
+''' '
+#endif
+
+// FIXME: Mis-generated in C mode with enum.prefix_with_name = false, and in
+// C++ mode with it set to true...
+#if defined(__cplusplus) && !defined(CBINDGEN_CPP_COMPAT)
+
+
+const std = @import("std");
+
+pub const AXIS_SHIFT: usize = 3;
+
+pub const SELF_WM_SHIFT: usize = 6;
+
+pub const SELF_WM: u8 = (1 << 6);
+
+/// Specifies which tracks(s) on the axis that the position-area span occupies.
+/// Represented as 3 bits: start, center, end track.
+pub const PositionAreaTrack = enum(u8) {
+  /// First track
+  Start = 1,
+  /// First and center.
+  SpanStart = 3,
+  /// Last track.
+  End = 4,
+  /// Last and center.
+  SpanEnd = 6,
+  /// Center track.
+  Center = 2,
+  /// All tracks
+  SpanAll = 7,
+};
+
+/// A three-bit value that represents the axis in which position-area operates on.
+/// Represented as 3 bits: axis type (physical or logical), direction type (physical or logical),
+/// axis value.
+pub const PositionAreaAxis = enum(u8) {
+  Horizontal = 0,
+  Vertical = 1,
+  X = 2,
+  Y = 3,
+  Inline = 6,
+  Block = 7,
+};
+
+/// Possible values for the `position-area` property's keywords.
+/// Represented by [0z xxx yyy], where z means "self wm resolution", xxx is the type (as in
+/// PositionAreaAxis and yyy is the PositionAreaTrack
+/// https://drafts.csswg.org/css-anchor-position-1/#propdef-position-area
+pub const PositionAreaKeyword = enum(u8) {
+  None = 0,
+  Center = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.Center))),
+  SpanAll = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.SpanAll))),
+  Start = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.Start))),
+  End = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.End))),
+  SpanStart = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.SpanStart))),
+  SpanEnd = @as(u8, @intCast(@intFromEnum(PositionAreaTrack.SpanEnd))),
+  Top = ((@as(u8, @intCast(@intFromEnum(PositionAreaAxis.Vertical))) << AXIS_SHIFT) | @as(u8, @intCast(@intFromEnum(PositionAreaTrack.Start)))),
+  Bottom = ((@as(u8, @intCast(@intFromEnum(PositionAreaAxis.Vertical))) << AXIS_SHIFT) | @as(u8, @intCast(@intFromEnum(PositionAreaTrack.End)))),
+};
+
+pub extern fn root(_: PositionAreaKeyword, _: PositionAreaTrack, _: PositionAreaAxis) void;
+
+#endif
+#if 0
+' '''
+#endif
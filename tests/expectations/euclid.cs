@@ -0,0 +1,112 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedLength_f32__UnknownUnit {
+  public float _0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedLength_f32__LayoutUnit {
+  public float _0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedSideOffsets2D_f32__UnknownUnit {
+  public float top;
+  public float right;
+  public float bottom;
+  public float left;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedSideOffsets2D_f32__LayoutUnit {
+  public float top;
+  public float right;
+  public float bottom;
+  public float left;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedSize2D_f32__UnknownUnit {
+  public float width;
+  public float height;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedSize2D_f32__LayoutUnit {
+  public float width;
+  public float height;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedPoint2D_f32__UnknownUnit {
+  public float x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedPoint2D_f32__LayoutUnit {
+  public float x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedRect_f32__UnknownUnit {
+  public TypedPoint2D_f32__UnknownUnit origin;
+  public TypedSize2D_f32__UnknownUnit size;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedRect_f32__LayoutUnit {
+  public TypedPoint2D_f32__LayoutUnit origin;
+  public TypedSize2D_f32__LayoutUnit size;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
+  public float m11;
+  public float m12;
+  public float m21;
+  public float m22;
+  public float m31;
+  public float m32;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypedTransform2D_f32__LayoutUnit__UnknownUnit {
+  public float m11;
+  public float m12;
+  public float m21;
+  public float m22;
+  public float m31;
+  public float m32;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(TypedLength_f32__UnknownUnit length_a,
+                                 TypedLength_f32__LayoutUnit length_b,
+                                 TypedLength_f32__UnknownUnit length_c,
+                                 TypedLength_f32__LayoutUnit length_d,
+                                 TypedSideOffsets2D_f32__UnknownUnit side_offsets_a,
+                                 TypedSideOffsets2D_f32__LayoutUnit side_offsets_b,
+                                 TypedSideOffsets2D_f32__UnknownUnit side_offsets_c,
+                                 TypedSideOffsets2D_f32__LayoutUnit side_offsets_d,
+                                 TypedSize2D_f32__UnknownUnit size_a,
+                                 TypedSize2D_f32__LayoutUnit size_b,
+                                 TypedSize2D_f32__UnknownUnit size_c,
+                                 TypedSize2D_f32__LayoutUnit size_d,
+                                 TypedPoint2D_f32__UnknownUnit point_a,
+                                 TypedPoint2D_f32__LayoutUnit point_b,
+                                 TypedPoint2D_f32__UnknownUnit point_c,
+                                 TypedPoint2D_f32__LayoutUnit point_d,
+                                 TypedRect_f32__UnknownUnit rect_a,
+                                 TypedRect_f32__LayoutUnit rect_b,
+                                 TypedRect_f32__UnknownUnit rect_c,
+                                 TypedRect_f32__LayoutUnit rect_d,
+                                 TypedTransform2D_f32__UnknownUnit__LayoutUnit transform_a,
+                                 TypedTransform2D_f32__LayoutUnit__UnknownUnit transform_b);
+}
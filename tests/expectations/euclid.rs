@@ -0,0 +1,140 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedLength_f32__UnknownUnit {
+  pub _0: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedLength_f32__LayoutUnit {
+  pub _0: f32,
+}
+
+pub type Length_f32 = TypedLength_f32__UnknownUnit;
+
+pub type LayoutLength = TypedLength_f32__LayoutUnit;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedSideOffsets2D_f32__UnknownUnit {
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32,
+  pub left: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedSideOffsets2D_f32__LayoutUnit {
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32,
+  pub left: f32,
+}
+
+pub type SideOffsets2D_f32 = TypedSideOffsets2D_f32__UnknownUnit;
+
+pub type LayoutSideOffsets2D = TypedSideOffsets2D_f32__LayoutUnit;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedSize2D_f32__UnknownUnit {
+  pub width: f32,
+  pub height: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedSize2D_f32__LayoutUnit {
+  pub width: f32,
+  pub height: f32,
+}
+
+pub type Size2D_f32 = TypedSize2D_f32__UnknownUnit;
+
+pub type LayoutSize2D = TypedSize2D_f32__LayoutUnit;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedPoint2D_f32__UnknownUnit {
+  pub x: f32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedPoint2D_f32__LayoutUnit {
+  pub x: f32,
+  pub y: f32,
+}
+
+pub type Point2D_f32 = TypedPoint2D_f32__UnknownUnit;
+
+pub type LayoutPoint2D = TypedPoint2D_f32__LayoutUnit;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedRect_f32__UnknownUnit {
+  pub origin: TypedPoint2D_f32__UnknownUnit,
+  pub size: TypedSize2D_f32__UnknownUnit,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedRect_f32__LayoutUnit {
+  pub origin: TypedPoint2D_f32__LayoutUnit,
+  pub size: TypedSize2D_f32__LayoutUnit,
+}
+
+pub type Rect_f32 = TypedRect_f32__UnknownUnit;
+
+pub type LayoutRect = TypedRect_f32__LayoutUnit;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedTransform2D_f32__UnknownUnit__LayoutUnit {
+  pub m11: f32,
+  pub m12: f32,
+  pub m21: f32,
+  pub m22: f32,
+  pub m31: f32,
+  pub m32: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypedTransform2D_f32__LayoutUnit__UnknownUnit {
+  pub m11: f32,
+  pub m12: f32,
+  pub m21: f32,
+  pub m22: f32,
+  pub m31: f32,
+  pub m32: f32,
+}
+
+extern "C" {
+  pub fn root(
+    length_a: TypedLength_f32__UnknownUnit,
+    length_b: TypedLength_f32__LayoutUnit,
+    length_c: Length_f32,
+    length_d: LayoutLength,
+    side_offsets_a: TypedSideOffsets2D_f32__UnknownUnit,
+    side_offsets_b: TypedSideOffsets2D_f32__LayoutUnit,
+    side_offsets_c: SideOffsets2D_f32,
+    side_offsets_d: LayoutSideOffsets2D,
+    size_a: TypedSize2D_f32__UnknownUnit,
+    size_b: TypedSize2D_f32__LayoutUnit,
+    size_c: Size2D_f32,
+    size_d: LayoutSize2D,
+    point_a: TypedPoint2D_f32__UnknownUnit,
+    point_b: TypedPoint2D_f32__LayoutUnit,
+    point_c: Point2D_f32,
+    point_d: LayoutPoint2D,
+    rect_a: TypedRect_f32__UnknownUnit,
+    rect_b: TypedRect_f32__LayoutUnit,
+    rect_c: Rect_f32,
+    rect_d: LayoutRect,
+    transform_a: TypedTransform2D_f32__UnknownUnit__LayoutUnit,
+    transform_b: TypedTransform2D_f32__LayoutUnit__UnknownUnit,
+  );
+}
@@ -0,0 +1,7 @@
+typedef struct Bar Bar;
+
+struct Bar {
+  Option_Foo foo;
+};
+
+void root(Bar f);
@@ -0,0 +1,27 @@
+#include <stdint.h>
+
+#if 0
+''' '
+#endif
+
+typedef uint64_t Option_Foo;
+
+#if 0
+' '''
+#endif
+
+#if 0
+from libc.stdint cimport uint64_t
+ctypedef uint64_t Option_Foo
+#endif
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar {
+  pub foo: Option_Foo,
+}
+
+extern "C" {
+  pub fn root(f: Bar);
+}
@@ -0,0 +1,25 @@
+#include <stdint.h>
+
+#if 0
+''' '
+#endif
+
+typedef uint64_t Option_Foo;
+
+#if 0
+' '''
+#endif
+
+#if 0
+from libc.stdint cimport uint64_t
+ctypedef uint64_t Option_Foo
+#endif
+
+
+const std = @import("std");
+
+pub const Bar = extern struct {
+  foo: Option_Foo,
+};
+
+pub extern fn root(f: Bar) void;
@@ -0,0 +1,9 @@
+typedef struct Foo Foo;
+
+struct Foo {
+
+};
+
+void extra_debug_fn(void);
+
+void root(Foo a);
@@ -0,0 +1,11 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+
+}
+
+extern "C" {
+  pub fn extra_debug_fn();
+
+  pub fn root(a: Foo);
+}
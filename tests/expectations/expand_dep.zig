@@ -0,0 +1,8 @@
+const std = @import("std");
+
+pub const dep_struct = extern struct {
+  x: u32,
+  y: f64,
+};
+
+pub extern fn get_x(dep_struct: ?*const dep_struct) u32;
@@ -0,0 +1,10 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct dep_struct {
+  pub x: u32,
+  pub y: f64,
+}
+
+extern "C" {
+  pub fn get_x(dep_struct: *const dep_struct) -> u32;
+}
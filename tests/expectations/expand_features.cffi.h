@@ -0,0 +1,11 @@
+typedef struct Foo Foo;
+
+struct Foo {
+
+};
+
+void extra_debug_fn(void);
+
+void cbindgen(void);
+
+void root(Foo a);
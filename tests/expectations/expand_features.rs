@@ -0,0 +1,15 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+
+}
+
+extern "C" {
+  #[cfg(feature = "extra_headers")]
+  pub fn extra_debug_fn();
+
+  #[cfg(feature = "cbindgen")]
+  pub fn cbindgen();
+
+  pub fn root(a: Foo);
+}
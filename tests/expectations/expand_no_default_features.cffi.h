@@ -0,0 +1,7 @@
+typedef struct Foo Foo;
+
+struct Foo {
+
+};
+
+void root(Foo a);
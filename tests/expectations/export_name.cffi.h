@@ -0,0 +1,3 @@
+void do_the_thing_with_export_name(void);
+
+void do_the_thing_with_unsafe_export_name(void);
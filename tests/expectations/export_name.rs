@@ -0,0 +1,5 @@
+extern "C" {
+  pub fn do_the_thing_with_export_name();
+
+  pub fn do_the_thing_with_unsafe_export_name();
+}
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern fn do_the_thing_with_export_name() void;
+
+pub extern fn do_the_thing_with_unsafe_export_name() void;
@@ -0,0 +1,12 @@
+typedef struct Normal Normal;
+
+struct Normal {
+  int32_t x;
+  float y;
+};
+
+int32_t foo(void);
+
+void bar(Normal a);
+
+int32_t baz(void);
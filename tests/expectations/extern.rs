@@ -0,0 +1,14 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Normal {
+  pub x: i32,
+  pub y: f32,
+}
+
+extern "C" {
+  pub fn foo() -> i32;
+
+  pub fn bar(a: Normal);
+
+  pub fn baz() -> i32;
+}
@@ -0,0 +1,3 @@
+void first(void);
+
+void second(void);
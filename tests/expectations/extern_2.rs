@@ -0,0 +1,5 @@
+extern "C" {
+  pub fn first();
+
+  pub fn second();
+}
@@ -0,0 +1,5 @@
+const std = @import("std");
+
+pub extern fn first() void;
+
+pub extern fn second() void;
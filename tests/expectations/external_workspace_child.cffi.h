@@ -0,0 +1,7 @@
+typedef struct ExtType ExtType;
+
+struct ExtType {
+  uint32_t data;
+};
+
+void consume_ext(ExtType _ext);
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HALF_ONE 1.0
+
+typedef struct {
+  _Float16 half;
+  float single;
+  __float128 quad;
+} Floats;
+
+__float128 root(Floats a, _Float16 b);
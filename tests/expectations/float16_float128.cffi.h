@@ -0,0 +1,11 @@
+#define HALF_ONE ...
+
+typedef struct Floats Floats;
+
+struct Floats {
+  _Float16 half;
+  float single;
+  __float128 quad;
+};
+
+__float128 root(Floats a, _Float16 b);
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const _Float16 HALF_ONE = 1.0;
+
+struct Floats {
+  _Float16 half;
+  float single;
+  __float128 quad;
+};
+
+extern "C" {
+
+__float128 root(Floats a, _Float16 b);
+
+}  // extern "C"
@@ -0,0 +1,18 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Floats {
+  public Half half;
+  public float single;
+  public UInt128 quad;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly Half HALF_ONE = (Half)(1.0);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern UInt128 root(Floats a, Half b);
+}
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+  ctypedef float float16_t "_Float16"
+  ctypedef long double float128_t "__float128"
+
+cdef extern from *:
+
+  const float16_t HALF_ONE # = 1.0
+
+  ctypedef struct Floats:
+    float16_t half;
+    float single;
+    float128_t quad;
+
+  float128_t root(Floats a, float16_t b);
@@ -0,0 +1,13 @@
+pub const HALF_ONE: f16 = 1.0;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Floats {
+  pub half: f16,
+  pub single: f32,
+  pub quad: f128,
+}
+
+extern "C" {
+  pub fn root(a: Floats, b: f16) -> f128;
+}
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const HALF_ONE: f16 = 1.0;
+
+pub const Floats = extern struct {
+  half: f16,
+  single: f32,
+  quad: f128,
+};
+
+pub extern fn root(a: Floats, b: f16) f128;
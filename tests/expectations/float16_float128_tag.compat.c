@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HALF_ONE 1.0
+
+struct Floats {
+  _Float16 half;
+  float single;
+  __float128 quad;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__float128 root(struct Floats a, _Float16 b);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Fns {
+  public delegate* unmanaged[Cdecl]<void> noArgs;
+  public delegate* unmanaged[Cdecl]<int, void> anonymousArg;
+  public delegate* unmanaged[Cdecl]<int> returnsNumber;
+  public delegate* unmanaged[Cdecl]<int, short, sbyte> namedArgs;
+  public delegate* unmanaged[Cdecl]<int, short, long, sbyte> namedArgsWildcards;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Fns _fns);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_return();
+}
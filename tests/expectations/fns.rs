@@ -0,0 +1,15 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Fns {
+  pub noArgs: unsafe extern "C" fn(),
+  pub anonymousArg: unsafe extern "C" fn(i32),
+  pub returnsNumber: unsafe extern "C" fn() -> i32,
+  pub namedArgs: unsafe extern "C" fn(first: i32, snd: i16) -> i8,
+  pub namedArgsWildcards: unsafe extern "C" fn(_: i32, named: i16, _1: i64) -> i8,
+}
+
+extern "C" {
+  pub fn root(_fns: Fns);
+
+  pub fn no_return() -> !;
+}
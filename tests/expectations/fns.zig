@@ -0,0 +1,13 @@
+const std = @import("std");
+
+pub const Fns = extern struct {
+  noArgs: *const fn () callconv(.c) void,
+  anonymousArg: *const fn (i32) callconv(.c) void,
+  returnsNumber: *const fn () callconv(.c) i32,
+  namedArgs: *const fn (first: i32, snd: i16) callconv(.c) i8,
+  namedArgsWildcards: *const fn (_: i32, named: i16, _1: i64) callconv(.c) i8,
+};
+
+pub extern fn root(_fns: Fns) void;
+
+pub extern fn no_return() noreturn;
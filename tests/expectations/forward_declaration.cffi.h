@@ -0,0 +1,28 @@
+typedef struct StructInfo StructInfo;
+typedef struct TypeData TypeData;
+typedef struct TypeInfo TypeInfo;
+
+struct StructInfo {
+  const TypeInfo *const *fields;
+  uintptr_t num_fields;
+};
+
+typedef enum TypeData_Tag {
+  Primitive,
+  Struct,
+} TypeData_Tag;
+
+struct TypeData {
+  TypeData_Tag tag;
+  union {
+    struct {
+      StructInfo struct_;
+    };
+  };
+};
+
+struct TypeInfo {
+  TypeData data;
+};
+
+void root(TypeInfo x);
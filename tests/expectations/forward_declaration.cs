@@ -0,0 +1,62 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct StructInfo {
+  public TypeInfo** fields;
+  public nuint num_fields;
+}
+
+public enum TypeData_Tag {
+  Primitive,
+  Struct,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypeData {
+  public TypeData_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Struct_Body struct_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Struct_Body {
+    public StructInfo struct_;
+  }
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TypeInfo {
+  public TypeData data;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(TypeInfo x);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
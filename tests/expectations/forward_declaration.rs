@@ -0,0 +1,44 @@
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+/* ANONYMOUS STRUCTS DO NOT SUPPORT FORWARD DECLARATIONS!
+#endif
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StructInfo {
+  pub fields: *const *const TypeInfo,
+  pub num_fields: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum TypeData {
+  Primitive,
+  Struct(StructInfo),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TypeInfo {
+  pub data: TypeData,
+}
+
+extern "C" {
+  pub fn root(x: TypeInfo);
+}
+
+#if 0
+''' '
+#endif
+#if defined(CBINDGEN_STYLE_TYPE)
+*/
+#endif
+#if 0
+' '''
+#endif
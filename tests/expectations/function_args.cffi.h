@@ -0,0 +1,5 @@
+void unnamed(const uint64_t*);
+
+void pointer_test(const uint64_t *a);
+
+void print_from_rust(void);
@@ -0,0 +1,7 @@
+extern "C" {
+  pub fn unnamed(_: *const u64);
+
+  pub fn pointer_test(a: *const u64);
+
+  pub fn print_from_rust();
+}
@@ -0,0 +1,9 @@
+typedef struct Example Example;
+
+struct Example {
+  void (*f)(uintptr_t, uintptr_t);
+};
+
+void loop_forever(void);
+
+uint8_t normal_return(Example arg, void (*other)(uint8_t));
@@ -0,0 +1,26 @@
+using System;
+using System.Runtime.InteropServices;
+#ifndef NO_RETURN_ATTR
+  #ifdef __GNUC__
+    #define NO_RETURN_ATTR __attribute__ ((noreturn))
+  #else // __GNUC__
+    #define NO_RETURN_ATTR
+  #endif // __GNUC__
+#endif // NO_RETURN_ATTR
+
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Example {
+  public delegate* unmanaged[Cdecl]<nuint, nuint, void> f;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void loop_forever();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern byte normal_return(Example arg,
+                                          delegate* unmanaged[Cdecl]<byte, void> other);
+}
@@ -0,0 +1,20 @@
+#ifndef NO_RETURN_ATTR
+  #ifdef __GNUC__
+    #define NO_RETURN_ATTR __attribute__ ((noreturn))
+  #else // __GNUC__
+    #define NO_RETURN_ATTR
+  #endif // __GNUC__
+#endif // NO_RETURN_ATTR
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Example {
+  pub f: unsafe extern "C" fn(usize, usize) -> !,
+}
+
+extern "C" {
+  pub fn loop_forever() -> !;
+
+  pub fn normal_return(arg: Example, other: unsafe extern "C" fn(u8) -> !) -> u8;
+}
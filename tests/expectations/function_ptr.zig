@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub const MyCallback = ?*const fn (a: usize, b: usize) callconv(.c) void;
+
+pub const MyOtherCallback = ?*const fn (a: usize, lot: usize, of: usize, args: usize, and_then_some: usize) callconv(.c) void;
+
+pub extern fn my_function(a: MyCallback, b: MyOtherCallback) void;
@@ -0,0 +1,7 @@
+void A(void);
+
+void B(void);
+
+void C(void);
+
+void D(void);
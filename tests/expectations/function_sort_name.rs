@@ -0,0 +1,9 @@
+extern "C" {
+  pub fn A();
+
+  pub fn B();
+
+  pub fn C();
+
+  pub fn D();
+}
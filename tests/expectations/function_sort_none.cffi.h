@@ -0,0 +1,7 @@
+void C(void);
+
+void B(void);
+
+void D(void);
+
+void A(void);
@@ -0,0 +1,9 @@
+extern "C" {
+  pub fn C();
+
+  pub fn B();
+
+  pub fn D();
+
+  pub fn A();
+}
@@ -0,0 +1,23 @@
+typedef struct Bar_i32__u32 Bar_i32__u32;
+typedef struct NeverUsedWithDefault_i32 NeverUsedWithDefault_i32;
+
+typedef int16_t Foo_i16;
+
+typedef int32_t Foo_i32;
+
+struct Bar_i32__u32 {
+  Foo_i32 f;
+  uint32_t p;
+};
+
+typedef int64_t Foo_i64;
+
+typedef Foo_i64 Baz_i64;
+
+struct NeverUsedWithDefault_i32 {
+  int32_t field;
+};
+
+void foo_root(Foo_i16 f, Bar_i32__u32 b, Baz_i64 z);
+
+void with_i32(NeverUsedWithDefault_i32 x);
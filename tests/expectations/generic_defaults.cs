@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar_i32__u32 {
+  public int f;
+  public uint p;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct NeverUsedWithDefault_i32 {
+  public int field;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo_root(short f, Bar_i32__u32 b, long z);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void with_i32(NeverUsedWithDefault_i32 x);
+}
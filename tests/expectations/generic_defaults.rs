@@ -0,0 +1,26 @@
+pub type Foo_i16 = i16;
+
+pub type Foo_i32 = i32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar_i32__u32 {
+  pub f: Foo_i32,
+  pub p: u32,
+}
+
+pub type Foo_i64 = i64;
+
+pub type Baz_i64 = Foo_i64;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NeverUsedWithDefault_i32 {
+  pub field: i32,
+}
+
+extern "C" {
+  pub fn foo_root(f: Foo_i16, b: Bar_i32__u32, z: Baz_i64);
+
+  pub fn with_i32(x: NeverUsedWithDefault_i32);
+}
@@ -0,0 +1,22 @@
+const std = @import("std");
+
+pub const Foo_i16 = i16;
+
+pub const Foo_i32 = i32;
+
+pub const Bar_i32__u32 = extern struct {
+  f: Foo_i32,
+  p: u32,
+};
+
+pub const Foo_i64 = i64;
+
+pub const Baz_i64 = Foo_i64;
+
+pub const NeverUsedWithDefault_i32 = extern struct {
+  field: i32,
+};
+
+pub extern fn foo_root(f: Foo_i16, b: Bar_i32__u32, z: Baz_i64) void;
+
+pub extern fn with_i32(x: NeverUsedWithDefault_i32) void;
@@ -0,0 +1,16 @@
+typedef struct Foo_____u8 Foo_____u8;
+typedef struct Foo__________u8__________4 Foo__________u8__________4;
+
+struct Foo_____u8 {
+  uint8_t *a;
+};
+
+typedef Foo_____u8 Boo;
+
+struct Foo__________u8__________4 {
+  uint8_t a[4];
+};
+
+void root(Boo x);
+
+void my_function(Foo__________u8__________4 x);
@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_____u8 {
+  public byte* a;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo__________u8__________4 {
+  public fixed byte a[4];
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo_____u8 x);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void my_function(Foo__________u8__________4 x);
+}
@@ -0,0 +1,19 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_____u8 {
+  pub a: *mut u8,
+}
+
+pub type Boo = Foo_____u8;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo__________u8__________4 {
+  pub a: [u8; 4],
+}
+
+extern "C" {
+  pub fn root(x: Boo);
+
+  pub fn my_function(x: Foo__________u8__________4);
+}
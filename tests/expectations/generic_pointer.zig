@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Foo_____u8 = extern struct {
+  a: ?*u8,
+};
+
+pub const Boo = Foo_____u8;
+
+pub const Foo__________u8__________4 = extern struct {
+  a: [4]u8,
+};
+
+pub extern fn root(x: Boo) void;
+
+pub extern fn my_function(x: Foo__________u8__________4) void;
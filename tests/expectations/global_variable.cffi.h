@@ -0,0 +1,3 @@
+char MUT_GLOBAL_ARRAY[128];
+
+const char CONST_GLOBAL_ARRAY[128];
@@ -0,0 +1,10 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  // `MUT_GLOBAL_ARRAY` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  // `CONST_GLOBAL_ARRAY` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+}
@@ -0,0 +1,5 @@
+extern "C" {
+  pub static mut MUT_GLOBAL_ARRAY: [::core::ffi::c_char; 128];
+
+  pub static CONST_GLOBAL_ARRAY: [::core::ffi::c_char; 128];
+}
@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const uint NO_IGNORE_CONST = 0;
+
+  public const uint NoIgnoreStructWithImpl_NO_IGNORE_INNER_CONST = 0;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_ignore_root();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_ignore_associated_method();
+}
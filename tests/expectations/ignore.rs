@@ -0,0 +1,9 @@
+pub const NO_IGNORE_CONST: u32 = 0;
+
+pub const NoIgnoreStructWithImpl_NO_IGNORE_INNER_CONST: u32 = 0;
+
+extern "C" {
+  pub fn no_ignore_root();
+
+  pub fn no_ignore_associated_method();
+}
@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const NO_IGNORE_CONST: u32 = 0;
+
+pub const NoIgnoreStructWithImpl_NO_IGNORE_INNER_CONST: u32 = 0;
+
+pub extern fn no_ignore_root() void;
+
+pub extern fn no_ignore_associated_method() void;
@@ -0,0 +1,9 @@
+typedef struct DummyStruct DummyStruct;
+
+struct DummyStruct {
+  int32_t dummy_field;
+};
+
+DummyStruct new_dummy(void);
+
+DummyStruct new_dummy_param(int32_t dummy_field);
@@ -0,0 +1,11 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DummyStruct {
+  pub dummy_field: i32,
+}
+
+extern "C" {
+  pub fn new_dummy() -> DummyStruct;
+
+  pub fn new_dummy_param(dummy_field: i32) -> DummyStruct;
+}
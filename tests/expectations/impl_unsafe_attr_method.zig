@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub const DummyStruct = extern struct {
+  dummy_field: i32,
+};
+
+pub extern fn new_dummy() DummyStruct;
+
+pub extern fn new_dummy_param(dummy_field: i32) DummyStruct;
@@ -0,0 +1,11 @@
+typedef struct A A;
+typedef struct B B;
+
+struct A {
+  int32_t x;
+  float y;
+};
+
+struct B {
+  A data;
+};
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct A {
+  public int x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct B {
+  public A data;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct A {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct B {
+  pub data: A,
+}
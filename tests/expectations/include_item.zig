@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const A = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub const B = extern struct {
+  data: A,
+};
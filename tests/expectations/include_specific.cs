@@ -0,0 +1,3 @@
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
@@ -0,0 +1,7 @@
+typedef struct Foo Foo;
+
+struct Foo {
+  float x;
+};
+
+void root(Foo a);
@@ -0,0 +1,9 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: f32,
+}
+
+extern "C" {
+  pub fn root(a: Foo);
+}
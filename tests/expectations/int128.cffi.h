@@ -0,0 +1,36 @@
+#define HASH_SEED 1512366075204170947332355369683137040
+
+#define SMALL 42
+
+#define LOWEST -170141183460469231731687303715884105728
+
+#define HIGHEST 170141183460469231731687303715884105727
+
+#define ALL_ONES -1
+
+#define HIGH_HALF -18446744073709551616
+
+typedef struct Hash Hash;
+
+enum Wide {
+  Small = 1,
+  Large = 18446744073709551615,
+};
+typedef unsigned __int128 Wide;
+
+enum SignedWide {
+  Negative = -1,
+  Zero,
+};
+typedef __int128 SignedWide;
+
+struct Hash {
+  uint64_t low;
+  uint64_t high;
+  unsigned __int128 full;
+  __int128 signed_;
+};
+
+unsigned __int128 hash(const uint8_t *data, uintptr_t len, unsigned __int128 seed);
+
+void root(Hash a, Wide b, SignedWide c, __int128 d);
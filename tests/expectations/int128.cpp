@@ -0,0 +1,42 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const unsigned __int128 HASH_SEED = (((unsigned __int128)81985529216486895ull << 64) | 18364758544493064720ull);
+
+constexpr static const unsigned __int128 SMALL = 42;
+
+constexpr static const __int128 LOWEST = (__int128)(((unsigned __int128)9223372036854775808ull << 64) | 0ull);
+
+constexpr static const __int128 HIGHEST = (__int128)(((unsigned __int128)9223372036854775807ull << 64) | 18446744073709551615ull);
+
+constexpr static const unsigned __int128 ALL_ONES = (((unsigned __int128)18446744073709551615ull << 64) | 18446744073709551615ull);
+
+constexpr static const unsigned __int128 HIGH_HALF = (ALL_ONES << 64);
+
+enum class Wide : unsigned __int128 {
+  Small = 1,
+  Large = 18446744073709551615ull,
+};
+
+enum class SignedWide : __int128 {
+  Negative = -1,
+  Zero,
+};
+
+struct Hash {
+  uint64_t low;
+  uint64_t high;
+  unsigned __int128 full;
+  __int128 signed_;
+};
+
+extern "C" {
+
+unsigned __int128 hash(const uint8_t *data, uintptr_t len, unsigned __int128 seed);
+
+void root(Hash a, Wide b, SignedWide c, __int128 d);
+
+}  // extern "C"
@@ -0,0 +1,42 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Wide : ulong {
+  Small = 1,
+  Large = 18446744073709551615UL,
+}
+
+public enum SignedWide : long {
+  Negative = -1,
+  Zero,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Hash {
+  public ulong low;
+  public ulong high;
+  public UInt128 full;
+  public Int128 signed_;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly UInt128 HASH_SEED = (UInt128)(((unchecked((UInt128)(81985529216486895UL)) << 64) | 18364758544493064720UL));
+
+  public static readonly UInt128 SMALL = (UInt128)(42);
+
+  public static readonly Int128 LOWEST = (Int128)(unchecked((Int128)(((unchecked((UInt128)(9223372036854775808UL)) << 64) | 0UL))));
+
+  public static readonly Int128 HIGHEST = (Int128)(unchecked((Int128)(((unchecked((UInt128)(9223372036854775807UL)) << 64) | 18446744073709551615UL))));
+
+  public static readonly UInt128 ALL_ONES = (UInt128)(((unchecked((UInt128)(18446744073709551615UL)) << 64) | 18446744073709551615UL));
+
+  public static readonly UInt128 HIGH_HALF = (UInt128)((NativeMethods.ALL_ONES << 64));
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern UInt128 hash(byte* data, nuint len, UInt128 seed);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Hash a, Wide b, SignedWide c, Int128 d);
+}
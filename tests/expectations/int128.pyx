@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+  ctypedef struct uint128_t "unsigned __int128":
+    pass
+  ctypedef struct int128_t "__int128":
+    pass
+
+cdef extern from *:
+
+  const uint128_t HASH_SEED # = ((<uint128_t>81985529216486895ull << 64) | 18364758544493064720ull)
+
+  const uint128_t SMALL # = 42
+
+  const int128_t LOWEST # = <int128_t>((<uint128_t>9223372036854775808ull << 64) | 0ull)
+
+  const int128_t HIGHEST # = <int128_t>((<uint128_t>9223372036854775807ull << 64) | 18446744073709551615ull)
+
+  const uint128_t ALL_ONES # = ((<uint128_t>18446744073709551615ull << 64) | 18446744073709551615ull)
+
+  const uint128_t HIGH_HALF # = (ALL_ONES << 64)
+
+  cdef enum:
+    Small # = 1,
+    Large # = 18446744073709551615ull,
+  ctypedef uint128_t Wide;
+
+  cdef enum:
+    Negative # = -1,
+    Zero,
+  ctypedef int128_t SignedWide;
+
+  ctypedef struct Hash:
+    uint64_t low;
+    uint64_t high;
+    uint128_t full;
+    int128_t signed_;
+
+  uint128_t hash(const uint8_t *data, uintptr_t len, uint128_t seed);
+
+  void root(Hash a, Wide b, SignedWide c, int128_t d);
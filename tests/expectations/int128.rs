@@ -0,0 +1,40 @@
+pub const HASH_SEED: u128 = 1512366075204170947332355369683137040;
+
+pub const SMALL: u128 = 42;
+
+pub const LOWEST: i128 = -170141183460469231731687303715884105728;
+
+pub const HIGHEST: i128 = 170141183460469231731687303715884105727;
+
+pub const ALL_ONES: u128 = 340282366920938463463374607431768211455;
+
+pub const HIGH_HALF: u128 = 340282366920938463444927863358058659840;
+
+#[repr(u128)]
+#[derive(Clone, Copy)]
+pub enum Wide {
+  Small = 1,
+  Large = 18446744073709551615,
+}
+
+#[repr(i128)]
+#[derive(Clone, Copy)]
+pub enum SignedWide {
+  Negative = -1,
+  Zero,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Hash {
+  pub low: u64,
+  pub high: u64,
+  pub full: u128,
+  pub signed_: i128,
+}
+
+extern "C" {
+  pub fn hash(data: *const u8, len: usize, seed: u128) -> u128;
+
+  pub fn root(a: Hash, b: Wide, c: SignedWide, d: i128);
+}
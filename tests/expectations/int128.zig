@@ -0,0 +1,34 @@
+const std = @import("std");
+
+pub const HASH_SEED: u128 = ((@as(u128, 81985529216486895) << 64) | 18364758544493064720);
+
+pub const SMALL: u128 = 42;
+
+pub const LOWEST: i128 = @as(i128, -170141183460469231731687303715884105728);
+
+pub const HIGHEST: i128 = @as(i128, 170141183460469231731687303715884105727);
+
+pub const ALL_ONES: u128 = ((@as(u128, 18446744073709551615) << 64) | 18446744073709551615);
+
+pub const HIGH_HALF: u128 = (ALL_ONES << 64);
+
+pub const Wide = enum(u128) {
+  Small = 1,
+  Large = 18446744073709551615,
+};
+
+pub const SignedWide = enum(i128) {
+  Negative = -1,
+  Zero,
+};
+
+pub const Hash = extern struct {
+  low: u64,
+  high: u64,
+  full: u128,
+  signed_: i128,
+};
+
+pub extern fn hash(data: ?*const u8, len: usize, seed: u128) u128;
+
+pub extern fn root(a: Hash, b: Wide, c: SignedWide, d: i128) void;
@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HASH_SEED (((unsigned __int128)81985529216486895ull << 64) | 18364758544493064720ull)
+
+#define SMALL 42
+
+#define LOWEST (__int128)(((unsigned __int128)9223372036854775808ull << 64) | 0ull)
+
+#define HIGHEST (__int128)(((unsigned __int128)9223372036854775807ull << 64) | 18446744073709551615ull)
+
+#define ALL_ONES (((unsigned __int128)18446744073709551615ull << 64) | 18446744073709551615ull)
+
+#define HIGH_HALF (ALL_ONES << 64)
+
+enum Wide
+#if __STDC_VERSION__ >= 202311L
+  : unsigned __int128
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Small = 1,
+  Large = 18446744073709551615ull,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Wide Wide;
+#else
+typedef unsigned __int128 Wide;
+#endif // __STDC_VERSION__ >= 202311L
+
+enum SignedWide
+#if __STDC_VERSION__ >= 202311L
+  : __int128
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Negative = -1,
+  Zero,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum SignedWide SignedWide;
+#else
+typedef __int128 SignedWide;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct Hash {
+  uint64_t low;
+  uint64_t high;
+  unsigned __int128 full;
+  __int128 signed_;
+} Hash;
+
+unsigned __int128 hash(const uint8_t *data, uintptr_t len, unsigned __int128 seed);
+
+void root(struct Hash a, Wide b, SignedWide c, __int128 d);
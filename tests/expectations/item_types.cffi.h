@@ -0,0 +1,5 @@
+enum OnlyThisShouldBeGenerated {
+  Foo,
+  Bar,
+};
+typedef uint8_t OnlyThisShouldBeGenerated;
@@ -0,0 +1,5 @@
+enum StyleOnlyThisShouldBeGenerated {
+  Foo,
+  Bar,
+};
+typedef uint8_t StyleOnlyThisShouldBeGenerated;
@@ -0,0 +1,11 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum StyleOnlyThisShouldBeGenerated : byte {
+  Foo,
+  Bar,
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
@@ -0,0 +1,6 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum StyleOnlyThisShouldBeGenerated {
+  Foo,
+  Bar,
+}
@@ -0,0 +1,6 @@
+const std = @import("std");
+
+pub const StyleOnlyThisShouldBeGenerated = enum(u8) {
+  Foo,
+  Bar,
+};
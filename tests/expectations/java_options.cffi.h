@@ -0,0 +1,14 @@
+#define ORIGIN_ID 4294967295
+
+#define DIMENSIONS 2
+
+typedef struct Point Point;
+
+struct Point {
+  float x;
+  float y;
+};
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
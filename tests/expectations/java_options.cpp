@@ -0,0 +1,32 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t ORIGIN_ID = 4294967295;
+
+#if !defined(DEFINE_Z)
+constexpr static const uint8_t DIMENSIONS = 2;
+#endif
+
+#if defined(DEFINE_Z)
+constexpr static const uint8_t DIMENSIONS = 3;
+#endif
+
+struct Point {
+  float x;
+  float y;
+#if defined(DEFINE_Z)
+  float z
+#endif
+  ;
+};
+
+extern "C" {
+
+bool point_is_origin(const Point *p);
+
+float point_length(Point p);
+
+}  // extern "C"
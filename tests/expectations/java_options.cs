@@ -0,0 +1,32 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public float x;
+  public float y;
+#if DEFINE_Z
+  public float z;
+#endif
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const uint ORIGIN_ID = 4294967295;
+
+#if !DEFINE_Z
+  public const byte DIMENSIONS = 2;
+#endif
+
+#if DEFINE_Z
+  public const byte DIMENSIONS = 3;
+#endif
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  [return: MarshalAs(UnmanagedType.U1)]
+  public static extern bool point_is_origin(Point* p);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern float point_length(Point p);
+}
@@ -0,0 +1,22 @@
+pub const ORIGIN_ID: u32 = 4294967295;
+
+#[cfg(not(feature = "z"))]
+pub const DIMENSIONS: u8 = 2;
+
+#[cfg(feature = "z")]
+pub const DIMENSIONS: u8 = 3;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: f32,
+  pub y: f32,
+  #[cfg(feature = "z")]
+  pub z: f32,
+}
+
+extern "C" {
+  pub fn point_is_origin(p: *const Point) -> bool;
+
+  pub fn point_length(p: Point) -> f32;
+}
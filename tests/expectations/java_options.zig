@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const ORIGIN_ID: u32 = 4294967295;
+
+// #if !defined(DEFINE_Z)
+pub const DIMENSIONS: u8 = 2;
+// #endif
+
+// #if defined(DEFINE_Z)
+pub const DIMENSIONS: u8 = 3;
+// #endif
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+  // #if defined(DEFINE_Z)
+  z: f32,
+  // #endif
+};
+
+pub extern fn point_is_origin(p: ?*const Point) bool;
+
+pub extern fn point_length(p: Point) f32;
@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 4294967295
+
+#if !defined(DEFINE_Z)
+#define DIMENSIONS 2
+#endif
+
+#if defined(DEFINE_Z)
+#define DIMENSIONS 3
+#endif
+
+typedef struct Point {
+  float x;
+  float y;
+#if defined(DEFINE_Z)
+  float z
+#endif
+  ;
+} Point;
+
+bool point_is_origin(const struct Point *p);
+
+float point_length(struct Point p);
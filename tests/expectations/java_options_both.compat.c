@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ORIGIN_ID 4294967295
+
+#if !defined(DEFINE_Z)
+#define DIMENSIONS 2
+#endif
+
+#if defined(DEFINE_Z)
+#define DIMENSIONS 3
+#endif
+
+typedef struct Point {
+  float x;
+  float y;
+#if defined(DEFINE_Z)
+  float z
+#endif
+  ;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool point_is_origin(const struct Point *p);
+
+float point_length(struct Point p);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
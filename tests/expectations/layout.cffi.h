@@ -0,0 +1,52 @@
+typedef struct Align1Struct Align1Struct;
+typedef struct Align2Struct Align2Struct;
+typedef struct Align4Struct Align4Struct;
+typedef struct Align8Struct Align8Struct;
+typedef struct Align32Struct Align32Struct;
+typedef struct PackedStruct PackedStruct;
+typedef union Align1Union Align1Union;
+typedef union Align4Union Align4Union;
+typedef union Align16Union Align16Union;
+typedef union PackedUnion PackedUnion;
+typedef struct Packed1Struct Packed1Struct;
+typedef struct Packed2Struct Packed2Struct;
+typedef struct Packed4Struct Packed4Struct;
+typedef union Packed4Union Packed4Union;
+
+typedef struct RustAlign4Struct RustAlign4Struct;
+
+typedef struct RustAlign4Union RustAlign4Union;
+
+typedef struct RustPackedStruct RustPackedStruct;
+
+typedef struct RustPackedUnion RustPackedUnion;
+
+typedef struct UnsupportedAlign4Enum UnsupportedAlign4Enum;
+
+// Align1Struct is packed or aligned, which cffi can't declare.
+
+// Align2Struct is packed or aligned, which cffi can't declare.
+
+// Align4Struct is packed or aligned, which cffi can't declare.
+
+// Align8Struct is packed or aligned, which cffi can't declare.
+
+// Align32Struct is packed or aligned, which cffi can't declare.
+
+// PackedStruct is packed or aligned, which cffi can't declare.
+
+// Align1Union is packed or aligned, which cffi can't declare.
+
+// Align4Union is packed or aligned, which cffi can't declare.
+
+// Align16Union is packed or aligned, which cffi can't declare.
+
+// PackedUnion is packed or aligned, which cffi can't declare.
+
+// Packed1Struct is packed or aligned, which cffi can't declare.
+
+// Packed2Struct is packed or aligned, which cffi can't declare.
+
+// Packed4Struct is packed or aligned, which cffi can't declare.
+
+// Packed4Union is packed or aligned, which cffi can't declare.
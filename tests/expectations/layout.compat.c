@@ -17,10 +17,6 @@ typedef struct RustPackedUnion RustPackedUnion;
 
 typedef struct UnsupportedAlign4Enum UnsupportedAlign4Enum;
 
-typedef struct UnsupportedPacked4Struct UnsupportedPacked4Struct;
-
-typedef struct UnsupportedPacked4Union UnsupportedPacked4Union;
-
 typedef struct CBINDGEN_ALIGNED(1) {
   uintptr_t arg1;
   uint8_t *arg2;
@@ -70,3 +66,30 @@ typedef union CBINDGEN_PACKED {
   uintptr_t variant1;
   uint8_t *variant2;
 } PackedUnion;
+
+typedef struct CBINDGEN_PACKED {
+  uint8_t arg1;
+  uint32_t arg2;
+} Packed1Struct;
+
+#pragma pack(push, 2)
+typedef struct {
+  uint8_t arg1;
+  uint32_t arg2;
+  uint16_t arg3;
+} Packed2Struct;
+#pragma pack(pop)
+
+#pragma pack(push, 4)
+typedef struct {
+  uintptr_t arg1;
+  uint8_t *arg2;
+} Packed4Struct;
+#pragma pack(pop)
+
+#pragma pack(push, 4)
+typedef union {
+  uintptr_t variant1;
+  uint8_t *variant2;
+} Packed4Union;
+#pragma pack(pop)
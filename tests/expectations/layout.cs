@@ -0,0 +1,105 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct RustAlign4Struct { }
+
+public partial struct RustAlign4Union { }
+
+public partial struct RustPackedStruct { }
+
+public partial struct RustPackedUnion { }
+
+public partial struct UnsupportedAlign4Enum { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align1Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align2Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align4Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align8Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align32Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential, Pack = 1)]
+public unsafe partial struct PackedStruct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align1Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align4Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align16Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Explicit, Pack = 1)]
+public unsafe partial struct PackedUnion {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Sequential, Pack = 1)]
+public unsafe partial struct Packed1Struct {
+  public byte arg1;
+  public uint arg2;
+}
+
+[StructLayout(LayoutKind.Sequential, Pack = 2)]
+public unsafe partial struct Packed2Struct {
+  public byte arg1;
+  public uint arg2;
+  public ushort arg3;
+}
+
+[StructLayout(LayoutKind.Sequential, Pack = 4)]
+public unsafe partial struct Packed4Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Explicit, Pack = 4)]
+public unsafe partial struct Packed4Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
@@ -25,48 +25,46 @@ cdef extern from *:
   ctypedef struct UnsupportedAlign4Enum:
     pass
 
-  ctypedef struct UnsupportedPacked4Struct:
-    pass
-
-  ctypedef struct UnsupportedPacked4Union:
-    pass
-
   ctypedef struct Align1Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align2Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align4Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align8Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align32Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef packed struct PackedStruct:
     uintptr_t arg1;
     uint8_t *arg2;
 
   ctypedef union Align1Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef union Align4Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef union Align16Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef union PackedUnion:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
+
+  ctypedef packed struct Packed1Struct:
+    uint8_t arg1;
+    uint32_t arg2;
+
+  ctypedef struct Packed2Struct:
+    pass
+
+  ctypedef struct Packed4Struct:
+    pass
+
+  ctypedef union Packed4Union:
+    pass
@@ -0,0 +1,86 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const RustAlign4Struct = opaque {};
+
+pub const RustAlign4Union = opaque {};
+
+pub const RustPackedStruct = opaque {};
+
+pub const RustPackedUnion = opaque {};
+
+pub const UnsupportedAlign4Enum = opaque {};
+
+pub const Align1Struct = extern struct {
+  arg1: usize align(1),
+  arg2: ?*u8,
+};
+
+pub const Align2Struct = extern struct {
+  arg1: usize align(2),
+  arg2: ?*u8,
+};
+
+pub const Align4Struct = extern struct {
+  arg1: usize align(4),
+  arg2: ?*u8,
+};
+
+pub const Align8Struct = extern struct {
+  arg1: usize align(8),
+  arg2: ?*u8,
+};
+
+pub const Align32Struct = extern struct {
+  arg1: usize align(32),
+  arg2: ?*u8,
+};
+
+pub const PackedStruct = extern struct {
+  arg1: usize align(1),
+  arg2: ?*u8 align(1),
+};
+
+pub const Align1Union = extern union {
+  variant1: usize align(1),
+  variant2: ?*u8,
+};
+
+pub const Align4Union = extern union {
+  variant1: usize align(4),
+  variant2: ?*u8,
+};
+
+pub const Align16Union = extern union {
+  variant1: usize align(16),
+  variant2: ?*u8,
+};
+
+pub const PackedUnion = extern union {
+  variant1: usize align(1),
+  variant2: ?*u8 align(1),
+};
+
+pub const Packed1Struct = extern struct {
+  arg1: u8 align(1),
+  arg2: u32 align(1),
+};
+
+pub const Packed2Struct = extern struct {
+  arg1: u8 align(@min(@alignOf(u8), 2)),
+  arg2: u32 align(@min(@alignOf(u32), 2)),
+  arg3: u16 align(@min(@alignOf(u16), 2)),
+};
+
+pub const Packed4Struct = extern struct {
+  arg1: usize align(@min(@alignOf(usize), 4)),
+  arg2: ?*u8 align(@min(@alignOf(?*u8), 4)),
+};
+
+pub const Packed4Union = extern union {
+  variant1: usize align(@min(@alignOf(usize), 4)),
+  variant2: ?*u8 align(@min(@alignOf(?*u8), 4)),
+};
@@ -0,0 +1,22 @@
+typedef struct PackedStruct PackedStruct;
+typedef union PackedUnion PackedUnion;
+
+typedef struct OpaqueAlign16Union OpaqueAlign16Union;
+
+typedef struct OpaqueAlign1Struct OpaqueAlign1Struct;
+
+typedef struct OpaqueAlign1Union OpaqueAlign1Union;
+
+typedef struct OpaqueAlign2Struct OpaqueAlign2Struct;
+
+typedef struct OpaqueAlign32Struct OpaqueAlign32Struct;
+
+typedef struct OpaqueAlign4Struct OpaqueAlign4Struct;
+
+typedef struct OpaqueAlign4Union OpaqueAlign4Union;
+
+typedef struct OpaqueAlign8Struct OpaqueAlign8Struct;
+
+// PackedStruct is packed or aligned, which cffi can't declare.
+
+// PackedUnion is packed or aligned, which cffi can't declare.
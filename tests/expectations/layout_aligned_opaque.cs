@@ -0,0 +1,38 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct OpaqueAlign16Union { }
+
+public partial struct OpaqueAlign1Struct { }
+
+public partial struct OpaqueAlign1Union { }
+
+public partial struct OpaqueAlign2Struct { }
+
+public partial struct OpaqueAlign32Struct { }
+
+public partial struct OpaqueAlign4Struct { }
+
+public partial struct OpaqueAlign4Union { }
+
+public partial struct OpaqueAlign8Struct { }
+
+[StructLayout(LayoutKind.Sequential, Pack = 1)]
+public unsafe partial struct PackedStruct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Explicit, Pack = 1)]
+public unsafe partial struct PackedUnion {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
@@ -39,5 +39,4 @@ cdef extern from *:
     uint8_t *arg2;
 
   ctypedef union PackedUnion:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
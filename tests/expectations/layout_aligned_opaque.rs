@@ -0,0 +1,65 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#[repr(C)]
+pub struct OpaqueAlign16Union {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign1Struct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign1Union {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign2Struct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign32Struct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign4Struct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign4Union {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaqueAlign8Struct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct PackedStruct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub union PackedUnion {
+  pub variant1: usize,
+  pub variant2: *mut u8,
+}
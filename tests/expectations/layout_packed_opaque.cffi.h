@@ -0,0 +1,28 @@
+typedef union Align1Union Align1Union;
+typedef union Align4Union Align4Union;
+typedef union Align16Union Align16Union;
+typedef struct Align1Struct Align1Struct;
+typedef struct Align2Struct Align2Struct;
+typedef struct Align4Struct Align4Struct;
+typedef struct Align8Struct Align8Struct;
+typedef struct Align32Struct Align32Struct;
+
+typedef struct OpaquePackedStruct OpaquePackedStruct;
+
+typedef struct OpaquePackedUnion OpaquePackedUnion;
+
+// Align1Union is packed or aligned, which cffi can't declare.
+
+// Align4Union is packed or aligned, which cffi can't declare.
+
+// Align16Union is packed or aligned, which cffi can't declare.
+
+// Align1Struct is packed or aligned, which cffi can't declare.
+
+// Align2Struct is packed or aligned, which cffi can't declare.
+
+// Align4Struct is packed or aligned, which cffi can't declare.
+
+// Align8Struct is packed or aligned, which cffi can't declare.
+
+// Align32Struct is packed or aligned, which cffi can't declare.
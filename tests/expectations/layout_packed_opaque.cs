@@ -0,0 +1,62 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct OpaquePackedStruct { }
+
+public partial struct OpaquePackedUnion { }
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align1Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align4Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Align16Union {
+  [FieldOffset(0)] public nuint variant1;
+  [FieldOffset(0)] public byte* variant2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align1Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align2Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align4Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align8Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Align32Struct {
+  public nuint arg1;
+  public byte* arg2;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+}
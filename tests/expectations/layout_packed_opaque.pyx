@@ -17,33 +17,25 @@ cdef extern from *:
     pass
 
   ctypedef union Align1Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef union Align4Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef union Align16Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   ctypedef struct Align1Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align2Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align4Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align8Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   ctypedef struct Align32Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
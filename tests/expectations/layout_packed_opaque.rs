@@ -0,0 +1,71 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#[repr(C)]
+pub struct OpaquePackedStruct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct OpaquePackedUnion {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, align(1))]
+#[derive(Clone, Copy)]
+pub union Align1Union {
+  pub variant1: usize,
+  pub variant2: *mut u8,
+}
+
+#[repr(C, align(4))]
+#[derive(Clone, Copy)]
+pub union Align4Union {
+  pub variant1: usize,
+  pub variant2: *mut u8,
+}
+
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub union Align16Union {
+  pub variant1: usize,
+  pub variant2: *mut u8,
+}
+
+#[repr(C, align(1))]
+#[derive(Clone, Copy)]
+pub struct Align1Struct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
+
+#[repr(C, align(2))]
+#[derive(Clone, Copy)]
+pub struct Align2Struct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
+
+#[repr(C, align(4))]
+#[derive(Clone, Copy)]
+pub struct Align4Struct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct Align8Struct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
+
+#[repr(C, align(32))]
+#[derive(Clone, Copy)]
+pub struct Align32Struct {
+  pub arg1: usize,
+  pub arg2: *mut u8,
+}
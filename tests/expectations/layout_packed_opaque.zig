@@ -0,0 +1,49 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+const std = @import("std");
+
+pub const OpaquePackedStruct = opaque {};
+
+pub const OpaquePackedUnion = opaque {};
+
+pub const Align1Union = extern union {
+  variant1: usize align(1),
+  variant2: ?*u8,
+};
+
+pub const Align4Union = extern union {
+  variant1: usize align(4),
+  variant2: ?*u8,
+};
+
+pub const Align16Union = extern union {
+  variant1: usize align(16),
+  variant2: ?*u8,
+};
+
+pub const Align1Struct = extern struct {
+  arg1: usize align(1),
+  arg2: ?*u8,
+};
+
+pub const Align2Struct = extern struct {
+  arg1: usize align(2),
+  arg2: ?*u8,
+};
+
+pub const Align4Struct = extern struct {
+  arg1: usize align(4),
+  arg2: ?*u8,
+};
+
+pub const Align8Struct = extern struct {
+  arg1: usize align(8),
+  arg2: ?*u8,
+};
+
+pub const Align32Struct = extern struct {
+  arg1: usize align(32),
+  arg2: ?*u8,
+};
@@ -17,33 +17,25 @@ cdef extern from *:
     pass
 
   cdef union Align1Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef union Align4Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef union Align16Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef struct Align1Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align2Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align4Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align8Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align32Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
@@ -25,48 +25,46 @@ cdef extern from *:
   cdef struct UnsupportedAlign4Enum:
     pass
 
-  cdef struct UnsupportedPacked4Struct:
-    pass
-
-  cdef struct UnsupportedPacked4Union:
-    pass
-
   cdef struct Align1Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align2Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align4Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align8Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef struct Align32Struct:
-    uintptr_t arg1;
-    uint8_t *arg2;
+    pass
 
   cdef packed struct PackedStruct:
     uintptr_t arg1;
     uint8_t *arg2;
 
   cdef union Align1Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef union Align4Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef union Align16Union:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
 
   cdef union PackedUnion:
-    uintptr_t variant1;
-    uint8_t *variant2;
+    pass
+
+  cdef packed struct Packed1Struct:
+    uint8_t arg1;
+    uint32_t arg2;
+
+  cdef struct Packed2Struct:
+    pass
+
+  cdef struct Packed4Struct:
+    pass
+
+  cdef union Packed4Union:
+    pass
@@ -0,0 +1,22 @@
+typedef struct A A;
+typedef struct E E;
+
+struct A {
+  const int32_t *data;
+};
+
+typedef enum E_Tag {
+  V,
+  U,
+} E_Tag;
+
+struct E {
+  E_Tag tag;
+  union {
+    struct {
+      const uint8_t *u;
+    };
+  };
+};
+
+void root(A _a, E _e);
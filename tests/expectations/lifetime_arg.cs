@@ -0,0 +1,35 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct A {
+  public int* data;
+}
+
+public enum E_Tag {
+  V,
+  U,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct E {
+  public E_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public U_Body u;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct U_Body {
+    public byte* u;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A _a, E _e);
+}
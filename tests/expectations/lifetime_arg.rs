@@ -0,0 +1,16 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct A {
+  pub data: *const i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum E {
+  V,
+  U(*const u8),
+}
+
+extern "C" {
+  pub fn root(_a: A, _e: E);
+}
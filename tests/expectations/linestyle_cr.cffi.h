@@ -0,0 +1 @@
+typedef struct Dummy Dummy;struct Dummy {  int32_t x;  float y;};void root(Dummy d);
\ No newline at end of file
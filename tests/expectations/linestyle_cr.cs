@@ -0,0 +1 @@
+using System;using System.Runtime.InteropServices;[StructLayout(LayoutKind.Sequential)]public unsafe partial struct Dummy {  public int x;  public float y;}public static unsafe partial class NativeMethods {  private const string LibraryName = "native";  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]  public static extern void root(Dummy d);}
\ No newline at end of file
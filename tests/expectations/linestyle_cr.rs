@@ -0,0 +1 @@
+#[repr(C)]#[derive(Clone, Copy)]pub struct Dummy {  pub x: i32,  pub y: f32,}extern "C" {  pub fn root(d: Dummy);}
\ No newline at end of file
@@ -0,0 +1,20 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct Mutex {
+  alignas(8) unsigned char _private[8];
+} Mutex;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+Handle make_handle(void);
+
+void use_handle(Handle *handle, Point point, Mutex mutex);
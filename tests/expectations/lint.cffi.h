@@ -0,0 +1,16 @@
+typedef struct Point Point;
+
+typedef struct Handle Handle;
+
+typedef struct Mutex {
+  uint64_t _private[1];
+} Mutex;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+Handle make_handle(void);
+
+void use_handle(Handle *handle, Point point, Mutex mutex);
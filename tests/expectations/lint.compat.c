@@ -0,0 +1,28 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct Mutex {
+  alignas(8) unsigned char _private[8];
+} Mutex;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Handle make_handle(void);
+
+void use_handle(Handle *handle, Point point, Mutex mutex);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
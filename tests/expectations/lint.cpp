@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Handle;
+
+struct Mutex {
+  alignas(8) unsigned char _private[8];
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+Handle make_handle();
+
+void use_handle(Handle *handle, Point point, Mutex mutex);
+
+}  // extern "C"
@@ -0,0 +1,25 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Handle { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Mutex {
+  private fixed ulong _private[1];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public int x;
+  public int y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Handle make_handle();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void use_handle(Handle* handle, Point point, Mutex mutex);
+}
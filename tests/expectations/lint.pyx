@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Handle:
+    pass
+
+  ctypedef struct Mutex:
+    unsigned char _private[8];
+
+  ctypedef struct Point:
+    int32_t x;
+    int32_t y;
+
+  Handle make_handle();
+
+  void use_handle(Handle *handle, Point point, Mutex mutex);
@@ -0,0 +1,25 @@
+#[repr(C)]
+pub struct Handle {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct Mutex {
+  _private: [u8; 8],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: i32,
+  pub y: i32,
+}
+
+extern "C" {
+  pub fn make_handle() -> Handle;
+
+  pub fn use_handle(handle: *mut Handle, point: Point, mutex: Mutex);
+}
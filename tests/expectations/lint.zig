@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const Handle = opaque {};
+
+pub const Mutex = extern struct {
+  _private: [8]u8 align(8),
+};
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub extern fn make_handle() Handle;
+
+pub extern fn use_handle(handle: ?*Handle, point: Point, mutex: Mutex) void;
@@ -0,0 +1,20 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle;
+
+struct Mutex {
+  alignas(8) unsigned char _private[8];
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Handle make_handle(void);
+
+void use_handle(struct Handle *handle, struct Point point, struct Mutex mutex);
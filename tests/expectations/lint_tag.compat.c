@@ -0,0 +1,28 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle;
+
+struct Mutex {
+  alignas(8) unsigned char _private[8];
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Handle make_handle(void);
+
+void use_handle(struct Handle *handle, struct Point point, struct Mutex mutex);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
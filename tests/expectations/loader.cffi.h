@@ -0,0 +1,16 @@
+typedef struct Point Point;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+const uint32_t VERSION;
+
+Point ORIGIN;
+
+int32_t point_sum(Point point);
+
+void point_scale(Point *point, int32_t factor);
+
+int32_t point_norm(const Point *point);
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t VERSION;
+
+extern Point ORIGIN;
+
+/**
+ * Sums the coordinates of `point`.
+ */
+int32_t point_sum(Point point);
+
+void point_scale(Point *point, int32_t factor);
+
+#if !defined(OLD_VERSION)
+int32_t point_norm(const Point *point);
+#endif
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
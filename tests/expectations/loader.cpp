@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+extern const uint32_t VERSION;
+
+extern Point ORIGIN;
+
+/// Sums the coordinates of `point`.
+int32_t point_sum(Point point);
+
+void point_scale(Point *point, int32_t factor);
+
+#if !defined(OLD_VERSION)
+int32_t point_norm(const Point *point);
+#endif
+
+}  // extern "C"
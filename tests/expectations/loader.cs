@@ -0,0 +1,30 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public int x;
+  public int y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  // `VERSION` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  // `ORIGIN` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  /// <summary>
+  /// Sums the coordinates of `point`.
+  /// </summary>
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int point_sum(Point point);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void point_scale(Point* point, int factor);
+
+#if !OLD_VERSION
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int point_norm(Point* point);
+#endif
+}
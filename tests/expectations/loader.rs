@@ -0,0 +1,20 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: i32,
+  pub y: i32,
+}
+
+extern "C" {
+  pub static VERSION: u32;
+
+  pub static mut ORIGIN: Point;
+
+  /// Sums the coordinates of `point`.
+  pub fn point_sum(point: Point) -> i32;
+
+  pub fn point_scale(point: *mut Point, factor: i32);
+
+  #[cfg(not(old_version))]
+  pub fn point_norm(point: *const Point) -> i32;
+}
@@ -0,0 +1,19 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub extern const VERSION: u32;
+
+pub extern var ORIGIN: Point;
+
+/// Sums the coordinates of `point`.
+pub extern fn point_sum(point: Point) i32;
+
+pub extern fn point_scale(point: ?*Point, factor: i32) void;
+
+// #if !defined(OLD_VERSION)
+pub extern fn point_norm(point: *const Point) i32;
+// #endif
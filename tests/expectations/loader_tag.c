@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern const uint32_t VERSION;
+
+extern struct Point ORIGIN;
+
+/**
+ * Sums the coordinates of `point`.
+ */
+int32_t point_sum(struct Point point);
+
+void point_scale(struct Point *point, int32_t factor);
+
+#if !defined(OLD_VERSION)
+int32_t point_norm(const struct Point *point);
+#endif
@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  extern const uint32_t VERSION;
+
+  extern Point ORIGIN;
+
+  # Sums the coordinates of `point`.
+  int32_t point_sum(Point point);
+
+  void point_scale(Point *point, int32_t factor);
+
+  IF not OLD_VERSION:
+    int32_t point_norm(const Point *point);
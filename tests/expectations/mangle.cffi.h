@@ -0,0 +1,16 @@
+typedef struct FooU8 FooU8;
+
+typedef enum Bar {
+  BarSome,
+  BarThing,
+} Bar;
+
+struct FooU8 {
+  uint8_t a;
+};
+
+typedef FooU8 Boo;
+
+void root(Boo x, Bar y);
+
+void unsafe_root(Boo x, Bar y);
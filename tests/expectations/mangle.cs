@@ -0,0 +1,22 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Bar {
+  BarSome,
+  BarThing,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct FooU8 {
+  public byte a;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(FooU8 x, Bar y);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unsafe_root(FooU8 x, Bar y);
+}
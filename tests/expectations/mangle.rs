@@ -0,0 +1,20 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum Bar {
+  BarSome,
+  BarThing,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FooU8 {
+  pub a: u8,
+}
+
+pub type Boo = FooU8;
+
+extern "C" {
+  pub fn root(x: Boo, y: Bar);
+
+  pub fn unsafe_root(x: Boo, y: Bar);
+}
@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const Bar = enum(c_int) {
+  BarSome,
+  BarThing,
+};
+
+pub const FooU8 = extern struct {
+  a: u8,
+};
+
+pub const Boo = FooU8;
+
+pub extern fn root(x: Boo, y: Bar) void;
+
+pub extern fn unsafe_root(x: Boo, y: Bar) void;
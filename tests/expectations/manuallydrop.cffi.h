@@ -0,0 +1,19 @@
+typedef struct Point Point;
+typedef struct MyStruct MyStruct;
+
+typedef struct NotReprC_Point NotReprC_Point;
+
+typedef NotReprC_Point Foo;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct MyStruct {
+  Point point;
+};
+
+void root(const Foo *a, const MyStruct *with_manual_drop);
+
+void take(Point with_manual_drop);
@@ -0,0 +1,39 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using ManuallyDrop = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct NotReprC_Point { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public int x;
+  public int y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyStruct {
+  public Point point;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(NotReprC_Point* a, MyStruct* with_manual_drop);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void take(Point with_manual_drop);
+}
@@ -0,0 +1,40 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using ManuallyDrop = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+pub struct NotReprC_Point {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+pub type Foo = NotReprC_Point;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: i32,
+  pub y: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyStruct {
+  pub point: Point,
+}
+
+extern "C" {
+  pub fn root(a: *const Foo, with_manual_drop: *const MyStruct);
+
+  pub fn take(with_manual_drop: Point);
+}
@@ -0,0 +1,11 @@
+typedef struct MyStruct MyStruct;
+
+typedef struct NotReprC______i32 NotReprC______i32;
+
+typedef NotReprC______i32 Foo;
+
+struct MyStruct {
+  const int32_t *number;
+};
+
+void root(const Foo *a, const MyStruct *with_maybe_uninit);
@@ -0,0 +1,30 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using MaybeUninit = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct NotReprC______i32 { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyStruct {
+  public int* number;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(NotReprC______i32* a, MyStruct* with_maybe_uninit);
+}
@@ -0,0 +1,31 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using MaybeUninit = T;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+pub struct NotReprC______i32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+pub type Foo = NotReprC______i32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MyStruct {
+  pub number: *const i32,
+}
+
+extern "C" {
+  pub fn root(a: *const Foo, with_maybe_uninit: *const MyStruct);
+}
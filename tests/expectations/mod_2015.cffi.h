@@ -0,0 +1,11 @@
+#define EXPORT_ME_TOO 42
+
+typedef struct ExportMe ExportMe;
+
+struct ExportMe {
+  uint64_t val;
+};
+
+void export_me(ExportMe *val);
+
+void from_really_nested_mod(void);
@@ -0,0 +1,11 @@
+const std = @import("std");
+
+pub const EXPORT_ME_TOO: u8 = 42;
+
+pub const ExportMe = extern struct {
+  val: u64,
+};
+
+pub extern fn export_me(val: ?*ExportMe) void;
+
+pub extern fn from_really_nested_mod() void;
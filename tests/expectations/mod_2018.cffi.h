@@ -0,0 +1,18 @@
+#define EXPORT_ME_TOO 42
+
+typedef struct ExportMe ExportMe;
+typedef struct ExportMe2 ExportMe2;
+
+struct ExportMe {
+  uint64_t val;
+};
+
+struct ExportMe2 {
+  uint64_t val;
+};
+
+void export_me(ExportMe *val);
+
+void export_me_2(ExportMe2*);
+
+void from_really_nested_mod(void);
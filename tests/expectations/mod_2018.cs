@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ExportMe {
+  public ulong val;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ExportMe2 {
+  public ulong val;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const byte EXPORT_ME_TOO = 42;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me(ExportMe* val);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void export_me_2(ExportMe2* _0);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void from_really_nested_mod();
+}
@@ -0,0 +1,21 @@
+pub const EXPORT_ME_TOO: u8 = 42;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ExportMe {
+  pub val: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ExportMe2 {
+  pub val: u64,
+}
+
+extern "C" {
+  pub fn export_me(val: *mut ExportMe);
+
+  pub fn export_me_2(_: *mut ExportMe2);
+
+  pub fn from_really_nested_mod();
+}
@@ -0,0 +1,17 @@
+const std = @import("std");
+
+pub const EXPORT_ME_TOO: u8 = 42;
+
+pub const ExportMe = extern struct {
+  val: u64,
+};
+
+pub const ExportMe2 = extern struct {
+  val: u64,
+};
+
+pub extern fn export_me(val: ?*ExportMe) void;
+
+pub extern fn export_me_2(_: ?*ExportMe2) void;
+
+pub extern fn from_really_nested_mod() void;
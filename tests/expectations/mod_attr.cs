@@ -0,0 +1,44 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+#if FOO
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+
+}
+#endif
+
+#if BAR
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bar {
+
+}
+#endif
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+#if FOO
+  public const int FOO = 1;
+#endif
+
+#if BAR
+  public const int BAR = 2;
+#endif
+
+#if FOO
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(Foo* foo);
+#endif
+
+#if BAR
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(Bar* bar);
+#endif
+}
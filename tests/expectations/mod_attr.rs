@@ -0,0 +1,33 @@
+#if 0
+DEF FOO = 0
+DEF BAR = 0
+#endif
+
+
+#[cfg(foo)]
+pub const FOO: i32 = 1;
+
+#[cfg(all(all(feature = "foobar"), bar))]
+pub const BAR: i32 = 2;
+
+#[cfg(foo)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+
+}
+
+#[cfg(all(all(feature = "foobar"), bar))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bar {
+
+}
+
+extern "C" {
+  #[cfg(foo)]
+  pub fn foo(foo: *const Foo);
+
+  #[cfg(all(all(feature = "foobar"), bar))]
+  pub fn bar(bar: *const Bar);
+}
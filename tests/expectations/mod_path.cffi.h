@@ -0,0 +1,9 @@
+#define EXPORT_ME_TOO 42
+
+typedef struct ExportMe ExportMe;
+
+struct ExportMe {
+  uint64_t val;
+};
+
+void export_me(ExportMe *val);
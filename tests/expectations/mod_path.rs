@@ -0,0 +1,11 @@
+pub const EXPORT_ME_TOO: u8 = 42;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ExportMe {
+  pub val: u64,
+}
+
+extern "C" {
+  pub fn export_me(val: *mut ExportMe);
+}
@@ -0,0 +1,47 @@
+#define MAX 32
+
+#define limits_MAX 16
+
+typedef struct Error Error;
+typedef struct b_Error b_Error;
+typedef struct Flags Flags;
+typedef struct a_Config a_Config;
+typedef struct Wrapper_a_Config Wrapper_a_Config;
+typedef struct b_Config b_Config;
+
+enum Mode {
+  Fast,
+  Safe,
+};
+typedef uint8_t Mode;
+
+struct Error {
+  const uint8_t *message;
+};
+
+struct b_Error {
+  int32_t code;
+};
+
+struct Flags {
+  uint8_t bits;
+};
+
+struct a_Config {
+  bool verbose;
+};
+
+struct Wrapper_a_Config {
+  a_Config value;
+};
+
+struct b_Config {
+  uint32_t level;
+  a_Config inner;
+};
+
+void fail(Error error, b_Error inner, Flags flags);
+
+void wrapped(Wrapper_a_Config wrapper);
+
+Error configure(const b_Config *config, const a_Config *other, Mode mode);
@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX 32
+
+#define limits_MAX 16
+
+enum Mode
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  Fast,
+  Safe,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Mode Mode;
+#else
+typedef uint8_t Mode;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef struct {
+  const uint8_t *message;
+} Error;
+
+typedef struct {
+  int32_t code;
+} b_Error;
+
+typedef struct {
+  uint8_t bits;
+} Flags;
+
+typedef struct {
+  bool verbose;
+} a_Config;
+
+typedef struct {
+  a_Config value;
+} Wrapper_a_Config;
+
+typedef struct {
+  uint32_t level;
+  a_Config inner;
+} b_Config;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void fail(Error error, b_Error inner, Flags flags);
+
+void wrapped(Wrapper_a_Config wrapper);
+
+Error configure(const b_Config *config, const a_Config *other, Mode mode);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,50 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t MAX = 32;
+
+constexpr static const uint32_t limits_MAX = 16;
+
+enum class Mode : uint8_t {
+  Fast,
+  Safe,
+};
+
+struct Error {
+  const uint8_t *message;
+};
+
+struct b_Error {
+  int32_t code;
+};
+
+struct Flags {
+  uint8_t bits;
+};
+
+struct a_Config {
+  bool verbose;
+};
+
+template<typename Config>
+struct Wrapper {
+  Config value;
+};
+
+struct b_Config {
+  uint32_t level;
+  a_Config inner;
+};
+
+extern "C" {
+
+void fail(Error error, b_Error inner, Flags flags);
+
+void wrapped(Wrapper<a_Config> wrapper);
+
+Error configure(const b_Config *config, const a_Config *other, Mode mode);
+
+}  // extern "C"
@@ -0,0 +1,55 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Mode : byte {
+  Fast,
+  Safe,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Error {
+  public byte* message;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct b_Error {
+  public int code;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Flags {
+  public byte bits;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct a_Config {
+  [MarshalAs(UnmanagedType.U1)] public bool verbose;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Wrapper_a_Config {
+  public a_Config value;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct b_Config {
+  public uint level;
+  public a_Config inner;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const uint MAX = 32;
+
+  public const uint limits_MAX = 16;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void fail(Error error, b_Error inner, Flags flags);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void wrapped(Wrapper_a_Config wrapper);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Error configure(b_Config* config, a_Config* other, Mode mode);
+}
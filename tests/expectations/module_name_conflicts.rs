@@ -0,0 +1,55 @@
+pub const MAX: u32 = 32;
+
+pub const limits_MAX: u32 = 16;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Mode {
+  Fast,
+  Safe,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Error {
+  pub message: *const u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct b_Error {
+  pub code: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Flags {
+  pub bits: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct a_Config {
+  pub verbose: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Wrapper_a_Config {
+  pub value: a_Config,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct b_Config {
+  pub level: u32,
+  pub inner: a_Config,
+}
+
+extern "C" {
+  pub fn fail(error: Error, inner: b_Error, flags: Flags);
+
+  pub fn wrapped(wrapper: Wrapper_a_Config);
+
+  pub fn configure(config: *const b_Config, other: *const a_Config, mode: Mode) -> Error;
+}
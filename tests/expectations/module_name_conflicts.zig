@@ -0,0 +1,41 @@
+const std = @import("std");
+
+pub const MAX: u32 = 32;
+
+pub const limits_MAX: u32 = 16;
+
+pub const Mode = enum(u8) {
+  Fast,
+  Safe,
+};
+
+pub const Error = extern struct {
+  message: ?*const u8,
+};
+
+pub const b_Error = extern struct {
+  code: i32,
+};
+
+pub const Flags = extern struct {
+  bits: u8,
+};
+
+pub const a_Config = extern struct {
+  verbose: bool,
+};
+
+pub const Wrapper_a_Config = extern struct {
+  value: a_Config,
+};
+
+pub const b_Config = extern struct {
+  level: u32,
+  inner: a_Config,
+};
+
+pub extern fn fail(@"error": Error, inner: b_Error, flags: Flags) void;
+
+pub extern fn wrapped(wrapper: Wrapper_a_Config) void;
+
+pub extern fn configure(config: *const b_Config, other: *const a_Config, mode: Mode) Error;
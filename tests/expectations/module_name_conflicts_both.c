@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX 32
+
+#define limits_MAX 16
+
+enum Mode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Fast,
+  Safe,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Mode Mode;
+#else
+typedef uint8_t Mode;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct Error {
+  const uint8_t *message;
+} Error;
+
+typedef struct b_Error {
+  int32_t code;
+} b_Error;
+
+typedef struct Flags {
+  uint8_t bits;
+} Flags;
+
+typedef struct a_Config {
+  bool verbose;
+} a_Config;
+
+typedef struct Wrapper_a_Config {
+  struct a_Config value;
+} Wrapper_a_Config;
+
+typedef struct b_Config {
+  uint32_t level;
+  struct a_Config inner;
+} b_Config;
+
+void fail(struct Error error, struct b_Error inner, struct Flags flags);
+
+void wrapped(struct Wrapper_a_Config wrapper);
+
+struct Error configure(const struct b_Config *config, const struct a_Config *other, Mode mode);
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const uint8_t *name;
+} Config;
+
+typedef struct {
+  bool verbose;
+} AConfig;
+
+typedef struct {
+  uint32_t level;
+} BConfig;
+
+void configure(Config root, AConfig a, BConfig b);
@@ -0,0 +1,17 @@
+typedef struct Config Config;
+typedef struct AConfig AConfig;
+typedef struct BConfig BConfig;
+
+struct Config {
+  const uint8_t *name;
+};
+
+struct AConfig {
+  bool verbose;
+};
+
+struct BConfig {
+  uint32_t level;
+};
+
+void configure(Config root, AConfig a, BConfig b);
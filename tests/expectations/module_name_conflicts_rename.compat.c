@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const uint8_t *name;
+} Config;
+
+typedef struct {
+  bool verbose;
+} AConfig;
+
+typedef struct {
+  uint32_t level;
+} BConfig;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(Config root, AConfig a, BConfig b);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Config {
+  const uint8_t *name;
+};
+
+struct AConfig {
+  bool verbose;
+};
+
+struct BConfig {
+  uint32_t level;
+};
+
+extern "C" {
+
+void configure(Config root, AConfig a, BConfig b);
+
+}  // extern "C"
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Config {
+  public byte* name;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct AConfig {
+  [MarshalAs(UnmanagedType.U1)] public bool verbose;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct BConfig {
+  public uint level;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void configure(Config root, AConfig a, BConfig b);
+}
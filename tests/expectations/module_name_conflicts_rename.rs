@@ -0,0 +1,21 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Config {
+  pub name: *const u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AConfig {
+  pub verbose: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BConfig {
+  pub level: u32,
+}
+
+extern "C" {
+  pub fn configure(root: Config, a: AConfig, b: BConfig);
+}
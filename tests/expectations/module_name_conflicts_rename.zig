@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Config = extern struct {
+  name: ?*const u8,
+};
+
+pub const AConfig = extern struct {
+  verbose: bool,
+};
+
+pub const BConfig = extern struct {
+  level: u32,
+};
+
+pub extern fn configure(root: Config, a: AConfig, b: BConfig) void;
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Config {
+  const uint8_t *name;
+} Config;
+
+typedef struct AConfig {
+  bool verbose;
+} AConfig;
+
+typedef struct BConfig {
+  uint32_t level;
+} BConfig;
+
+void configure(struct Config root, struct AConfig a, struct BConfig b);
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  const uint8_t *name;
+};
+
+struct AConfig {
+  bool verbose;
+};
+
+struct BConfig {
+  uint32_t level;
+};
+
+void configure(struct Config root, struct AConfig a, struct BConfig b);
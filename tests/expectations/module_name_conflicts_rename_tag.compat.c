@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  const uint8_t *name;
+};
+
+struct AConfig {
+  bool verbose;
+};
+
+struct BConfig {
+  uint32_t level;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(struct Config root, struct AConfig a, struct BConfig b);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
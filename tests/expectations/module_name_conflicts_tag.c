@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX 32
+
+#define limits_MAX 16
+
+enum Mode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Fast,
+  Safe,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Mode Mode;
+#else
+typedef uint8_t Mode;
+#endif // __STDC_VERSION__ >= 202311L
+
+struct Error {
+  const uint8_t *message;
+};
+
+struct b_Error {
+  int32_t code;
+};
+
+struct Flags {
+  uint8_t bits;
+};
+
+struct a_Config {
+  bool verbose;
+};
+
+struct Wrapper_a_Config {
+  struct a_Config value;
+};
+
+struct b_Config {
+  uint32_t level;
+  struct a_Config inner;
+};
+
+void fail(struct Error error, struct b_Error inner, struct Flags flags);
+
+void wrapped(struct Wrapper_a_Config wrapper);
+
+struct Error configure(const struct b_Config *config, const struct a_Config *other, Mode mode);
@@ -0,0 +1,41 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t MAX # = 32
+
+  const uint32_t limits_MAX # = 16
+
+  cdef enum:
+    Fast,
+    Safe,
+  ctypedef uint8_t Mode;
+
+  cdef struct Error:
+    const uint8_t *message;
+
+  cdef struct b_Error:
+    int32_t code;
+
+  cdef struct Flags:
+    uint8_t bits;
+
+  cdef struct a_Config:
+    bool verbose;
+
+  cdef struct Wrapper_a_Config:
+    a_Config value;
+
+  cdef struct b_Config:
+    uint32_t level;
+    a_Config inner;
+
+  void fail(Error error, b_Error inner, Flags flags);
+
+  void wrapped(Wrapper_a_Config wrapper);
+
+  Error configure(const b_Config *config, const a_Config *other, Mode mode);
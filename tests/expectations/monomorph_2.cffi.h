@@ -0,0 +1,20 @@
+typedef struct List_A List_A;
+typedef struct List_B List_B;
+
+typedef struct A A;
+
+typedef struct B B;
+
+struct List_A {
+  A *members;
+  uintptr_t count;
+};
+
+struct List_B {
+  B *members;
+  uintptr_t count;
+};
+
+void foo(List_A a);
+
+void bar(List_B b);
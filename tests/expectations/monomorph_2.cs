@@ -0,0 +1,28 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct A { }
+
+public partial struct B { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct List_A {
+  public A* members;
+  public nuint count;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct List_B {
+  public B* members;
+  public nuint count;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void foo(List_A a);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void bar(List_B b);
+}
@@ -0,0 +1,31 @@
+#[repr(C)]
+pub struct A {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct B {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct List_A {
+  pub members: *mut A,
+  pub count: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct List_B {
+  pub members: *mut B,
+  pub count: usize,
+}
+
+extern "C" {
+  pub fn foo(a: List_A);
+
+  pub fn bar(b: List_B);
+}
@@ -0,0 +1,44 @@
+typedef union Foo_i32 Foo_i32;
+typedef union Foo_f32 Foo_f32;
+typedef union Foo_Bar_f32 Foo_Bar_f32;
+typedef union Tuple_Foo_f32_____f32 Tuple_Foo_f32_____f32;
+typedef union Tuple_f32__f32 Tuple_f32__f32;
+
+typedef struct Bar_Bar_f32 Bar_Bar_f32;
+
+typedef struct Bar_Foo_f32 Bar_Foo_f32;
+
+typedef struct Bar_f32 Bar_f32;
+
+union Foo_i32 {
+  const int32_t *data;
+};
+
+union Foo_f32 {
+  const float *data;
+};
+
+union Foo_Bar_f32 {
+  const Bar_f32 *data;
+};
+
+union Tuple_Foo_f32_____f32 {
+  const Foo_f32 *a;
+  const float *b;
+};
+
+union Tuple_f32__f32 {
+  const float *a;
+  const float *b;
+};
+
+typedef Tuple_f32__f32 Indirection_f32;
+
+void root(Foo_i32 a,
+          Foo_f32 b,
+          Bar_f32 c,
+          Foo_Bar_f32 d,
+          Bar_Foo_f32 e,
+          Bar_Bar_f32 f,
+          Tuple_Foo_f32_____f32 g,
+          Indirection_f32 h);
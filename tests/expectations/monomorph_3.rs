@@ -0,0 +1,64 @@
+#[repr(C)]
+pub struct Bar_Bar_f32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Bar_Foo_f32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Bar_f32 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Foo_i32 {
+  pub data: *const i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Foo_f32 {
+  pub data: *const f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Foo_Bar_f32 {
+  pub data: *const Bar_f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Tuple_Foo_f32_____f32 {
+  pub a: *const Foo_f32,
+  pub b: *const f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Tuple_f32__f32 {
+  pub a: *const f32,
+  pub b: *const f32,
+}
+
+pub type Indirection_f32 = Tuple_f32__f32;
+
+extern "C" {
+  pub fn root(
+    a: Foo_i32,
+    b: Foo_f32,
+    c: Bar_f32,
+    d: Foo_Bar_f32,
+    e: Bar_Foo_f32,
+    f: Bar_Bar_f32,
+    g: Tuple_Foo_f32_____f32,
+    h: Indirection_f32,
+  );
+}
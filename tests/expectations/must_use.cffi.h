@@ -0,0 +1,23 @@
+typedef struct MaybeOwnedPtr_i32 MaybeOwnedPtr_i32;
+typedef struct OwnedPtr_i32 OwnedPtr_i32;
+
+enum MaybeOwnedPtr_i32_Tag {
+  Owned_i32,
+  None_i32,
+};
+typedef uint8_t MaybeOwnedPtr_i32_Tag;
+
+struct MaybeOwnedPtr_i32 {
+  MaybeOwnedPtr_i32_Tag tag;
+  union {
+    struct {
+      int32_t *owned;
+    };
+  };
+};
+
+struct OwnedPtr_i32 {
+  int32_t *ptr;
+};
+
+MaybeOwnedPtr_i32 maybe_consume(OwnedPtr_i32 input);
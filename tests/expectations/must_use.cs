@@ -0,0 +1,40 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public enum MaybeOwnedPtr_i32_Tag : byte {
+  Owned_i32,
+  None_i32,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MaybeOwnedPtr_i32 {
+  public MaybeOwnedPtr_i32_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Owned_Body_i32 owned;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Owned_Body_i32 {
+    public int* owned;
+  }
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct OwnedPtr_i32 {
+  public int* ptr;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern MaybeOwnedPtr_i32 maybe_consume(OwnedPtr_i32 input);
+}
@@ -0,0 +1,24 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+#[must_use]
+pub enum MaybeOwnedPtr_i32 {
+  Owned_i32(*mut i32),
+  None_i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct OwnedPtr_i32 {
+  pub ptr: *mut i32,
+}
+
+extern "C" {
+  #[must_use]
+  pub fn maybe_consume(input: OwnedPtr_i32) -> MaybeOwnedPtr_i32;
+}
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} a_Config;
+
+typedef struct {
+  uint32_t level;
+} b_Config;
+
+typedef struct {
+  uint8_t depth;
+} Depth;
+
+void configure(a_Config a, b_Config b, Depth c);
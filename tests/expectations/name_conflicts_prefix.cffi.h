@@ -0,0 +1,17 @@
+typedef struct a_Config a_Config;
+typedef struct b_Config b_Config;
+typedef struct Depth Depth;
+
+struct a_Config {
+  bool verbose;
+};
+
+struct b_Config {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+void configure(a_Config a, b_Config b, Depth c);
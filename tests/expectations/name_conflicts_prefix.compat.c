@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} a_Config;
+
+typedef struct {
+  uint32_t level;
+} b_Config;
+
+typedef struct {
+  uint8_t depth;
+} Depth;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(a_Config a, b_Config b, Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
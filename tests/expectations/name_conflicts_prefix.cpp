@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct a_Config {
+  bool verbose;
+};
+
+struct b_Config {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+extern "C" {
+
+void configure(a_Config a, b_Config b, Depth c);
+
+}  // extern "C"
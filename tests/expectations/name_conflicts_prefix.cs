@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct a_Config {
+  [MarshalAs(UnmanagedType.U1)] public bool verbose;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct b_Config {
+  public uint level;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Depth {
+  public byte depth;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void configure(a_Config a, b_Config b, Depth c);
+}
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct a_Config:
+    bool verbose;
+
+  ctypedef struct b_Config:
+    uint32_t level;
+
+  ctypedef struct Depth:
+    uint8_t depth;
+
+  void configure(a_Config a, b_Config b, Depth c);
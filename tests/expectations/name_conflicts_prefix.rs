@@ -0,0 +1,21 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct a_Config {
+  pub verbose: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct b_Config {
+  pub level: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Depth {
+  pub depth: u8,
+}
+
+extern "C" {
+  pub fn configure(a: a_Config, b: b_Config, c: Depth);
+}
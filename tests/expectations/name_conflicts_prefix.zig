@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const a_Config = extern struct {
+  verbose: bool,
+};
+
+pub const b_Config = extern struct {
+  level: u32,
+};
+
+pub const Depth = extern struct {
+  depth: u8,
+};
+
+pub extern fn configure(a: a_Config, b: b_Config, c: Depth) void;
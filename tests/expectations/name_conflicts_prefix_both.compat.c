@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct a_Config {
+  bool verbose;
+} a_Config;
+
+typedef struct b_Config {
+  uint32_t level;
+} b_Config;
+
+typedef struct Depth {
+  uint8_t depth;
+} Depth;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(struct a_Config a, struct b_Config b, struct Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct a_Config {
+  bool verbose;
+};
+
+struct b_Config {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+void configure(struct a_Config a, struct b_Config b, struct Depth c);
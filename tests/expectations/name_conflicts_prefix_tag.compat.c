@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct a_Config {
+  bool verbose;
+};
+
+struct b_Config {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(struct a_Config a, struct b_Config b, struct Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} Config;
+
+typedef struct {
+  uint32_t level;
+} Level;
+
+typedef struct {
+  uint8_t depth;
+} Depth;
+
+void configure(Config a, Level b, Depth c);
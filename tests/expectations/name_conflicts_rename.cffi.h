@@ -0,0 +1,17 @@
+typedef struct Config Config;
+typedef struct Level Level;
+typedef struct Depth Depth;
+
+struct Config {
+  bool verbose;
+};
+
+struct Level {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+void configure(Config a, Level b, Depth c);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} Config;
+
+typedef struct {
+  uint32_t level;
+} Level;
+
+typedef struct {
+  uint8_t depth;
+} Depth;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(Config a, Level b, Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
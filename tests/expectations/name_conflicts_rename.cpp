@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Config {
+  bool verbose;
+};
+
+struct Level {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+extern "C" {
+
+void configure(Config a, Level b, Depth c);
+
+}  // extern "C"
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Config {
+  [MarshalAs(UnmanagedType.U1)] public bool verbose;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Level {
+  public uint level;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Depth {
+  public byte depth;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void configure(Config a, Level b, Depth c);
+}
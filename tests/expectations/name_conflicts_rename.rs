@@ -0,0 +1,21 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Config {
+  pub verbose: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Level {
+  pub level: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Depth {
+  pub depth: u8,
+}
+
+extern "C" {
+  pub fn configure(a: Config, b: Level, c: Depth);
+}
@@ -0,0 +1,15 @@
+const std = @import("std");
+
+pub const Config = extern struct {
+  verbose: bool,
+};
+
+pub const Level = extern struct {
+  level: u32,
+};
+
+pub const Depth = extern struct {
+  depth: u8,
+};
+
+pub extern fn configure(a: Config, b: Level, c: Depth) void;
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Config {
+  bool verbose;
+} Config;
+
+typedef struct Level {
+  uint32_t level;
+} Level;
+
+typedef struct Depth {
+  uint8_t depth;
+} Depth;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(struct Config a, struct Level b, struct Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  bool verbose;
+};
+
+struct Level {
+  uint32_t level;
+};
+
+struct Depth {
+  uint8_t depth;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(struct Config a, struct Level b, struct Depth c);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,9 @@
+#define FOO 10
+
+typedef struct Foo Foo;
+
+struct Foo {
+  int32_t x[FOO];
+};
+
+void root(Foo x);
@@ -0,0 +1,13 @@
+pub const FOO: i32 = 10;
+
+pub const ZOM: f32 = 3.14;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: [i32; FOO as usize],
+}
+
+extern "C" {
+  pub fn root(x: Foo);
+}
@@ -0,0 +1,7 @@
+const uint32_t FIRST;
+
+const uint32_t RENAMED;
+
+void first(void);
+
+void renamed(void);
@@ -0,0 +1,9 @@
+extern "C" {
+  pub static FIRST: u32;
+
+  pub static RENAMED: u32;
+
+  pub fn first();
+
+  pub fn renamed();
+}
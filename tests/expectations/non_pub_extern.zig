@@ -0,0 +1,9 @@
+const std = @import("std");
+
+pub extern const FIRST: u32;
+
+pub extern const RENAMED: u32;
+
+pub extern fn first() void;
+
+pub extern fn renamed() void;
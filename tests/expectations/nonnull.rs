@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_u64 {
+  pub a: *mut f32,
+  pub b: *mut u64,
+  pub c: *mut Opaque,
+  pub d: *mut *mut u64,
+  pub e: *mut *mut f32,
+  pub f: *mut *mut Opaque,
+  pub g: *mut u64,
+  pub h: *mut i32,
+  pub i: *mut *mut i32,
+}
+
+extern "C" {
+  pub fn root(arg: *mut i32, foo: *mut Foo_u64, d: *mut *mut Opaque);
+}
@@ -0,0 +1,62 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Opaque { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct References {
+  public Opaque* a;
+  public Opaque* b;
+  public Opaque* c;
+  public Opaque* d;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Pointers_u64 {
+  public float* a;
+  public ulong* b;
+  public Opaque* c;
+  public ulong** d;
+  public float** e;
+  public Opaque** f;
+  public ulong* g;
+  public int* h;
+  public int** i;
+  public ulong* j;
+  public ulong* k;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void value_arg(References arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mutltiple_args(int* arg, Pointers_u64* foo, Opaque** d);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void mut_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void optional_mut_ref_arg(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_const_ptr(Pointers_u64* arg);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nullable_mut_ptr(Pointers_u64* arg);
+}
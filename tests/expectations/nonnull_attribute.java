@@ -0,0 +1,154 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  // `Opaque` is opaque, so it's only ever passed around as an `ADDRESS`.
+
+  public static final class References {
+    private References() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      ADDRESS.withName("a"),
+      ADDRESS.withName("b"),
+      ADDRESS.withName("c"),
+      ADDRESS.withName("d")
+    ).withName("References");
+  }
+
+  public static final class Pointers_u64 {
+    private Pointers_u64() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      ADDRESS.withName("a"),
+      ADDRESS.withName("b"),
+      ADDRESS.withName("c"),
+      ADDRESS.withName("d"),
+      ADDRESS.withName("e"),
+      ADDRESS.withName("f"),
+      ADDRESS.withName("g"),
+      ADDRESS.withName("h"),
+      ADDRESS.withName("i"),
+      ADDRESS.withName("j"),
+      ADDRESS.withName("k")
+    ).withName("Pointers_u64");
+  }
+
+  public static final MethodHandle value_arg = downcall("value_arg", FunctionDescriptor.ofVoid(References.LAYOUT));
+
+  public static final MethodHandle mutltiple_args = downcall("mutltiple_args", FunctionDescriptor.ofVoid(ADDRESS,
+                                                                                                         ADDRESS,
+                                                                                                         ADDRESS));
+
+  public static final MethodHandle ref_arg = downcall("ref_arg", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle mut_ref_arg = downcall("mut_ref_arg", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle optional_ref_arg = downcall("optional_ref_arg", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle optional_mut_ref_arg = downcall("optional_mut_ref_arg", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle nullable_const_ptr = downcall("nullable_const_ptr", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle nullable_mut_ptr = downcall("nullable_mut_ptr", FunctionDescriptor.ofVoid(ADDRESS));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
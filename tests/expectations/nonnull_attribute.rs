@@ -0,0 +1,55 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct References {
+  pub a: *const Opaque,
+  pub b: *mut Opaque,
+  pub c: *const Opaque,
+  pub d: *mut Opaque,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Pointers_u64 {
+  pub a: *mut f32,
+  pub b: *mut u64,
+  pub c: *mut Opaque,
+  pub d: *mut *mut u64,
+  pub e: *mut *mut f32,
+  pub f: *mut *mut Opaque,
+  pub g: *mut u64,
+  pub h: *mut i32,
+  pub i: *mut *mut i32,
+  pub j: *const u64,
+  pub k: *mut u64,
+}
+
+extern "C" {
+  pub fn value_arg(arg: References);
+
+  pub fn mutltiple_args(arg: *mut i32, foo: *mut Pointers_u64, d: *mut *mut Opaque);
+
+  pub fn ref_arg(arg: *const Pointers_u64);
+
+  pub fn mut_ref_arg(arg: *mut Pointers_u64);
+
+  pub fn optional_ref_arg(arg: *const Pointers_u64);
+
+  pub fn optional_mut_ref_arg(arg: *mut Pointers_u64);
+
+  pub fn nullable_const_ptr(arg: *const Pointers_u64);
+
+  pub fn nullable_mut_ptr(arg: *mut Pointers_u64);
+}
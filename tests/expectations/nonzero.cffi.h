@@ -0,0 +1,54 @@
+typedef struct NonZeroAliases NonZeroAliases;
+typedef struct NonZeroGenerics NonZeroGenerics;
+
+typedef struct Option_i64 Option_i64;
+
+struct NonZeroAliases {
+  uint8_t a;
+  uint16_t b;
+  uint32_t c;
+  uint64_t d;
+  int8_t e;
+  int16_t f;
+  int32_t g;
+  int64_t h;
+  int64_t i;
+  const Option_i64 *j;
+};
+
+struct NonZeroGenerics {
+  uint8_t a;
+  uint16_t b;
+  uint32_t c;
+  uint64_t d;
+  int8_t e;
+  int16_t f;
+  int32_t g;
+  int64_t h;
+  int64_t i;
+  const Option_i64 *j;
+};
+
+void root_nonzero_aliases(NonZeroAliases test,
+                          uint8_t a,
+                          uint16_t b,
+                          uint32_t c,
+                          uint64_t d,
+                          int8_t e,
+                          int16_t f,
+                          int32_t g,
+                          int64_t h,
+                          int64_t i,
+                          const Option_i64 *j);
+
+void root_nonzero_generics(NonZeroGenerics test,
+                           uint8_t a,
+                           uint16_t b,
+                           uint32_t c,
+                           uint64_t d,
+                           int8_t e,
+                           int16_t f,
+                           int32_t g,
+                           int64_t h,
+                           int64_t i,
+                           const Option_i64 *j);
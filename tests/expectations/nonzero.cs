@@ -0,0 +1,75 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Option_i64 { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct NonZeroAliases {
+  public byte a;
+  public ushort b;
+  public uint c;
+  public ulong d;
+  public sbyte e;
+  public short f;
+  public int g;
+  public long h;
+  public long i;
+  public Option_i64* j;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct NonZeroGenerics {
+  public byte a;
+  public ushort b;
+  public uint c;
+  public ulong d;
+  public sbyte e;
+  public short f;
+  public int g;
+  public long h;
+  public long i;
+  public Option_i64* j;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root_nonzero_aliases(NonZeroAliases test,
+                                                 byte a,
+                                                 ushort b,
+                                                 uint c,
+                                                 ulong d,
+                                                 sbyte e,
+                                                 short f,
+                                                 int g,
+                                                 long h,
+                                                 long i,
+                                                 Option_i64* j);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root_nonzero_generics(NonZeroGenerics test,
+                                                  byte a,
+                                                  ushort b,
+                                                  uint c,
+                                                  ulong d,
+                                                  sbyte e,
+                                                  short f,
+                                                  int g,
+                                                  long h,
+                                                  long i,
+                                                  Option_i64* j);
+}
@@ -0,0 +1,78 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+pub struct Option_i64 {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NonZeroAliases {
+  pub a: u8,
+  pub b: u16,
+  pub c: u32,
+  pub d: u64,
+  pub e: i8,
+  pub f: i16,
+  pub g: i32,
+  pub h: i64,
+  pub i: i64,
+  pub j: *const Option_i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NonZeroGenerics {
+  pub a: u8,
+  pub b: u16,
+  pub c: u32,
+  pub d: u64,
+  pub e: i8,
+  pub f: i16,
+  pub g: i32,
+  pub h: i64,
+  pub i: i64,
+  pub j: *const Option_i64,
+}
+
+extern "C" {
+  pub fn root_nonzero_aliases(
+    test: NonZeroAliases,
+    a: u8,
+    b: u16,
+    c: u32,
+    d: u64,
+    e: i8,
+    f: i16,
+    g: i32,
+    h: i64,
+    i: i64,
+    j: *const Option_i64,
+  );
+
+  pub fn root_nonzero_generics(
+    test: NonZeroGenerics,
+    a: u8,
+    b: u16,
+    c: u32,
+    d: u64,
+    e: i8,
+    f: i16,
+    g: i32,
+    h: i64,
+    i: i64,
+    j: *const Option_i64,
+  );
+}
@@ -0,0 +1,66 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+struct NonZeroI64;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+const std = @import("std");
+
+pub const Option_i64 = opaque {};
+
+pub const NonZeroAliases = extern struct {
+  a: u8,
+  b: u16,
+  c: u32,
+  d: u64,
+  e: i8,
+  f: i16,
+  g: i32,
+  h: i64,
+  i: i64,
+  j: ?*const Option_i64,
+};
+
+pub const NonZeroGenerics = extern struct {
+  a: u8,
+  b: u16,
+  c: u32,
+  d: u64,
+  e: i8,
+  f: i16,
+  g: i32,
+  h: i64,
+  i: i64,
+  j: ?*const Option_i64,
+};
+
+pub extern fn root_nonzero_aliases(@"test": NonZeroAliases,
+                                   a: u8,
+                                   b: u16,
+                                   c: u32,
+                                   d: u64,
+                                   e: i8,
+                                   f: i16,
+                                   g: i32,
+                                   h: i64,
+                                   i: i64,
+                                   j: ?*const Option_i64) void;
+
+pub extern fn root_nonzero_generics(@"test": NonZeroGenerics,
+                                    a: u8,
+                                    b: u16,
+                                    c: u32,
+                                    d: u64,
+                                    e: i8,
+                                    f: i16,
+                                    g: i32,
+                                    h: i64,
+                                    i: i64,
+                                    j: ?*const Option_i64) void;
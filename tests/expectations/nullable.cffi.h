@@ -0,0 +1,17 @@
+typedef struct Foo_u64 Foo_u64;
+
+typedef struct Opaque Opaque;
+
+struct Foo_u64 {
+  float *a;
+  uint64_t *b;
+  Opaque *c;
+  uint64_t **d;
+  float **e;
+  Opaque **f;
+  uint64_t *g;
+  int32_t *h;
+  int32_t **i;
+};
+
+void root(int32_t *arg, Foo_u64 *foo, Opaque **d);
@@ -0,0 +1,47 @@
+#ifdef __clang__
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NULLABLE
+#endif
+
+
+const std = @import("std");
+
+pub const Opaque = opaque {};
+
+pub const References = extern struct {
+  a: *const Opaque,
+  b: *Opaque,
+  c: ?*const Opaque,
+  d: ?*Opaque,
+};
+
+pub const Pointers_u64 = extern struct {
+  a: *f32,
+  b: *u64,
+  c: *Opaque,
+  d: **u64,
+  e: **f32,
+  f: **Opaque,
+  g: ?*u64,
+  h: ?*i32,
+  i: ?**i32,
+  j: ?*const u64,
+  k: ?*u64,
+};
+
+pub extern fn value_arg(arg: References) void;
+
+pub extern fn mutltiple_args(arg: *i32, foo: ?*Pointers_u64, d: **Opaque) void;
+
+pub extern fn ref_arg(arg: *const Pointers_u64) void;
+
+pub extern fn mut_ref_arg(arg: *Pointers_u64) void;
+
+pub extern fn optional_ref_arg(arg: ?*const Pointers_u64) void;
+
+pub extern fn optional_mut_ref_arg(arg: ?*Pointers_u64) void;
+
+pub extern fn nullable_const_ptr(arg: ?*const Pointers_u64) void;
+
+pub extern fn nullable_mut_ptr(arg: ?*Pointers_u64) void;
@@ -0,0 +1,31 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+// These could be added as opaque types I guess.
+template <typename T>
+struct BuildHasherDefault;
+
+struct DefaultHasher;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct HashMap_i32__i32__BuildHasherDefault_DefaultHasher { }
+
+public partial struct Result_Foo { }
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(HashMap_i32__i32__BuildHasherDefault_DefaultHasher* a,
+                                 Result_Foo* b);
+}
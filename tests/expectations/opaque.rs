@@ -0,0 +1,39 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+// These could be added as opaque types I guess.
+template <typename T>
+struct BuildHasherDefault;
+
+struct DefaultHasher;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+pub struct HashMap_i32__i32__BuildHasherDefault_DefaultHasher {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Result_Foo {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+/// Fast hash map used internally.
+pub type FastHashMap_i32__i32 = HashMap_i32__i32__BuildHasherDefault_DefaultHasher;
+
+pub type Foo = FastHashMap_i32__i32;
+
+pub type Bar = Result_Foo;
+
+extern "C" {
+  pub fn root(a: *const Foo, b: *const Bar);
+}
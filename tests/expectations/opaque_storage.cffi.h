@@ -0,0 +1,27 @@
+typedef struct Slot Slot;
+
+typedef struct Block {
+  uint64_t _private[4];
+} Block;
+
+typedef struct Counter {
+  uint32_t _private[1];
+} Counter;
+
+typedef struct Handle {
+  uint64_t _private[2];
+} Handle;
+
+typedef struct Invalid Invalid;
+
+struct Slot {
+  uint8_t tag;
+  Handle handle;
+  Counter counter;
+};
+
+void slot_init(Slot *slot, Invalid *invalid);
+
+Handle handle_copy(Handle handle);
+
+void block_clear(Block *block);
@@ -0,0 +1,45 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Storage C# and cffi can only align to 8 bytes.
+ */
+typedef struct Block {
+  alignas(16) unsigned char _private[32];
+} Block;
+
+typedef struct Counter {
+  alignas(4) unsigned char _private[4];
+} Counter;
+
+/**
+ * A handle that C can allocate, but not look inside.
+ */
+typedef struct Handle {
+  alignas(8) unsigned char _private[16];
+} Handle;
+
+typedef struct Invalid Invalid;
+
+typedef struct {
+  uint8_t tag;
+  Handle handle;
+  Counter counter;
+} Slot;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void slot_init(Slot *slot, Invalid *invalid);
+
+Handle handle_copy(Handle handle);
+
+void block_clear(Block *block);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
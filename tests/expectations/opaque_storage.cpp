@@ -0,0 +1,37 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Storage C# and cffi can only align to 8 bytes.
+struct Block {
+  alignas(16) unsigned char _private[32];
+};
+
+struct Counter {
+  alignas(4) unsigned char _private[4];
+};
+
+/// A handle that C can allocate, but not look inside.
+struct Handle {
+  alignas(8) unsigned char _private[16];
+};
+
+struct Invalid;
+
+struct Slot {
+  uint8_t tag;
+  Handle handle;
+  Counter counter;
+};
+
+extern "C" {
+
+void slot_init(Slot *slot, Invalid *invalid);
+
+Handle handle_copy(Handle handle);
+
+void block_clear(Block *block);
+
+}  // extern "C"
@@ -0,0 +1,45 @@
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// Storage C# and cffi can only align to 8 bytes.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Block {
+  private fixed ulong _private[4];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Counter {
+  private fixed uint _private[1];
+}
+
+/// <summary>
+/// A handle that C can allocate, but not look inside.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Handle {
+  private fixed ulong _private[2];
+}
+
+public partial struct Invalid { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slot {
+  public byte tag;
+  public Handle handle;
+  public Counter counter;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void slot_init(Slot* slot, Invalid* invalid);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Handle handle_copy(Handle handle);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void block_clear(Block* block);
+}
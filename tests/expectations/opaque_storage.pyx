@@ -0,0 +1,32 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # Storage C# and cffi can only align to 8 bytes.
+  ctypedef struct Block:
+    unsigned char _private[32];
+
+  ctypedef struct Counter:
+    unsigned char _private[4];
+
+  # A handle that C can allocate, but not look inside.
+  ctypedef struct Handle:
+    unsigned char _private[16];
+
+  ctypedef struct Invalid:
+    pass
+
+  ctypedef struct Slot:
+    uint8_t tag;
+    Handle handle;
+    Counter counter;
+
+  void slot_init(Slot *slot, Invalid *invalid);
+
+  Handle handle_copy(Handle handle);
+
+  void block_clear(Block *block);
@@ -0,0 +1,44 @@
+/// Storage C# and cffi can only align to 8 bytes.
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub struct Block {
+  _private: [u8; 32],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, align(4))]
+#[derive(Clone, Copy)]
+pub struct Counter {
+  _private: [u8; 4],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+/// A handle that C can allocate, but not look inside.
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct Handle {
+  _private: [u8; 16],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Invalid {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slot {
+  pub tag: u8,
+  pub handle: Handle,
+  pub counter: Counter,
+}
+
+extern "C" {
+  pub fn slot_init(slot: *mut Slot, invalid: *mut Invalid);
+
+  pub fn handle_copy(handle: Handle) -> Handle;
+
+  pub fn block_clear(block: *mut Block);
+}
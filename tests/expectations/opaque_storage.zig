@@ -0,0 +1,29 @@
+const std = @import("std");
+
+/// Storage C# and cffi can only align to 8 bytes.
+pub const Block = extern struct {
+  _private: [32]u8 align(16),
+};
+
+pub const Counter = extern struct {
+  _private: [4]u8 align(4),
+};
+
+/// A handle that C can allocate, but not look inside.
+pub const Handle = extern struct {
+  _private: [16]u8 align(8),
+};
+
+pub const Invalid = opaque {};
+
+pub const Slot = extern struct {
+  tag: u8,
+  handle: Handle,
+  counter: Counter,
+};
+
+pub extern fn slot_init(slot: ?*Slot, invalid: ?*Invalid) void;
+
+pub extern fn handle_copy(handle: Handle) Handle;
+
+pub extern fn block_clear(block: ?*Block) void;
@@ -0,0 +1,37 @@
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Storage C# and cffi can only align to 8 bytes.
+ */
+struct Block {
+  alignas(16) unsigned char _private[32];
+};
+
+struct Counter {
+  alignas(4) unsigned char _private[4];
+};
+
+/**
+ * A handle that C can allocate, but not look inside.
+ */
+struct Handle {
+  alignas(8) unsigned char _private[16];
+};
+
+struct Invalid;
+
+struct Slot {
+  uint8_t tag;
+  struct Handle handle;
+  struct Counter counter;
+};
+
+void slot_init(struct Slot *slot, struct Invalid *invalid);
+
+struct Handle handle_copy(struct Handle handle);
+
+void block_clear(struct Block *block);
@@ -0,0 +1,9 @@
+/* Package version: 0.1.0 */
+
+typedef struct Foo Foo;
+
+struct Foo {
+  uint64_t bar;
+};
+
+void doit(const Foo*);
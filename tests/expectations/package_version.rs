@@ -0,0 +1,11 @@
+// Package version: 0.1.0
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub bar: u64,
+}
+
+extern "C" {
+  pub fn doit(_: *const Foo);
+}
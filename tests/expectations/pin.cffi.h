@@ -0,0 +1,8 @@
+typedef struct PinTest PinTest;
+
+struct PinTest {
+  int32_t *pinned_box;
+  int32_t *pinned_ref;
+};
+
+void root(int32_t *s, PinTest p);
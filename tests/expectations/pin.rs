@@ -0,0 +1,26 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Pin = T;
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PinTest {
+  pub pinned_box: *mut i32,
+  pub pinned_ref: *mut i32,
+}
+
+extern "C" {
+  pub fn root(s: *mut i32, p: PinTest);
+}
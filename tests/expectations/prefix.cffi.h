@@ -0,0 +1,28 @@
+#define PREFIX_LEN 22
+
+#define PREFIX_X 92274688
+
+#define PREFIX_Y 184549376
+
+typedef union PREFIX_AbsoluteFontWeight PREFIX_AbsoluteFontWeight;
+
+typedef int32_t PREFIX_NamedLenArray[PREFIX_LEN];
+
+typedef int32_t PREFIX_ValuedLenArray[22];
+
+enum PREFIX_AbsoluteFontWeight_Tag {
+  Weight,
+  Normal,
+  Bold,
+};
+typedef uint8_t PREFIX_AbsoluteFontWeight_Tag;
+
+union PREFIX_AbsoluteFontWeight {
+  PREFIX_AbsoluteFontWeight_Tag tag;
+  struct {
+    PREFIX_AbsoluteFontWeight_Tag weight_tag;
+    float weight;
+  };
+};
+
+void root(PREFIX_NamedLenArray x, PREFIX_ValuedLenArray y, PREFIX_AbsoluteFontWeight z);
@@ -0,0 +1,33 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum PREFIX_AbsoluteFontWeight_Tag : byte {
+  Weight,
+  Normal,
+  Bold,
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct PREFIX_AbsoluteFontWeight {
+  [FieldOffset(0)] public PREFIX_AbsoluteFontWeight_Tag tag;
+  [FieldOffset(0)] public PREFIX_Weight_Body weight;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct PREFIX_Weight_Body {
+    public PREFIX_AbsoluteFontWeight_Tag weight_tag;
+    public float weight;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const int PREFIX_LEN = 22;
+
+  public const long PREFIX_X = unchecked((long)((22 << 22)));
+
+  public const long PREFIX_Y = unchecked((long)((NativeMethods.PREFIX_X + NativeMethods.PREFIX_X)));
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(int* x, int* y, PREFIX_AbsoluteFontWeight z);
+}
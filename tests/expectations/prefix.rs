@@ -0,0 +1,21 @@
+pub const PREFIX_LEN: i32 = 22;
+
+pub const PREFIX_X: i64 = 92274688;
+
+pub const PREFIX_Y: i64 = 184549376;
+
+pub type PREFIX_NamedLenArray = [i32; PREFIX_LEN as usize];
+
+pub type PREFIX_ValuedLenArray = [i32; 22];
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum PREFIX_AbsoluteFontWeight {
+  Weight(f32),
+  Normal,
+  Bold,
+}
+
+extern "C" {
+  pub fn root(x: PREFIX_NamedLenArray, y: PREFIX_ValuedLenArray, z: PREFIX_AbsoluteFontWeight);
+}
@@ -0,0 +1,8 @@
+typedef struct PREFIXFoo PREFIXFoo;
+
+struct PREFIXFoo {
+  int32_t a;
+  uint32_t b;
+};
+
+void root(PREFIXFoo x);
@@ -0,0 +1,19 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct PREFIXFoo {
+  public int a;
+  public uint b;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly PREFIXFoo PREFIXFoo_FOO = new PREFIXFoo { a = 42, b = 47 };
+
+  public static readonly PREFIXFoo PREFIXBAR = new PREFIXFoo { a = 42, b = 1337 };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(PREFIXFoo x);
+}
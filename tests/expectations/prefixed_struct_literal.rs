@@ -0,0 +1,14 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PREFIXFoo {
+  pub a: i32,
+  pub b: u32,
+}
+
+pub const PREFIXFoo_FOO: PREFIXFoo = PREFIXFoo { a: 42, b: 47 };
+
+pub const PREFIXBAR: PREFIXFoo = PREFIXFoo { a: 42, b: 1337 };
+
+extern "C" {
+  pub fn root(x: PREFIXFoo);
+}
@@ -0,0 +1,14 @@
+typedef struct PREFIXBar PREFIXBar;
+typedef struct PREFIXFoo PREFIXFoo;
+
+struct PREFIXBar {
+  int32_t a;
+};
+
+struct PREFIXFoo {
+  int32_t a;
+  uint32_t b;
+  PREFIXBar bar;
+};
+
+void root(PREFIXFoo x);
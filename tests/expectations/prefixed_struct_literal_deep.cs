@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct PREFIXBar {
+  public int a;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct PREFIXFoo {
+  public int a;
+  public uint b;
+  public PREFIXBar bar;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly PREFIXFoo PREFIXVAL = new PREFIXFoo { a = 42, b = 1337, bar = new PREFIXBar { a = 323 } };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(PREFIXFoo x);
+}
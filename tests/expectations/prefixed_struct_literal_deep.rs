@@ -0,0 +1,19 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PREFIXBar {
+  pub a: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PREFIXFoo {
+  pub a: i32,
+  pub b: u32,
+  pub bar: PREFIXBar,
+}
+
+pub const PREFIXVAL: PREFIXFoo = PREFIXFoo { a: 42, b: 1337, bar: PREFIXBar { a: 323 } };
+
+extern "C" {
+  pub fn root(x: PREFIXFoo);
+}
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array(uint n, uint* arg, ulong* v);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array1(uint n, uint* arg, ulong* v);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array2(uint n, uint* arg, ulong* v);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array_wrong_syntax(uint* arg, uint* v, uint* _2);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void ptr_as_array_unnamed(uint* _0, uint* _1);
+}
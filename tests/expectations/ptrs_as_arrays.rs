@@ -0,0 +1,11 @@
+extern "C" {
+  pub fn ptr_as_array(n: u32, arg: *const u32, v: *const u64);
+
+  pub fn ptr_as_array1(n: u32, arg: *const u32, v: *mut u64);
+
+  pub fn ptr_as_array2(n: u32, arg: *mut u32, v: *const u64);
+
+  pub fn ptr_as_array_wrong_syntax(arg: *mut u32, v: *const u32, _: *const u32);
+
+  pub fn ptr_as_array_unnamed(_: *mut u32, _: *const u32);
+}
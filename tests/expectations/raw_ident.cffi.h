@@ -0,0 +1,15 @@
+typedef struct Struct Struct;
+
+enum Enum {
+  a,
+  b,
+};
+typedef uint8_t Enum;
+
+struct Struct {
+  Enum field;
+};
+
+const Enum STATIC;
+
+void fn(Struct arg);
@@ -0,0 +1,21 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Enum : byte {
+  a,
+  b,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Struct {
+  public Enum field;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  // `STATIC` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void fn(Struct arg);
+}
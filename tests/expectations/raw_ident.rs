@@ -0,0 +1,18 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Enum {
+  a,
+  b,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Struct {
+  pub field: Enum,
+}
+
+extern "C" {
+  pub static STATIC: Enum;
+
+  pub fn r#fn(arg: Struct);
+}
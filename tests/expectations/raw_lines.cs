@@ -0,0 +1,10 @@
+using System;
+using System.Runtime.InteropServices;
+#define VERSION 1
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root();
+}
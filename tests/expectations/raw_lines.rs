@@ -0,0 +1,5 @@
+#define VERSION 1
+
+extern "C" {
+  pub fn root();
+}
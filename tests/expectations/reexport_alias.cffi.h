@@ -0,0 +1,17 @@
+typedef struct ResourceHandle ResourceHandle;
+
+enum ResourceStatus {
+  Ok,
+  Failed,
+};
+typedef uint8_t ResourceStatus;
+
+typedef struct ResourceBuffer ResourceBuffer;
+
+struct ResourceHandle {
+  uint32_t id;
+};
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const ResourceHandle *handle, ResourceBuffer *buffer);
@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class ResourceStatus : uint8_t {
+  Ok,
+  Failed,
+};
+
+struct ResourceBuffer;
+
+struct ResourceHandle {
+  uint32_t id;
+};
+
+extern "C" {
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const ResourceHandle *handle, ResourceBuffer *buffer);
+
+}  // extern "C"
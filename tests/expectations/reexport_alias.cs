@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum ResourceStatus : byte {
+  Ok,
+  Failed,
+}
+
+public partial struct ResourceBuffer { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ResourceHandle {
+  public uint id;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern ResourceHandle open_resource(uint id);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int read_resource(ResourceHandle* handle, ResourceBuffer* buffer);
+}
@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Ok,
+    Failed,
+  ctypedef uint8_t ResourceStatus;
+
+  ctypedef struct ResourceBuffer:
+    pass
+
+  ctypedef struct ResourceHandle:
+    uint32_t id;
+
+  ResourceHandle open_resource(uint32_t id);
+
+  int32_t read_resource(const ResourceHandle *handle, ResourceBuffer *buffer);
@@ -0,0 +1,24 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum ResourceStatus {
+  Ok,
+  Failed,
+}
+
+#[repr(C)]
+pub struct ResourceBuffer {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ResourceHandle {
+  pub id: u32,
+}
+
+extern "C" {
+  pub fn open_resource(id: u32) -> ResourceHandle;
+
+  pub fn read_resource(handle: *const ResourceHandle, buffer: *mut ResourceBuffer) -> i32;
+}
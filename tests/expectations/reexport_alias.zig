@@ -0,0 +1,16 @@
+const std = @import("std");
+
+pub const ResourceStatus = enum(u8) {
+  Ok,
+  Failed,
+};
+
+pub const ResourceBuffer = opaque {};
+
+pub const ResourceHandle = extern struct {
+  id: u32,
+};
+
+pub extern fn open_resource(id: u32) ResourceHandle;
+
+pub extern fn read_resource(handle: *const ResourceHandle, buffer: ?*ResourceBuffer) i32;
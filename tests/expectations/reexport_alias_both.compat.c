@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum ResourceStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  Ok,
+  Failed,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ResourceStatus ResourceStatus;
+#else
+typedef uint8_t ResourceStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef struct ResourceBuffer ResourceBuffer;
+
+typedef struct ResourceHandle {
+  uint32_t id;
+} ResourceHandle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const struct ResourceHandle *handle, struct ResourceBuffer *buffer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum ResourceStatus
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Ok,
+  Failed,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum ResourceStatus ResourceStatus;
+#else
+typedef uint8_t ResourceStatus;
+#endif // __STDC_VERSION__ >= 202311L
+
+struct ResourceBuffer;
+
+struct ResourceHandle {
+  uint32_t id;
+};
+
+struct ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const struct ResourceHandle *handle, struct ResourceBuffer *buffer);
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Ok,
+  Failed,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Status Status;
+#else
+typedef uint8_t Status;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct Buffer Buffer;
+
+typedef struct {
+  uint32_t id;
+} Handle;
+
+/**
+ * The handle to a resource.
+ */
+typedef Handle ResourceHandle;
+
+typedef Buffer ResourceBuffer;
+
+typedef Status ResourceStatus;
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const Handle *handle, ResourceBuffer *buffer);
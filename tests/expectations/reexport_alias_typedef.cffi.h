@@ -0,0 +1,23 @@
+typedef struct Handle Handle;
+
+enum Status {
+  Ok,
+  Failed,
+};
+typedef uint8_t Status;
+
+typedef struct Buffer Buffer;
+
+struct Handle {
+  uint32_t id;
+};
+
+typedef Handle ResourceHandle;
+
+typedef Buffer ResourceBuffer;
+
+typedef Status ResourceStatus;
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const Handle *handle, ResourceBuffer *buffer);
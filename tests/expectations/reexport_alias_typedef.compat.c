@@ -0,0 +1,47 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  Ok,
+  Failed,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Status Status;
+#else
+typedef uint8_t Status;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+typedef struct Buffer Buffer;
+
+typedef struct {
+  uint32_t id;
+} Handle;
+
+/**
+ * The handle to a resource.
+ */
+typedef Handle ResourceHandle;
+
+typedef Buffer ResourceBuffer;
+
+typedef Status ResourceStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const Handle *handle, ResourceBuffer *buffer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Status : uint8_t {
+  Ok,
+  Failed,
+};
+
+struct Buffer;
+
+struct Handle {
+  uint32_t id;
+};
+
+/// The handle to a resource.
+using ResourceHandle = Handle;
+
+using ResourceBuffer = Buffer;
+
+using ResourceStatus = Status;
+
+extern "C" {
+
+ResourceHandle open_resource(uint32_t id);
+
+int32_t read_resource(const Handle *handle, ResourceBuffer *buffer);
+
+}  // extern "C"
@@ -0,0 +1,24 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Status : byte {
+  Ok,
+  Failed,
+}
+
+public partial struct Buffer { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Handle {
+  public uint id;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Handle open_resource(uint id);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int read_resource(Handle* handle, Buffer* buffer);
+}
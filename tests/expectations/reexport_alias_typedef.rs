@@ -0,0 +1,31 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Status {
+  Ok,
+  Failed,
+}
+
+#[repr(C)]
+pub struct Buffer {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Handle {
+  pub id: u32,
+}
+
+/// The handle to a resource.
+pub type ResourceHandle = Handle;
+
+pub type ResourceBuffer = Buffer;
+
+pub type ResourceStatus = Status;
+
+extern "C" {
+  pub fn open_resource(id: u32) -> ResourceHandle;
+
+  pub fn read_resource(handle: *const Handle, buffer: *mut ResourceBuffer) -> i32;
+}
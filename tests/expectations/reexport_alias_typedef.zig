@@ -0,0 +1,23 @@
+const std = @import("std");
+
+pub const Status = enum(u8) {
+  Ok,
+  Failed,
+};
+
+pub const Buffer = opaque {};
+
+pub const Handle = extern struct {
+  id: u32,
+};
+
+/// The handle to a resource.
+pub const ResourceHandle = Handle;
+
+pub const ResourceBuffer = Buffer;
+
+pub const ResourceStatus = Status;
+
+pub extern fn open_resource(id: u32) ResourceHandle;
+
+pub extern fn read_resource(handle: *const Handle, buffer: ?*ResourceBuffer) i32;
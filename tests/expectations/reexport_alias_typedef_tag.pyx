@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Ok,
+    Failed,
+  ctypedef uint8_t Status;
+
+  cdef struct Buffer:
+    pass
+
+  cdef struct Handle:
+    uint32_t id;
+
+  # The handle to a resource.
+  ctypedef Handle ResourceHandle;
+
+  ctypedef Buffer ResourceBuffer;
+
+  ctypedef Status ResourceStatus;
+
+  ResourceHandle open_resource(uint32_t id);
+
+  int32_t read_resource(const Handle *handle, ResourceBuffer *buffer);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  float width;
+  float height;
+} Size;
+
+typedef struct {
+  Point origin;
+  Size size;
+} Bounds;
+
+float area(const Bounds *bounds);
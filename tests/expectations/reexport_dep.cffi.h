@@ -0,0 +1,20 @@
+typedef struct Point Point;
+typedef struct Size Size;
+typedef struct Bounds Bounds;
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Size {
+  float width;
+  float height;
+};
+
+struct Bounds {
+  Point origin;
+  Size size;
+};
+
+float area(const Bounds *bounds);
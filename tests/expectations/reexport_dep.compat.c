@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  float width;
+  float height;
+} Size;
+
+typedef struct {
+  Point origin;
+  Size size;
+} Bounds;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float area(const Bounds *bounds);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Size {
+  float width;
+  float height;
+};
+
+struct Bounds {
+  Point origin;
+  Size size;
+};
+
+extern "C" {
+
+float area(const Bounds *bounds);
+
+}  // extern "C"
@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public float x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Size {
+  public float width;
+  public float height;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Bounds {
+  public Point origin;
+  public Size size;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern float area(Bounds* bounds);
+}
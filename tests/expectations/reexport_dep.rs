@@ -0,0 +1,24 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: f32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Size {
+  pub width: f32,
+  pub height: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Bounds {
+  pub origin: Point,
+  pub size: Size,
+}
+
+extern "C" {
+  pub fn area(bounds: *const Bounds) -> f32;
+}
@@ -0,0 +1,18 @@
+const std = @import("std");
+
+pub const Point = extern struct {
+  x: f32,
+  y: f32,
+};
+
+pub const Size = extern struct {
+  width: f32,
+  height: f32,
+};
+
+pub const Bounds = extern struct {
+  origin: Point,
+  size: Size,
+};
+
+pub extern fn area(bounds: *const Bounds) f32;
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Size {
+  float width;
+  float height;
+} Size;
+
+typedef struct Bounds {
+  struct Point origin;
+  struct Size size;
+} Bounds;
+
+float area(const struct Bounds *bounds);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Size {
+  float width;
+  float height;
+} Size;
+
+typedef struct Bounds {
+  struct Point origin;
+  struct Size size;
+} Bounds;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float area(const struct Bounds *bounds);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
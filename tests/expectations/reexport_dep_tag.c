@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Size {
+  float width;
+  float height;
+};
+
+struct Bounds {
+  struct Point origin;
+  struct Size size;
+};
+
+float area(const struct Bounds *bounds);
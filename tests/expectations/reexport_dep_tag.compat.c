@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Size {
+  float width;
+  float height;
+};
+
+struct Bounds {
+  struct Point origin;
+  struct Size size;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float area(const struct Bounds *bounds);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,32 @@
+#define C_H 10
+
+#define C_I ...
+
+typedef struct C_AwesomeB C_AwesomeB;
+typedef union C_D C_D;
+
+enum C_E {
+  x = 0,
+  y = 1,
+};
+typedef uint8_t C_E;
+
+typedef struct C_A C_A;
+
+typedef struct C_C C_C;
+
+struct C_AwesomeB {
+  int32_t x;
+  float y;
+};
+
+union C_D {
+  int32_t x;
+  float y;
+};
+
+typedef C_A C_F;
+
+const int32_t G;
+
+void root(const C_A *a, C_AwesomeB b, C_C c, C_D d, C_E e, C_F f);
@@ -0,0 +1,36 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum C_E : byte {
+  x = 0,
+  y = 1,
+}
+
+public partial struct C_A { }
+
+public partial struct C_C { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct C_AwesomeB {
+  public int x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct C_D {
+  [FieldOffset(0)] public int x;
+  [FieldOffset(0)] public float y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const int C_H = 10;
+
+  public static readonly nint C_I = unchecked((nint)((C_A*)(10)));
+
+  // `G` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(C_A* a, C_AwesomeB b, C_C c, C_D d, C_E e, C_A f);
+}
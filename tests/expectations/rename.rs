@@ -0,0 +1,44 @@
+pub const C_H: i32 = 10;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum C_E {
+  x = 0,
+  y = 1,
+}
+
+#[repr(C)]
+pub struct C_A {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct C_C {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct C_AwesomeB {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union C_D {
+  pub x: i32,
+  pub y: f32,
+}
+
+pub type C_F = C_A;
+
+pub const C_I: isize = (((10 as *mut C_F) as isize) as isize);
+
+extern "C" {
+  pub static G: i32;
+
+  pub fn root(a: *const C_A, b: C_AwesomeB, c: C_C, d: C_D, e: C_E, f: C_F);
+}
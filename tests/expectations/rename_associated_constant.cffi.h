@@ -0,0 +1,9 @@
+#define FOO_GA 10
+
+typedef struct Foo Foo;
+
+struct Foo {
+
+};
+
+void root(Foo x);
@@ -0,0 +1,26 @@
+typedef struct Foo Foo;
+typedef struct RenamedTy RenamedTy;
+typedef struct NoExternTy NoExternTy;
+typedef struct ContainsNoExternTy ContainsNoExternTy;
+
+struct Foo {
+  int32_t x;
+};
+
+struct RenamedTy {
+  uint64_t y;
+};
+
+struct NoExternTy {
+  uint8_t field;
+};
+
+struct ContainsNoExternTy {
+  NoExternTy field;
+};
+
+void root(Foo a);
+
+void renamed_func(RenamedTy a);
+
+void no_extern_func(ContainsNoExternTy a);
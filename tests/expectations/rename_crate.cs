@@ -0,0 +1,51 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+  public int x;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct RenamedTy {
+  public ulong y;
+}
+
+#if !DEFINE_FREEBSD
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct NoExternTy {
+  public byte field;
+}
+#endif
+
+#if !DEFINE_FREEBSD
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ContainsNoExternTy {
+  public NoExternTy field;
+}
+#endif
+
+#if DEFINE_FREEBSD
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ContainsNoExternTy {
+  public ulong field;
+}
+#endif
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Foo a);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void renamed_func(RenamedTy a);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void no_extern_func(ContainsNoExternTy a);
+}
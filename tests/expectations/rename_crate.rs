@@ -0,0 +1,45 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct RenamedTy {
+  pub y: u64,
+}
+
+#[cfg(all(not(target_os = "freebsd")))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NoExternTy {
+  pub field: u8,
+}
+
+#[cfg(not(target_os = "freebsd"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ContainsNoExternTy {
+  pub field: NoExternTy,
+}
+
+#[cfg(target_os = "freebsd")]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ContainsNoExternTy {
+  pub field: u64,
+}
+
+extern "C" {
+  pub fn root(a: Foo);
+
+  pub fn renamed_func(a: RenamedTy);
+
+  pub fn no_extern_func(a: ContainsNoExternTy);
+}
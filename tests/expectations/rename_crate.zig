@@ -0,0 +1,38 @@
+#if 0
+DEF DEFINE_FREEBSD = 0
+#endif
+
+
+const std = @import("std");
+
+pub const Foo = extern struct {
+  x: i32,
+};
+
+pub const RenamedTy = extern struct {
+  y: u64,
+};
+
+// #if !defined(DEFINE_FREEBSD)
+pub const NoExternTy = extern struct {
+  field: u8,
+};
+// #endif
+
+// #if !defined(DEFINE_FREEBSD)
+pub const ContainsNoExternTy = extern struct {
+  field: NoExternTy,
+};
+// #endif
+
+// #if defined(DEFINE_FREEBSD)
+pub const ContainsNoExternTy = extern struct {
+  field: u64,
+};
+// #endif
+
+pub extern fn root(a: Foo) void;
+
+pub extern fn renamed_func(a: RenamedTy) void;
+
+pub extern fn no_extern_func(a: ContainsNoExternTy) void;
@@ -0,0 +1,10 @@
+typedef struct B B;
+
+typedef struct StyleA StyleA;
+
+struct B {
+  int32_t x;
+  float y;
+};
+
+void root(const StyleA *a, B b);
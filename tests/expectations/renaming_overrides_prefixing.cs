@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct StyleA { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct B {
+  public int x;
+  public float y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(StyleA* a, B b);
+}
@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct StyleA {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct B {
+  pub x: i32,
+  pub y: f32,
+}
+
+extern "C" {
+  pub fn root(a: *const StyleA, b: B);
+}
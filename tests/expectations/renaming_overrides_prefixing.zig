@@ -0,0 +1,10 @@
+const std = @import("std");
+
+pub const StyleA = opaque {};
+
+pub const B = extern struct {
+  x: i32,
+  y: f32,
+};
+
+pub extern fn root(a: ?*const StyleA, b: B) void;
@@ -0,0 +1,71 @@
+typedef struct A A;
+typedef struct B B;
+typedef struct D_Body D_Body;
+typedef struct C C;
+typedef struct E E;
+typedef struct F F;
+
+struct A {
+  int32_t namespace_;
+  float float_;
+};
+
+struct B {
+  int32_t namespace_;
+  float float_;
+};
+
+enum C_Tag {
+  D,
+};
+typedef uint8_t C_Tag;
+
+struct D_Body {
+  int32_t namespace_;
+  float float_;
+};
+
+struct C {
+  C_Tag tag;
+  union {
+    D_Body d;
+  };
+};
+
+enum E_Tag {
+  Double,
+  Float,
+};
+typedef uint8_t E_Tag;
+
+struct E {
+  E_Tag tag;
+  union {
+    struct {
+      double double_;
+    };
+    struct {
+      float float_;
+    };
+  };
+};
+
+enum F_Tag {
+  double_,
+  float_,
+};
+typedef uint8_t F_Tag;
+
+struct F {
+  F_Tag tag;
+  union {
+    struct {
+      double double_;
+    };
+    struct {
+      float float_;
+    };
+  };
+};
+
+void root(A a, B b, C c, E e, F f, int32_t namespace_, float float_);
@@ -0,0 +1,96 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct A {
+  public int namespace_;
+  public float float_;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct B {
+  public int namespace_;
+  public float float_;
+}
+
+public enum C_Tag : byte {
+  D,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct D_Body {
+  public int namespace_;
+  public float float_;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct C {
+  public C_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public D_Body d;
+  }
+}
+
+public enum E_Tag : byte {
+  Double,
+  Float,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct E {
+  public E_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Double_Body double_;
+    [FieldOffset(0)] public Float_Body float_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Double_Body {
+    public double double_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Float_Body {
+    public float float_;
+  }
+}
+
+public enum F_Tag : byte {
+  double_,
+  float_,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct F {
+  public F_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public double_Body double_;
+    [FieldOffset(0)] public float_Body float_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct double_Body {
+    public double double_;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct float_Body {
+    public float float_;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c, E e, F f, int namespace_, float float_);
+}
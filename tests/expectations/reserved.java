@@ -0,0 +1,186 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class A {
+    private A() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("namespace_"),
+      JAVA_FLOAT.withName("float_")
+    ).withName("A");
+  }
+
+  public static final class B {
+    private B() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("namespace_"),
+      JAVA_FLOAT.withName("float_")
+    ).withName("B");
+  }
+
+  public static final class C_Tag {
+    private C_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte D = 0;
+  }
+
+  public static final class D_Body {
+    private D_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("namespace_"),
+      JAVA_FLOAT.withName("float_")
+    ).withName("D_Body");
+  }
+
+  public static final class C {
+    private C() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      C_Tag.LAYOUT.withName("tag"),
+      unionLayout(D_Body.LAYOUT.withName("d"))
+    ).withName("C");
+  }
+
+  public static final class E_Tag {
+    private E_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Double = 0;
+    public static final byte Float = 1;
+  }
+
+  public static final class E {
+    private E() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      E_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_DOUBLE.withName("double_")), structLayout(JAVA_FLOAT.withName("float_")))
+    ).withName("E");
+  }
+
+  public static final class F_Tag {
+    private F_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte double_ = 0;
+    public static final byte float_ = 1;
+  }
+
+  public static final class F {
+    private F() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      F_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_DOUBLE.withName("double_")), structLayout(JAVA_FLOAT.withName("float_")))
+    ).withName("F");
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(A.LAYOUT,
+                                                                                     B.LAYOUT,
+                                                                                     C.LAYOUT,
+                                                                                     E.LAYOUT,
+                                                                                     F.LAYOUT,
+                                                                                     JAVA_INT,
+                                                                                     JAVA_FLOAT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,40 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct A {
+  pub namespace_: i32,
+  pub float_: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct B {
+  pub namespace_: i32,
+  pub float_: f32,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum C {
+  D {
+    namespace_: i32,
+    float_: f32,
+  },
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum E {
+  Double(f64),
+  Float(f32),
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum F {
+  double_(f64),
+  float_(f32),
+}
+
+extern "C" {
+  pub fn root(a: A, b: B, c: C, e: E, f: F, namespace_: i32, float_: f32);
+}
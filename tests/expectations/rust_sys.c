@@ -0,0 +1,71 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_EVENTS 32
+
+#define SCALE 2.0
+
+#define MASK ~(1 << 3)
+
+/**
+ * An opaque handle.
+ */
+typedef struct Context Context;
+
+enum Event_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint16_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Quit,
+  Key,
+  Resize,
+  Other = 10,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Event_Tag Event_Tag;
+#else
+typedef uint16_t Event_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Resize_Body;
+
+typedef struct {
+  Event_Tag tag;
+  union {
+    struct {
+      uint32_t key;
+    };
+    Resize_Body resize;
+  };
+} Event;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+typedef struct {
+  uint32_t type;
+#if defined(DEFINE_EXTRA)
+  uint32_t extra
+#endif
+  ;
+} Header;
+
+#define ORIGIN (Point){ .x = 0, .y = -1 }
+
+extern uint32_t EVENT_COUNT;
+
+Context *context_new(void);
+
+uintptr_t context_poll(Context *ctx,
+                       Event (*events)[MAX_EVENTS],
+                       void (*callback)(const Event *event));
+
+void context_abort(Context *ctx, Point fn, Header header);
@@ -0,0 +1,52 @@
+#define MAX_EVENTS 32
+
+#define MASK 4294967287
+
+typedef struct Resize_Body Resize_Body;
+typedef struct Event Event;
+typedef struct Point Point;
+typedef struct Header Header;
+
+typedef struct Context Context;
+
+enum Event_Tag {
+  Quit,
+  Key,
+  Resize,
+  Other = 10,
+};
+typedef uint16_t Event_Tag;
+
+struct Resize_Body {
+  uint32_t width;
+  uint32_t height;
+};
+
+struct Event {
+  Event_Tag tag;
+  union {
+    struct {
+      uint32_t key;
+    };
+    Resize_Body resize;
+  };
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Header {
+  uint32_t type;
+};
+
+uint32_t EVENT_COUNT;
+
+Context *context_new(void);
+
+uintptr_t context_poll(Context *ctx,
+                       Event (*events)[MAX_EVENTS],
+                       void (*callback)(const Event *event));
+
+void context_abort(Context *ctx, Point fn, Header header);
@@ -0,0 +1,70 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint8_t MAX_EVENTS = 32;
+
+constexpr static const float SCALE = 2.0;
+
+constexpr static const uint32_t MASK = ~(1 << 3);
+
+/// An opaque handle.
+struct Context;
+
+struct Event {
+  enum class Tag : uint16_t {
+    Quit,
+    Key,
+    Resize,
+    Other = 10,
+  };
+
+  struct Key_Body {
+    uint32_t _0;
+  };
+
+  struct Resize_Body {
+    uint32_t width;
+    uint32_t height;
+  };
+
+  Tag tag;
+  union {
+    Key_Body key;
+    Resize_Body resize;
+  };
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Header {
+  uint32_t type;
+#if defined(DEFINE_EXTRA)
+  uint32_t extra
+#endif
+  ;
+};
+
+constexpr static const Point ORIGIN = Point{
+  /* .x = */ 0,
+  /* .y = */ -1
+};
+
+extern "C" {
+
+extern uint32_t EVENT_COUNT;
+
+Context *context_new();
+
+uintptr_t context_poll(Context *ctx,
+                       Event (*events)[MAX_EVENTS],
+                       void (*callback)(const Event *event));
+
+void context_abort(Context *ctx, Point fn, Header header);
+
+}  // extern "C"
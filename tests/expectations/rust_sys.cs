@@ -0,0 +1,76 @@
+using System;
+using System.Runtime.InteropServices;
+
+/// <summary>
+/// An opaque handle.
+/// </summary>
+public partial struct Context { }
+
+public enum Event_Tag : ushort {
+  Quit,
+  Key,
+  Resize,
+  Other = 10,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Resize_Body {
+  public uint width;
+  public uint height;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Event {
+  public Event_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public Key_Body key;
+    [FieldOffset(0)] public Resize_Body resize;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Key_Body {
+    public uint key;
+  }
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Point {
+  public int x;
+  public int y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Header {
+  public uint type;
+#if DEFINE_EXTRA
+  public uint extra;
+#endif
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public const byte MAX_EVENTS = 32;
+
+  public const float SCALE = (float)2.0;
+
+  public const uint MASK = unchecked((uint)(~(1 << 3)));
+
+  public static readonly Point ORIGIN = new Point { x = 0, y = -1 };
+
+  // `EVENT_COUNT` is a global variable, which P/Invoke can't import; use `NativeLibrary.GetExport` to look it up instead.
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Context* context_new();
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern nuint context_poll(Context* ctx,
+                                          Event* events,
+                                          delegate* unmanaged[Cdecl]<Event*, void> callback);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void context_abort(Context* ctx, Point fn, Header header);
+}
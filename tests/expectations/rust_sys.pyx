@@ -0,0 +1,53 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint8_t MAX_EVENTS # = 32
+
+  const float SCALE # = 2.0
+
+  const uint32_t MASK # = ~(1 << 3)
+
+  # An opaque handle.
+  ctypedef struct Context:
+    pass
+
+  cdef enum:
+    Quit,
+    Key,
+    Resize,
+    Other # = 10,
+  ctypedef uint16_t Event_Tag;
+
+  ctypedef struct Resize_Body:
+    uint32_t width;
+    uint32_t height;
+
+  ctypedef struct Event:
+    Event_Tag tag;
+    uint32_t key;
+    Resize_Body resize;
+
+  ctypedef struct Point:
+    int32_t x;
+    int32_t y;
+
+  ctypedef struct Header:
+    uint32_t type;
+    uint32_t extra;
+
+  const Point ORIGIN # = <Point>{ 0, -1 }
+
+  extern uint32_t EVENT_COUNT;
+
+  Context *context_new();
+
+  uintptr_t context_poll(Context *ctx,
+                         Event (*events)[MAX_EVENTS],
+                         void (*callback)(const Event *event));
+
+  void context_abort(Context *ctx, Point fn, Header header);
@@ -0,0 +1,56 @@
+pub const MAX_EVENTS: u8 = 32;
+
+pub const SCALE: f32 = 2.0;
+
+pub const MASK: u32 = 4294967287;
+
+/// An opaque handle.
+#[repr(C)]
+pub struct Context {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C, u16)]
+#[derive(Clone, Copy)]
+pub enum Event {
+  Quit,
+  Key(u32),
+  Resize {
+    width: u32,
+    height: u32,
+  },
+  Other = 10,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Point {
+  pub x: i32,
+  pub y: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Header {
+  pub r#type: u32,
+  #[cfg(feature = "extra")]
+  pub extra: u32,
+}
+
+pub const ORIGIN: Point = Point { x: 0, y: -1 };
+
+extern "C" {
+  pub static mut EVENT_COUNT: u32;
+
+  #[must_use]
+  pub fn context_new() -> *mut Context;
+
+  pub fn context_poll(
+    ctx: *mut Context,
+    events: *mut [Event; MAX_EVENTS as usize],
+    callback: Option<unsafe extern "C" fn(event: *const Event)>,
+  ) -> usize;
+
+  pub fn context_abort(ctx: *mut Context, r#fn: Point, header: Header) -> !;
+}
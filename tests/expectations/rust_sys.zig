@@ -0,0 +1,56 @@
+const std = @import("std");
+
+pub const MAX_EVENTS: u8 = 32;
+
+pub const SCALE: f32 = 2.0;
+
+pub const MASK: u32 = ~(1 << 3);
+
+/// An opaque handle.
+pub const Context = opaque {};
+
+pub const Event_Tag = enum(u16) {
+  Quit,
+  Key,
+  Resize,
+  Other = 10,
+};
+
+pub const Resize_Body = extern struct {
+  width: u32,
+  height: u32,
+};
+
+pub const Event = extern struct {
+  tag: Event_Tag,
+  payload: extern union {
+    key: extern struct {
+      key: u32,
+    },
+    resize: Resize_Body,
+  },
+};
+
+pub const Point = extern struct {
+  x: i32,
+  y: i32,
+};
+
+pub const Header = extern struct {
+  @"type": u32,
+  // #if defined(DEFINE_EXTRA)
+  extra: u32,
+  // #endif
+};
+
+pub const ORIGIN: Point = Point{ .x = 0, .y = -1 };
+
+pub extern var EVENT_COUNT: u32;
+
+pub extern fn context_new() ?*Context;
+
+pub extern fn context_poll(ctx: *Context,
+                           events: *[MAX_EVENTS]Event,
+                           callback: ?*const fn (event: *const Event) callconv(.c) void) usize;
+
+pub extern fn context_abort(ctx: ?*Context, @"fn": Point, header: Header) noreturn;
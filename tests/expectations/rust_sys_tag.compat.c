@@ -0,0 +1,81 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_EVENTS 32
+
+#define SCALE 2.0
+
+#define MASK ~(1 << 3)
+
+/**
+ * An opaque handle.
+ */
+struct Context;
+
+enum Event_Tag
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint16_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  Quit,
+  Key,
+  Resize,
+  Other = 10,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum Event_Tag Event_Tag;
+#else
+typedef uint16_t Event_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+struct Resize_Body {
+  uint32_t width;
+  uint32_t height;
+};
+
+struct Event {
+  Event_Tag tag;
+  union {
+    struct {
+      uint32_t key;
+    };
+    struct Resize_Body resize;
+  };
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Header {
+  uint32_t type;
+#if defined(DEFINE_EXTRA)
+  uint32_t extra
+#endif
+  ;
+};
+
+#define ORIGIN (Point){ .x = 0, .y = -1 }
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t EVENT_COUNT;
+
+struct Context *context_new(void);
+
+uintptr_t context_poll(struct Context *ctx,
+                       struct Event (*events)[MAX_EVENTS],
+                       void (*callback)(const struct Event *event));
+
+void context_abort(struct Context *ctx, struct Point fn, struct Header header);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,58 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum A : byte {
+  A_A1,
+  A_A2,
+  A_A3,
+  /// <summary>
+  /// Must be last for serialization purposes
+  /// </summary>
+  A_Sentinel,
+}
+
+public enum B : byte {
+  B_B1,
+  B_B2,
+  B_B3,
+  /// <summary>
+  /// Must be last for serialization purposes
+  /// </summary>
+  B_Sentinel,
+}
+
+public enum C_Tag : byte {
+  C_C1,
+  C_C2,
+  C_C3,
+  /// <summary>
+  /// Must be last for serialization purposes
+  /// </summary>
+  C_Sentinel,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct C_C1_Body {
+  public C_Tag tag;
+  public uint a;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct C_C2_Body {
+  public C_Tag tag;
+  public uint b;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct C {
+  [FieldOffset(0)] public C_Tag tag;
+  [FieldOffset(0)] public C_C1_Body c1;
+  [FieldOffset(0)] public C_C2_Body c2;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(A a, B b, C c);
+}
@@ -0,0 +1,37 @@
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum A {
+  A_A1,
+  A_A2,
+  A_A3,
+  /// Must be last for serialization purposes
+  A_Sentinel,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum B {
+  B_B1,
+  B_B2,
+  B_B3,
+  /// Must be last for serialization purposes
+  B_Sentinel,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum C {
+  C_C1 {
+    a: u32,
+  },
+  C_C2 {
+    b: u32,
+  },
+  C_C3,
+  /// Must be last for serialization purposes
+  C_Sentinel,
+}
+
+extern "C" {
+  pub fn root(a: A, b: B, c: C);
+}
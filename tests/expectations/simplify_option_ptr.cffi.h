@@ -0,0 +1,22 @@
+typedef struct Foo Foo;
+typedef union Bar Bar;
+
+typedef struct Opaque Opaque;
+
+typedef struct Option_____Opaque Option_____Opaque;
+
+struct Foo {
+  const Opaque *x;
+  Opaque *y;
+  void (*z)(void);
+  void (**zz)(void);
+};
+
+union Bar {
+  const Opaque *x;
+  Opaque *y;
+  void (*z)(void);
+  void (**zz)(void);
+};
+
+void root(const Opaque *a, Opaque *b, Foo c, Bar d, Option_____Opaque *e, void (*f)(const Opaque*));
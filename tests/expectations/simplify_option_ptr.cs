@@ -0,0 +1,34 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Opaque { }
+
+public partial struct Option_____Opaque { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo {
+  public Opaque* x;
+  public Opaque* y;
+  public delegate* unmanaged[Cdecl]<void> z;
+  public delegate* unmanaged[Cdecl]<void>* zz;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Bar {
+  [FieldOffset(0)] public Opaque* x;
+  [FieldOffset(0)] public Opaque* y;
+  [FieldOffset(0)] public delegate* unmanaged[Cdecl]<void> z;
+  [FieldOffset(0)] public delegate* unmanaged[Cdecl]<void>* zz;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Opaque* a,
+                                 Opaque* b,
+                                 Foo c,
+                                 Bar d,
+                                 Option_____Opaque* e,
+                                 delegate* unmanaged[Cdecl]<Opaque*, void> f);
+}
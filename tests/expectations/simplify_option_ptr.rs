@@ -0,0 +1,40 @@
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct Option_____Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub x: *const Opaque,
+  pub y: *mut Opaque,
+  pub z: Option<unsafe extern "C" fn()>,
+  pub zz: *mut Option<unsafe extern "C" fn()>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Bar {
+  pub x: *const Opaque,
+  pub y: *mut Opaque,
+  pub z: Option<unsafe extern "C" fn()>,
+  pub zz: *mut Option<unsafe extern "C" fn()>,
+}
+
+extern "C" {
+  pub fn root(
+    a: *const Opaque,
+    b: *mut Opaque,
+    c: Foo,
+    d: Bar,
+    e: *mut Option_____Opaque,
+    f: unsafe extern "C" fn(*const Opaque),
+  );
+}
@@ -0,0 +1,15 @@
+enum UE {
+  UV,
+};
+typedef size_t UE;
+
+enum IE {
+  IV,
+};
+typedef ptrdiff_t IE;
+
+typedef size_t Usize;
+
+typedef ptrdiff_t Isize;
+
+void root(Usize, Isize, UE, IE);
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum UE : ulong {
+  UV,
+}
+
+public enum IE : long {
+  IV,
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(nuint _0, nint _1, UE _2, IE _3);
+}
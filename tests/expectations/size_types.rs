@@ -0,0 +1,19 @@
+#[repr(usize)]
+#[derive(Clone, Copy)]
+pub enum UE {
+  UV,
+}
+
+#[repr(isize)]
+#[derive(Clone, Copy)]
+pub enum IE {
+  IV,
+}
+
+pub type Usize = usize;
+
+pub type Isize = isize;
+
+extern "C" {
+  pub fn root(_: Usize, _: Isize, _: UE, _: IE);
+}
@@ -0,0 +1,52 @@
+typedef struct Slice_i32 Slice_i32;
+typedef struct SliceMut_f32 SliceMut_f32;
+typedef struct Slice_u16 Slice_u16;
+typedef struct SliceMut_u16 SliceMut_u16;
+typedef struct Slice_u8 Slice_u8;
+typedef struct Buffer Buffer;
+typedef struct Slice_Slice_u8 Slice_Slice_u8;
+
+struct Slice_i32 {
+  const int32_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceMut_f32 {
+  float *ptr;
+  uintptr_t len;
+};
+
+struct Slice_u16 {
+  const uint16_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceMut_u16 {
+  uint16_t *ptr;
+  uintptr_t len;
+};
+
+struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct Buffer {
+  Slice_u8 bytes;
+  Slice_u8 name;
+};
+
+struct Slice_Slice_u8 {
+  const Slice_u8 *ptr;
+  uintptr_t len;
+};
+
+int64_t sum(Slice_i32 values);
+
+void fill(SliceMut_f32 values, float value);
+
+void copy(Slice_u16 from, SliceMut_u16 to);
+
+void write(Buffer buffer, Slice_Slice_u8 lines);
+
+bool find(Slice_u8 haystack, Slice_u8 needle);
@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const int32_t *ptr;
+  uintptr_t len;
+} Slice_i32;
+
+typedef struct {
+  float *ptr;
+  uintptr_t len;
+} SliceMut_f32;
+
+typedef struct {
+  const uint16_t *ptr;
+  uintptr_t len;
+} Slice_u16;
+
+typedef struct {
+  uint16_t *ptr;
+  uintptr_t len;
+} SliceMut_u16;
+
+typedef struct {
+  const uint8_t *ptr;
+  uintptr_t len;
+} Slice_u8;
+
+typedef struct {
+  Slice_u8 bytes;
+  Slice_u8 name;
+} Buffer;
+
+typedef struct {
+  const Slice_u8 *ptr;
+  uintptr_t len;
+} Slice_Slice_u8;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int64_t sum(Slice_i32 values);
+
+void fill(SliceMut_f32 values, float value);
+
+void copy(Slice_u16 from, SliceMut_u16 to);
+
+void write(Buffer buffer, Slice_Slice_u8 lines);
+
+bool find(Slice_u8 haystack, Slice_u8 needle);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
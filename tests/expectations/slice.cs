@@ -0,0 +1,64 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_i32 {
+  public int* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct SliceMut_f32 {
+  public float* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_u16 {
+  public ushort* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct SliceMut_u16 {
+  public ushort* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_u8 {
+  public byte* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Buffer {
+  public Slice_u8 bytes;
+  public Slice_u8 name;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_Slice_u8 {
+  public Slice_u8* ptr;
+  public nuint len;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern long sum(Slice_i32 values);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void fill(SliceMut_f32 values, float value);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void copy(Slice_u16 from, SliceMut_u16 to);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void write(Buffer buffer, Slice_Slice_u8 lines);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  [return: MarshalAs(UnmanagedType.U1)]
+  public static extern bool find(Slice_u8 haystack, Slice_u8 needle);
+}
@@ -0,0 +1,45 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Slice_i32:
+    const int32_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct SliceMut_f32:
+    float *ptr;
+    uintptr_t len;
+
+  ctypedef struct Slice_u16:
+    const uint16_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct SliceMut_u16:
+    uint16_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct Slice_u8:
+    const uint8_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct Buffer:
+    Slice_u8 bytes;
+    Slice_u8 name;
+
+  ctypedef struct Slice_Slice_u8:
+    const Slice_u8 *ptr;
+    uintptr_t len;
+
+  int64_t sum(Slice_i32 values);
+
+  void fill(SliceMut_f32 values, float value);
+
+  void copy(Slice_u16 from, SliceMut_u16 to);
+
+  void write(Buffer buffer, Slice_Slice_u8 lines);
+
+  bool find(Slice_u8 haystack, Slice_u8 needle);
@@ -0,0 +1,60 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_i32 {
+  pub ptr: *const i32,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SliceMut_f32 {
+  pub ptr: *mut f32,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_u16 {
+  pub ptr: *const u16,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SliceMut_u16 {
+  pub ptr: *mut u16,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_u8 {
+  pub ptr: *const u8,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Buffer {
+  pub bytes: Slice_u8,
+  pub name: Slice_u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_Slice_u8 {
+  pub ptr: *const Slice_u8,
+  pub len: usize,
+}
+
+extern "C" {
+  pub fn sum(values: Slice_i32) -> i64;
+
+  pub fn fill(values: SliceMut_f32, value: f32);
+
+  pub fn copy(from: Slice_u16, to: SliceMut_u16);
+
+  pub fn write(buffer: Buffer, lines: Slice_Slice_u8);
+
+  pub fn find(haystack: Slice_u8, needle: Slice_u8) -> bool;
+}
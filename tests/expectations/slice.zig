@@ -0,0 +1,46 @@
+const std = @import("std");
+
+pub const Slice_i32 = extern struct {
+  ptr: *const i32,
+  len: usize,
+};
+
+pub const SliceMut_f32 = extern struct {
+  ptr: *f32,
+  len: usize,
+};
+
+pub const Slice_u16 = extern struct {
+  ptr: *const u16,
+  len: usize,
+};
+
+pub const SliceMut_u16 = extern struct {
+  ptr: *u16,
+  len: usize,
+};
+
+pub const Slice_u8 = extern struct {
+  ptr: *const u8,
+  len: usize,
+};
+
+pub const Buffer = extern struct {
+  bytes: Slice_u8,
+  name: Slice_u8,
+};
+
+pub const Slice_Slice_u8 = extern struct {
+  ptr: *const Slice_u8,
+  len: usize,
+};
+
+pub extern fn sum(values: Slice_i32) i64;
+
+pub extern fn fill(values: SliceMut_f32, value: f32) void;
+
+pub extern fn copy(from: Slice_u16, to: SliceMut_u16) void;
+
+pub extern fn write(buffer: Buffer, lines: Slice_Slice_u8) void;
+
+pub extern fn find(haystack: Slice_u8, needle: Slice_u8) bool;
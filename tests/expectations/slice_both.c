@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Slice_i32 {
+  const int32_t *ptr;
+  uintptr_t len;
+} Slice_i32;
+
+typedef struct SliceMut_f32 {
+  float *ptr;
+  uintptr_t len;
+} SliceMut_f32;
+
+typedef struct Slice_u16 {
+  const uint16_t *ptr;
+  uintptr_t len;
+} Slice_u16;
+
+typedef struct SliceMut_u16 {
+  uint16_t *ptr;
+  uintptr_t len;
+} SliceMut_u16;
+
+typedef struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+} Slice_u8;
+
+typedef struct Buffer {
+  struct Slice_u8 bytes;
+  struct Slice_u8 name;
+} Buffer;
+
+typedef struct Slice_Slice_u8 {
+  const struct Slice_u8 *ptr;
+  uintptr_t len;
+} Slice_Slice_u8;
+
+int64_t sum(struct Slice_i32 values);
+
+void fill(struct SliceMut_f32 values, float value);
+
+void copy(struct Slice_u16 from, struct SliceMut_u16 to);
+
+void write(struct Buffer buffer, struct Slice_Slice_u8 lines);
+
+bool find(struct Slice_u8 haystack, struct Slice_u8 needle);
@@ -0,0 +1,32 @@
+typedef struct Slice_u32 Slice_u32;
+typedef struct Slice_u8 Slice_u8;
+typedef struct SliceMut_Slice_u8 SliceMut_Slice_u8;
+typedef struct Items_u32 Items_u32;
+typedef struct SliceMut_f64 SliceMut_f64;
+
+struct Slice_u32 {
+  const uint32_t *ptr;
+  uintptr_t len;
+};
+
+struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceMut_Slice_u8 {
+  Slice_u8 *ptr;
+  uintptr_t len;
+};
+
+struct Items_u32 {
+  Slice_u32 items;
+  SliceMut_Slice_u8 names;
+};
+
+struct SliceMut_f64 {
+  double *ptr;
+  uintptr_t len;
+};
+
+void items(Items_u32 items, SliceMut_f64 values);
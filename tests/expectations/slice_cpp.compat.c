@@ -0,0 +1,50 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const uint32_t *ptr;
+  uintptr_t len;
+} Slice_u32;
+
+typedef struct {
+  const uint8_t *ptr;
+  uintptr_t len;
+} Slice_u8;
+
+typedef struct {
+  Slice_u8 *ptr;
+  uintptr_t len;
+} SliceMut_Slice_u8;
+
+typedef struct {
+  Slice_u32 items;
+  SliceMut_Slice_u8 names;
+} Items_u32;
+
+typedef struct {
+  double *ptr;
+  uintptr_t len;
+} SliceMut_f64;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void items(Items_u32 items, SliceMut_f64 values);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
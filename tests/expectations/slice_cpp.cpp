@@ -0,0 +1,28 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Items {
+  Span<const T> items;
+  Span<Span<const uint8_t>> names;
+};
+
+extern "C" {
+
+void items(Items<uint32_t> items, Span<double> values);
+
+}  // extern "C"
@@ -0,0 +1,50 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_u32 {
+  public uint* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Slice_u8 {
+  public byte* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct SliceMut_Slice_u8 {
+  public Slice_u8* ptr;
+  public nuint len;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Items_u32 {
+  public Slice_u32 items;
+  public SliceMut_Slice_u8 names;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct SliceMut_f64 {
+  public double* ptr;
+  public nuint len;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void items(Items_u32 items, SliceMut_f64 values);
+}
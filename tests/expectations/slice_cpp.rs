@@ -0,0 +1,49 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_u32 {
+  pub ptr: *const u32,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Slice_u8 {
+  pub ptr: *const u8,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SliceMut_Slice_u8 {
+  pub ptr: *mut Slice_u8,
+  pub len: usize,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Items_u32 {
+  pub items: Slice_u32,
+  pub names: SliceMut_Slice_u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SliceMut_f64 {
+  pub ptr: *mut f64,
+  pub len: usize,
+}
+
+extern "C" {
+  pub fn items(items: Items_u32, values: SliceMut_f64);
+}
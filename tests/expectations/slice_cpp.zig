@@ -0,0 +1,39 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+const std = @import("std");
+
+pub const Slice_u32 = extern struct {
+  ptr: *const u32,
+  len: usize,
+};
+
+pub const Slice_u8 = extern struct {
+  ptr: *const u8,
+  len: usize,
+};
+
+pub const SliceMut_Slice_u8 = extern struct {
+  ptr: *Slice_u8,
+  len: usize,
+};
+
+pub const Items_u32 = extern struct {
+  items: Slice_u32,
+  names: SliceMut_Slice_u8,
+};
+
+pub const SliceMut_f64 = extern struct {
+  ptr: *f64,
+  len: usize,
+};
+
+pub extern fn items(items: Items_u32, values: SliceMut_f64) void;
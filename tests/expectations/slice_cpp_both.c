@@ -0,0 +1,42 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Slice_u32 {
+  const uint32_t *ptr;
+  uintptr_t len;
+} Slice_u32;
+
+typedef struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+} Slice_u8;
+
+typedef struct SliceMut_Slice_u8 {
+  struct Slice_u8 *ptr;
+  uintptr_t len;
+} SliceMut_Slice_u8;
+
+typedef struct Items_u32 {
+  struct Slice_u32 items;
+  struct SliceMut_Slice_u8 names;
+} Items_u32;
+
+typedef struct SliceMut_f64 {
+  double *ptr;
+  uintptr_t len;
+} SliceMut_f64;
+
+void items(struct Items_u32 items, struct SliceMut_f64 values);
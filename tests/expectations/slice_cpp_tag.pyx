@@ -0,0 +1,40 @@
+#if defined(__cplusplus)
+#include <cstddef>
+
+template<typename T>
+struct Span {
+  T *ptr;
+  size_t len;
+};
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Slice_u32:
+    const uint32_t *ptr;
+    uintptr_t len;
+
+  cdef struct Slice_u8:
+    const uint8_t *ptr;
+    uintptr_t len;
+
+  cdef struct SliceMut_Slice_u8:
+    Slice_u8 *ptr;
+    uintptr_t len;
+
+  cdef struct Items_u32:
+    Slice_u32 items;
+    SliceMut_Slice_u8 names;
+
+  cdef struct SliceMut_f64:
+    double *ptr;
+    uintptr_t len;
+
+  void items(Items_u32 items, SliceMut_f64 values);
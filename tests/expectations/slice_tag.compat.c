@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Slice_i32 {
+  const int32_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceMut_f32 {
+  float *ptr;
+  uintptr_t len;
+};
+
+struct Slice_u16 {
+  const uint16_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceMut_u16 {
+  uint16_t *ptr;
+  uintptr_t len;
+};
+
+struct Slice_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct Buffer {
+  struct Slice_u8 bytes;
+  struct Slice_u8 name;
+};
+
+struct Slice_Slice_u8 {
+  const struct Slice_u8 *ptr;
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int64_t sum(struct Slice_i32 values);
+
+void fill(struct SliceMut_f32 values, float value);
+
+void copy(struct Slice_u16 from, struct SliceMut_u16 to);
+
+void write(struct Buffer buffer, struct Slice_Slice_u8 lines);
+
+bool find(struct Slice_u8 haystack, struct Slice_u8 needle);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
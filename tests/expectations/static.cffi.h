@@ -0,0 +1,15 @@
+typedef struct Foo Foo;
+
+typedef struct Bar Bar;
+
+struct Foo {
+
+};
+
+const int32_t NUMBER;
+
+Foo FOO;
+
+const Bar BAR;
+
+void root(void);
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Bar {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+
+}
+
+extern "C" {
+  pub static NUMBER: i32;
+
+  pub static mut FOO: Foo;
+
+  pub static BAR: Bar;
+
+  pub fn root();
+}
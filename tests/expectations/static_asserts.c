@@ -0,0 +1,178 @@
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Red,
+  Green,
+  Blue,
+} Color;
+
+static_assert(sizeof(Color) == 4, "unexpected size of Color");
+static_assert(alignof(Color) == 4, "unexpected alignment of Color");
+
+enum Small
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  A,
+  B,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Small Small;
+#else
+typedef uint8_t Small;
+#endif // __STDC_VERSION__ >= 202311L
+
+static_assert(sizeof(Small) == 1, "unexpected size of Small");
+static_assert(alignof(Small) == 1, "unexpected alignment of Small");
+
+typedef struct Private {
+  alignas(8) unsigned char _private[16];
+} Private;
+
+static_assert(sizeof(Private) == 16, "unexpected size of Private");
+static_assert(alignof(Private) == 8, "unexpected alignment of Private");
+
+typedef struct {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+} Inner;
+
+static_assert(sizeof(Inner) == 12, "unexpected size of Inner");
+static_assert(alignof(Inner) == 4, "unexpected alignment of Inner");
+static_assert(offsetof(Inner, a) == 0, "unexpected offset of Inner.a");
+static_assert(offsetof(Inner, b) == 4, "unexpected offset of Inner.b");
+static_assert(offsetof(Inner, c) == 8, "unexpected offset of Inner.c");
+
+typedef struct {
+  bool flag;
+  Inner inner;
+  uint16_t values[3];
+  const Inner *ptr;
+  uint8_t (*callback)(uint32_t);
+} Outer;
+
+static_assert(sizeof(Outer) == 40, "unexpected size of Outer");
+static_assert(alignof(Outer) == 8, "unexpected alignment of Outer");
+static_assert(offsetof(Outer, flag) == 0, "unexpected offset of Outer.flag");
+static_assert(offsetof(Outer, inner) == 4, "unexpected offset of Outer.inner");
+static_assert(offsetof(Outer, values) == 16, "unexpected offset of Outer.values");
+static_assert(offsetof(Outer, ptr) == 24, "unexpected offset of Outer.ptr");
+static_assert(offsetof(Outer, callback) == 32, "unexpected offset of Outer.callback");
+
+#pragma pack(push, 2)
+typedef struct {
+  uint8_t a;
+  uint32_t b;
+} Packed;
+#pragma pack(pop)
+
+static_assert(sizeof(Packed) == 6, "unexpected size of Packed");
+static_assert(alignof(Packed) == 2, "unexpected alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "unexpected offset of Packed.a");
+static_assert(offsetof(Packed, b) == 2, "unexpected offset of Packed.b");
+
+typedef uint64_t Handle;
+
+static_assert(sizeof(Handle) == 8, "unexpected size of Handle");
+static_assert(alignof(Handle) == 8, "unexpected alignment of Handle");
+
+typedef union {
+  uint8_t small;
+  uint64_t big;
+} Either;
+
+static_assert(sizeof(Either) == 8, "unexpected size of Either");
+static_assert(alignof(Either) == 8, "unexpected alignment of Either");
+static_assert(offsetof(Either, small) == 0, "unexpected offset of Either.small");
+static_assert(offsetof(Either, big) == 0, "unexpected offset of Either.big");
+
+enum Inline_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Unit,
+  Single,
+  Struct,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Inline_Tag Inline_Tag;
+#else
+typedef uint8_t Inline_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct {
+  Inline_Tag tag;
+  uint16_t x;
+  uint64_t y;
+} Struct_Body;
+
+typedef union {
+  Inline_Tag tag;
+  struct {
+    Inline_Tag single_tag;
+    uint32_t single;
+  };
+  Struct_Body struct_;
+} Inline;
+
+static_assert(sizeof(Inline) == 16, "unexpected size of Inline");
+static_assert(alignof(Inline) == 8, "unexpected alignment of Inline");
+static_assert(offsetof(Inline, tag) == 0, "unexpected offset of Inline.tag");
+static_assert(offsetof(Inline, single_tag) == 0, "unexpected offset of Inline.single_tag");
+static_assert(offsetof(Inline, single) == 4, "unexpected offset of Inline.single");
+static_assert(offsetof(Inline, struct_) == 0, "unexpected offset of Inline.struct_");
+
+enum Separate_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint16_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  None,
+  One,
+  Pair,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Separate_Tag Separate_Tag;
+#else
+typedef uint16_t Separate_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+typedef struct {
+  uint32_t first;
+  uint32_t second;
+} Pair_Body;
+
+typedef struct {
+  Separate_Tag tag;
+  union {
+    struct {
+      uint8_t one;
+    };
+    Pair_Body pair;
+  };
+} Separate;
+
+static_assert(sizeof(Separate) == 12, "unexpected size of Separate");
+static_assert(alignof(Separate) == 4, "unexpected alignment of Separate");
+static_assert(offsetof(Separate, tag) == 0, "unexpected offset of Separate.tag");
+static_assert(offsetof(Separate, one) == 4, "unexpected offset of Separate.one");
+static_assert(offsetof(Separate, pair) == 4, "unexpected offset of Separate.pair");
+
+void root(Outer outer,
+          Packed packed,
+          Handle handle,
+          Either either,
+          Color color,
+          Small small,
+          Inline inline_,
+          Separate separate,
+          Private private_);
@@ -0,0 +1,101 @@
+typedef struct Inner Inner;
+typedef struct Outer Outer;
+typedef struct Packed Packed;
+typedef union Either Either;
+typedef struct Struct_Body Struct_Body;
+typedef union Inline Inline;
+typedef struct Pair_Body Pair_Body;
+typedef struct Separate Separate;
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue,
+} Color;
+
+enum Small {
+  A,
+  B,
+};
+typedef uint8_t Small;
+
+typedef struct Private {
+  uint64_t _private[2];
+} Private;
+
+struct Inner {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+
+struct Outer {
+  bool flag;
+  Inner inner;
+  uint16_t values[3];
+  const Inner *ptr;
+  uint8_t (*callback)(uint32_t);
+};
+
+// Packed is packed or aligned, which cffi can't declare.
+
+typedef uint64_t Handle;
+
+union Either {
+  uint8_t small;
+  uint64_t big;
+};
+
+enum Inline_Tag {
+  Unit,
+  Single,
+  Struct,
+};
+typedef uint8_t Inline_Tag;
+
+struct Struct_Body {
+  Inline_Tag tag;
+  uint16_t x;
+  uint64_t y;
+};
+
+union Inline {
+  Inline_Tag tag;
+  struct {
+    Inline_Tag single_tag;
+    uint32_t single;
+  };
+  Struct_Body struct_;
+};
+
+enum Separate_Tag {
+  None,
+  One,
+  Pair,
+};
+typedef uint16_t Separate_Tag;
+
+struct Pair_Body {
+  uint32_t first;
+  uint32_t second;
+};
+
+struct Separate {
+  Separate_Tag tag;
+  union {
+    struct {
+      uint8_t one;
+    };
+    Pair_Body pair;
+  };
+};
+
+void root(Outer outer,
+          Packed packed,
+          Handle handle,
+          Either either,
+          Color color,
+          Small small,
+          Inline inline_,
+          Separate separate,
+          Private private_);
@@ -0,0 +1,159 @@
+#include <cstdarg>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Color {
+  Red,
+  Green,
+  Blue,
+};
+
+static_assert(sizeof(Color) == 4, "unexpected size of Color");
+static_assert(alignof(Color) == 4, "unexpected alignment of Color");
+
+enum class Small : uint8_t {
+  A,
+  B,
+};
+
+static_assert(sizeof(Small) == 1, "unexpected size of Small");
+static_assert(alignof(Small) == 1, "unexpected alignment of Small");
+
+struct Private {
+  alignas(8) unsigned char _private[16];
+};
+
+static_assert(sizeof(Private) == 16, "unexpected size of Private");
+static_assert(alignof(Private) == 8, "unexpected alignment of Private");
+
+struct Inner {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+
+static_assert(sizeof(Inner) == 12, "unexpected size of Inner");
+static_assert(alignof(Inner) == 4, "unexpected alignment of Inner");
+static_assert(offsetof(Inner, a) == 0, "unexpected offset of Inner.a");
+static_assert(offsetof(Inner, b) == 4, "unexpected offset of Inner.b");
+static_assert(offsetof(Inner, c) == 8, "unexpected offset of Inner.c");
+
+struct Outer {
+  bool flag;
+  Inner inner;
+  uint16_t values[3];
+  const Inner *ptr;
+  uint8_t (*callback)(uint32_t);
+};
+
+static_assert(sizeof(Outer) == 40, "unexpected size of Outer");
+static_assert(alignof(Outer) == 8, "unexpected alignment of Outer");
+static_assert(offsetof(Outer, flag) == 0, "unexpected offset of Outer.flag");
+static_assert(offsetof(Outer, inner) == 4, "unexpected offset of Outer.inner");
+static_assert(offsetof(Outer, values) == 16, "unexpected offset of Outer.values");
+static_assert(offsetof(Outer, ptr) == 24, "unexpected offset of Outer.ptr");
+static_assert(offsetof(Outer, callback) == 32, "unexpected offset of Outer.callback");
+
+#pragma pack(push, 2)
+struct Packed {
+  uint8_t a;
+  uint32_t b;
+};
+#pragma pack(pop)
+
+static_assert(sizeof(Packed) == 6, "unexpected size of Packed");
+static_assert(alignof(Packed) == 2, "unexpected alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "unexpected offset of Packed.a");
+static_assert(offsetof(Packed, b) == 2, "unexpected offset of Packed.b");
+
+using Handle = uint64_t;
+
+static_assert(sizeof(Handle) == 8, "unexpected size of Handle");
+static_assert(alignof(Handle) == 8, "unexpected alignment of Handle");
+
+union Either {
+  uint8_t small;
+  uint64_t big;
+};
+
+static_assert(sizeof(Either) == 8, "unexpected size of Either");
+static_assert(alignof(Either) == 8, "unexpected alignment of Either");
+static_assert(offsetof(Either, small) == 0, "unexpected offset of Either.small");
+static_assert(offsetof(Either, big) == 0, "unexpected offset of Either.big");
+
+union Inline {
+  enum class Tag : uint8_t {
+    Unit,
+    Single,
+    Struct,
+  };
+
+  struct Single_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct Struct_Body {
+    Tag tag;
+    uint16_t x;
+    uint64_t y;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Single_Body single;
+  Struct_Body struct_;
+};
+
+static_assert(sizeof(Inline) == 16, "unexpected size of Inline");
+static_assert(alignof(Inline) == 8, "unexpected alignment of Inline");
+static_assert(offsetof(Inline, tag) == 0, "unexpected offset of Inline.tag");
+static_assert(offsetof(Inline, single) == 0, "unexpected offset of Inline.single");
+static_assert(offsetof(Inline, struct_) == 0, "unexpected offset of Inline.struct_");
+
+struct Separate {
+  enum class Tag : uint16_t {
+    None,
+    One,
+    Pair,
+  };
+
+  struct One_Body {
+    uint8_t _0;
+  };
+
+  struct Pair_Body {
+    uint32_t first;
+    uint32_t second;
+  };
+
+  Tag tag;
+  union {
+    One_Body one;
+    Pair_Body pair;
+  };
+};
+
+static_assert(sizeof(Separate) == 12, "unexpected size of Separate");
+static_assert(alignof(Separate) == 4, "unexpected alignment of Separate");
+static_assert(offsetof(Separate, tag) == 0, "unexpected offset of Separate.tag");
+static_assert(offsetof(Separate, one) == 4, "unexpected offset of Separate.one");
+static_assert(offsetof(Separate, pair) == 4, "unexpected offset of Separate.pair");
+
+extern "C" {
+
+void root(Outer outer,
+          Packed packed,
+          Handle handle,
+          Either either,
+          Color color,
+          Small small,
+          Inline inline_,
+          Separate separate,
+          Private private_);
+
+}  // extern "C"
@@ -0,0 +1,116 @@
+using System;
+using System.Runtime.InteropServices;
+
+public enum Color {
+  Red,
+  Green,
+  Blue,
+}
+
+public enum Small : byte {
+  A,
+  B,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Private {
+  private fixed ulong _private[2];
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Inner {
+  public byte a;
+  public uint b;
+  public ushort c;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Outer {
+  [MarshalAs(UnmanagedType.U1)] public bool flag;
+  public Inner inner;
+  public fixed ushort values[3];
+  public Inner* ptr;
+  public delegate* unmanaged[Cdecl]<uint, byte> callback;
+}
+
+[StructLayout(LayoutKind.Sequential, Pack = 2)]
+public unsafe partial struct Packed {
+  public byte a;
+  public uint b;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Either {
+  [FieldOffset(0)] public byte small;
+  [FieldOffset(0)] public ulong big;
+}
+
+public enum Inline_Tag : byte {
+  Unit,
+  Single,
+  Struct,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Struct_Body {
+  public Inline_Tag tag;
+  public ushort x;
+  public ulong y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Inline {
+  [FieldOffset(0)] public Inline_Tag tag;
+  [FieldOffset(0)] public Single_Body single;
+  [FieldOffset(0)] public Struct_Body struct_;
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct Single_Body {
+    public Inline_Tag single_tag;
+    public uint single;
+  }
+}
+
+public enum Separate_Tag : ushort {
+  None,
+  One,
+  Pair,
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Pair_Body {
+  public uint first;
+  public uint second;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Separate {
+  public Separate_Tag tag;
+  public Payload payload;
+
+  [StructLayout(LayoutKind.Explicit)]
+  public unsafe struct Payload {
+    [FieldOffset(0)] public One_Body one;
+    [FieldOffset(0)] public Pair_Body pair;
+  }
+
+  [StructLayout(LayoutKind.Sequential)]
+  public unsafe partial struct One_Body {
+    public byte one;
+  }
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Outer outer,
+                                 Packed packed,
+                                 ulong handle,
+                                 Either either,
+                                 Color color,
+                                 Small small,
+                                 Inline inline_,
+                                 Separate separate,
+                                 Private private_);
+}
@@ -0,0 +1,231 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class Color {
+    private Color() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Red = 0;
+    public static final int Green = 1;
+    public static final int Blue = 2;
+  }
+
+  public static final class Small {
+    private Small() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte A = 0;
+    public static final byte B = 1;
+  }
+
+  public static final class Private {
+    private Private() {}
+
+    public static final MemoryLayout LAYOUT = MemoryLayout.sequenceLayout(16, JAVA_BYTE).withByteAlignment(8).withName("Private");
+  }
+
+  public static final class Inner {
+    private Inner() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("a"),
+      JAVA_INT.withName("b"),
+      JAVA_SHORT.withName("c")
+    ).withName("Inner");
+  }
+
+  public static final class Outer {
+    private Outer() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BOOLEAN.withName("flag"),
+      Inner.LAYOUT.withName("inner"),
+      MemoryLayout.sequenceLayout(3, JAVA_SHORT).withName("values"),
+      ADDRESS.withName("ptr"),
+      ADDRESS.withName("callback")
+    ).withName("Outer");
+  }
+
+  public static final class Packed {
+    private Packed() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      packed(2, JAVA_BYTE.withName("a")),
+      packed(2, JAVA_INT.withName("b"))
+    ).withName("Packed");
+  }
+
+  public static final class Either {
+    private Either() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      JAVA_BYTE.withName("small"),
+      JAVA_LONG.withName("big")
+    ).withName("Either");
+  }
+
+  public static final class Inline_Tag {
+    private Inline_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Unit = 0;
+    public static final byte Single = 1;
+    public static final byte Struct = 2;
+  }
+
+  public static final class Struct_Body {
+    private Struct_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Inline_Tag.LAYOUT.withName("tag"),
+      JAVA_SHORT.withName("x"),
+      JAVA_LONG.withName("y")
+    ).withName("Struct_Body");
+  }
+
+  public static final class Inline {
+    private Inline() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      Inline_Tag.LAYOUT.withName("tag"),
+      structLayout(Inline_Tag.LAYOUT.withName("single_tag"), JAVA_INT.withName("single")),
+      Struct_Body.LAYOUT.withName("struct_")
+    ).withName("Inline");
+  }
+
+  public static final class Separate_Tag {
+    private Separate_Tag() {}
+
+    public static final ValueLayout.OfShort LAYOUT = JAVA_SHORT;
+
+    public static final short None = 0;
+    public static final short One = 1;
+    public static final short Pair = 2;
+  }
+
+  public static final class Pair_Body {
+    private Pair_Body() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("first"),
+      JAVA_INT.withName("second")
+    ).withName("Pair_Body");
+  }
+
+  public static final class Separate {
+    private Separate() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Separate_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(JAVA_BYTE.withName("one")), Pair_Body.LAYOUT.withName("pair"))
+    ).withName("Separate");
+  }
+
+  public static final MethodHandle root = downcall("root", FunctionDescriptor.ofVoid(Outer.LAYOUT,
+                                                                                     Packed.LAYOUT,
+                                                                                     JAVA_LONG,
+                                                                                     Either.LAYOUT,
+                                                                                     Color.LAYOUT,
+                                                                                     Small.LAYOUT,
+                                                                                     Inline.LAYOUT,
+                                                                                     Separate.LAYOUT,
+                                                                                     Private.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,91 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum Color {
+  Red,
+  Green,
+  Blue,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Small {
+  A,
+  B,
+}
+
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct Private {
+  _private: [u8; 16],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Inner {
+  pub a: u8,
+  pub b: u32,
+  pub c: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Outer {
+  pub flag: bool,
+  pub inner: Inner,
+  pub values: [u16; 3],
+  pub ptr: *const Inner,
+  pub callback: unsafe extern "C" fn(u32) -> u8,
+}
+
+#[repr(C, packed(2))]
+#[derive(Clone, Copy)]
+pub struct Packed {
+  pub a: u8,
+  pub b: u32,
+}
+
+pub type Handle = u64;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Either {
+  pub small: u8,
+  pub big: u64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Inline {
+  Unit,
+  Single(u32),
+  Struct {
+    x: u16,
+    y: u64,
+  },
+}
+
+#[repr(C, u16)]
+#[derive(Clone, Copy)]
+pub enum Separate {
+  None,
+  One(u8),
+  Pair {
+    first: u32,
+    second: u32,
+  },
+}
+
+extern "C" {
+  pub fn root(
+    outer: Outer,
+    packed: Packed,
+    handle: Handle,
+    either: Either,
+    color: Color,
+    small: Small,
+    inline_: Inline,
+    separate: Separate,
+    private_: Private,
+  );
+}
@@ -0,0 +1,94 @@
+const std = @import("std");
+
+pub const Color = enum(c_int) {
+  Red,
+  Green,
+  Blue,
+};
+
+pub const Small = enum(u8) {
+  A,
+  B,
+};
+
+pub const Private = extern struct {
+  _private: [16]u8 align(8),
+};
+
+pub const Inner = extern struct {
+  a: u8,
+  b: u32,
+  c: u16,
+};
+
+pub const Outer = extern struct {
+  flag: bool,
+  inner: Inner,
+  values: [3]u16,
+  ptr: ?*const Inner,
+  callback: *const fn (u32) callconv(.c) u8,
+};
+
+pub const Packed = extern struct {
+  a: u8 align(@min(@alignOf(u8), 2)),
+  b: u32 align(@min(@alignOf(u32), 2)),
+};
+
+pub const Handle = u64;
+
+pub const Either = extern union {
+  small: u8,
+  big: u64,
+};
+
+pub const Inline_Tag = enum(u8) {
+  Unit,
+  Single,
+  Struct,
+};
+
+pub const Struct_Body = extern struct {
+  tag: Inline_Tag,
+  x: u16,
+  y: u64,
+};
+
+pub const Inline = extern union {
+  tag: Inline_Tag,
+  single: extern struct {
+    single_tag: Inline_Tag,
+    single: u32,
+  },
+  struct_: Struct_Body,
+};
+
+pub const Separate_Tag = enum(u16) {
+  None,
+  One,
+  Pair,
+};
+
+pub const Pair_Body = extern struct {
+  first: u32,
+  second: u32,
+};
+
+pub const Separate = extern struct {
+  tag: Separate_Tag,
+  payload: extern union {
+    one: extern struct {
+      one: u8,
+    },
+    pair: Pair_Body,
+  },
+};
+
+pub extern fn root(outer: Outer,
+                   @"packed": Packed,
+                   handle: Handle,
+                   either: Either,
+                   color: Color,
+                   small: Small,
+                   inline_: Inline,
+                   separate: Separate,
+                   private_: Private) void;
@@ -0,0 +1,178 @@
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+
+static_assert(sizeof(enum Color) == 4, "unexpected size of enum Color");
+static_assert(alignof(enum Color) == 4, "unexpected alignment of enum Color");
+
+enum Small
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  A,
+  B,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Small Small;
+#else
+typedef uint8_t Small;
+#endif // __STDC_VERSION__ >= 202311L
+
+static_assert(sizeof(Small) == 1, "unexpected size of Small");
+static_assert(alignof(Small) == 1, "unexpected alignment of Small");
+
+struct Private {
+  alignas(8) unsigned char _private[16];
+};
+
+static_assert(sizeof(struct Private) == 16, "unexpected size of struct Private");
+static_assert(alignof(struct Private) == 8, "unexpected alignment of struct Private");
+
+struct Inner {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+
+static_assert(sizeof(struct Inner) == 12, "unexpected size of struct Inner");
+static_assert(alignof(struct Inner) == 4, "unexpected alignment of struct Inner");
+static_assert(offsetof(struct Inner, a) == 0, "unexpected offset of struct Inner.a");
+static_assert(offsetof(struct Inner, b) == 4, "unexpected offset of struct Inner.b");
+static_assert(offsetof(struct Inner, c) == 8, "unexpected offset of struct Inner.c");
+
+struct Outer {
+  bool flag;
+  struct Inner inner;
+  uint16_t values[3];
+  const struct Inner *ptr;
+  uint8_t (*callback)(uint32_t);
+};
+
+static_assert(sizeof(struct Outer) == 40, "unexpected size of struct Outer");
+static_assert(alignof(struct Outer) == 8, "unexpected alignment of struct Outer");
+static_assert(offsetof(struct Outer, flag) == 0, "unexpected offset of struct Outer.flag");
+static_assert(offsetof(struct Outer, inner) == 4, "unexpected offset of struct Outer.inner");
+static_assert(offsetof(struct Outer, values) == 16, "unexpected offset of struct Outer.values");
+static_assert(offsetof(struct Outer, ptr) == 24, "unexpected offset of struct Outer.ptr");
+static_assert(offsetof(struct Outer, callback) == 32, "unexpected offset of struct Outer.callback");
+
+#pragma pack(push, 2)
+struct Packed {
+  uint8_t a;
+  uint32_t b;
+};
+#pragma pack(pop)
+
+static_assert(sizeof(struct Packed) == 6, "unexpected size of struct Packed");
+static_assert(alignof(struct Packed) == 2, "unexpected alignment of struct Packed");
+static_assert(offsetof(struct Packed, a) == 0, "unexpected offset of struct Packed.a");
+static_assert(offsetof(struct Packed, b) == 2, "unexpected offset of struct Packed.b");
+
+typedef uint64_t Handle;
+
+static_assert(sizeof(Handle) == 8, "unexpected size of Handle");
+static_assert(alignof(Handle) == 8, "unexpected alignment of Handle");
+
+union Either {
+  uint8_t small;
+  uint64_t big;
+};
+
+static_assert(sizeof(union Either) == 8, "unexpected size of union Either");
+static_assert(alignof(union Either) == 8, "unexpected alignment of union Either");
+static_assert(offsetof(union Either, small) == 0, "unexpected offset of union Either.small");
+static_assert(offsetof(union Either, big) == 0, "unexpected offset of union Either.big");
+
+enum Inline_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  Unit,
+  Single,
+  Struct,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Inline_Tag Inline_Tag;
+#else
+typedef uint8_t Inline_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+struct Struct_Body {
+  Inline_Tag tag;
+  uint16_t x;
+  uint64_t y;
+};
+
+union Inline {
+  Inline_Tag tag;
+  struct {
+    Inline_Tag single_tag;
+    uint32_t single;
+  };
+  struct Struct_Body struct_;
+};
+
+static_assert(sizeof(union Inline) == 16, "unexpected size of union Inline");
+static_assert(alignof(union Inline) == 8, "unexpected alignment of union Inline");
+static_assert(offsetof(union Inline, tag) == 0, "unexpected offset of union Inline.tag");
+static_assert(offsetof(union Inline, single_tag) == 0, "unexpected offset of union Inline.single_tag");
+static_assert(offsetof(union Inline, single) == 4, "unexpected offset of union Inline.single");
+static_assert(offsetof(union Inline, struct_) == 0, "unexpected offset of union Inline.struct_");
+
+enum Separate_Tag
+#if __STDC_VERSION__ >= 202311L
+  : uint16_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  None,
+  One,
+  Pair,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum Separate_Tag Separate_Tag;
+#else
+typedef uint16_t Separate_Tag;
+#endif // __STDC_VERSION__ >= 202311L
+
+struct Pair_Body {
+  uint32_t first;
+  uint32_t second;
+};
+
+struct Separate {
+  Separate_Tag tag;
+  union {
+    struct {
+      uint8_t one;
+    };
+    struct Pair_Body pair;
+  };
+};
+
+static_assert(sizeof(struct Separate) == 12, "unexpected size of struct Separate");
+static_assert(alignof(struct Separate) == 4, "unexpected alignment of struct Separate");
+static_assert(offsetof(struct Separate, tag) == 0, "unexpected offset of struct Separate.tag");
+static_assert(offsetof(struct Separate, one) == 4, "unexpected offset of struct Separate.one");
+static_assert(offsetof(struct Separate, pair) == 4, "unexpected offset of struct Separate.pair");
+
+void root(struct Outer outer,
+          struct Packed packed,
+          Handle handle,
+          union Either either,
+          enum Color color,
+          Small small,
+          union Inline inline_,
+          struct Separate separate,
+          struct Private private_);
@@ -0,0 +1,82 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Color:
+    Red,
+    Green,
+    Blue,
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint8_t Small;
+
+  cdef struct Private:
+    unsigned char _private[16];
+
+  cdef struct Inner:
+    uint8_t a;
+    uint32_t b;
+    uint16_t c;
+
+  cdef struct Outer:
+    bool flag;
+    Inner inner;
+    uint16_t values[3];
+    const Inner *ptr;
+    uint8_t (*callback)(uint32_t);
+
+  cdef struct Packed:
+    pass
+
+  ctypedef uint64_t Handle;
+
+  cdef union Either:
+    uint8_t small;
+    uint64_t big;
+
+  cdef enum:
+    Unit,
+    Single,
+    Struct,
+  ctypedef uint8_t Inline_Tag;
+
+  cdef struct Struct_Body:
+    Inline_Tag tag;
+    uint16_t x;
+    uint64_t y;
+
+  cdef union Inline:
+    Inline_Tag tag;
+    uint32_t single;
+    Struct_Body struct_;
+
+  cdef enum:
+    None,
+    One,
+    Pair,
+  ctypedef uint16_t Separate_Tag;
+
+  cdef struct Pair_Body:
+    uint32_t first;
+    uint32_t second;
+
+  cdef struct Separate:
+    Separate_Tag tag;
+    uint8_t one;
+    Pair_Body pair;
+
+  void root(Outer outer,
+            Packed packed,
+            Handle handle,
+            Either either,
+            Color color,
+            Small small,
+            Inline inline_,
+            Separate separate,
+            Private private_);
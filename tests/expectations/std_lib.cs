@@ -0,0 +1,15 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Option_i32 { }
+
+public partial struct Result_i32__String { }
+
+public partial struct Vec_String { }
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Vec_String* a, Option_i32* b, Result_i32__String* c);
+}
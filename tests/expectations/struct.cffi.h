@@ -0,0 +1,28 @@
+typedef struct Normal Normal;
+typedef struct NormalWithZST NormalWithZST;
+typedef struct TupleRenamed TupleRenamed;
+typedef struct TupleNamed TupleNamed;
+
+typedef struct Opaque Opaque;
+
+struct Normal {
+  int32_t x;
+  float y;
+};
+
+struct NormalWithZST {
+  int32_t x;
+  float y;
+};
+
+struct TupleRenamed {
+  int32_t m0;
+  float m1;
+};
+
+struct TupleNamed {
+  int32_t x;
+  float y;
+};
+
+void root(Opaque *a, Normal b, NormalWithZST c, TupleRenamed d, TupleNamed e);
@@ -0,0 +1,39 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Opaque { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Normal {
+  public int x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct NormalWithZST {
+  public int x;
+  public float y;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TupleRenamed {
+  public int m0;
+  public float m1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TupleNamed {
+  public int x;
+  public float y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Opaque* a,
+                                 Normal b,
+                                 NormalWithZST c,
+                                 TupleRenamed d,
+                                 TupleNamed e);
+}
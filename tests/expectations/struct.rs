@@ -0,0 +1,37 @@
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Normal {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NormalWithZST {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TupleRenamed {
+  pub m0: i32,
+  pub m1: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TupleNamed {
+  pub x: i32,
+  pub y: f32,
+}
+
+extern "C" {
+  pub fn root(a: *mut Opaque, b: Normal, c: NormalWithZST, d: TupleRenamed, e: TupleNamed);
+}
@@ -0,0 +1,10 @@
+typedef struct Foo Foo;
+
+typedef struct Bar Bar;
+
+struct Foo {
+  int32_t a;
+  uint32_t b;
+};
+
+void root(Foo x, Bar bar);
@@ -0,0 +1,28 @@
+#[repr(C)]
+pub struct Bar {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo {
+  pub a: i32,
+  pub b: u32,
+}
+
+pub const Foo_FOO: Foo = Foo { a: 42, b: 47 };
+
+pub const Foo_FOO2: Foo = Foo { a: 42, b: 47 };
+
+pub const Foo_FOO3: Foo = Foo { a: 42, b: 47 };
+
+
+
+pub const BAR: Foo = Foo { a: 42, b: 1337 };
+
+
+
+extern "C" {
+  pub fn root(x: Foo, bar: Bar);
+}
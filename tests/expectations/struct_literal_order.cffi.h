@@ -0,0 +1,16 @@
+typedef struct ABC ABC;
+typedef struct BAC BAC;
+
+struct ABC {
+  float a;
+  uint32_t b;
+  uint32_t c;
+};
+
+struct BAC {
+  uint32_t b;
+  float a;
+  int32_t c;
+};
+
+void root(ABC a1, BAC a2);
@@ -0,0 +1,35 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct ABC {
+  public float a;
+  public uint b;
+  public uint c;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct BAC {
+  public uint b;
+  public float a;
+  public int c;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly ABC ABC_abc = new ABC { a = 1.0f, b = 2, c = 3 };
+
+  public static readonly ABC ABC_bac = new ABC { a = 1.0f, b = 2, c = 3 };
+
+  public static readonly ABC ABC_cba = new ABC { a = 1.0f, b = 2, c = 3 };
+
+  public static readonly BAC BAC_abc = new BAC { b = 1, a = 2.0f, c = 3 };
+
+  public static readonly BAC BAC_bac = new BAC { b = 1, a = 2.0f, c = 3 };
+
+  public static readonly BAC BAC_cba = new BAC { b = 1, a = 2.0f, c = 3 };
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(ABC a1, BAC a2);
+}
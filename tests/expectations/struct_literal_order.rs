@@ -0,0 +1,31 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ABC {
+  pub a: f32,
+  pub b: u32,
+  pub c: u32,
+}
+
+pub const ABC_abc: ABC = ABC { a: 1.0, b: 2, c: 3 };
+
+pub const ABC_bac: ABC = ABC { a: 1.0, b: 2, c: 3 };
+
+pub const ABC_cba: ABC = ABC { a: 1.0, b: 2, c: 3 };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BAC {
+  pub b: u32,
+  pub a: f32,
+  pub c: i32,
+}
+
+pub const BAC_abc: BAC = BAC { b: 1, a: 2.0, c: 3 };
+
+pub const BAC_bac: BAC = BAC { b: 1, a: 2.0, c: 3 };
+
+pub const BAC_cba: BAC = BAC { b: 1, a: 2.0, c: 3 };
+
+extern "C" {
+  pub fn root(a1: ABC, a2: BAC);
+}
@@ -0,0 +1,13 @@
+typedef struct Foo_Bar Foo_Bar;
+typedef struct Bar Bar;
+
+struct Foo_Bar {
+  const int32_t *something;
+};
+
+struct Bar {
+  int32_t something;
+  Foo_Bar subexpressions;
+};
+
+void root(Bar b);
@@ -0,0 +1,157 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  // `Opaque` is opaque, so it's only ever passed around as an `ADDRESS`.
+
+  public static final class SelfTypeTestStruct {
+    private SelfTypeTestStruct() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("times")
+    ).withName("SelfTypeTestStruct");
+  }
+
+  public static final class PointerToOpaque {
+    private PointerToOpaque() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      ADDRESS.withName("ptr")
+    ).withName("PointerToOpaque");
+  }
+
+  public static final MethodHandle rust_print_hello_world = downcall("rust_print_hello_world", FunctionDescriptor.ofVoid());
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_ref = downcall("SelfTypeTestStruct_should_exist_ref", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_ref_mut = downcall("SelfTypeTestStruct_should_exist_ref_mut", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle SelfTypeTestStruct_should_not_exist_box = downcall("SelfTypeTestStruct_should_not_exist_box", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle SelfTypeTestStruct_should_not_exist_return_box = downcall("SelfTypeTestStruct_should_not_exist_return_box", FunctionDescriptor.of(ADDRESS));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_annotated_self = downcall("SelfTypeTestStruct_should_exist_annotated_self", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_annotated_mut_self = downcall("SelfTypeTestStruct_should_exist_annotated_mut_self", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_annotated_by_name = downcall("SelfTypeTestStruct_should_exist_annotated_by_name", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_annotated_mut_by_name = downcall("SelfTypeTestStruct_should_exist_annotated_mut_by_name", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_unannotated = downcall("SelfTypeTestStruct_should_exist_unannotated", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle SelfTypeTestStruct_should_exist_mut_unannotated = downcall("SelfTypeTestStruct_should_exist_mut_unannotated", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle free_function_should_exist_ref = downcall("free_function_should_exist_ref", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle free_function_should_exist_ref_mut = downcall("free_function_should_exist_ref_mut", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle unnamed_argument = downcall("unnamed_argument", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle free_function_should_not_exist_box = downcall("free_function_should_not_exist_box", FunctionDescriptor.ofVoid(ADDRESS));
+
+  public static final MethodHandle free_function_should_exist_annotated_by_name = downcall("free_function_should_exist_annotated_by_name", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle free_function_should_exist_annotated_mut_by_name = downcall("free_function_should_exist_annotated_mut_by_name", FunctionDescriptor.ofVoid(SelfTypeTestStruct.LAYOUT));
+
+  public static final MethodHandle PointerToOpaque_create = downcall("PointerToOpaque_create", FunctionDescriptor.of(PointerToOpaque.LAYOUT,
+                                                                                                                     JAVA_BYTE));
+
+  public static final MethodHandle PointerToOpaque_sayHello = downcall("PointerToOpaque_sayHello", FunctionDescriptor.ofVoid(PointerToOpaque.LAYOUT));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,59 @@
+#define CF_SWIFT_NAME(_name) __attribute__((swift_name(#_name)))
+
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SelfTypeTestStruct {
+  pub times: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PointerToOpaque {
+  pub ptr: *mut Opaque,
+}
+
+extern "C" {
+  pub fn rust_print_hello_world();
+
+  pub fn SelfTypeTestStruct_should_exist_ref(self_: *const SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_ref_mut(self_: *mut SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_not_exist_box(self_: *mut SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_not_exist_return_box() -> *mut SelfTypeTestStruct;
+
+  pub fn SelfTypeTestStruct_should_exist_annotated_self(self_: SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_annotated_mut_self(self_: SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_annotated_by_name(self_: SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_annotated_mut_by_name(self_: SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_unannotated(self_: SelfTypeTestStruct);
+
+  pub fn SelfTypeTestStruct_should_exist_mut_unannotated(self_: SelfTypeTestStruct);
+
+  pub fn free_function_should_exist_ref(test_struct: *const SelfTypeTestStruct);
+
+  pub fn free_function_should_exist_ref_mut(test_struct: *mut SelfTypeTestStruct);
+
+  pub fn unnamed_argument(_: *mut SelfTypeTestStruct);
+
+  pub fn free_function_should_not_exist_box(boxed: *mut SelfTypeTestStruct);
+
+  pub fn free_function_should_exist_annotated_by_name(test_struct: SelfTypeTestStruct);
+
+  pub fn free_function_should_exist_annotated_mut_by_name(test_struct: SelfTypeTestStruct);
+
+  pub fn PointerToOpaque_create(times: u8) -> PointerToOpaque;
+
+  pub fn PointerToOpaque_sayHello(self_: PointerToOpaque);
+}
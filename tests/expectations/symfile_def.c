@@ -0,0 +1,10 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+extern const uint32_t MYLIB_MAX_FLAGS;
+
+void mylib_open(void);
+
+void mylib_open_with_flags(uint32_t flags);
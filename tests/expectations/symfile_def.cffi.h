@@ -0,0 +1,5 @@
+const uint32_t MYLIB_MAX_FLAGS;
+
+void mylib_open(void);
+
+void mylib_open_with_flags(uint32_t flags);
@@ -0,0 +1,15 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+extern const uint32_t MYLIB_MAX_FLAGS;
+
+void mylib_open();
+
+void mylib_open_with_flags(uint32_t flags);
+
+}  // extern "C"
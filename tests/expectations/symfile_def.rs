@@ -0,0 +1,7 @@
+extern "C" {
+  pub static MYLIB_MAX_FLAGS: u32;
+
+  pub fn mylib_open();
+
+  pub fn mylib_open_with_flags(flags: u32);
+}
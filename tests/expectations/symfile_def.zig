@@ -0,0 +1,7 @@
+const std = @import("std");
+
+pub extern const MYLIB_MAX_FLAGS: u32;
+
+pub extern fn mylib_open() void;
+
+pub extern fn mylib_open_with_flags(flags: u32) void;
@@ -0,0 +1,9 @@
+const uint32_t MYLIB_MAX_FLAGS;
+
+void mylib_open(void);
+
+void mylib_open_with_flags(uint32_t flags);
+
+void mylib_reopen(void);
+
+void mylib_close(void);
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+extern const uint32_t MYLIB_MAX_FLAGS;
+
+void mylib_open();
+
+void mylib_open_with_flags(uint32_t flags);
+
+void mylib_reopen();
+
+void mylib_close();
+
+}  // extern "C"
@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  extern const uint32_t MYLIB_MAX_FLAGS;
+
+  void mylib_open();
+
+  void mylib_open_with_flags(uint32_t flags);
+
+  void mylib_reopen();
+
+  void mylib_close();
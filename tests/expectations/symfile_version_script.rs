@@ -0,0 +1,11 @@
+extern "C" {
+  pub static MYLIB_MAX_FLAGS: u32;
+
+  pub fn mylib_open();
+
+  pub fn mylib_open_with_flags(flags: u32);
+
+  pub fn mylib_reopen();
+
+  pub fn mylib_close();
+}
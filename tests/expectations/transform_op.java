@@ -0,0 +1,264 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class StylePoint_i32 {
+    private StylePoint_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("x"),
+      JAVA_INT.withName("y")
+    ).withName("StylePoint_i32");
+  }
+
+  public static final class StylePoint_f32 {
+    private StylePoint_f32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_FLOAT.withName("x"),
+      JAVA_FLOAT.withName("y")
+    ).withName("StylePoint_f32");
+  }
+
+  public static final class StyleFoo_i32_Tag {
+    private StyleFoo_i32_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Foo_i32 = 0;
+    public static final byte Bar_i32 = 1;
+    public static final byte Baz_i32 = 2;
+    public static final byte Bazz_i32 = 3;
+  }
+
+  public static final class StyleFoo_Body_i32 {
+    private StyleFoo_Body_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      StyleFoo_i32_Tag.LAYOUT.withName("tag"),
+      JAVA_INT.withName("x"),
+      StylePoint_i32.LAYOUT.withName("y"),
+      StylePoint_f32.LAYOUT.withName("z")
+    ).withName("StyleFoo_Body_i32");
+  }
+
+  public static final class StyleFoo_i32 {
+    private StyleFoo_i32() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      StyleFoo_i32_Tag.LAYOUT.withName("tag"),
+      StyleFoo_Body_i32.LAYOUT.withName("foo"),
+      structLayout(StyleFoo_i32_Tag.LAYOUT.withName("bar_tag"), JAVA_INT.withName("bar")),
+      structLayout(StyleFoo_i32_Tag.LAYOUT.withName("baz_tag"), StylePoint_i32.LAYOUT.withName("baz"))
+    ).withName("StyleFoo_i32");
+  }
+
+  public static final class StyleBar_i32_Tag {
+    private StyleBar_i32_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Bar1_i32 = 0;
+    public static final int Bar2_i32 = 1;
+    public static final int Bar3_i32 = 2;
+    public static final int Bar4_i32 = 3;
+  }
+
+  public static final class StyleBar1_Body_i32 {
+    private StyleBar1_Body_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("x"),
+      StylePoint_i32.LAYOUT.withName("y"),
+      StylePoint_f32.LAYOUT.withName("z"),
+      ADDRESS.withName("u")
+    ).withName("StyleBar1_Body_i32");
+  }
+
+  public static final class StyleBar_i32 {
+    private StyleBar_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      StyleBar_i32_Tag.LAYOUT.withName("tag"),
+      unionLayout(StyleBar1_Body_i32.LAYOUT.withName("bar1"), structLayout(JAVA_INT.withName("bar2")), structLayout(StylePoint_i32.LAYOUT.withName("bar3")))
+    ).withName("StyleBar_i32");
+  }
+
+  public static final class StylePoint_u32 {
+    private StylePoint_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("x"),
+      JAVA_INT.withName("y")
+    ).withName("StylePoint_u32");
+  }
+
+  public static final class StyleBar_u32_Tag {
+    private StyleBar_u32_Tag() {}
+
+    public static final ValueLayout.OfInt LAYOUT = JAVA_INT;
+
+    public static final int Bar1_u32 = 0;
+    public static final int Bar2_u32 = 1;
+    public static final int Bar3_u32 = 2;
+    public static final int Bar4_u32 = 3;
+  }
+
+  public static final class StyleBar1_Body_u32 {
+    private StyleBar1_Body_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("x"),
+      StylePoint_u32.LAYOUT.withName("y"),
+      StylePoint_f32.LAYOUT.withName("z"),
+      ADDRESS.withName("u")
+    ).withName("StyleBar1_Body_u32");
+  }
+
+  public static final class StyleBar_u32 {
+    private StyleBar_u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      StyleBar_u32_Tag.LAYOUT.withName("tag"),
+      unionLayout(StyleBar1_Body_u32.LAYOUT.withName("bar1"), structLayout(JAVA_INT.withName("bar2")), structLayout(StylePoint_u32.LAYOUT.withName("bar3")))
+    ).withName("StyleBar_u32");
+  }
+
+  public static final class StyleBaz_Tag {
+    private StyleBaz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Baz1 = 0;
+    public static final byte Baz2 = 1;
+    public static final byte Baz3 = 2;
+  }
+
+  public static final class StyleBaz {
+    private StyleBaz() {}
+
+    public static final UnionLayout LAYOUT = unionLayout(
+      StyleBaz_Tag.LAYOUT.withName("tag"),
+      structLayout(StyleBaz_Tag.LAYOUT.withName("baz1_tag"), StyleBar_u32.LAYOUT.withName("baz1")),
+      structLayout(StyleBaz_Tag.LAYOUT.withName("baz2_tag"), StylePoint_i32.LAYOUT.withName("baz2"))
+    ).withName("StyleBaz");
+  }
+
+  public static final class StyleTaz_Tag {
+    private StyleTaz_Tag() {}
+
+    public static final ValueLayout.OfByte LAYOUT = JAVA_BYTE;
+
+    public static final byte Taz1 = 0;
+    public static final byte Taz2 = 1;
+    public static final byte Taz3 = 2;
+  }
+
+  public static final class StyleTaz {
+    private StyleTaz() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      StyleTaz_Tag.LAYOUT.withName("tag"),
+      unionLayout(structLayout(StyleBar_u32.LAYOUT.withName("taz1")), structLayout(StyleBaz.LAYOUT.withName("taz2")))
+    ).withName("StyleTaz");
+  }
+
+  public static final MethodHandle foo = downcall("foo", FunctionDescriptor.ofVoid(ADDRESS,
+                                                                                   ADDRESS,
+                                                                                   ADDRESS,
+                                                                                   ADDRESS));
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,86 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StylePoint_i32 {
+  pub x: i32,
+  pub y: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StylePoint_f32 {
+  pub x: f32,
+  pub y: f32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum StyleFoo_i32 {
+  Foo_i32 {
+    x: i32,
+    y: StylePoint_i32,
+    z: StylePoint_f32,
+  },
+  Bar_i32(i32),
+  Baz_i32(StylePoint_i32),
+  Bazz_i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum StyleBar_i32 {
+  Bar1_i32 {
+    x: i32,
+    y: StylePoint_i32,
+    z: StylePoint_f32,
+    u: unsafe extern "C" fn(i32) -> i32,
+  },
+  Bar2_i32(i32),
+  Bar3_i32(StylePoint_i32),
+  Bar4_i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct StylePoint_u32 {
+  pub x: u32,
+  pub y: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum StyleBar_u32 {
+  Bar1_u32 {
+    x: i32,
+    y: StylePoint_u32,
+    z: StylePoint_f32,
+    u: unsafe extern "C" fn(i32) -> i32,
+  },
+  Bar2_u32(u32),
+  Bar3_u32(StylePoint_u32),
+  Bar4_u32,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum StyleBaz {
+  Baz1(StyleBar_u32),
+  Baz2(StylePoint_i32),
+  Baz3,
+}
+
+#[repr(C, u8)]
+#[derive(Clone, Copy)]
+pub enum StyleTaz {
+  Taz1(StyleBar_u32),
+  Taz2(StyleBaz),
+  Taz3,
+}
+
+extern "C" {
+  pub fn foo(
+    foo: *const StyleFoo_i32,
+    bar: *const StyleBar_i32,
+    baz: *const StyleBaz,
+    taz: *const StyleTaz,
+  );
+}
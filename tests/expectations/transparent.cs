@@ -0,0 +1,32 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct DummyStruct { }
+
+public partial struct EnumWithAssociatedConstantInImpl { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct TransparentEmptyStructure {
+
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  public static readonly uint TransparentPrimitiveWithAssociatedConstants_ZERO = 0;
+
+  public static readonly uint TransparentPrimitiveWithAssociatedConstants_ONE = 1;
+
+  public static readonly uint EnumWithAssociatedConstantInImpl_TEN = 10;
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(DummyStruct a,
+                                 uint b,
+                                 DummyStruct c,
+                                 uint d,
+                                 DummyStruct e,
+                                 uint f,
+                                 uint g,
+                                 TransparentEmptyStructure h,
+                                 EnumWithAssociatedConstantInImpl i);
+}
@@ -0,0 +1,51 @@
+#[repr(C)]
+pub struct DummyStruct {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub struct EnumWithAssociatedConstantInImpl {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+pub type TransparentComplexWrappingStructTuple = DummyStruct;
+
+pub type TransparentPrimitiveWrappingStructTuple = u32;
+
+pub type TransparentComplexWrappingStructure = DummyStruct;
+
+pub type TransparentPrimitiveWrappingStructure = u32;
+
+pub type TransparentComplexWrapper_i32 = DummyStruct;
+
+pub type TransparentPrimitiveWrapper_i32 = u32;
+
+pub type TransparentPrimitiveWithAssociatedConstants = u32;
+
+pub const TransparentPrimitiveWithAssociatedConstants_ZERO: TransparentPrimitiveWithAssociatedConstants = 0;
+
+pub const TransparentPrimitiveWithAssociatedConstants_ONE: TransparentPrimitiveWithAssociatedConstants = 1;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransparentEmptyStructure {
+
+}
+
+pub const EnumWithAssociatedConstantInImpl_TEN: TransparentPrimitiveWrappingStructure = 10;
+
+extern "C" {
+  pub fn root(
+    a: TransparentComplexWrappingStructTuple,
+    b: TransparentPrimitiveWrappingStructTuple,
+    c: TransparentComplexWrappingStructure,
+    d: TransparentPrimitiveWrappingStructure,
+    e: TransparentComplexWrapper_i32,
+    f: TransparentPrimitiveWrapper_i32,
+    g: TransparentPrimitiveWithAssociatedConstants,
+    h: TransparentEmptyStructure,
+    i: EnumWithAssociatedConstantInImpl,
+  );
+}
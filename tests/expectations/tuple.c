@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float _0;
+  float _1;
+} Tuple_f32__f32;
+
+typedef struct {
+  Tuple_f32__f32 start;
+  Tuple_f32__f32 end;
+} Segment;
+
+typedef struct {
+  uint8_t _0;
+  uint16_t _1;
+} Tuple_u8__u16;
+
+typedef struct {
+  int32_t _0;
+} Tuple_i32;
+
+typedef struct {
+  Tuple_u8__u16 _0;
+  const Tuple_i32 *_1;
+} Tuple_Tuple_u8__u16__________Tuple_i32;
+
+typedef struct {
+  uint8_t _0;
+  uint8_t _1;
+} Tuple_u8__u8;
+
+typedef struct {
+  Tuple_u8__u8 first;
+  Tuple_u8__u8 second;
+} Pair_Tuple_u8__u8;
+
+typedef struct {
+  uint32_t _0;
+  uint32_t _1;
+} Tuple_u32__u32;
+
+typedef Tuple_u32__u32 Range;
+
+Tuple_f32__f32 midpoint(Segment segment);
+
+void nested(Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+void pairs(Pair_Tuple_u8__u8 pair, Range range);
+
+void unit(void);
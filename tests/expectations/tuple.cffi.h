@@ -0,0 +1,57 @@
+typedef struct Tuple_f32__f32 Tuple_f32__f32;
+typedef struct Segment Segment;
+typedef struct Tuple_u8__u16 Tuple_u8__u16;
+typedef struct Tuple_i32 Tuple_i32;
+typedef struct Tuple_Tuple_u8__u16__________Tuple_i32 Tuple_Tuple_u8__u16__________Tuple_i32;
+typedef struct Tuple_u8__u8 Tuple_u8__u8;
+typedef struct Pair_Tuple_u8__u8 Pair_Tuple_u8__u8;
+typedef struct Tuple_u32__u32 Tuple_u32__u32;
+
+struct Tuple_f32__f32 {
+  float _0;
+  float _1;
+};
+
+struct Segment {
+  Tuple_f32__f32 start;
+  Tuple_f32__f32 end;
+};
+
+struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+};
+
+struct Tuple_i32 {
+  int32_t _0;
+};
+
+struct Tuple_Tuple_u8__u16__________Tuple_i32 {
+  Tuple_u8__u16 _0;
+  const Tuple_i32 *_1;
+};
+
+struct Tuple_u8__u8 {
+  uint8_t _0;
+  uint8_t _1;
+};
+
+struct Pair_Tuple_u8__u8 {
+  Tuple_u8__u8 first;
+  Tuple_u8__u8 second;
+};
+
+struct Tuple_u32__u32 {
+  uint32_t _0;
+  uint32_t _1;
+};
+
+typedef Tuple_u32__u32 Range;
+
+Tuple_f32__f32 midpoint(Segment segment);
+
+void nested(Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+void pairs(Pair_Tuple_u8__u8 pair, Range range);
+
+void unit(void);
@@ -0,0 +1,59 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Tuple_f32__f32 {
+  float _0;
+  float _1;
+};
+
+struct Segment {
+  Tuple_f32__f32 start;
+  Tuple_f32__f32 end;
+};
+
+struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+};
+
+struct Tuple_i32 {
+  int32_t _0;
+};
+
+struct Tuple_Tuple_u8__u16__________Tuple_i32 {
+  Tuple_u8__u16 _0;
+  const Tuple_i32 *_1;
+};
+
+struct Tuple_u8__u8 {
+  uint8_t _0;
+  uint8_t _1;
+};
+
+template<typename T>
+struct Pair {
+  T first;
+  T second;
+};
+
+struct Tuple_u32__u32 {
+  uint32_t _0;
+  uint32_t _1;
+};
+
+using Range = Tuple_u32__u32;
+
+extern "C" {
+
+Tuple_f32__f32 midpoint(Segment segment);
+
+void nested(Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+void pairs(Pair<Tuple_u8__u8> pair, Range range);
+
+void unit();
+
+}  // extern "C"
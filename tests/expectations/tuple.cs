@@ -0,0 +1,65 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_f32__f32 {
+  public float _0;
+  public float _1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Segment {
+  public Tuple_f32__f32 start;
+  public Tuple_f32__f32 end;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_u8__u16 {
+  public byte _0;
+  public ushort _1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_i32 {
+  public int _0;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_Tuple_u8__u16__________Tuple_i32 {
+  public Tuple_u8__u16 _0;
+  public Tuple_i32* _1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_u8__u8 {
+  public byte _0;
+  public byte _1;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Pair_Tuple_u8__u8 {
+  public Tuple_u8__u8 first;
+  public Tuple_u8__u8 second;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Tuple_u32__u32 {
+  public uint _0;
+  public uint _1;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern Tuple_f32__f32 midpoint(Segment segment);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void nested(Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void pairs(Pair_Tuple_u8__u8 pair, Tuple_u32__u32 range);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void unit();
+}
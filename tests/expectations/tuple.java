@@ -0,0 +1,179 @@
+import java.lang.foreign.*;
+import java.lang.invoke.MethodHandle;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static java.lang.foreign.ValueLayout.*;
+
+public final class Bindings {
+  private Bindings() {}
+
+  private static final Linker LINKER = Linker.nativeLinker();
+  private static final SymbolLookup LOOKUP = SymbolLookup.loaderLookup();
+
+  public static final class Tuple_f32__f32 {
+    private Tuple_f32__f32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_FLOAT.withName("_0"),
+      JAVA_FLOAT.withName("_1")
+    ).withName("Tuple_f32__f32");
+  }
+
+  public static final class Segment {
+    private Segment() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Tuple_f32__f32.LAYOUT.withName("start"),
+      Tuple_f32__f32.LAYOUT.withName("end")
+    ).withName("Segment");
+  }
+
+  public static final class Tuple_u8__u16 {
+    private Tuple_u8__u16() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("_0"),
+      JAVA_SHORT.withName("_1")
+    ).withName("Tuple_u8__u16");
+  }
+
+  public static final class Tuple_i32 {
+    private Tuple_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("_0")
+    ).withName("Tuple_i32");
+  }
+
+  public static final class Tuple_Tuple_u8__u16__________Tuple_i32 {
+    private Tuple_Tuple_u8__u16__________Tuple_i32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Tuple_u8__u16.LAYOUT.withName("_0"),
+      ADDRESS.withName("_1")
+    ).withName("Tuple_Tuple_u8__u16__________Tuple_i32");
+  }
+
+  public static final class Tuple_u8__u8 {
+    private Tuple_u8__u8() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_BYTE.withName("_0"),
+      JAVA_BYTE.withName("_1")
+    ).withName("Tuple_u8__u8");
+  }
+
+  public static final class Pair_Tuple_u8__u8 {
+    private Pair_Tuple_u8__u8() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      Tuple_u8__u8.LAYOUT.withName("first"),
+      Tuple_u8__u8.LAYOUT.withName("second")
+    ).withName("Pair_Tuple_u8__u8");
+  }
+
+  public static final class Tuple_u32__u32 {
+    private Tuple_u32__u32() {}
+
+    public static final StructLayout LAYOUT = structLayout(
+      JAVA_INT.withName("_0"),
+      JAVA_INT.withName("_1")
+    ).withName("Tuple_u32__u32");
+  }
+
+  public static final MethodHandle midpoint = downcall("midpoint", FunctionDescriptor.of(Tuple_f32__f32.LAYOUT,
+                                                                                         Segment.LAYOUT));
+
+  public static final MethodHandle nested = downcall("nested", FunctionDescriptor.ofVoid(Tuple_Tuple_u8__u16__________Tuple_i32.LAYOUT));
+
+  public static final MethodHandle pairs = downcall("pairs", FunctionDescriptor.ofVoid(Pair_Tuple_u8__u8.LAYOUT,
+                                                                                       Tuple_u32__u32.LAYOUT));
+
+  public static final MethodHandle unit = downcall("unit", FunctionDescriptor.ofVoid());
+
+  private static MethodHandle downcall(String name, FunctionDescriptor descriptor, Linker.Option... options) {
+    return LOOKUP.find(name).map(symbol -> LINKER.downcallHandle(symbol, descriptor, options)).orElse(null);
+  }
+
+  private static MemorySegment global(String name) {
+    return LOOKUP.find(name).orElse(null);
+  }
+
+  private static MemorySegment global(String name, MemoryLayout layout) {
+    return LOOKUP.find(name).map(symbol -> symbol.reinterpret(layout.byteSize())).orElse(null);
+  }
+
+  private static MemoryLayout int128() {
+    return MemoryLayout.structLayout(JAVA_LONG, JAVA_LONG).withByteAlignment(16);
+  }
+
+  private static long paddingTo(long size, long alignment) {
+    return (alignment - size % alignment) % alignment;
+  }
+
+  private static StructLayout structLayout(MemoryLayout... members) {
+    return structLayout(1, members);
+  }
+
+  private static StructLayout structLayout(long alignment, MemoryLayout... members) {
+    List<MemoryLayout> padded = new ArrayList<>();
+    long size = 0;
+    for (MemoryLayout member : members) {
+      long padding = paddingTo(size, member.byteAlignment());
+      if (padding != 0) {
+        padded.add(MemoryLayout.paddingLayout(padding));
+      }
+      padded.add(member);
+      size += padding + member.byteSize();
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding != 0) {
+      padded.add(MemoryLayout.paddingLayout(padding));
+    }
+    return MemoryLayout.structLayout(padded.toArray(MemoryLayout[]::new)).withByteAlignment(alignment);
+  }
+
+  private static UnionLayout unionLayout(MemoryLayout... members) {
+    return unionLayout(1, members);
+  }
+
+  private static UnionLayout unionLayout(long alignment, MemoryLayout... members) {
+    long size = 0;
+    for (MemoryLayout member : members) {
+      size = Math.max(size, member.byteSize());
+      alignment = Math.max(alignment, member.byteAlignment());
+    }
+    long padding = paddingTo(size, alignment);
+    if (padding == 0) {
+      return MemoryLayout.unionLayout(members).withByteAlignment(alignment);
+    }
+    MemoryLayout[] padded = Arrays.copyOf(members, members.length + 1);
+    padded[members.length] = MemoryLayout.paddingLayout(size + padding);
+    return MemoryLayout.unionLayout(padded).withByteAlignment(alignment);
+  }
+
+  private static MemoryLayout packed(long pack, MemoryLayout layout) {
+    MemoryLayout result = switch (layout) {
+      case StructLayout struct -> MemoryLayout.structLayout(packed(pack, struct.memberLayouts()));
+      case UnionLayout union -> MemoryLayout.unionLayout(packed(pack, union.memberLayouts()));
+      case SequenceLayout sequence ->
+        MemoryLayout.sequenceLayout(sequence.elementCount(), packed(pack, sequence.elementLayout()));
+      default -> layout;
+    };
+    if (layout.name().isPresent()) {
+      result = result.withName(layout.name().get());
+    }
+    return result.withByteAlignment(Math.min(pack, layout.byteAlignment()));
+  }
+
+  private static MemoryLayout[] packed(long pack, List<MemoryLayout> layouts) {
+    MemoryLayout[] result = new MemoryLayout[layouts.size()];
+    for (int i = 0; i < result.length; i++) {
+      result[i] = packed(pack, layouts.get(i));
+    }
+    return result;
+  }
+}
@@ -0,0 +1,48 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Tuple_f32__f32:
+    float _0;
+    float _1;
+
+  ctypedef struct Segment:
+    Tuple_f32__f32 start;
+    Tuple_f32__f32 end;
+
+  ctypedef struct Tuple_u8__u16:
+    uint8_t _0;
+    uint16_t _1;
+
+  ctypedef struct Tuple_i32:
+    int32_t _0;
+
+  ctypedef struct Tuple_Tuple_u8__u16__________Tuple_i32:
+    Tuple_u8__u16 _0;
+    const Tuple_i32 *_1;
+
+  ctypedef struct Tuple_u8__u8:
+    uint8_t _0;
+    uint8_t _1;
+
+  ctypedef struct Pair_Tuple_u8__u8:
+    Tuple_u8__u8 first;
+    Tuple_u8__u8 second;
+
+  ctypedef struct Tuple_u32__u32:
+    uint32_t _0;
+    uint32_t _1;
+
+  ctypedef Tuple_u32__u32 Range;
+
+  Tuple_f32__f32 midpoint(Segment segment);
+
+  void nested(Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+  void pairs(Pair_Tuple_u8__u8 pair, Range range);
+
+  void unit();
@@ -0,0 +1,66 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_f32__f32 {
+  pub _0: f32,
+  pub _1: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Segment {
+  pub start: Tuple_f32__f32,
+  pub end: Tuple_f32__f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_u8__u16 {
+  pub _0: u8,
+  pub _1: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_i32 {
+  pub _0: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_Tuple_u8__u16__________Tuple_i32 {
+  pub _0: Tuple_u8__u16,
+  pub _1: *const Tuple_i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_u8__u8 {
+  pub _0: u8,
+  pub _1: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Pair_Tuple_u8__u8 {
+  pub first: Tuple_u8__u8,
+  pub second: Tuple_u8__u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Tuple_u32__u32 {
+  pub _0: u32,
+  pub _1: u32,
+}
+
+pub type Range = Tuple_u32__u32;
+
+extern "C" {
+  pub fn midpoint(segment: Segment) -> Tuple_f32__f32;
+
+  pub fn nested(value: Tuple_Tuple_u8__u16__________Tuple_i32);
+
+  pub fn pairs(pair: Pair_Tuple_u8__u8, range: Range);
+
+  pub fn unit();
+}
@@ -0,0 +1,50 @@
+const std = @import("std");
+
+pub const Tuple_f32__f32 = extern struct {
+  _0: f32,
+  _1: f32,
+};
+
+pub const Segment = extern struct {
+  start: Tuple_f32__f32,
+  end: Tuple_f32__f32,
+};
+
+pub const Tuple_u8__u16 = extern struct {
+  _0: u8,
+  _1: u16,
+};
+
+pub const Tuple_i32 = extern struct {
+  _0: i32,
+};
+
+pub const Tuple_Tuple_u8__u16__________Tuple_i32 = extern struct {
+  _0: Tuple_u8__u16,
+  _1: ?*const Tuple_i32,
+};
+
+pub const Tuple_u8__u8 = extern struct {
+  _0: u8,
+  _1: u8,
+};
+
+pub const Pair_Tuple_u8__u8 = extern struct {
+  first: Tuple_u8__u8,
+  second: Tuple_u8__u8,
+};
+
+pub const Tuple_u32__u32 = extern struct {
+  _0: u32,
+  _1: u32,
+};
+
+pub const Range = Tuple_u32__u32;
+
+pub extern fn midpoint(segment: Segment) Tuple_f32__f32;
+
+pub extern fn nested(value: Tuple_Tuple_u8__u16__________Tuple_i32) void;
+
+pub extern fn pairs(pair: Pair_Tuple_u8__u8, range: Range) void;
+
+pub extern fn unit() void;
@@ -0,0 +1,61 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Tuple_f32__f32 {
+  float _0;
+  float _1;
+} Tuple_f32__f32;
+
+typedef struct Segment {
+  struct Tuple_f32__f32 start;
+  struct Tuple_f32__f32 end;
+} Segment;
+
+typedef struct Tuple_u8__u16 {
+  uint8_t _0;
+  uint16_t _1;
+} Tuple_u8__u16;
+
+typedef struct Tuple_i32 {
+  int32_t _0;
+} Tuple_i32;
+
+typedef struct Tuple_Tuple_u8__u16__________Tuple_i32 {
+  struct Tuple_u8__u16 _0;
+  const struct Tuple_i32 *_1;
+} Tuple_Tuple_u8__u16__________Tuple_i32;
+
+typedef struct Tuple_u8__u8 {
+  uint8_t _0;
+  uint8_t _1;
+} Tuple_u8__u8;
+
+typedef struct Pair_Tuple_u8__u8 {
+  struct Tuple_u8__u8 first;
+  struct Tuple_u8__u8 second;
+} Pair_Tuple_u8__u8;
+
+typedef struct Tuple_u32__u32 {
+  uint32_t _0;
+  uint32_t _1;
+} Tuple_u32__u32;
+
+typedef struct Tuple_u32__u32 Range;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Tuple_f32__f32 midpoint(struct Segment segment);
+
+void nested(struct Tuple_Tuple_u8__u16__________Tuple_i32 value);
+
+void pairs(struct Pair_Tuple_u8__u8 pair, Range range);
+
+void unit(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
@@ -0,0 +1,10 @@
+typedef struct Foo_i32__i32 Foo_i32__i32;
+
+struct Foo_i32__i32 {
+  int32_t x;
+  int32_t y;
+};
+
+typedef Foo_i32__i32 IntFoo_i32;
+
+void root(IntFoo_i32 a);
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_i32__i32 {
+  pub x: i32,
+  pub y: i32,
+}
+
+pub type IntFoo_i32 = Foo_i32__i32;
+
+extern "C" {
+  pub fn root(a: IntFoo_i32);
+}
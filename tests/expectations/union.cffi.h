@@ -0,0 +1,16 @@
+typedef union Normal Normal;
+typedef union NormalWithZST NormalWithZST;
+
+typedef struct Opaque Opaque;
+
+union Normal {
+  int32_t x;
+  float y;
+};
+
+union NormalWithZST {
+  int32_t x;
+  float y;
+};
+
+void root(Opaque *a, Normal b, NormalWithZST c);
@@ -0,0 +1,23 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct Opaque { }
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Normal {
+  [FieldOffset(0)] public int x;
+  [FieldOffset(0)] public float y;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct NormalWithZST {
+  [FieldOffset(0)] public int x;
+  [FieldOffset(0)] public float y;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Opaque* a, Normal b, NormalWithZST c);
+}
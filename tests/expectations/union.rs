@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Opaque {
+  _data: [u8; 0],
+  _marker: ::core::marker::PhantomData<(*mut u8, ::core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Normal {
+  pub x: i32,
+  pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union NormalWithZST {
+  pub x: i32,
+  pub y: f32,
+}
+
+extern "C" {
+  pub fn root(a: *mut Opaque, b: Normal, c: NormalWithZST);
+}
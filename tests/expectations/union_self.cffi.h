@@ -0,0 +1,13 @@
+typedef struct Foo_Bar Foo_Bar;
+typedef union Bar Bar;
+
+struct Foo_Bar {
+  const int32_t *something;
+};
+
+union Bar {
+  int32_t something;
+  Foo_Bar subexpressions;
+};
+
+void root(Bar b);
@@ -0,0 +1,20 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Foo_Bar {
+  public int* something;
+}
+
+[StructLayout(LayoutKind.Explicit)]
+public unsafe partial struct Bar {
+  [FieldOffset(0)] public int something;
+  [FieldOffset(0)] public Foo_Bar subexpressions;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(Bar b);
+}
@@ -0,0 +1,16 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Foo_Bar {
+  pub something: *const i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Bar {
+  pub something: i32,
+  pub subexpressions: Foo_Bar,
+}
+
+extern "C" {
+  pub fn root(b: Bar);
+}
@@ -0,0 +1,12 @@
+const std = @import("std");
+
+pub const Foo_Bar = extern struct {
+  something: ?*const i32,
+};
+
+pub const Bar = extern union {
+  something: i32,
+  subexpressions: Foo_Bar,
+};
+
+pub extern fn root(b: Bar) void;
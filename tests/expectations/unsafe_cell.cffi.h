@@ -0,0 +1,11 @@
+typedef struct MyStruct MyStruct;
+
+typedef struct NotReprC_i32 NotReprC_i32;
+
+typedef NotReprC_i32 Foo;
+
+struct MyStruct {
+  int32_t number;
+};
+
+void root(const Foo *a, const MyStruct *with_cell);
@@ -0,0 +1,16 @@
+using System;
+using System.Runtime.InteropServices;
+
+public partial struct NotReprC_i32 { }
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct MyStruct {
+  public int number;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void root(NotReprC_i32* a, MyStruct* with_cell);
+}
@@ -0,0 +1,20 @@
+typedef struct Interface_______i32_______i32_______va_list Interface_______i32_______i32_______va_list;
+
+typedef int32_t (*VaListFnPtr)(int32_t count, ...);
+
+typedef int32_t (*VaListFnPtr2)(int32_t count, ...);
+
+struct Interface_______i32_______i32_______va_list {
+  int32_t (*fn1)(int32_t count, ...);
+};
+
+int32_t va_list_test(int32_t count, ...);
+
+int32_t va_list_test2(int32_t count, ...);
+
+void va_list_fn_ptrs(int32_t (*fn1)(int32_t count, ...),
+                     int32_t (*fn2)(int32_t count, ...),
+                     VaListFnPtr fn3,
+                     VaListFnPtr2 fn4,
+                     Interface_______i32_______i32_______va_list fn5,
+                     Interface_______i32_______i32_______va_list fn6);
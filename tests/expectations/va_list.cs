@@ -0,0 +1,25 @@
+using System;
+using System.Runtime.InteropServices;
+
+[StructLayout(LayoutKind.Sequential)]
+public unsafe partial struct Interface_______i32_______i32_______va_list {
+  public delegate* unmanaged[Cdecl]<int, int> fn1;
+}
+
+public static unsafe partial class NativeMethods {
+  private const string LibraryName = "native";
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int va_list_test(int count, __arglist);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern int va_list_test2(int count, __arglist);
+
+  [DllImport(LibraryName, CallingConvention = CallingConvention.Cdecl)]
+  public static extern void va_list_fn_ptrs(delegate* unmanaged[Cdecl]<int, int> fn1,
+                                            delegate* unmanaged[Cdecl]<int, int> fn2,
+                                            delegate* unmanaged[Cdecl]<int, int> fn3,
+                                            delegate* unmanaged[Cdecl]<int, int> fn4,
+                                            Interface_______i32_______i32_______va_list fn5,
+                                            Interface_______i32_______i32_______va_list fn6);
+}
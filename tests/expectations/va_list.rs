@@ -0,0 +1,24 @@
+pub type VaListFnPtr = Option<unsafe extern "C" fn(count: i32, ...) -> i32>;
+
+pub type VaListFnPtr2 = Option<unsafe extern "C" fn(count: i32, ...) -> i32>;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Interface_______i32_______i32_______va_list {
+  pub fn1: Option<unsafe extern "C" fn(count: i32, ...) -> i32>,
+}
+
+extern "C" {
+  pub fn va_list_test(count: i32, ...) -> i32;
+
+  pub fn va_list_test2(count: i32, ...) -> i32;
+
+  pub fn va_list_fn_ptrs(
+    fn1: Option<unsafe extern "C" fn(count: i32, ...) -> i32>,
+    fn2: Option<unsafe extern "C" fn(count: i32, ...) -> i32>,
+    fn3: VaListFnPtr,
+    fn4: VaListFnPtr2,
+    fn5: Interface_______i32_______i32_______va_list,
+    fn6: Interface_______i32_______i32_______va_list,
+  );
+}
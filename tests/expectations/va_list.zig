@@ -0,0 +1,20 @@
+const std = @import("std");
+
+pub const VaListFnPtr = ?*const fn (count: i32, ...) callconv(.c) i32;
+
+pub const VaListFnPtr2 = ?*const fn (count: i32, ...) callconv(.c) i32;
+
+pub const Interface_______i32_______i32_______va_list = extern struct {
+  fn1: ?*const fn (count: i32, ...) callconv(.c) i32,
+};
+
+pub extern fn va_list_test(count: i32, ...) i32;
+
+pub extern fn va_list_test2(count: i32, ...) i32;
+
+pub extern fn va_list_fn_ptrs(fn1: ?*const fn (count: i32, ...) callconv(.c) i32,
+                              fn2: ?*const fn (count: i32, ...) callconv(.c) i32,
+                              fn3: VaListFnPtr,
+                              fn4: VaListFnPtr2,
+                              fn5: Interface_______i32_______i32_______va_list,
+                              fn6: Interface_______i32_______i32_______va_list) void;
@@ -0,0 +1,9 @@
+#define EXT_CONST 0
+
+typedef struct ExtType ExtType;
+
+struct ExtType {
+  uint32_t data;
+};
+
+void consume_ext(ExtType _ext);
@@ -0,0 +1,11 @@
+pub const EXT_CONST: i32 = 0;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ExtType {
+  pub data: u32,
+}
+
+extern "C" {
+  pub fn consume_ext(_ext: ExtType);
+}
@@ -0,0 +1,8 @@
+typedef struct TraitObject TraitObject;
+
+struct TraitObject {
+  void *data;
+  void *vtable;
+};
+
+void *root(const void *ptr, TraitObject t);
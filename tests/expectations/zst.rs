@@ -0,0 +1,10 @@
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TraitObject {
+  pub data: *mut ::core::ffi::c_void,
+  pub vtable: *mut ::core::ffi::c_void,
+}
+
+extern "C" {
+  pub fn root(ptr: *const ::core::ffi::c_void, t: TraitObject) -> *mut ::core::ffi::c_void;
+}
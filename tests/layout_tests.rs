@@ -0,0 +1,63 @@
+use std::fs;
+use std::process::Command;
+
+mod common;
+
+use common::{cbindgen, run, test_path, tmp_dir, tool};
+
+/// Generates the bindings of `tests/rust/static_asserts.rs` for the host, and the layout tests
+/// checking them, which must then compile on the C side and pass on the Rust side.
+fn check_layout_tests(lang: &str, header: &str, c_tests: &str, mut cc: Command) {
+    let source = test_path("rust/static_asserts.rs");
+    let tmp_dir = tmp_dir("cbindgen-layout-tests-");
+    let tmp_dir = tmp_dir.path();
+
+    run(cbindgen()
+        .arg("--lang")
+        .arg(lang)
+        .arg("--output")
+        .arg(tmp_dir.join(header))
+        .arg("--layout-tests")
+        .arg(tmp_dir.join("layout"))
+        .arg(&source));
+
+    run(cc
+        .current_dir(tmp_dir)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-c")
+        .arg(c_tests)
+        .arg("-o")
+        .arg("layout.o"));
+
+    let crate_root = tmp_dir.join("root.rs");
+    fs::write(
+        &crate_root,
+        format!(
+            "#![allow(dead_code, unused_variables, improper_ctypes_definitions)]\n\
+             include!({source:?});\n\
+             mod layout {{\n    use super::*;\n    include!({:?});\n}}\n",
+            tmp_dir.join("layout.rs"),
+        ),
+    )
+    .unwrap();
+    let test_binary = tmp_dir.join("layout_tests");
+    run(tool("RUSTC", "rustc")
+        .arg("--edition")
+        .arg("2021")
+        .arg("--test")
+        .arg("-o")
+        .arg(&test_binary)
+        .arg(&crate_root));
+    run(&mut Command::new(&test_binary));
+}
+
+#[test]
+fn test_layout_tests_c() {
+    check_layout_tests("c", "bindings.h", "layout.c", tool("CC", "gcc"));
+}
+
+#[test]
+fn test_layout_tests_cxx() {
+    check_layout_tests("c++", "bindings.hpp", "layout.cpp", tool("CXX", "g++"));
+}
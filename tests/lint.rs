@@ -0,0 +1,41 @@
+use std::fs;
+
+mod common;
+
+use common::{cbindgen, generate, test_path, tmp_dir};
+
+/// The lints themselves are checked with the other expectations, in `expectations/lint.lint`.
+#[test]
+fn test_lint_locations() {
+    let source = test_path("rust/lint.rs");
+    let bindings = generate(&source);
+    let lints = bindings.lints();
+    assert!(!lints.is_empty());
+    for lint in lints {
+        assert_eq!(lint.location.file.as_deref(), Some(source.as_path()));
+    }
+}
+
+#[test]
+fn test_deny_lints() {
+    let output = cbindgen()
+        .arg("--lint")
+        .arg("--deny-lints")
+        .arg(test_path("rust/lint.rs"))
+        .output()
+        .expect("Failed to execute process");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().count(), 5);
+
+    let tmp_dir = tmp_dir("cbindgen-lint-");
+    let source = tmp_dir.path().join("lib.rs");
+    fs::write(&source, "#[no_mangle]\npub extern \"C\" fn root() {}\n").unwrap();
+    let output = cbindgen()
+        .arg("--lint")
+        .arg("--deny-lints")
+        .arg(&source)
+        .output()
+        .expect("Failed to execute process");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
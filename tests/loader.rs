@@ -0,0 +1,94 @@
+#![cfg(unix)]
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::{cbindgen, run, test_path, tmp_dir, tool};
+
+fn build_library(source: &Path, output: &Path, cfg: Option<&str>) {
+    let mut command = tool("RUSTC", "rustc");
+    command
+        .arg("--edition")
+        .arg("2021")
+        .arg("--crate-type")
+        .arg("cdylib")
+        .arg("-o")
+        .arg(output)
+        .arg(source);
+    if let Some(cfg) = cfg {
+        command.arg("--cfg").arg(cfg);
+    }
+    run(&mut command);
+}
+
+/// Generates the loader of `tests/rust/loader.rs`, and checks that it loads a current and an
+/// older build of it, which misses a function.
+#[test]
+fn test_loader() {
+    let source = test_path("rust/loader.rs");
+    let tmp_dir = tmp_dir("cbindgen-loader-");
+    let tmp_dir = tmp_dir.path();
+
+    run(cbindgen()
+        .arg("--lang")
+        .arg("c")
+        .arg("--config")
+        .arg(source.with_extension("toml"))
+        .arg("--output")
+        .arg(tmp_dir.join("point.h"))
+        .arg("--loader")
+        .arg(tmp_dir.join("point_loader"))
+        .arg(&source));
+
+    let library = tmp_dir.join(format!("libpoint{}", env::consts::DLL_SUFFIX));
+    let old_library = tmp_dir.join(format!("libpoint_old{}", env::consts::DLL_SUFFIX));
+    build_library(&source, &library, None);
+    build_library(&source, &old_library, Some("old_version"));
+
+    fs::write(
+        tmp_dir.join("main.c"),
+        r#"#include "point_loader.h"
+
+int main(int argc, char **argv) {
+  point_loader_functions functions;
+  if (argc != 3 || point_loader_load(argv[1], &functions) != 0) {
+    return 1;
+  }
+  struct Point point = {1, -2};
+  functions.point_scale(&point, 3);
+  if (functions.point_sum(point) != -3 || functions.point_norm(&point) != 9 ||
+      *functions.VERSION != 2 || functions.ORIGIN->x != 0) {
+    return 2;
+  }
+  point_loader_unload(&functions);
+
+  if (point_loader_load(argv[2], &functions) != 1 || functions.point_norm ||
+      !functions.point_sum) {
+    return 3;
+  }
+  point_loader_unload(&functions);
+
+  if (point_loader_load("./libmissing.so", &functions) != -1) {
+    return 4;
+  }
+  return 0;
+}
+"#,
+    )
+    .unwrap();
+    let main = tmp_dir.join("main");
+    run(tool("CC", "gcc")
+        .current_dir(tmp_dir)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-o")
+        .arg(&main)
+        .arg("main.c")
+        .arg("point_loader.c")
+        .arg("-ldl"));
+    run(Command::new(&main).arg(&library).arg(&old_library));
+}
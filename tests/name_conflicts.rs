@@ -0,0 +1,52 @@
+use cbindgen::{Builder, Config, NameConflicts};
+
+mod common;
+
+use common::test_path;
+
+/// Generates the bindings of `tests/rust/name_conflicts_prefix.rs`, with another strategy and
+/// renames. Those which succeed are checked with the other expectations.
+fn generate(strategy: NameConflicts, renames: &[(&str, &str)]) -> Result<(), String> {
+    let mut config = Config::default();
+    config.export.name_conflicts = strategy;
+    for &(path, name) in renames {
+        config
+            .export
+            .rename
+            .insert(path.to_owned(), name.to_owned());
+    }
+    Builder::new()
+        .with_config(config)
+        .with_src(test_path("rust/name_conflicts_prefix.rs"))
+        .generate()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_rename() {
+    let error = "`a::Config`, `b::Config`, `c::Config` are all used by the bindings, and exported \
+                 as `Config`.";
+    assert_eq!(generate(NameConflicts::Rename, &[]).unwrap_err(), error);
+
+    let error = "`a::Config`, `c::Config` are all used by the bindings, and exported as `Config`.";
+    assert_eq!(
+        generate(NameConflicts::Rename, &[("b::Config", "Level")]).unwrap_err(),
+        error
+    );
+}
+
+#[test]
+fn test_error() {
+    let error = "`a::Config`, `b::Config`, `c::Config` are all used by the bindings, and exported \
+                 as `Config`.";
+    assert_eq!(generate(NameConflicts::Error, &[]).unwrap_err(), error);
+    assert_eq!(
+        generate(
+            NameConflicts::Error,
+            &[("b::Config", "Level"), ("c::Config", "Depth")]
+        )
+        .unwrap_err(),
+        error
+    );
+}
@@ -0,0 +1,56 @@
+/// The largest length.
+pub const MAX_LEN: u32 = 16;
+
+/// A point in the plane.
+///
+/// # Examples
+///
+/// ```
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+#[repr(C)]
+pub struct Point {
+    /// The abscissa.
+    x: i32,
+    y: i32,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    /// A circle around the point.
+    Circle,
+    Square,
+}
+
+pub struct Canvas;
+
+#[cfg(target_os = "linux")]
+#[repr(u8)]
+pub enum Handle {
+    /// A file descriptor.
+    File,
+    Socket,
+}
+
+#[cfg(target_os = "windows")]
+#[repr(u8)]
+pub enum Handle {
+    /// A raw handle.
+    Raw(u32),
+}
+
+/// The origin.
+///
+/// # Point
+///
+/// Not the [`Point`] type.
+#[no_mangle]
+pub extern "C" fn point(handle: Handle) -> Point {}
+
+/// Draws a shape.
+#[no_mangle]
+pub extern "C" fn draw(canvas: *mut Canvas, shape: Shape, at: &Point) {}
+
+#[deprecated(note = "Use `draw` instead")]
+#[no_mangle]
+pub extern "C" fn draw_circle(canvas: *mut Canvas, at: Point) {}
@@ -0,0 +1,12 @@
+use std::ffi::CStr;
+
+/// The magic number of RIFF files.
+pub const MAGIC: &[u8; 4] = b"RIFF";
+pub const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+pub const FORMAT: [u8; 4] = *b"WAVE";
+pub const QUOTED: &'static [u8; 8] = b"say \"hi\"";
+pub const GREETING: &CStr = c"hello";
+pub const PATH: &'static CStr = c"C:\\temp\n";
+
+#[no_mangle]
+pub extern "C" fn read_chunk(id: *const u8, len: usize) {}
@@ -0,0 +1,45 @@
+const HEADER_LEN: usize = 4;
+pub const PAYLOAD_LEN: usize = 12;
+
+const fn kib(n: u32) -> u32 {
+    n * 1024
+}
+
+const fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+const fn max(a: u32, b: u32) -> u32 {
+    if a > b { a } else { b }
+}
+
+pub const BUFFER_SIZE: u32 = kib(4);
+pub const ALIGNED: usize = align_up(13, 8);
+pub const LARGEST: u32 = max(kib(1), 3000);
+pub const WORD_BITS: u32 = u32::BITS;
+pub const WORD_SIZE: usize = core::mem::size_of::<u64>();
+pub const HALF_MAX: u16 = u16::MAX / 2;
+pub const SCALE: f32 = 1.0 / (WORD_SIZE as f32);
+pub const THRESHOLD: f64 = PAYLOAD_LEN as f64 * 0.5;
+pub const HIDDEN: usize = HEADER_LEN * 2;
+pub const FLAG: u8 = 1 << 3;
+pub const ENABLED: bool = WORD_BITS > 16 && !(HALF_MAX == 0);
+
+#[repr(C)]
+pub struct Packet {
+    pub header: [u8; HEADER_LEN],
+    pub payload: [u8; PAYLOAD_LEN],
+    pub trailer: [u8; size_of::<u32>() * 2],
+    pub padding: [u8; ALIGNED - HEADER_LEN - 1],
+}
+
+#[repr(u32)]
+pub enum Size {
+    Small = kib(1),
+    Medium = kib(4) + HEADER_LEN as u32,
+    Large,
+    Huge = 1 << 20,
+}
+
+#[no_mangle]
+pub extern "C" fn send(packet: &Packet, size: Size) {}
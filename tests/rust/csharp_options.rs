@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+pub const ORIGIN_ID: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn point_is_origin(p: *const Point) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn point_length(p: Point) -> f32 {
+    0.0
+}
@@ -0,0 +1,17 @@
+use std::ffi::CStr;
+
+pub const VERSION: &CStr = c"1.0.0";
+
+#[repr(C)]
+pub struct Entry {
+    pub name: &'static CStr,
+    pub description: Option<&'static CStr>,
+}
+
+#[no_mangle]
+pub extern "C" fn set_name(entry: &mut Entry, name: &'static CStr) {}
+
+#[no_mangle]
+pub extern "C" fn find(name: &CStr) -> Option<&'static CStr> {
+    None
+}
@@ -0,0 +1,54 @@
+/// The largest length of a [`Buffer`].
+pub const MAX_LEN: u32 = 16;
+
+/// A buffer of bytes, see [`buffer_new`] and [`Self::len`].
+#[repr(C)]
+pub struct Buffer {
+    /// The bytes, as many as [`len`](Self::len).
+    data: *mut u8,
+    len: usize,
+}
+
+impl Buffer {
+    /// The capacity of a [`Buffer`].
+    pub const CAPACITY: usize = 64;
+}
+
+/// The result of an operation on a [Buffer][`crate::Buffer`].
+///
+/// [`crate::Buffer`]: Buffer
+#[repr(u8)]
+pub enum Status {
+    /// Everything went well.
+    Ok,
+    /// The buffer is longer than [`MAX_LEN`], see [`Buffer::CAPACITY`].
+    TooLong,
+}
+
+/// Creates a new [`Buffer`].
+///
+/// # Arguments
+///
+/// * `len` - The length of the buffer, at most [`MAX_LEN`].
+/// * `status` - Set to [`Status::TooLong`] if `len` is too large.
+///
+/// # Returns
+///
+/// The new buffer, to be freed with [`buffer_free()`], or null.
+///
+/// # Examples
+///
+/// ```
+/// # use lib::*;
+/// let buffer = buffer_new(4, &mut Status::Ok);
+/// ```
+#[no_mangle]
+pub extern "C" fn buffer_new(len: usize, status: &mut Status) -> *mut Buffer {}
+
+/// Frees a [`Buffer`], see the [Rust documentation](https://doc.rust-lang.org/std/).
+///
+/// # Safety
+///
+/// The buffer must come from [`buffer_new`], and must not be used after `a[0]` is freed.
+#[no_mangle]
+pub unsafe extern "C" fn buffer_free(buffer: *mut Buffer) {}
@@ -0,0 +1,18 @@
+/// A buffer of bytes, see [`Self::my_len`] and [`Buffer::my_data`].
+#[repr(C)]
+pub struct Buffer {
+    /// The bytes, as many as [`my_len`](Self::my_len).
+    my_data: *mut u8,
+    my_len: usize,
+}
+
+/// A buffer or a length, see [`Either::byte_count`].
+#[repr(C)]
+pub union Either {
+    buffer: Buffer,
+    byte_count: usize,
+}
+
+/// Reads the [`Buffer::my_data`] of `buffer`.
+#[no_mangle]
+pub extern "C" fn buffer_data(buffer: &Buffer, either: Either) -> *mut u8 {}
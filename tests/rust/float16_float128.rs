@@ -0,0 +1,13 @@
+#[repr(C)]
+pub struct Floats {
+    half: f16,
+    single: f32,
+    quad: f128,
+}
+
+pub const HALF_ONE: f16 = 1.0;
+
+#[no_mangle]
+pub extern "C" fn root(a: Floats, b: f16) -> f128 {
+    todo!()
+}
@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct Hash {
+    low: u64,
+    high: u64,
+    full: u128,
+    signed: i128,
+}
+
+#[repr(u128)]
+pub enum Wide {
+    Small = 1,
+    Large = 0xFFFF_FFFF_FFFF_FFFF,
+}
+
+#[repr(i128)]
+pub enum SignedWide {
+    Negative = -1,
+    Zero,
+}
+
+pub const HASH_SEED: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+pub const SMALL: u128 = 42;
+pub const LOWEST: i128 = -170141183460469231731687303715884105728;
+pub const HIGHEST: i128 = i128::MAX;
+pub const ALL_ONES: u128 = u128::MAX;
+pub const HIGH_HALF: u128 = ALL_ONES << 64;
+
+#[no_mangle]
+pub extern "C" fn hash(data: *const u8, len: usize, seed: u128) -> u128 {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Hash, b: Wide, c: SignedWide, d: i128) {}
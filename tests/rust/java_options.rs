@@ -0,0 +1,25 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+    #[cfg(feature = "z")]
+    z: f32,
+}
+
+pub const ORIGIN_ID: u32 = 0xFFFF_FFFF;
+
+#[cfg(not(feature = "z"))]
+pub const DIMENSIONS: u8 = 2;
+
+#[cfg(feature = "z")]
+pub const DIMENSIONS: u8 = 3;
+
+#[no_mangle]
+pub extern "C" fn point_is_origin(p: *const Point) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn point_length(p: Point) -> f32 {
+    0.0
+}
@@ -58,16 +58,27 @@ pub union PackedUnion {
     pub variant2: *mut u8,
 }
 
-// #[repr(packed(n), C)] structs are currently unsupported.
+#[repr(packed(1), C)]
+pub struct Packed1Struct {
+    pub arg1: u8,
+    pub arg2: u32,
+}
+
+#[repr(packed(2), C)]
+pub struct Packed2Struct {
+    pub arg1: u8,
+    pub arg2: u32,
+    pub arg3: u16,
+}
+
 #[repr(packed(4), C)]
-pub struct UnsupportedPacked4Struct {
+pub struct Packed4Struct {
     pub arg1: usize,
     pub arg2: *mut u8,
 }
 
-// #[repr(packed(n), C)] unions are currently unsupported.
 #[repr(packed(4), C)]
-pub union UnsupportedPacked4Union {
+pub union Packed4Union {
     pub variant1: usize,
     pub variant2: *mut u8,
 }
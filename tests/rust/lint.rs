@@ -0,0 +1,33 @@
+pub struct Handle {
+    id: u32,
+}
+
+/// cbindgen:opaque-storage=[8, 8]
+pub struct Mutex {
+    state: u64,
+}
+
+pub struct Unused {
+    id: u32,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "Rust" fn rust_abi(x: i32) {}
+
+#[no_mangle]
+pub extern "C" fn make_handle() -> Handle {}
+
+#[no_mangle]
+pub extern "C" fn take_pair(pair: (i32, i32)) {}
+
+#[no_mangle]
+pub extern "C" fn take_slice(values: &[u8]) {}
+
+#[no_mangle]
+pub extern "C" fn use_handle(handle: *mut Handle, point: Point, mutex: Mutex) {}
@@ -0,0 +1,31 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// Sums the coordinates of `point`.
+#[no_mangle]
+pub extern "C" fn point_sum(point: Point) -> i32 {
+    point.x + point.y
+}
+
+#[no_mangle]
+pub extern "C" fn point_scale(point: *mut Point, factor: i32) {
+    unsafe {
+        (*point).x *= factor;
+        (*point).y *= factor;
+    }
+}
+
+#[cfg(not(old_version))]
+#[no_mangle]
+pub extern "C" fn point_norm(point: &Point) -> i32 {
+    point.x.abs() + point.y.abs()
+}
+
+#[no_mangle]
+pub static VERSION: u32 = 2;
+
+#[no_mangle]
+pub static mut ORIGIN: Point = Point { x: 0, y: 0 };
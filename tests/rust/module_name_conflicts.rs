@@ -0,0 +1,71 @@
+mod a {
+    #[repr(C)]
+    pub struct Config {
+        pub verbose: bool,
+    }
+}
+
+mod b {
+    use crate::a;
+
+    #[repr(C)]
+    pub struct Config {
+        pub level: u32,
+        pub inner: a::Config,
+    }
+
+    #[repr(C)]
+    pub struct Error {
+        pub code: i32,
+    }
+
+    #[repr(u8)]
+    pub enum Mode {
+        Fast,
+        Safe,
+    }
+}
+
+mod c {
+    use super::b::Config;
+    use super::b::Mode as BMode;
+
+    #[no_mangle]
+    pub extern "C" fn configure(config: &Config, other: &crate::a::Config, mode: BMode) -> super::Error {
+    }
+}
+
+mod limits {
+    pub const MAX: u32 = 16;
+}
+
+pub const MAX: u32 = 32;
+
+/// Only used once, so it keeps its name.
+mod d {
+    #[repr(C)]
+    pub struct Flags {
+        pub bits: u8,
+    }
+}
+
+#[repr(C)]
+pub struct Flags {
+    pub unused: u8,
+}
+
+#[repr(C)]
+pub struct Error {
+    pub message: *const u8,
+}
+
+#[repr(C)]
+pub struct Wrapper<Config> {
+    pub value: Config,
+}
+
+#[no_mangle]
+pub extern "C" fn fail(error: Error, inner: b::Error, flags: d::Flags) {}
+
+#[no_mangle]
+pub extern "C" fn wrapped(wrapper: Wrapper<a::Config>) {}
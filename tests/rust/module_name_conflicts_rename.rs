@@ -0,0 +1,21 @@
+mod a {
+    #[repr(C)]
+    pub struct Config {
+        pub verbose: bool,
+    }
+}
+
+mod b {
+    #[repr(C)]
+    pub struct Config {
+        pub level: u32,
+    }
+}
+
+#[repr(C)]
+pub struct Config {
+    pub name: *const u8,
+}
+
+#[no_mangle]
+pub extern "C" fn configure(root: Config, a: a::Config, b: b::Config) {}
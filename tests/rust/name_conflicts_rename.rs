@@ -0,0 +1,23 @@
+mod a {
+    #[repr(C)]
+    pub struct Config {
+        pub verbose: bool,
+    }
+}
+
+mod b {
+    #[repr(C)]
+    pub struct Config {
+        pub level: u32,
+    }
+}
+
+mod c {
+    #[repr(C)]
+    pub struct Config {
+        pub depth: u8,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn configure(a: a::Config, b: b::Config, c: c::Config) {}
@@ -0,0 +1,39 @@
+/// A handle that C can allocate, but not look inside.
+/// cbindgen:opaque-storage=[16, 8]
+pub struct Handle {
+    id: u64,
+    generation: u32,
+}
+
+pub struct Counter {
+    count: std::sync::atomic::AtomicU32,
+}
+
+/// cbindgen:opaque-storage=[3, 2]
+pub struct Invalid {
+    a: u16,
+    b: u8,
+}
+
+/// Storage C# and cffi can only align to 8 bytes.
+/// cbindgen:opaque-storage=[32, 16]
+#[repr(align(16))]
+pub struct Block {
+    data: [u8; 32],
+}
+
+#[repr(C)]
+pub struct Slot {
+    tag: u8,
+    handle: Handle,
+    counter: Counter,
+}
+
+#[no_mangle]
+pub extern "C" fn slot_init(slot: *mut Slot, invalid: *mut Invalid) {}
+
+#[no_mangle]
+pub extern "C" fn handle_copy(handle: Handle) -> Handle {}
+
+#[no_mangle]
+pub extern "C" fn block_clear(block: *mut Block) {}
@@ -0,0 +1,31 @@
+mod ffi {
+    #[repr(C)]
+    pub struct Handle {
+        pub id: u32,
+    }
+
+    #[repr(u8)]
+    pub enum Status {
+        Ok,
+        Failed,
+    }
+
+    #[repr(C)]
+    pub struct Unused {
+        pub id: u32,
+    }
+
+    pub struct Buffer {
+        data: Vec<u8>,
+    }
+}
+
+/// The handle to a resource.
+pub use self::ffi::Handle as ResourceHandle;
+pub use self::ffi::{Buffer as ResourceBuffer, Status as ResourceStatus};
+
+#[no_mangle]
+pub extern "C" fn open_resource(id: u32) -> ResourceHandle {}
+
+#[no_mangle]
+pub extern "C" fn read_resource(handle: &ffi::Handle, buffer: *mut ResourceBuffer) -> i32 {}
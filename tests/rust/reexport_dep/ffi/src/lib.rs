@@ -0,0 +1,24 @@
+pub mod types {
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    #[repr(C)]
+    pub struct Rect {
+        pub origin: Point,
+        pub size: Size,
+    }
+
+    #[repr(C)]
+    pub struct Size {
+        pub width: f32,
+        pub height: f32,
+    }
+}
+
+pub const UNUSED: u32 = 1;
+
+#[no_mangle]
+pub extern "C" fn ffi_only() {}
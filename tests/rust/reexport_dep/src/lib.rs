@@ -0,0 +1,6 @@
+extern crate reexport_ffi;
+
+pub use reexport_ffi::types::Rect as Bounds;
+
+#[no_mangle]
+pub extern "C" fn area(bounds: &Bounds) -> f32 {}
@@ -0,0 +1,51 @@
+/// An opaque handle.
+pub struct Context;
+
+#[repr(C)]
+pub struct Header {
+    r#type: u32,
+    #[cfg(feature = "extra")]
+    extra: u32,
+}
+
+#[repr(C, u16)]
+pub enum Event {
+    Quit,
+    Key(u32),
+    Resize { width: u32, height: u32 },
+    Other = 10,
+}
+
+pub const MAX_EVENTS: u8 = 32;
+pub const SCALE: f32 = 2.0;
+pub const ORIGIN: Point = Point { x: 0, y: -1 };
+pub const MASK: u32 = !(1 << 3);
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub static mut EVENT_COUNT: u32 = 0;
+
+#[no_mangle]
+#[must_use]
+pub extern "C" fn context_new() -> *mut Context {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn context_poll(
+    ctx: &mut Context,
+    events: &mut [Event; MAX_EVENTS as usize],
+    callback: Option<extern "C" fn(event: &Event)>,
+) -> usize {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn context_abort(ctx: *mut Context, r#fn: Point, header: Header) -> ! {
+    loop {}
+}
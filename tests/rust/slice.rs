@@ -0,0 +1,26 @@
+pub const NAME: &str = "hello";
+
+#[repr(C)]
+pub struct Buffer<'a> {
+    pub bytes: &'a [u8],
+    pub name: &'a str,
+}
+
+#[no_mangle]
+pub extern "C" fn sum(values: &[i32]) -> i64 {
+    values.iter().map(|&value| value as i64).sum()
+}
+
+#[no_mangle]
+pub extern "C" fn fill(values: &mut [f32], value: f32) {}
+
+#[no_mangle]
+pub extern "C" fn copy(from: *const [u16], to: *mut [u16]) {}
+
+#[no_mangle]
+pub extern "C" fn write(buffer: Buffer, lines: &[&str]) {}
+
+#[no_mangle]
+pub extern "C" fn find(haystack: Option<&[u8]>, needle: Option<&str>) -> bool {
+    false
+}
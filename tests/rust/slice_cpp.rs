@@ -0,0 +1,8 @@
+#[repr(C)]
+pub struct Items<'a, T> {
+    pub items: &'a [T],
+    pub names: &'a mut [&'a str],
+}
+
+#[no_mangle]
+pub extern "C" fn items(items: Items<u32>, values: &mut [f64]) {}
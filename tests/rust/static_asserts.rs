@@ -0,0 +1,77 @@
+#[repr(C)]
+pub struct Inner {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+#[repr(C)]
+pub struct Outer {
+    flag: bool,
+    inner: Inner,
+    values: [u16; 3],
+    ptr: *const Inner,
+    callback: extern "C" fn(u32) -> u8,
+}
+
+#[repr(C, packed(2))]
+pub struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[repr(transparent)]
+pub struct Handle(u64);
+
+#[repr(C)]
+pub union Either {
+    small: u8,
+    big: u64,
+}
+
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[repr(u8)]
+pub enum Small {
+    A,
+    B,
+}
+
+#[repr(u8)]
+pub enum Inline {
+    Unit,
+    Single(u32),
+    Struct { x: u16, y: u64 },
+}
+
+#[repr(C, u16)]
+pub enum Separate {
+    None,
+    One(u8),
+    Pair { first: u32, second: u32 },
+}
+
+/// cbindgen:opaque-storage=[16, 8]
+pub struct Private {
+    id: u64,
+    generation: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    outer: Outer,
+    packed: Packed,
+    handle: Handle,
+    either: Either,
+    color: Color,
+    small: Small,
+    inline: Inline,
+    separate: Separate,
+    private: Private,
+) {
+}
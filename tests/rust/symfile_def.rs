@@ -0,0 +1,9 @@
+#[no_mangle]
+pub extern "C" fn mylib_open() {}
+
+/// cbindgen:since=1.1
+#[no_mangle]
+pub extern "C" fn mylib_open_with_flags(flags: u32) {}
+
+#[no_mangle]
+pub static MYLIB_MAX_FLAGS: u32 = 8;
@@ -0,0 +1,17 @@
+#[no_mangle]
+pub extern "C" fn mylib_open() {}
+
+/// cbindgen:since=1.1
+#[no_mangle]
+pub extern "C" fn mylib_open_with_flags(flags: u32) {}
+
+/// cbindgen:since=1.10
+#[no_mangle]
+pub extern "C" fn mylib_reopen() {}
+
+/// cbindgen:since=1.2
+#[no_mangle]
+pub static MYLIB_MAX_FLAGS: u32 = 8;
+
+#[no_mangle]
+pub extern "C" fn mylib_close() {}
@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Segment {
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+}
+
+#[repr(C)]
+pub struct Pair<T> {
+    pub first: T,
+    pub second: T,
+}
+
+pub type Range = (u32, u32);
+
+#[no_mangle]
+pub extern "C" fn midpoint(segment: Segment) -> (f32, f32) {
+    (
+        (segment.start.0 + segment.end.0) / 2.0,
+        (segment.start.1 + segment.end.1) / 2.0,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn nested(value: ((u8, u16), *const (i32,))) {}
+
+#[no_mangle]
+pub extern "C" fn pairs(pair: Pair<(u8, u8)>, range: Range) {}
+
+#[no_mangle]
+pub extern "C" fn unit(value: ()) {}
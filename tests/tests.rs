@@ -28,6 +28,7 @@ struct CBindgenOutput {
     symfile_content: Option<String>,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_cbindgen(
     path: &Path,
     output: Option<&Path>,
@@ -76,6 +77,21 @@ fn run_cbindgen(
         Language::Cython => {
             command.arg("--lang").arg("cython");
         }
+        Language::Zig => {
+            command.arg("--lang").arg("zig");
+        }
+        Language::CSharp => {
+            command.arg("--lang").arg("csharp");
+        }
+        Language::Cffi => {
+            command.arg("--lang").arg("cffi");
+        }
+        Language::Java => {
+            command.arg("--lang").arg("java");
+        }
+        Language::Rust => {
+            command.arg("--lang").arg("rust");
+        }
     }
 
     if package_version {
@@ -141,6 +157,11 @@ fn compile(
         Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
         Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
         Language::Cython => env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned()),
+        Language::Zig => env::var("ZIG").unwrap_or_else(|_| "zig".to_owned()),
+        Language::CSharp => env::var("CSC").unwrap_or_else(|_| "csc".to_owned()),
+        Language::Java => env::var("JAVAC").unwrap_or_else(|_| "javac".to_owned()),
+        Language::Rust => env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned()),
+        Language::Cffi => env::var("PYTHON").unwrap_or_else(|_| "python3".to_owned()),
     };
 
     let file_name = cbindgen_output
@@ -211,6 +232,59 @@ fn compile(
             command.arg("-o").arg(&object);
             command.arg(cbindgen_output);
         }
+        Language::Zig => {
+            // Declarations in a Zig file are only analyzed once referenced, so
+            // reference all of them from a test, which is analyzed but not linked.
+            let bindings = tmp_dir.join("bindings.zig");
+            fs::copy(cbindgen_output, &bindings).unwrap();
+            let test = tmp_dir.join("bindings_test.zig");
+            fs::write(
+                &test,
+                "test {\n    @import(\"std\").testing.refAllDeclsRecursive(@import(\"bindings.zig\"));\n}\n",
+            )
+            .unwrap();
+            command.arg("test");
+            command.arg("-fno-emit-bin");
+            command.arg("--cache-dir").arg(tmp_dir.join("zig-cache"));
+            command.arg(test);
+        }
+        Language::CSharp => {
+            command.arg("-nologo");
+            command.arg("-target:library");
+            command.arg("-unsafe");
+            command.arg("-define:DEFINED");
+            command.arg(format!("-out:{}", object.display()));
+            command.arg(cbindgen_output);
+        }
+        Language::Cffi => {
+            command.arg("-c");
+            command.arg("import cffi, sys; cffi.FFI().cdef(open(sys.argv[1]).read())");
+            command.arg(cbindgen_output);
+        }
+        Language::Java => {
+            // javac wants the file to be named after the public class it declares.
+            let source = fs::read_to_string(cbindgen_output).unwrap();
+            let class_name = source
+                .split(['\r', '\n'])
+                .find_map(|line| line.strip_prefix("public final class "))
+                .and_then(|line| line.split_whitespace().next())
+                .expect("Java bindings should declare a class");
+            let java_dir = tmp_dir.join("java");
+            fs::create_dir_all(&java_dir).unwrap();
+            let java_file = java_dir.join(format!("{class_name}.java"));
+            fs::write(&java_file, source).unwrap();
+            command.arg("--release").arg("22");
+            command.arg("-d").arg(&java_dir);
+            command.arg(java_file);
+        }
+        Language::Rust => {
+            command.arg("--edition").arg("2021");
+            command.arg("--crate-type").arg("lib");
+            command.arg("--emit").arg("metadata");
+            command.arg("-A").arg("warnings");
+            command.arg("-o").arg(&object);
+            command.arg(cbindgen_output);
+        }
     }
 
     println!("Running: {command:?}");
@@ -224,6 +298,77 @@ fn compile(
 
 const SKIP_WARNING_AS_ERROR_SUFFIX: &str = ".skip_warning_as_error";
 
+/// Tests whose cffi output can't be parsed on its own: they rely on types
+/// declared in their `header`, on an enumerator being declared twice, or on
+/// the 128-bit and half precision types cffi doesn't know.
+const CFFI_SKIP_COMPILE: &[&str] = &[
+    "enum_self_flags",
+    "exclude_generic_monomorph",
+    "float16_float128",
+    "int128",
+];
+
+/// Tests that can only be compiled in C, C++ or Cython: their `header`,
+/// `trailer` or `body` is C or C++ code.
+const C_ONLY_COMPILE: &[&str] = &[
+    "asserted_cast",
+    "associated_in_body",
+    "body",
+    "box",
+    "cfg",
+    "cfg_2",
+    "cstr",
+    "custom_header",
+    "deprecated",
+    "destructor_and_copy_ctor",
+    "enum",
+    "enum_self_flags",
+    "exclude_generic_monomorph",
+    "forward_declaration",
+    "function_noreturn",
+    "layout",
+    "layout_aligned_opaque",
+    "layout_packed_opaque",
+    "manuallydrop",
+    "maybeuninit",
+    "mod_attr",
+    "must_use",
+    "nonnull_attribute",
+    "nonzero",
+    "nullable_attribute",
+    "opaque",
+    "pin",
+    "raw_lines",
+    "rename_crate",
+    "slice_cpp",
+    "swift_name",
+];
+
+/// Tests whose Zig output can't be compiled besides [`C_ONLY_COMPILE`]: they
+/// declare an item under several `#[cfg]`s, which Zig only writes as comments.
+const ZIG_SKIP_COMPILE: &[&str] = &["api_docs"];
+
+/// Tests whose Java output can't be compiled besides [`C_ONLY_COMPILE`]: they
+/// have no imports because of `no_includes`.
+const JAVA_SKIP_COMPILE: &[&str] = &["include_guard", "include_specific", "no_includes"];
+
+/// Tests whose Rust output can't be compiled besides [`C_ONLY_COMPILE`]: a
+/// constant casts a pointer to an integer, which isn't allowed in a Rust
+/// constant either, or they use the unstable `f16` and `f128` types.
+const RUST_SKIP_COMPILE: &[&str] = &["float16_float128", "rename"];
+
+fn skip_compile(language: Language, name: &str) -> bool {
+    let (c_only, skip): (bool, &[&str]) = match language {
+        Language::C | Language::Cxx | Language::Cython => return false,
+        Language::Cffi => (false, CFFI_SKIP_COMPILE),
+        Language::Zig => (true, ZIG_SKIP_COMPILE),
+        Language::CSharp => (true, &[]),
+        Language::Java => (true, JAVA_SKIP_COMPILE),
+        Language::Rust => (true, RUST_SKIP_COMPILE),
+    };
+    (c_only && C_ONLY_COMPILE.contains(&name)) || skip.contains(&name)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn run_compile_test(
     name: &'static str,
@@ -262,6 +407,11 @@ fn run_compile_test(
         // is extension-sensitive and won't work on them, so we use implementation files (`.pyx`)
         // in the test suite.
         Language::Cython => ".pyx",
+        Language::Zig => ".zig",
+        Language::CSharp => ".cs",
+        Language::Cffi => ".cffi.h",
+        Language::Java => ".java",
+        Language::Rust => ".rs",
     };
 
     let skip_warning_as_error = name.rfind(SKIP_WARNING_AS_ERROR_SUFFIX).is_some();
@@ -337,7 +487,7 @@ fn run_compile_test(
 
         cbindgen_outputs.insert(bindings_content);
 
-        if no_compile {
+        if no_compile || skip_compile(language, name) {
             return;
         }
 
@@ -365,6 +515,81 @@ fn run_compile_test(
     }
 }
 
+/// Writes a report cbindgen writes besides the bindings to `tests/expectations/<file>`, or
+/// compares it with the one there when verifying.
+fn check_report(file: &str, content: &str) {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let expectation = Path::new(&crate_dir)
+        .join("tests")
+        .join("expectations")
+        .join(file);
+    if env::var_os("CBINDGEN_TEST_VERIFY").is_some() {
+        assert_eq!(content, fs::read_to_string(&expectation).unwrap());
+    } else {
+        fs::write(&expectation, content).expect("Failed to write the report.");
+    }
+}
+
+fn run_report(mut command: Command, path: &Path) -> Vec<u8> {
+    let config = path.with_extension("toml");
+    if config.exists() {
+        command.arg("--config").arg(config);
+    }
+
+    println!("Running: {command:?}");
+    let output = command.output().expect("failed to execute process");
+    assert!(
+        output.status.success(),
+        "cbindgen failed to write the report: {}",
+        str::from_utf8(&output.stderr).unwrap_or_default()
+    );
+    output.stdout
+}
+
+/// Checks the API reference of the tests named `api_docs*`, in `<name>.md`.
+fn run_api_docs_test(name: &str, path: &Path, tmp_dir: &Path) {
+    let api_docs = tmp_dir.join(format!("{name}.md"));
+    let mut command = Command::new(CBINDGEN_PATH);
+    command
+        .arg("--lang")
+        .arg("c")
+        .arg("--output")
+        .arg(tmp_dir.join(format!("{name}.h")))
+        .arg("--api-docs")
+        .arg(&api_docs)
+        .arg(path);
+    run_report(command, path);
+    let content = fs::read_to_string(&api_docs).expect("No API reference written");
+    check_report(&format!("{name}.md"), &content);
+}
+
+/// Checks the JSON dump of the resolved items, in `<name>.json`. The layouts in it depend on the
+/// target, which would otherwise be the host.
+fn run_json_test(name: &str, path: &Path) {
+    let mut command = Command::new(CBINDGEN_PATH);
+    command
+        .arg("--lang")
+        .arg("json")
+        .arg("--target")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg(path);
+    let content = run_report(command, path);
+    let name = name.replace(SKIP_WARNING_AS_ERROR_SUFFIX, "");
+    check_report(&format!("{name}.json"), str::from_utf8(&content).unwrap());
+}
+
+/// Checks the lints of the tests named `lint*`, in `<name>.lint`. The source is passed relative
+/// to its directory, for the locations of the lints not to depend on where the tests are.
+fn run_lint_test(name: &str, path: &Path) {
+    let mut command = Command::new(CBINDGEN_PATH);
+    command
+        .current_dir(path.parent().unwrap())
+        .arg("--lint")
+        .arg(path.file_name().unwrap());
+    let content = run_report(command, path);
+    check_report(&format!("{name}.lint"), str::from_utf8(&content).unwrap());
+}
+
 fn test_file(name: &'static str, filename: &'static str) {
     let test = Path::new(filename);
     let tmp_dir = tempfile::Builder::new()
@@ -421,6 +646,34 @@ fn test_file(name: &'static str, filename: &'static str) {
             /* generate_symfile = */ false,
         );
     }
+
+    for language in [
+        Language::Zig,
+        Language::CSharp,
+        Language::Cffi,
+        Language::Java,
+        Language::Rust,
+    ] {
+        run_compile_test(
+            name,
+            test,
+            tmp_dir,
+            language,
+            /* cpp_compat = */ false,
+            None,
+            &mut HashSet::new(),
+            false,
+            /* generate_symfile = */ false,
+        );
+    }
+
+    run_json_test(name, test);
+    if name.starts_with("api_docs") {
+        run_api_docs_test(name, test, tmp_dir);
+    }
+    if name.starts_with("lint") {
+        run_lint_test(name, test);
+    }
 }
 
 macro_rules! test_file {